@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Totals for one road segment over every recorded run of a level
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SegmentStats {
+    /// Recorded samples taken on the segment (or nearest to it, when off the road)
+    pub samples: u32,
+    /// Sum of the car's speed (pixels per second) over those samples
+    pub speed_total: f32,
+    /// Samples taken with the car off the road
+    pub off_road_samples: u32,
+}
+
+impl SegmentStats {
+    /// Average speed over the segment's samples (None without any)
+    pub fn average_speed(&self) -> Option<f32> {
+        (self.samples > 0).then(|| self.speed_total / self.samples as f32)
+    }
+
+    /// Fraction of the segment's samples taken with the car off the road
+    pub fn off_road_fraction(&self) -> f32 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+        self.off_road_samples as f32 / self.samples as f32
+    }
+}
+
+/// A level's stats aggregated over the player's recorded runs, indexed by `RoadSegment::index`.
+/// Kept in its own file per player and level (see `analysis::io`) rather than in `SaveData`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LevelAnalysis {
+    /// Number of runs added
+    pub runs: u32,
+    pub segments: Vec<SegmentStats>,
+}
+
+/// Resource holding the analysis of the level just completed, including the run that completed it.
+/// None when there's nothing to show (no save, no runs, or a seed replacing the level's track).
+#[derive(Resource, Default)]
+pub struct CurrentAnalysis(pub Option<LevelAnalysis>);
+
+/// Marker for the heatmap's legend and back button
+#[derive(Component)]
+pub struct OnHeatmapOverlay;
+
+/// On a road piece tinted by the heatmap: its color before, restored when the heatmap closes
+#[derive(Component)]
+pub struct HeatmapTint(pub Color);
+
+/// Actions of the heatmap overlay's buttons
+#[derive(Component)]
+pub enum HeatmapButtonAction {
+    /// Restores the road's colors and the level complete menu
+    Close,
+}
@@ -0,0 +1,23 @@
+// ============================================================================
+// Recording Constants
+// ============================================================================
+
+/// Speed (pixels per second) no car reaches, even boosting. A step between two samples
+/// faster than this is the car being reset, not driven, and isn't counted.
+pub const MAX_SAMPLE_SPEED: f32 = 1000.0;
+
+// ============================================================================
+// Overlay Constants
+// ============================================================================
+
+/// Global z-index of the heatmap overlay, above the (hidden) level complete menu so it
+/// takes the keyboard focus
+pub const HEATMAP_OVERLAY_Z_INDEX: i32 = 10;
+/// Distance of the overlay panel from the bottom of the window
+pub const HEATMAP_PANEL_BOTTOM: f32 = 20.0;
+/// Padding inside the overlay panel
+pub const HEATMAP_PANEL_PADDING: f32 = 15.0;
+/// Number of swatches in the legend's gradient
+pub const LEGEND_SWATCH_COUNT: usize = 5;
+/// Side length of a legend swatch
+pub const LEGEND_SWATCH_SIZE: f32 = 24.0;
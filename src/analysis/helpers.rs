@@ -0,0 +1,142 @@
+use crate::analysis::components::{LevelAnalysis, SegmentStats};
+use crate::analysis::constants::MAX_SAMPLE_SPEED;
+use crate::replay::components::GhostSample;
+
+/// Adds a recorded run to the analysis of a track with `segment_count` segments.
+/// Each step between consecutive samples counts toward the segment `locate` places the later
+/// sample on, with the speed over the step and whether the car was off the road there.
+/// Stats kept for a different number of segments (an older layout of the level) are dropped.
+pub fn accumulate_run(
+    analysis: &mut LevelAnalysis,
+    segment_count: usize,
+    samples: &[GhostSample],
+    locate: impl Fn(&GhostSample) -> Option<(usize, bool)>,
+) {
+    if analysis.segments.len() != segment_count {
+        *analysis = LevelAnalysis {
+            runs: 0,
+            segments: vec![SegmentStats::default(); segment_count],
+        };
+    }
+
+    for step in samples.windows(2) {
+        let duration = step[1].time - step[0].time;
+        if duration <= 0.0 {
+            continue;
+        }
+        let speed = step[0].position.distance(step[1].position) / duration;
+        if speed > MAX_SAMPLE_SPEED {
+            continue;
+        }
+        let Some((index, off_road)) = locate(&step[1]) else {
+            continue;
+        };
+        let Some(stats) = analysis.segments.get_mut(index) else {
+            continue;
+        };
+        stats.samples += 1;
+        stats.speed_total += speed;
+        if off_road {
+            stats.off_road_samples += 1;
+        }
+    }
+    analysis.runs += 1;
+}
+
+/// How much trouble each segment gives the player, from 0 to 1: the larger of how much
+/// slower it's driven than the fastest segment and how often the car leaves the road on it.
+/// None for segments without samples.
+pub fn segment_trouble(analysis: &LevelAnalysis) -> Vec<Option<f32>> {
+    let fastest = analysis
+        .segments
+        .iter()
+        .filter_map(SegmentStats::average_speed)
+        .fold(0.0, f32::max);
+
+    analysis
+        .segments
+        .iter()
+        .map(|stats| {
+            let speed = stats.average_speed()?;
+            let slowness = if fastest > 0.0 { 1.0 - speed / fastest } else { 0.0 };
+            Some(slowness.max(stats.off_road_fraction()).clamp(0.0, 1.0))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::prelude::*;
+
+    /// Samples a car driving along the x axis at `speed` for `count` steps of 0.1s
+    fn straight_run(speed: f32, count: usize) -> Vec<GhostSample> {
+        (0..count)
+            .map(|i| GhostSample {
+                time: i as f32 * 0.1,
+                position: Vec2::new(i as f32 * 0.1 * speed, 0.0),
+                rotation: Quat::IDENTITY,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_runs_accumulate_per_segment() {
+        let mut analysis = LevelAnalysis::default();
+        // Segment 0 below x = 50, segment 1 beyond it and off the road
+        let locate = |sample: &GhostSample| {
+            let far = sample.position.x >= 50.0;
+            Some((far as usize, far))
+        };
+
+        accumulate_run(&mut analysis, 2, &straight_run(100.0, 11), locate);
+        accumulate_run(&mut analysis, 2, &straight_run(100.0, 11), locate);
+
+        assert_eq!(analysis.runs, 2);
+        assert_eq!(analysis.segments[0].samples, 8);
+        assert_eq!(analysis.segments[1].samples, 12);
+        assert!((analysis.segments[0].average_speed().unwrap() - 100.0).abs() < 1e-3);
+        assert_eq!(analysis.segments[0].off_road_fraction(), 0.0);
+        assert_eq!(analysis.segments[1].off_road_fraction(), 1.0);
+
+        // A layout with a different segment count starts over
+        accumulate_run(&mut analysis, 3, &straight_run(100.0, 11), locate);
+        assert_eq!(analysis.runs, 1);
+        assert_eq!(analysis.segments.len(), 3);
+    }
+
+    #[test]
+    fn test_resets_are_not_counted_as_driving() {
+        let mut analysis = LevelAnalysis::default();
+        let mut samples = straight_run(100.0, 3);
+        // Put back at the start between the last two samples
+        samples[2].position = Vec2::new(-1000.0, 0.0);
+
+        accumulate_run(&mut analysis, 1, &samples, |_| Some((0, false)));
+        assert_eq!(analysis.segments[0].samples, 1);
+    }
+
+    #[test]
+    fn test_slow_or_off_road_segments_are_hottest() {
+        let stats = |samples, speed_total, off_road_samples| SegmentStats {
+            samples,
+            speed_total,
+            off_road_samples,
+        };
+        let analysis = LevelAnalysis {
+            runs: 1,
+            segments: vec![
+                stats(10, 2000.0, 0),
+                stats(10, 1000.0, 0),
+                stats(10, 2000.0, 8),
+                SegmentStats::default(),
+            ],
+        };
+
+        let trouble = segment_trouble(&analysis);
+        assert_eq!(trouble[0], Some(0.0));
+        assert_eq!(trouble[1], Some(0.5));
+        assert_eq!(trouble[2], Some(0.8));
+        assert_eq!(trouble[3], None);
+    }
+}
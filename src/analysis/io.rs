@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use std::fs;
+use std::io;
+
+use crate::analysis::components::LevelAnalysis;
+use crate::save::{get_analysis_dir, write_atomically, SaveData};
+
+/// File a level's analysis is kept in, inside the player's analysis directory
+fn analysis_file_name(level: usize) -> String {
+    format!("level_{}.json", level)
+}
+
+/// Loads the save's analysis of a level (empty if there's none yet).
+/// An unreadable file is reported and started over.
+pub fn load_level_analysis(save_data: &SaveData, level: usize) -> LevelAnalysis {
+    let Ok(file_path) = get_analysis_dir(save_data).map(|dir| dir.join(analysis_file_name(level))) else {
+        return LevelAnalysis::default();
+    };
+    let Ok(json) = fs::read_to_string(&file_path) else {
+        return LevelAnalysis::default();
+    };
+    serde_json::from_str(&json).unwrap_or_else(|e| {
+        warn!("Discarding unreadable run analysis {}: {}", file_path.display(), e);
+        LevelAnalysis::default()
+    })
+}
+
+/// Saves the save's analysis of a level
+pub fn save_level_analysis(save_data: &SaveData, level: usize, analysis: &LevelAnalysis) -> io::Result<()> {
+    let file_path = get_analysis_dir(save_data)?.join(analysis_file_name(level));
+    let json = serde_json::to_string(analysis)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    write_atomically(&file_path, &json)
+}
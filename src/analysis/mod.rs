@@ -0,0 +1,33 @@
+pub mod components;
+pub mod constants;
+pub mod helpers;
+pub mod io;
+pub mod systems;
+
+use bevy::prelude::*;
+use crate::constants::GameState;
+use crate::level_complete::systems::spawn_level_complete_menu;
+use crate::utils::despawn_all;
+use components::{CurrentAnalysis, OnHeatmapOverlay};
+use systems::{close_heatmap, record_run_analysis, show_heatmap};
+
+/// Aggregates every recorded run of a level into per-segment stats, shown after a race as a
+/// heatmap of the track's hardest corners
+pub struct AnalysisPlugin;
+
+impl Plugin for AnalysisPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<CurrentAnalysis>()
+            // The menu only offers the heatmap once there's an analysis to show
+            .add_systems(
+                OnEnter(GameState::LevelComplete),
+                record_run_analysis.before(spawn_level_complete_menu),
+            )
+            .add_systems(OnExit(GameState::LevelComplete), despawn_all::<OnHeatmapOverlay>)
+            .add_systems(
+                Update,
+                (show_heatmap, close_heatmap).run_if(in_state(GameState::LevelComplete)),
+            );
+    }
+}
@@ -0,0 +1,312 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use std::collections::HashMap;
+
+use crate::analysis::components::{
+    CurrentAnalysis, HeatmapButtonAction, HeatmapTint, LevelAnalysis, OnHeatmapOverlay,
+};
+use crate::analysis::constants::*;
+use crate::analysis::helpers::{accumulate_run, segment_trouble};
+use crate::analysis::io::{load_level_analysis, save_level_analysis};
+use crate::car::helpers::get_car_corners;
+use crate::collision::world_to_local_2d;
+use crate::constants::{CurrentLevel, PracticeMode, SeedOverride, TwoPlayerMode};
+use crate::hud::components::RaceState;
+use crate::level_complete::components::{LevelCompleteButtonAction, OnLevelCompleteScreen};
+use crate::level_complete::systems::LevelCompleteInteractionQuery;
+use crate::replay::components::{GhostRecorder, GhostSample};
+use crate::road::components::{RoadEdgeCutout, RoadSegment};
+use crate::road::helpers::{corners_off_road, get_segment_center, is_point_in_segment};
+use crate::save::CurrentSave;
+use crate::settings::components::{FinishCondition, GameSettings};
+use crate::styles::colors::{MENU_TEXT_COLOR, OVERLAY_BACKGROUND_COLOR};
+use crate::styles::menu::{
+    spawn_button_with_width, CONFIRM_BUTTON_WIDTH, MEDIUM_TEXT_FONT_SIZE, SMALL_MARGIN,
+    SMALL_TEXT_FONT_SIZE,
+};
+use crate::styles::palette::Palette;
+
+// ============================================================================
+// Recording
+// ============================================================================
+
+/// Adds the finished run to the level's analysis file and keeps the result for the heatmap.
+/// Only runs that are recorded to the save count (see `spawn_level_complete_menu`); other runs
+/// still show the analysis so far, unless they're on a seed replacing the level's track.
+pub fn record_run_analysis(
+    race_state: Res<RaceState>,
+    current_level: Res<CurrentLevel>,
+    current_save: Res<CurrentSave>,
+    (recorder, settings): (Res<GhostRecorder>, Res<GameSettings>),
+    (practice, two_player, seed_override): (Res<PracticeMode>, Res<TwoPlayerMode>, Res<SeedOverride>),
+    segment_query: Query<(&Transform, &RoadSegment)>,
+    mut current_analysis: ResMut<CurrentAnalysis>,
+) {
+    current_analysis.0 = None;
+    let Some(save_data) = current_save.get() else {
+        return;
+    };
+    if seed_override.replaces(current_level.0) {
+        return;
+    }
+
+    let mut analysis = load_level_analysis(save_data, current_level.0);
+    // Lap-only finishes skip part of the track, so they're left out like the best times
+    let lap_only = race_state.finish_condition == FinishCondition::LapOnly;
+    let recorded = race_state.final_time.is_some()
+        && race_state.is_valid
+        && !practice.0
+        && !two_player.0
+        && !lap_only;
+    if recorded && recorder.has_samples() {
+        let segments: Vec<_> = segment_query.iter().collect();
+        accumulate_run(&mut analysis, segments.len(), &recorder.samples, |sample| {
+            locate_sample(&segments, sample, settings.difficulty.off_road_corner_allowance())
+        });
+        if let Err(e) = save_level_analysis(save_data, current_level.0, &analysis) {
+            warn!("Failed to save the run analysis: {}", e);
+        }
+    }
+    current_analysis.0 = (analysis.runs > 0).then_some(analysis);
+}
+
+/// Index of the segment a sample is on (or the nearest one when it's off the road), and
+/// whether more than `corner_allowance` corners of the car were off the road, as
+/// `check_car_on_road` judges it
+fn locate_sample(
+    segments: &[(&Transform, &RoadSegment)],
+    sample: &GhostSample,
+    corner_allowance: usize,
+) -> Option<(usize, bool)> {
+    let on_segment = |point: Vec3, (transform, segment): &(&Transform, &RoadSegment)| {
+        is_point_in_segment(world_to_local_2d(transform, point), segment.segment_type)
+    };
+    let car_transform = Transform::from_translation(sample.position.extend(0.0)).with_rotation(sample.rotation);
+    let off_road = corners_off_road(&get_car_corners(&car_transform), segments) > corner_allowance;
+
+    let distance = |(transform, segment): &&(&Transform, &RoadSegment)| {
+        get_segment_center(transform, segment.segment_type).distance(sample.position)
+    };
+    let (_, segment) = segments
+        .iter()
+        .find(|segment| on_segment(car_transform.translation, segment))
+        .or_else(|| segments.iter().min_by(|a, b| distance(a).total_cmp(&distance(b))))?;
+    Some((segment.index, off_road))
+}
+
+// ============================================================================
+// Heatmap
+// ============================================================================
+
+/// Heatmap overlay buttons whose interaction changed this frame
+type HeatmapInteractionQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Interaction, &'static HeatmapButtonAction),
+    (Changed<Interaction>, With<Button>),
+>;
+
+/// The road pieces the heatmap tints: segments (a sprite for straights, a mesh material for
+/// corners) and the cutouts drawn over the corners
+type RoadPieceQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<&'static RoadSegment>,
+        Option<&'static RoadEdgeCutout>,
+        Option<&'static mut Sprite>,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+    ),
+    Or<(With<RoadSegment>, With<RoadEdgeCutout>)>,
+>;
+
+/// Road pieces tinted by the heatmap
+type TintedPieceQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static HeatmapTint,
+        Option<&'static mut Sprite>,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+    ),
+>;
+
+/// Current color of a road piece
+fn piece_color(
+    sprite: Option<&Sprite>,
+    material: Option<&MeshMaterial2d<ColorMaterial>>,
+    materials: &Assets<ColorMaterial>,
+) -> Option<Color> {
+    match (sprite, material) {
+        (Some(sprite), _) => Some(sprite.color),
+        (None, Some(handle)) => materials.get(&handle.0).map(|material| material.color),
+        (None, None) => None,
+    }
+}
+
+/// Sets the color of a road piece
+fn set_piece_color(
+    sprite: Option<Mut<Sprite>>,
+    material: Option<&MeshMaterial2d<ColorMaterial>>,
+    materials: &mut Assets<ColorMaterial>,
+    color: Color,
+) {
+    if let Some(mut sprite) = sprite {
+        sprite.color = color;
+    } else if let Some(material) = material.and_then(|handle| materials.get_mut(&handle.0)) {
+        material.color = color;
+    }
+}
+
+/// Shows the heatmap when its button is pressed: hides the level complete menu, tints each
+/// segment from the palette's success color (no trouble) to its error color (the slowest or
+/// most often left), and spawns the legend. Segments without samples keep their color.
+pub fn show_heatmap(
+    interaction_query: LevelCompleteInteractionQuery,
+    mut commands: Commands,
+    current_analysis: Res<CurrentAnalysis>,
+    palette: Res<Palette>,
+    mut piece_query: RoadPieceQuery,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut menu_query: Query<&mut Visibility, With<OnLevelCompleteScreen>>,
+) {
+    let pressed = interaction_query.iter().any(|(interaction, action)| {
+        *interaction == Interaction::Pressed && matches!(action, LevelCompleteButtonAction::ShowHeatmap)
+    });
+    let Some(analysis) = current_analysis.0.as_ref().filter(|_| pressed) else {
+        return;
+    };
+
+    let trouble = segment_trouble(analysis);
+    // Cutouts are tinted with the corner they're drawn over
+    let segment_indices: HashMap<Entity, usize> = piece_query
+        .iter()
+        .filter_map(|(entity, segment, ..)| segment.map(|segment| (entity, segment.index)))
+        .collect();
+    for (entity, segment, cutout, sprite, material) in piece_query.iter_mut() {
+        let index = segment
+            .map(|segment| segment.index)
+            .or_else(|| cutout.and_then(|cutout| segment_indices.get(&cutout.parent_segment).copied()));
+        let Some(trouble) = index.and_then(|index| trouble.get(index).copied().flatten()) else {
+            continue;
+        };
+        let Some(original) = piece_color(sprite.as_deref(), material, &materials) else {
+            continue;
+        };
+        commands.entity(entity).insert(HeatmapTint(original));
+        set_piece_color(sprite, material, &mut materials, palette.success_text.mix(&palette.error_text, trouble));
+    }
+
+    for mut visibility in menu_query.iter_mut() {
+        *visibility = Visibility::Hidden;
+    }
+    spawn_heatmap_overlay(&mut commands, analysis, &palette);
+}
+
+/// Spawns the heatmap's legend and back button in a panel at the bottom of the window,
+/// leaving the track in view
+fn spawn_heatmap_overlay(commands: &mut Commands, analysis: &LevelAnalysis, palette: &Palette) {
+    let runs = match analysis.runs {
+        1 => "1 run".to_string(),
+        runs => format!("{} runs", runs),
+    };
+    let legend_text = |text: &str| {
+        (
+            Text::new(text),
+            TextFont {
+                font_size: SMALL_TEXT_FONT_SIZE,
+                ..default()
+            },
+            TextColor(MENU_TEXT_COLOR),
+        )
+    };
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(HEATMAP_PANEL_BOTTOM),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            OnHeatmapOverlay,
+            GlobalZIndex(HEATMAP_OVERLAY_Z_INDEX),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(HEATMAP_PANEL_PADDING)),
+                        ..default()
+                    },
+                    BackgroundColor(OVERLAY_BACKGROUND_COLOR),
+                    FocusPolicy::Block,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(format!("Hardest corners over {}", runs)),
+                        TextFont {
+                            font_size: MEDIUM_TEXT_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(MENU_TEXT_COLOR),
+                    ));
+                    parent
+                        .spawn(Node {
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(SMALL_MARGIN),
+                            margin: UiRect::vertical(Val::Px(SMALL_MARGIN)),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            parent.spawn(legend_text("Smooth"));
+                            for step in 0..LEGEND_SWATCH_COUNT {
+                                let trouble = step as f32 / (LEGEND_SWATCH_COUNT - 1) as f32;
+                                parent.spawn((
+                                    Node {
+                                        width: Val::Px(LEGEND_SWATCH_SIZE),
+                                        height: Val::Px(LEGEND_SWATCH_SIZE),
+                                        ..default()
+                                    },
+                                    BackgroundColor(palette.success_text.mix(&palette.error_text, trouble)),
+                                ));
+                            }
+                            parent.spawn(legend_text("Slow or off road"));
+                        });
+                    spawn_button_with_width(parent, "Back", HeatmapButtonAction::Close, CONFIRM_BUTTON_WIDTH);
+                });
+        });
+}
+
+/// Closes the heatmap: restores the road's colors and shows the level complete menu again
+pub fn close_heatmap(
+    interaction_query: HeatmapInteractionQuery,
+    mut commands: Commands,
+    mut tinted_query: TintedPieceQuery,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut menu_query: Query<&mut Visibility, With<OnLevelCompleteScreen>>,
+    overlay_query: Query<Entity, With<OnHeatmapOverlay>>,
+) {
+    let closed = interaction_query.iter().any(|(interaction, action)| {
+        *interaction == Interaction::Pressed && matches!(action, HeatmapButtonAction::Close)
+    });
+    if !closed {
+        return;
+    }
+
+    for (entity, tint, sprite, material) in tinted_query.iter_mut() {
+        set_piece_color(sprite, material, &mut materials, tint.0);
+        commands.entity(entity).remove::<HeatmapTint>();
+    }
+    for mut visibility in menu_query.iter_mut() {
+        *visibility = Visibility::Inherited;
+    }
+    for entity in &overlay_query {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,20 +1,215 @@
 use bevy::prelude::*;
 use bevy::time::Timer;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::car::constants::*;
 
 #[derive(Component)]
 pub struct Car;
 
+/// Which local player drives a car. Player one uses the arrow keys and SPACE,
+/// player two uses WASD and left shift.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PlayerId {
+    One,
+    Two,
+}
+
+/// The keys driving one player's car
+pub struct PlayerControls {
+    pub accelerate: KeyCode,
+    pub brake: KeyCode,
+    pub steer_left: KeyCode,
+    pub steer_right: KeyCode,
+    pub boost: KeyCode,
+}
+
+impl PlayerId {
+    /// Returns the keys this player drives with
+    pub fn controls(&self) -> PlayerControls {
+        match self {
+            PlayerId::One => PlayerControls {
+                accelerate: KeyCode::ArrowUp,
+                brake: KeyCode::ArrowDown,
+                steer_left: KeyCode::ArrowLeft,
+                steer_right: KeyCode::ArrowRight,
+                boost: KeyCode::Space,
+            },
+            PlayerId::Two => PlayerControls {
+                accelerate: KeyCode::KeyW,
+                brake: KeyCode::KeyS,
+                steer_left: KeyCode::KeyA,
+                steer_right: KeyCode::KeyD,
+                boost: KeyCode::ShiftLeft,
+            },
+        }
+    }
+
+    /// Short label shown on the HUD and results screen
+    pub fn label(&self) -> &'static str {
+        match self {
+            PlayerId::One => "P1",
+            PlayerId::Two => "P2",
+        }
+    }
+
+    /// Color of this player's car. Player one drives the color picked in the garage;
+    /// player two keeps blue, which isn't offered, so the cars can't be confused.
+    pub fn car_color(&self, chosen: CarColor) -> Color {
+        match self {
+            PlayerId::One => chosen.color(),
+            PlayerId::Two => PLAYER_TWO_CAR_COLOR,
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct Velocity(pub Vec2);
 
+/// Marker component for the lights on the back of a car (children of its sprite)
+#[derive(Component)]
+pub struct TailLight;
+
+/// What a car's tail lights show
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TailLightState {
+    Off,
+    Braking,
+    Reversing,
+}
+
+/// Positions the car passed through in its last frame of physics substeps, in order and
+/// ending where it stopped. Lets the checks that compare a car's position with last frame's
+/// (line crossings, the road check) follow its whole path, see `substep_positions`.
+#[derive(Component, Default)]
+pub struct SubstepPath(pub Vec<Vec2>);
+
+/// Handling of a car, set at spawn from the player's chosen class
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct CarStats {
+    pub max_speed: f32,
+    pub acceleration: f32,
+    /// Rolling resistance
+    pub friction: f32,
+    /// Radians per second at full steering authority
+    pub turn_speed: f32,
+    /// Drift factor: 0.0 = full grip (on rails), 1.0 = no grip (ice)
+    pub lateral_grip: f32,
+}
+
+/// Car handling presets the player picks from before a race (saved per profile)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CarClass {
+    #[default]
+    Balanced,
+    Speedster,
+    Gripper,
+}
+
+impl CarClass {
+    /// Returns the handling stats for this class
+    pub fn stats(&self) -> CarStats {
+        match self {
+            CarClass::Balanced => CarStats {
+                max_speed: CAR_MAX_SPEED,
+                acceleration: CAR_ACCELERATION,
+                friction: CAR_FRICTION,
+                turn_speed: CAR_TURN_SPEED,
+                lateral_grip: LATERAL_GRIP,
+            },
+            CarClass::Speedster => CarStats {
+                max_speed: SPEEDSTER_MAX_SPEED,
+                acceleration: SPEEDSTER_ACCELERATION,
+                friction: SPEEDSTER_FRICTION,
+                turn_speed: SPEEDSTER_TURN_SPEED,
+                lateral_grip: SPEEDSTER_LATERAL_GRIP,
+            },
+            CarClass::Gripper => CarStats {
+                max_speed: GRIPPER_MAX_SPEED,
+                acceleration: GRIPPER_ACCELERATION,
+                friction: GRIPPER_FRICTION,
+                turn_speed: GRIPPER_TURN_SPEED,
+                lateral_grip: GRIPPER_LATERAL_GRIP,
+            },
+        }
+    }
+
+    /// Name shown on the class selection button
+    pub fn label(&self) -> &'static str {
+        match self {
+            CarClass::Balanced => "Balanced",
+            CarClass::Speedster => "Speedster",
+            CarClass::Gripper => "Gripper",
+        }
+    }
+
+    /// The class after this one, wrapping around
+    pub fn next(&self) -> Self {
+        match self {
+            CarClass::Balanced => CarClass::Speedster,
+            CarClass::Speedster => CarClass::Gripper,
+            CarClass::Gripper => CarClass::Balanced,
+        }
+    }
+}
+
+/// Paint colors for player one's car, picked in the garage (saved per profile)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CarColor {
+    #[default]
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    White,
+}
+
+impl CarColor {
+    /// The paint color itself
+    pub fn color(&self) -> Color {
+        match self {
+            CarColor::Red => PLAYER_ONE_CAR_COLOR,
+            CarColor::Orange => CAR_COLOR_ORANGE,
+            CarColor::Yellow => CAR_COLOR_YELLOW,
+            CarColor::Green => CAR_COLOR_GREEN,
+            CarColor::White => CAR_COLOR_WHITE,
+        }
+    }
+
+    /// Name shown on the color selection button
+    pub fn label(&self) -> &'static str {
+        match self {
+            CarColor::Red => "Red",
+            CarColor::Orange => "Orange",
+            CarColor::Yellow => "Yellow",
+            CarColor::Green => "Green",
+            CarColor::White => "White",
+        }
+    }
+
+    /// The color after this one, wrapping around
+    pub fn next(&self) -> Self {
+        match self {
+            CarColor::Red => CarColor::Orange,
+            CarColor::Orange => CarColor::Yellow,
+            CarColor::Yellow => CarColor::Green,
+            CarColor::Green => CarColor::White,
+            CarColor::White => CarColor::Red,
+        }
+    }
+}
+
 /// Component indicating the car has collected a NOS powerup and can activate boost.
 /// The timer counts down the availability window - pressing SPACE activates the boost.
 #[derive(Component)]
 pub struct NosBoostAvailable {
     /// Timer counting down the availability window
     pub timer: Timer,
-    /// Whether the boost is currently active (SPACE held)
+    /// Whether the boost is currently active (SPACE held, or a burst running)
     pub active: bool,
+    /// Timer counting down the fixed-duration burst in tap mode (None until SPACE is pressed)
+    pub burst: Option<Timer>,
 }
 
 impl NosBoostAvailable {
@@ -22,11 +217,78 @@ impl NosBoostAvailable {
         Self {
             timer: Timer::from_seconds(duration, bevy::time::TimerMode::Once),
             active: false,
+            burst: None,
         }
     }
 
-    /// Returns the remaining fraction (1.0 = full, 0.0 = empty)
+    /// The burst timer while a burst runs, otherwise the availability window
+    fn shown_timer(&self) -> &Timer {
+        self.burst.as_ref().unwrap_or(&self.timer)
+    }
+
+    /// Returns the remaining fraction (1.0 = full, 0.0 = empty) of the running burst,
+    /// or of the availability window before one starts
     pub fn remaining_fraction(&self) -> f32 {
-        1.0 - self.timer.fraction()
+        1.0 - self.shown_timer().fraction()
+    }
+
+    /// Seconds left in the running burst, or in the availability window before one starts
+    pub fn remaining_secs(&self) -> f32 {
+        self.shown_timer().remaining_secs()
+    }
+}
+
+/// Resource holding player one's drive input from touches and mouse clicks this frame,
+/// read alongside the keyboard (see `read_pointer_input`). Each axis runs -1.0..=1.0.
+#[derive(Resource, Clone, Copy, Default, Debug, PartialEq)]
+pub struct PointerDrive {
+    /// Positive steers left, negative steers right
+    pub steering: f32,
+    /// Positive accelerates, negative brakes
+    pub throttle: f32,
+}
+
+/// Component remembering a recent SPACE press for a moment, so a NOS activation
+/// isn't dropped when the tap lands just as the boost window opens or expires.
+#[derive(Component, Default)]
+pub struct NosInputBuffer {
+    /// Time left before the buffered press is forgotten (zero = nothing buffered)
+    pub remaining: Duration,
+}
+
+impl NosInputBuffer {
+    /// Starts buffering a press for `window`, or counts down an earlier one by the frame's `delta`
+    pub fn update(&mut self, just_pressed: bool, window: Duration, delta: Duration) {
+        if just_pressed {
+            self.remaining = window;
+        } else {
+            self.remaining = self.remaining.saturating_sub(delta);
+        }
+    }
+
+    /// Whether a press is still buffered
+    pub fn is_buffered(&self) -> bool {
+        !self.remaining.is_zero()
+    }
+}
+
+/// Component indicating the car has collected a slow-mo powerup.
+/// While present, car physics run at a reduced time scale; the race timer keeps real time.
+#[derive(Component)]
+pub struct SlowMotion {
+    /// Timer counting down the slow-mo duration (ticks in real time)
+    pub timer: Timer,
+}
+
+impl SlowMotion {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(duration, bevy::time::TimerMode::Once),
+        }
+    }
+
+    /// Returns the remaining slow-mo time in seconds
+    pub fn remaining_secs(&self) -> f32 {
+        self.timer.remaining_secs()
     }
 }
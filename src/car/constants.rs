@@ -1,13 +1,70 @@
+use bevy::prelude::*;
+
+// Handling of the balanced car class; the other classes are tuned around these
 pub const CAR_ACCELERATION: f32 = 1000.0;
 pub const CAR_MAX_SPEED: f32 = 250.0;
 pub const CAR_TURN_SPEED: f32 = 3.0; // Radians per second
 pub const CAR_FRICTION: f32 = 400.0; // Rolling resistance
+/// Drift factor: 0.0 = full grip (on rails), 1.0 = no grip (ice)
+pub const LATERAL_GRIP: f32 = 0.1;
 pub const CAR_WIDTH: f32 = 10.0;
 pub const CAR_HEIGHT: f32 = 18.0;
 pub const CAR_TURN_FACTOR: f32 = 2.0; // Higher means more responsive turning
+/// Below this speed the car has no steering authority (can't spin in place)
+pub const STEERING_MIN_SPEED: f32 = 5.0;
+/// Speed at which steering reaches full authority
+pub const STEERING_FULL_SPEED: f32 = 60.0;
 
 /// Z-index for the car (above road and starting line)
 pub const CAR_Z: f32 = 2.0;
+/// Player one's car color (the default paint, see `CarColor`)
+pub const PLAYER_ONE_CAR_COLOR: Color = Color::srgb(1.0, 0.0, 0.0);
+/// The other paints player one can pick in the garage
+pub const CAR_COLOR_ORANGE: Color = Color::srgb(1.0, 0.5, 0.0);
+pub const CAR_COLOR_YELLOW: Color = Color::srgb(1.0, 0.9, 0.1);
+pub const CAR_COLOR_GREEN: Color = Color::srgb(0.1, 0.8, 0.2);
+pub const CAR_COLOR_WHITE: Color = Color::srgb(0.95, 0.95, 0.95);
+/// Player two's car color
+pub const PLAYER_TWO_CAR_COLOR: Color = Color::srgb(0.1, 0.5, 1.0);
+/// Size of each tail light, and its sideways offset from the car's center line
+pub const TAIL_LIGHT_WIDTH: f32 = 3.0;
+pub const TAIL_LIGHT_HEIGHT: f32 = 1.5;
+pub const TAIL_LIGHT_OFFSET: f32 = 3.0;
+/// Tail light colors: dim while driving, lit when braking, white when reversing
+pub const TAIL_LIGHT_OFF_COLOR: Color = Color::srgb(0.3, 0.05, 0.05);
+pub const TAIL_LIGHT_BRAKE_COLOR: Color = Color::srgb(1.5, 0.1, 0.1);
+pub const TAIL_LIGHT_REVERSE_COLOR: Color = Color::srgb(1.2, 1.2, 1.2);
+/// Speed along the car's heading below which it counts as stopped (lights off unless reversing)
+pub const TAIL_LIGHT_MIN_SPEED: f32 = 5.0;
+/// Sideways distance of each car from the start line's center in two-player races
+pub const TWO_PLAYER_START_OFFSET: f32 = 12.0;
+
+// ============================================================================
+// Pointer Input
+// ============================================================================
+
+/// Fraction of the half screen around the center lines where a touch or click
+/// neither steers (left/right) nor accelerates (up/down), so holding near the middle
+/// of one half drives straight
+pub const POINTER_DEAD_ZONE: f32 = 0.15;
+
+// ============================================================================
+// Car Classes
+// ============================================================================
+
+/// Speedster: higher top speed and acceleration, but turns slower and drifts more
+pub const SPEEDSTER_MAX_SPEED: f32 = 300.0;
+pub const SPEEDSTER_ACCELERATION: f32 = 1200.0;
+pub const SPEEDSTER_FRICTION: f32 = 350.0;
+pub const SPEEDSTER_TURN_SPEED: f32 = 2.4;
+pub const SPEEDSTER_LATERAL_GRIP: f32 = 0.2;
+
+/// Gripper: lower top speed, but sharp turning and almost no drift
+pub const GRIPPER_MAX_SPEED: f32 = 215.0;
+pub const GRIPPER_ACCELERATION: f32 = 900.0;
+pub const GRIPPER_FRICTION: f32 = 450.0;
+pub const GRIPPER_TURN_SPEED: f32 = 3.6;
+pub const GRIPPER_LATERAL_GRIP: f32 = 0.03;
 
 // ============================================================================
 // NOS Boost Settings
@@ -17,5 +74,17 @@ pub const CAR_Z: f32 = 2.0;
 pub const NOS_AVAILABILITY_DURATION: f32 = 2.0;
 /// Speed multiplier when NOS boost is active
 pub const NOS_BOOST_MULTIPLIER: f32 = 1.5;
-/// Maximum speed while boosting
-pub const NOS_BOOSTED_MAX_SPEED: f32 = CAR_MAX_SPEED * NOS_BOOST_MULTIPLIER;
+/// Duration (seconds) of the full-power burst a single press triggers in tap mode
+pub const NOS_BURST_DURATION: f32 = 1.2;
+/// Seconds a SPACE press is remembered, so taps at the edge of the boost window still count
+/// (6 frames at 60 FPS, and the same time at any frame rate)
+pub const NOS_INPUT_BUFFER_DURATION: f32 = 0.1;
+
+// ============================================================================
+// Slow-Mo Settings
+// ============================================================================
+
+/// Duration (seconds, real time) the slow-mo effect lasts after collecting the powerup
+pub const SLOW_MO_DURATION: f32 = 3.0;
+/// Physics time scale while slow-mo is active (applied once, never stacked)
+pub const SLOW_MO_TIME_SCALE: f32 = 0.5;
@@ -1,5 +1,10 @@
 use bevy::prelude::*;
-use crate::car::constants::{CAR_WIDTH, CAR_HEIGHT};
+use std::time::Duration;
+use crate::car::components::{NosBoostAvailable, PointerDrive, SubstepPath, TailLightState, Velocity};
+use crate::car::constants::{
+    CAR_HEIGHT, CAR_WIDTH, NOS_BURST_DURATION, POINTER_DEAD_ZONE, STEERING_FULL_SPEED,
+    STEERING_MIN_SPEED, TAIL_LIGHT_MIN_SPEED,
+};
 use crate::collision::get_rect_corners;
 
 /// Half dimensions for car collision box
@@ -14,3 +19,229 @@ const CAR_HALF_HEIGHT: f32 = CAR_HEIGHT / 2.0;
 pub fn get_car_corners(transform: &Transform) -> [Vec3; 4] {
     get_rect_corners(transform, CAR_HALF_WIDTH, CAR_HALF_HEIGHT)
 }
+
+/// Positions along the car's last physics step, ending at `position`: its substep positions,
+/// or just `position` if it has none or was moved since (e.g. reset to a checkpoint)
+pub fn substep_positions(path: Option<&SubstepPath>, position: Vec2) -> Vec<Vec2> {
+    match path {
+        Some(path) if path.0.last() == Some(&position) => path.0.clone(),
+        _ => vec![position],
+    }
+}
+
+/// Places the car at the given position and rotation, bringing it to a full stop.
+/// Keeps the car's Z so it stays layered above the road.
+pub fn respawn_car(transform: &mut Transform, velocity: &mut Velocity, position: Vec2, rotation: f32) {
+    transform.translation.x = position.x;
+    transform.translation.y = position.y;
+    transform.rotation = Quat::from_rotation_z(rotation);
+    velocity.0 = Vec2::ZERO;
+}
+
+/// Fraction of full steering available at the given speed: 0 below
+/// `STEERING_MIN_SPEED`, ramping linearly to 1 at `STEERING_FULL_SPEED`.
+pub fn steering_authority(speed: f32) -> f32 {
+    ((speed - STEERING_MIN_SPEED) / (STEERING_FULL_SPEED - STEERING_MIN_SPEED)).clamp(0.0, 1.0)
+}
+
+/// Rotation (radians) to apply this frame for the given steering input, car speed
+/// and the car's turn speed.
+pub fn steering_rotation(steering_input: f32, speed: f32, turn_speed: f32, delta: f32) -> f32 {
+    steering_input * turn_speed * steering_authority(speed) * delta
+}
+
+/// Advances the NOS boost window by one frame and updates whether the boost is active.
+/// The boost is active while SPACE is held inside the window; a buffered press also
+/// activates it, including on the frames right after the window expires.
+/// Returns false once the window has expired with no press buffered (the boost is over).
+pub fn step_nos_boost(
+    boost: &mut NosBoostAvailable,
+    space_held: bool,
+    press_buffered: bool,
+    delta: Duration,
+) -> bool {
+    boost.timer.tick(delta);
+
+    let expired = boost.timer.is_finished();
+    boost.active = if expired {
+        press_buffered
+    } else {
+        space_held || press_buffered
+    };
+
+    !expired || boost.active
+}
+
+/// Tap-mode counterpart of `step_nos_boost`: a buffered press inside the window (or just
+/// after it expires) starts a full-power burst that runs for `NOS_BURST_DURATION`
+/// whether or not SPACE stays held. Starting the burst consumes the availability window.
+/// Returns false once the burst has run out, or the window expired without a press.
+pub fn step_nos_burst(boost: &mut NosBoostAvailable, press_buffered: bool, delta: Duration) -> bool {
+    if let Some(burst) = boost.burst.as_mut() {
+        burst.tick(delta);
+        boost.active = !burst.is_finished();
+        return boost.active;
+    }
+
+    boost.timer.tick(delta);
+
+    if press_buffered {
+        boost.burst = Some(Timer::from_seconds(NOS_BURST_DURATION, TimerMode::Once));
+        boost.active = true;
+        return true;
+    }
+
+    !boost.timer.is_finished()
+}
+
+/// Converts a touch or click at `position` (window coordinates, y down) into drive input.
+/// Left or right of the center steers that way and above or below accelerates or brakes,
+/// scaling up from the dead zone to full input at the screen edge.
+pub fn pointer_drive_input(position: Vec2, window_size: Vec2) -> PointerDrive {
+    let half_size = window_size / 2.0;
+    if half_size.min_element() <= 0.0 {
+        return PointerDrive::default();
+    }
+    // Offset from the center as a fraction of the half screen, with y pointing up
+    let offset = ((position - half_size) / half_size * Vec2::new(1.0, -1.0))
+        .clamp(Vec2::NEG_ONE, Vec2::ONE);
+    let axis = |value: f32| {
+        let beyond_dead_zone = (value.abs() - POINTER_DEAD_ZONE).max(0.0);
+        value.signum() * beyond_dead_zone / (1.0 - POINTER_DEAD_ZONE)
+    };
+
+    PointerDrive {
+        steering: -axis(offset.x),
+        throttle: axis(offset.y),
+    }
+}
+
+/// Tail lights for the throttle input (negative for the brake) and the car's speed along
+/// its heading (negative when rolling backwards). Holding the brake while moving forward
+/// brakes; moving backwards reverses.
+pub fn tail_light_state(throttle: f32, forward_speed: f32) -> TailLightState {
+    if forward_speed < -TAIL_LIGHT_MIN_SPEED {
+        TailLightState::Reversing
+    } else if throttle < 0.0 && forward_speed > TAIL_LIGHT_MIN_SPEED {
+        TailLightState::Braking
+    } else {
+        TailLightState::Off
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::car::components::NosInputBuffer;
+    use crate::car::constants::{CAR_TURN_FACTOR, CAR_TURN_SPEED, NOS_INPUT_BUFFER_DURATION};
+
+    #[test]
+    fn test_stationary_car_does_not_rotate() {
+        assert_eq!(steering_rotation(CAR_TURN_FACTOR, 0.0, CAR_TURN_SPEED, 1.0), 0.0);
+        assert_eq!(steering_rotation(-CAR_TURN_FACTOR, STEERING_MIN_SPEED, CAR_TURN_SPEED, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_steering_authority_scales_with_speed() {
+        let half_speed = (STEERING_MIN_SPEED + STEERING_FULL_SPEED) / 2.0;
+
+        assert!((steering_authority(half_speed) - 0.5).abs() < 1e-6);
+        assert_eq!(steering_authority(STEERING_FULL_SPEED), 1.0);
+        assert_eq!(steering_authority(STEERING_FULL_SPEED * 3.0), 1.0);
+    }
+
+    #[test]
+    fn test_press_one_frame_before_expiry_still_boosts() {
+        let frame = Duration::from_secs_f32(0.06);
+        let window = Duration::from_secs_f32(NOS_INPUT_BUFFER_DURATION);
+        let mut boost = NosBoostAvailable::new(0.1);
+        let mut buffer = NosInputBuffer::default();
+
+        // Tap SPACE on the last frame before the window expires...
+        buffer.update(true, window, frame);
+        assert!(step_nos_boost(&mut boost, true, buffer.is_buffered(), frame));
+
+        // ...and release it on the frame the window expires
+        buffer.update(false, window, frame);
+        assert!(step_nos_boost(&mut boost, false, buffer.is_buffered(), frame));
+        assert!(boost.active);
+    }
+
+    #[test]
+    fn test_press_is_buffered_as_long_at_any_frame_rate() {
+        let window = Duration::from_secs_f32(NOS_INPUT_BUFFER_DURATION);
+        for fps in [30, 60, 144] {
+            let frame = Duration::from_secs(1) / fps;
+            let mut buffer = NosInputBuffer::default();
+            buffer.update(true, window, frame);
+            let mut buffered = Duration::ZERO;
+            while buffer.is_buffered() {
+                buffer.update(false, window, frame);
+                buffered += frame;
+            }
+            // Forgotten within a frame of the window, however long frames are
+            assert!(buffered >= window && buffered < window + frame, "{} FPS", fps);
+        }
+    }
+
+    #[test]
+    fn test_boost_ends_when_window_expires_without_press() {
+        let mut boost = NosBoostAvailable::new(0.1);
+
+        assert!(!step_nos_boost(&mut boost, false, false, Duration::from_secs_f32(0.2)));
+        assert!(!boost.active);
+    }
+
+    #[test]
+    fn test_tap_runs_full_burst_after_release() {
+        let frame = Duration::from_secs_f32(0.05);
+        let mut boost = NosBoostAvailable::new(0.1);
+
+        // A single tap starts the burst...
+        assert!(step_nos_burst(&mut boost, true, frame));
+        assert!(boost.active);
+
+        // ...which outlasts both the release and the availability window
+        let mut elapsed = Duration::ZERO;
+        while elapsed + frame < Duration::from_secs_f32(NOS_BURST_DURATION) {
+            assert!(step_nos_burst(&mut boost, false, frame));
+            assert!(boost.active);
+            elapsed += frame;
+        }
+
+        assert!(!step_nos_burst(&mut boost, false, frame * 2));
+        assert!(!boost.active);
+    }
+
+    #[test]
+    fn test_pointer_halves_steer_and_drive() {
+        let window = Vec2::new(800.0, 600.0);
+
+        // Top-left corner: full left and full throttle
+        let drive = pointer_drive_input(Vec2::ZERO, window);
+        assert_eq!(drive, PointerDrive { steering: 1.0, throttle: 1.0 });
+
+        // Bottom-right corner: full right and full brake
+        let drive = pointer_drive_input(window, window);
+        assert_eq!(drive, PointerDrive { steering: -1.0, throttle: -1.0 });
+
+        // Just off the center lines stays inside the dead zone
+        let near_center = window / 2.0 + window * POINTER_DEAD_ZONE / 4.0;
+        assert_eq!(pointer_drive_input(near_center, window), PointerDrive::default());
+
+        // Halfway up the right edge steers right without driving
+        let drive = pointer_drive_input(Vec2::new(800.0, 300.0), window);
+        assert_eq!(drive, PointerDrive { steering: -1.0, throttle: 0.0 });
+    }
+
+    #[test]
+    fn test_brake_lights_only_while_slowing_forward_motion() {
+        assert_eq!(tail_light_state(-1.0, 100.0), TailLightState::Braking);
+        assert_eq!(tail_light_state(1.0, 100.0), TailLightState::Off);
+        // Holding the brake from a standstill starts reversing, not braking
+        assert_eq!(tail_light_state(-1.0, 0.0), TailLightState::Off);
+        assert_eq!(tail_light_state(-1.0, -50.0), TailLightState::Reversing);
+        // Rolling backwards shows the reverse lights whatever is held
+        assert_eq!(tail_light_state(1.0, -50.0), TailLightState::Reversing);
+    }
+}
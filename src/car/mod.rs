@@ -5,17 +5,30 @@ pub mod systems;
 
 use bevy::prelude::*;
 use crate::constants::GameState;
-use systems::{handle_input, move_car, update_nos_boost};
+use crate::hud::systems::start_lights_green;
+use components::PointerDrive;
+use systems::{
+    handle_input, move_car, read_pointer_input, update_nos_boost, update_slow_motion,
+    update_tail_lights,
+};
 
 pub struct CarPlugin;
 
 impl Plugin for CarPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<PointerDrive>().add_systems(
             Update,
-            (handle_input, update_nos_boost, move_car)
+            (
+                read_pointer_input,
+                handle_input,
+                update_nos_boost,
+                update_slow_motion,
+                move_car,
+                update_tail_lights,
+            )
                 .chain()
-                .run_if(in_state(GameState::Playing)),
+                // The cars are held on the grid until the start lights turn green
+                .run_if(in_state(GameState::Playing).and(start_lights_green)),
         );
     }
 }
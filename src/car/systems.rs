@@ -1,79 +1,145 @@
-use crate::car::components::{Car, NosBoostAvailable, Velocity};
+use crate::car::components::{
+    Car, CarStats, NosBoostAvailable, NosInputBuffer, PlayerControls, PlayerId, PointerDrive,
+    SlowMotion, SubstepPath, TailLight, TailLightState, Velocity,
+};
 use crate::car::constants::*;
+use crate::car::helpers::{
+    pointer_drive_input, step_nos_boost, step_nos_burst, steering_rotation, tail_light_state,
+};
 use crate::constants::{BOTTOM_BOUNDARY, LEFT_BOUNDARY, RIGHT_BOUNDARY, TOP_BOUNDARY};
+use crate::settings::components::{GameSettings, NosMode};
 use crate::start_menu::components::GameEntity;
 use bevy::prelude::*;
-
-// ============================================================================
-// Constants for physics tuning
-// ============================================================================
-
-/// Drift factor: 0.0 = full grip (on rails), 1.0 = no grip (ice)
-const LATERAL_GRIP: f32 = 0.1;
+use bevy::window::PrimaryWindow;
+use std::time::Duration;
 
 // ============================================================================
 // Spawning
 // ============================================================================
 
-pub fn spawn_car(commands: &mut Commands, starting_point: Vec2) {
+/// Spawns a car for the given player with the given handling and returns its entity
+pub fn spawn_car(
+    commands: &mut Commands,
+    starting_point: Vec2,
+    rotation: f32,
+    player: PlayerId,
+    stats: CarStats,
+    color: Color,
+) -> Entity {
     let car_sprite = Sprite {
-        color: Color::srgb(1.0, 0.0, 0.0),
+        color,
         custom_size: Some(Vec2::new(CAR_WIDTH, CAR_HEIGHT)),
         ..default()
     };
     // Place the car slightly behind the starting line
-    let car_initial_position = Transform::from_xyz(starting_point.x, starting_point.y, CAR_Z);
+    let car_initial_position = Transform::from_xyz(starting_point.x, starting_point.y, CAR_Z)
+        .with_rotation(Quat::from_rotation_z(rotation));
     let car_initial_velocity = Velocity(Vec2::ZERO);
     let car_component = Car;
 
-    commands.spawn((
-        car_sprite,
-        car_initial_position,
-        car_initial_velocity,
-        car_component,
-        GameEntity,
-    ));
+    commands
+        .spawn((
+            car_sprite,
+            car_initial_position,
+            car_initial_velocity,
+            car_component,
+            player,
+            stats,
+            NosInputBuffer::default(),
+            SubstepPath::default(),
+            GameEntity,
+        ))
+        .with_children(|parent| {
+            // Tail lights at the back corners, turning with the car
+            for side in [-1.0, 1.0] {
+                parent.spawn((
+                    Sprite {
+                        color: TAIL_LIGHT_OFF_COLOR,
+                        custom_size: Some(Vec2::new(TAIL_LIGHT_WIDTH, TAIL_LIGHT_HEIGHT)),
+                        ..default()
+                    },
+                    Transform::from_xyz(
+                        side * TAIL_LIGHT_OFFSET,
+                        (TAIL_LIGHT_HEIGHT - CAR_HEIGHT) / 2.0,
+                        0.1,
+                    ),
+                    TailLight,
+                ));
+            }
+        })
+        .id()
 }
 
 // ============================================================================
 // Movement System
 // ============================================================================
 
-pub fn move_car(
-    mut query: Query<(&mut Transform, &mut Velocity, Option<&NosBoostAvailable>), With<Car>>,
-    time: Res<Time>,
-) {
-    let delta = time.delta_secs();
+/// Car physics state: transform, velocity, handling and the optional powerup effects that modify it
+type CarPhysicsQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Transform,
+        &'static mut Velocity,
+        &'static CarStats,
+        Option<&'static NosBoostAvailable>,
+        Option<&'static SlowMotion>,
+        Option<&'static mut SubstepPath>,
+    ),
+    With<Car>,
+>;
+
+/// Moves the cars, splitting the frame into `GameSettings::physics_substeps` steps.
+/// Each car's position after every step is kept in its `SubstepPath`.
+pub fn move_car(mut query: CarPhysicsQuery, time: Res<Time>, settings: Res<GameSettings>) {
+    let substeps = settings.physics_substeps();
+    for (mut transform, mut velocity, stats, boost, slow_mo, mut path) in query.iter_mut() {
+        let delta = physics_delta(&time, slow_mo) / substeps as f32;
+        if let Some(path) = path.as_mut() {
+            path.0.clear();
+        }
+        for _ in 0..substeps {
+            apply_lateral_friction(&transform, &mut velocity, stats);
+            apply_rolling_friction(&mut velocity, stats, delta);
+            clamp_speed(&mut velocity, stats, boost);
+            update_position(&mut transform, &velocity, delta);
+            clamp_position(&mut transform, &mut velocity);
+            if let Some(path) = path.as_mut() {
+                path.0.push(transform.translation.truncate());
+            }
+        }
+    }
+}
 
-    for (mut transform, mut velocity, boost) in query.iter_mut() {
-        apply_lateral_friction(&transform, &mut velocity);
-        apply_rolling_friction(&mut velocity, delta);
-        clamp_speed(&mut velocity, boost);
-        update_position(&mut transform, &velocity, delta);
-        clamp_position(&mut transform, &mut velocity);
+/// Returns the frame delta used for car physics, scaled down while slow-mo is active.
+/// The race timer and powerup timers keep using the real delta.
+fn physics_delta(time: &Time, slow_mo: Option<&SlowMotion>) -> f32 {
+    match slow_mo {
+        Some(_) => time.delta_secs() * SLOW_MO_TIME_SCALE,
+        None => time.delta_secs(),
     }
 }
 
 /// Reduces sideways velocity to prevent the car from sliding like on ice.
 /// Projects velocity onto forward/right vectors and dampens the lateral component.
-fn apply_lateral_friction(transform: &Transform, velocity: &mut Velocity) {
+fn apply_lateral_friction(transform: &Transform, velocity: &mut Velocity, stats: &CarStats) {
     let forward = (transform.rotation * Vec3::Y).xy();
     let right = (transform.rotation * Vec3::X).xy();
 
     let forward_velocity = velocity.0.dot(forward);
     let lateral_velocity = velocity.0.dot(right);
 
-    velocity.0 = forward * forward_velocity + right * lateral_velocity * LATERAL_GRIP;
+    velocity.0 = forward * forward_velocity + right * lateral_velocity * stats.lateral_grip;
 }
 
 /// Applies rolling resistance that slows the car over time.
-fn apply_rolling_friction(velocity: &mut Velocity, delta: f32) {
+fn apply_rolling_friction(velocity: &mut Velocity, stats: &CarStats, delta: f32) {
     let speed = velocity.0.length();
     if speed <= 0.0 {
         return;
     }
 
-    let friction_magnitude = CAR_FRICTION * delta;
+    let friction_magnitude = stats.friction * delta;
     if speed < friction_magnitude {
         velocity.0 = Vec2::ZERO;
     } else {
@@ -83,10 +149,10 @@ fn apply_rolling_friction(velocity: &mut Velocity, delta: f32) {
 
 /// Clamps the car's speed to the maximum allowed.
 /// Uses boosted max speed if NOS boost is active.
-fn clamp_speed(velocity: &mut Velocity, boost: Option<&NosBoostAvailable>) {
+fn clamp_speed(velocity: &mut Velocity, stats: &CarStats, boost: Option<&NosBoostAvailable>) {
     let max_speed = match boost {
-        Some(b) if b.active => NOS_BOOSTED_MAX_SPEED,
-        _ => CAR_MAX_SPEED,
+        Some(b) if b.active => stats.max_speed * NOS_BOOST_MULTIPLIER,
+        _ => stats.max_speed,
     };
     velocity.0 = velocity.0.clamp_length_max(max_speed);
 }
@@ -149,51 +215,114 @@ fn get_rotated_extents(transform: &Transform) -> (f32, f32) {
 // Input Handling System
 // ============================================================================
 
+/// Car state driven by player input, with the handling and slow-mo that scale it
+type CarInputQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Transform,
+        &'static mut Velocity,
+        &'static CarStats,
+        Option<&'static SlowMotion>,
+        &'static PlayerId,
+    ),
+    With<Car>,
+>;
+
+/// Reads touches and held mouse clicks into `PointerDrive` for player one.
+/// Several touches add up, so one thumb can steer while another holds the throttle.
+pub fn read_pointer_input(
+    touches: Res<Touches>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut pointer: ResMut<PointerDrive>,
+) {
+    let clicked = mouse
+        .pressed(MouseButton::Left)
+        .then(|| window.cursor_position())
+        .flatten();
+    let positions = touches.iter().map(|touch| touch.position()).chain(clicked);
+
+    let mut drive = PointerDrive::default();
+    for position in positions {
+        let input = pointer_drive_input(position, window.size());
+        drive.steering += input.steering;
+        drive.throttle += input.throttle;
+    }
+    drive.steering = drive.steering.clamp(-1.0, 1.0);
+    drive.throttle = drive.throttle.clamp(-1.0, 1.0);
+    *pointer = drive;
+}
+
+/// Steers and accelerates each car from its player's keys.
+/// Player one also drives with the pointer, on top of the arrow keys.
 pub fn handle_input(
     keyboard: Res<ButtonInput<KeyCode>>,
-    mut query: Query<(&mut Transform, &mut Velocity), With<Car>>,
+    pointer: Res<PointerDrive>,
+    mut query: CarInputQuery,
     time: Res<Time>,
 ) {
-    let (mut transform, mut velocity) = match query.single_mut() {
-        Ok(result) => result,
-        Err(_) => return,
-    };
-
-    let delta = time.delta_secs();
-
-    apply_steering(&keyboard, &mut transform, delta);
-    apply_acceleration(&keyboard, &transform, &mut velocity, delta);
+    for (mut transform, mut velocity, stats, slow_mo, player) in query.iter_mut() {
+        let controls = player.controls();
+        let pointer = match player {
+            PlayerId::One => *pointer,
+            PlayerId::Two => PointerDrive::default(),
+        };
+        let delta = physics_delta(&time, slow_mo);
+
+        apply_steering(&keyboard, &controls, &pointer, &mut transform, &velocity, stats, delta);
+        apply_acceleration(&keyboard, &controls, &pointer, &transform, &mut velocity, stats, delta);
+    }
 }
 
 /// Handles left/right steering input and rotates the car accordingly.
-fn apply_steering(keyboard: &ButtonInput<KeyCode>, transform: &mut Transform, delta: f32) {
-    let rotation_input = get_steering_input(keyboard);
+/// Steering scales with speed, so a stationary car can't spin in place.
+fn apply_steering(
+    keyboard: &ButtonInput<KeyCode>,
+    controls: &PlayerControls,
+    pointer: &PointerDrive,
+    transform: &mut Transform,
+    velocity: &Velocity,
+    stats: &CarStats,
+    delta: f32,
+) {
+    let rotation_input = get_steering_input(keyboard, controls, pointer);
     if rotation_input != 0.0 {
-        let rotation_amount = rotation_input * CAR_TURN_SPEED * delta;
+        let rotation_amount =
+            steering_rotation(rotation_input, velocity.0.length(), stats.turn_speed, delta);
         transform.rotate_z(rotation_amount);
     }
 }
 
 /// Returns the steering input as a value: positive for left, negative for right.
-fn get_steering_input(keyboard: &ButtonInput<KeyCode>) -> f32 {
-    let mut input = 0.0;
-    if keyboard.pressed(KeyCode::ArrowLeft) {
+/// Keys and pointer add up, capped at a full key press either way.
+fn get_steering_input(
+    keyboard: &ButtonInput<KeyCode>,
+    controls: &PlayerControls,
+    pointer: &PointerDrive,
+) -> f32 {
+    let mut input = pointer.steering * CAR_TURN_FACTOR;
+    if keyboard.pressed(controls.steer_left) {
         input += CAR_TURN_FACTOR;
     }
-    if keyboard.pressed(KeyCode::ArrowRight) {
+    if keyboard.pressed(controls.steer_right) {
         input -= CAR_TURN_FACTOR;
     }
-    input
+    input.clamp(-CAR_TURN_FACTOR, CAR_TURN_FACTOR)
 }
 
 /// Handles up/down acceleration input and applies force in the car's facing direction.
 fn apply_acceleration(
     keyboard: &ButtonInput<KeyCode>,
+    controls: &PlayerControls,
+    pointer: &PointerDrive,
     transform: &Transform,
     velocity: &mut Velocity,
+    stats: &CarStats,
     delta: f32,
 ) {
-    let acceleration_input = get_acceleration_input(keyboard);
+    let acceleration_input =
+        get_acceleration_input(keyboard, controls, pointer, stats.acceleration);
     if acceleration_input != 0.0 {
         let forward_direction = (transform.rotation * Vec3::Y).xy();
         velocity.0 += forward_direction * acceleration_input * delta;
@@ -201,43 +330,250 @@ fn apply_acceleration(
 }
 
 /// Returns the acceleration input: positive for forward, negative for reverse.
-fn get_acceleration_input(keyboard: &ButtonInput<KeyCode>) -> f32 {
-    let mut input = 0.0;
-    if keyboard.pressed(KeyCode::ArrowUp) {
-        input += CAR_ACCELERATION;
+/// Keys and pointer add up, capped at a full key press either way.
+fn get_acceleration_input(
+    keyboard: &ButtonInput<KeyCode>,
+    controls: &PlayerControls,
+    pointer: &PointerDrive,
+    acceleration: f32,
+) -> f32 {
+    let mut input = pointer.throttle * acceleration;
+    if keyboard.pressed(controls.accelerate) {
+        input += acceleration;
+    }
+    if keyboard.pressed(controls.brake) {
+        input -= acceleration;
     }
-    if keyboard.pressed(KeyCode::ArrowDown) {
-        input -= CAR_ACCELERATION;
+    input.clamp(-acceleration, acceleration)
+}
+
+// ============================================================================
+// Tail Lights
+// ============================================================================
+
+/// Lights each car's tail lights while it brakes or reverses (see `tail_light_state`)
+pub fn update_tail_lights(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    pointer: Res<PointerDrive>,
+    car_query: Query<(&Transform, &Velocity, &PlayerId, &Children), With<Car>>,
+    mut light_query: Query<&mut Sprite, With<TailLight>>,
+) {
+    for (transform, velocity, player, children) in &car_query {
+        let pointer = match player {
+            PlayerId::One => *pointer,
+            PlayerId::Two => PointerDrive::default(),
+        };
+        let throttle = get_acceleration_input(&keyboard, &player.controls(), &pointer, 1.0);
+        let forward_speed = velocity.0.dot((transform.rotation * Vec3::Y).xy());
+        let color = match tail_light_state(throttle, forward_speed) {
+            TailLightState::Off => TAIL_LIGHT_OFF_COLOR,
+            TailLightState::Braking => TAIL_LIGHT_BRAKE_COLOR,
+            TailLightState::Reversing => TAIL_LIGHT_REVERSE_COLOR,
+        };
+
+        let mut lights = light_query.iter_many_mut(children);
+        while let Some(mut light) = lights.fetch_next() {
+            light.color = color;
+        }
     }
-    input
 }
 
 // ============================================================================
 // NOS Boost System
 // ============================================================================
 
-/// System to update NOS boost availability timer and handle SPACE activation.
+/// System to update NOS boost availability timer and handle boost key activation.
+/// Each car listens to its own player's boost key (SPACE for player one).
+/// - Buffers SPACE presses for a moment so taps at the window edges aren't dropped
 /// - Ticks the availability timer (boost window counting down)
-/// - Sets active=true while SPACE is held (if boost is available)
-/// - Removes NosBoostAvailable component when timer expires
+/// - Hold mode: sets active=true while SPACE is held or a press is buffered
+/// - Tap mode: a buffered press starts a fixed-duration burst (see `step_nos_burst`)
+/// - Removes NosBoostAvailable component once the window (or burst) runs out
 pub fn update_nos_boost(
     mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
-    mut query: Query<(Entity, &mut NosBoostAvailable), With<Car>>,
+    settings: Res<GameSettings>,
+    mut query: Query<
+        (Entity, &PlayerId, &mut NosInputBuffer, Option<&mut NosBoostAvailable>),
+        With<Car>,
+    >,
 ) {
-    for (entity, mut boost) in query.iter_mut() {
-        // Tick the availability timer
-        boost.timer.tick(time.delta());
-
-        // Activate boost while SPACE is held (only if timer hasn't expired)
-        boost.active = keyboard.pressed(KeyCode::Space) && !boost.timer.is_finished();
+    for (entity, player, mut buffer, boost) in query.iter_mut() {
+        let boost_key = player.controls().boost;
+        let buffer_window = Duration::from_secs_f32(NOS_INPUT_BUFFER_DURATION);
+        buffer.update(keyboard.just_pressed(boost_key), buffer_window, time.delta());
+
+        let Some(mut boost) = boost else {
+            continue;
+        };
+
+        let still_available = match settings.nos_mode {
+            NosMode::Hold => step_nos_boost(
+                &mut boost,
+                keyboard.pressed(boost_key),
+                buffer.is_buffered(),
+                time.delta(),
+            ),
+            NosMode::Tap => step_nos_burst(&mut boost, buffer.is_buffered(), time.delta()),
+        };
 
         // Remove component when availability window expires
-        if boost.timer.is_finished() {
+        if !still_available {
             commands.entity(entity).remove::<NosBoostAvailable>();
         }
 
         // TODO: Spawn particle trail while boost.active is true
     }
 }
+
+// ============================================================================
+// Slow-Mo System
+// ============================================================================
+
+/// System to tick the slow-mo timer in real time and remove the effect when it expires
+pub fn update_slow_motion(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut SlowMotion), With<Car>>,
+) {
+    for (entity, mut slow_mo) in query.iter_mut() {
+        slow_mo.timer.tick(time.delta());
+
+        if slow_mo.timer.is_finished() {
+            commands.entity(entity).remove::<SlowMotion>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::car::components::CarClass;
+    use crate::settings::systems::sync_max_frame_time;
+    use bevy::time::TimeUpdateStrategy;
+    use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+    use std::time::Duration;
+
+    const EPSILON: f32 = 1e-4;
+
+    /// A car at `position` rotated by `angle`, clamped while moving at `velocity`
+    fn clamp(position: Vec2, angle: f32, velocity: Vec2) -> (Transform, Velocity) {
+        let mut transform = Transform::from_translation(position.extend(0.0))
+            .with_rotation(Quat::from_rotation_z(angle));
+        let mut velocity = Velocity(velocity);
+        clamp_position(&mut transform, &mut velocity);
+        (transform, velocity)
+    }
+
+    /// Asserts the car's whole rotated bounding box lies within the boundaries
+    fn assert_inside_boundaries(transform: &Transform) {
+        let (extent_x, extent_y) = get_rotated_extents(transform);
+        let position = transform.translation;
+        assert!(position.x - extent_x >= LEFT_BOUNDARY - EPSILON);
+        assert!(position.x + extent_x <= RIGHT_BOUNDARY + EPSILON);
+        assert!(position.y - extent_y >= BOTTOM_BOUNDARY - EPSILON);
+        assert!(position.y + extent_y <= TOP_BOUNDARY + EPSILON);
+    }
+
+    #[test]
+    fn test_axis_aligned_car_is_clamped_at_each_boundary() {
+        let (left, velocity) = clamp(
+            Vec2::new(LEFT_BOUNDARY - 50.0, 0.0),
+            0.0,
+            Vec2::new(-100.0, 20.0),
+        );
+        assert!((left.translation.x - (LEFT_BOUNDARY + CAR_WIDTH / 2.0)).abs() < EPSILON);
+        assert_eq!(velocity.0, Vec2::new(0.0, 20.0));
+
+        let (right, velocity) = clamp(
+            Vec2::new(RIGHT_BOUNDARY + 50.0, 0.0),
+            0.0,
+            Vec2::new(100.0, 20.0),
+        );
+        assert!((right.translation.x - (RIGHT_BOUNDARY - CAR_WIDTH / 2.0)).abs() < EPSILON);
+        assert_eq!(velocity.0, Vec2::new(0.0, 20.0));
+
+        let (bottom, velocity) = clamp(
+            Vec2::new(0.0, BOTTOM_BOUNDARY - 50.0),
+            0.0,
+            Vec2::new(20.0, -100.0),
+        );
+        assert!((bottom.translation.y - (BOTTOM_BOUNDARY + CAR_HEIGHT / 2.0)).abs() < EPSILON);
+        assert_eq!(velocity.0, Vec2::new(20.0, 0.0));
+
+        let (top, velocity) = clamp(
+            Vec2::new(0.0, TOP_BOUNDARY + 50.0),
+            0.0,
+            Vec2::new(20.0, 100.0),
+        );
+        assert!((top.translation.y - (TOP_BOUNDARY - CAR_HEIGHT / 2.0)).abs() < EPSILON);
+        assert_eq!(velocity.0, Vec2::new(20.0, 0.0));
+
+        for transform in [left, right, bottom, top] {
+            assert_inside_boundaries(&transform);
+        }
+    }
+
+    #[test]
+    fn test_diagonal_car_extents_grow() {
+        let transform = Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_4));
+        let (extent_x, extent_y) = get_rotated_extents(&transform);
+
+        let expected = (CAR_WIDTH + CAR_HEIGHT) / 2.0 * FRAC_PI_4.cos();
+        assert!((extent_x - expected).abs() < EPSILON);
+        assert!((extent_y - expected).abs() < EPSILON);
+        assert!(extent_x > CAR_WIDTH / 2.0);
+
+        // Heading into the bottom-right corner, both walls stop the car
+        let corner = Vec2::new(RIGHT_BOUNDARY, BOTTOM_BOUNDARY);
+        let (transform, velocity) = clamp(corner, FRAC_PI_4, Vec2::new(80.0, -80.0));
+        assert_inside_boundaries(&transform);
+        assert!((transform.translation.x - (RIGHT_BOUNDARY - expected)).abs() < EPSILON);
+        assert_eq!(velocity.0, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_sideways_car_swaps_width_and_height() {
+        let transform = Transform::from_rotation(Quat::from_rotation_z(FRAC_PI_2));
+        let (extent_x, extent_y) = get_rotated_extents(&transform);
+        assert!((extent_x - CAR_HEIGHT / 2.0).abs() < EPSILON);
+        assert!((extent_y - CAR_WIDTH / 2.0).abs() < EPSILON);
+
+        // Moving away from the wall it's pressed against keeps that velocity
+        let (transform, velocity) = clamp(
+            Vec2::new(LEFT_BOUNDARY, 0.0),
+            FRAC_PI_2,
+            Vec2::new(30.0, 0.0),
+        );
+        assert_inside_boundaries(&transform);
+        assert!((transform.translation.x - (LEFT_BOUNDARY + CAR_HEIGHT / 2.0)).abs() < EPSILON);
+        assert_eq!(velocity.0, Vec2::new(30.0, 0.0));
+    }
+
+    #[test]
+    fn test_hitch_moves_the_car_one_capped_frame() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<GameSettings>()
+            // Every frame takes a whole second, as while the window is dragged
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)))
+            .add_systems(Update, (sync_max_frame_time, move_car).chain());
+        let stats = CarClass::default().stats();
+        let speed = stats.max_speed;
+        let car = app
+            .world_mut()
+            .spawn((Car, Transform::default(), Velocity(Vec2::new(0.0, speed)), stats))
+            .id();
+
+        // The cap set in the first frame applies from the next
+        app.update();
+        let before = app.world().get::<Transform>(car).unwrap().translation.y;
+        app.update();
+        let moved = app.world().get::<Transform>(car).unwrap().translation.y - before;
+
+        let max_frame_time = GameSettings::default().max_frame_time().as_secs_f32();
+        assert!(moved > 0.0);
+        assert!(moved <= speed * max_frame_time + EPSILON);
+    }
+}
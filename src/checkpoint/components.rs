@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+
+/// Resource tracking where the car is returned to after staying off-road too long.
+/// Updated whenever a new road segment is visited; starts at the track's starting point.
+#[derive(Resource)]
+pub struct Checkpoint {
+    /// Respawn position (world coordinates)
+    pub position: Vec2,
+    /// Respawn rotation (radians), facing the direction of travel
+    pub rotation: f32,
+    /// Seconds the car has continuously been off the road
+    pub off_road_time: f32,
+    /// Where the race starts, for resetting the car before the start line is crossed
+    pub start_position: Vec2,
+    /// Rotation (radians) the car starts the race with
+    pub start_rotation: f32,
+}
+
+/// Message sent when the car is reset to the start before the race has started,
+/// so the segments it visited on the way are cleared
+#[derive(Message)]
+pub struct StartReset;
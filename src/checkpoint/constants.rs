@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+/// Seconds the car may stay off-road before it is returned to the last checkpoint
+pub const OFF_ROAD_RESPAWN_DELAY: f32 = 3.0;
+/// Key that returns the car to the start while waiting to cross the start line
+/// (the same key rewinds to the checkpoint in practice mode once racing)
+pub const RESET_TO_START_KEY: KeyCode = KeyCode::Backspace;
+/// Seconds added to the race timer for each checkpoint respawn
+pub const RESPAWN_TIME_PENALTY: f32 = 5.0;
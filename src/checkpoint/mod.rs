@@ -0,0 +1,22 @@
+pub mod components;
+pub mod constants;
+pub mod systems;
+
+use bevy::prelude::*;
+use crate::constants::GameState;
+use crate::utils::is_two_player;
+use components::StartReset;
+use systems::{reset_to_start, respawn_at_checkpoint, rewind_to_checkpoint, update_checkpoint};
+
+pub struct CheckpointPlugin;
+
+impl Plugin for CheckpointPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<StartReset>().add_systems(
+            Update,
+            // Checkpoints follow a single car, so they're off in two-player mode
+            (update_checkpoint, respawn_at_checkpoint, rewind_to_checkpoint, reset_to_start)
+                .run_if(in_state(GameState::Playing).and(not(is_two_player))),
+        );
+    }
+}
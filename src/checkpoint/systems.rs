@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+
+use crate::car::components::{Car, Velocity};
+use crate::car::helpers::respawn_car;
+use crate::checkpoint::components::{Checkpoint, StartReset};
+use crate::constants::PracticeMode;
+use crate::checkpoint::constants::{OFF_ROAD_RESPAWN_DELAY, RESET_TO_START_KEY, RESPAWN_TIME_PENALTY};
+use crate::hud::components::{LineCrossing, RaceState, RaceStatus};
+use crate::road::components::{RoadSegment, VisitOrder, Visited};
+use crate::road::helpers::{get_exit_direction, get_rotation, get_segment_center};
+
+/// Initialize the checkpoint at the track's starting point, facing the way the track
+/// starts (used until a segment is visited)
+pub fn init_checkpoint(commands: &mut Commands, starting_point: Vec2, start_rotation: f32) {
+    commands.insert_resource(Checkpoint {
+        position: starting_point,
+        rotation: start_rotation,
+        off_road_time: 0.0,
+        start_position: starting_point,
+        start_rotation,
+    });
+}
+
+/// System to move the checkpoint to the most recently visited road segment.
+/// The car respawns facing the segment's exit direction.
+pub fn update_checkpoint(
+    mut checkpoint: ResMut<Checkpoint>,
+    visited_query: Query<(&Transform, &RoadSegment), Added<Visited>>,
+) {
+    for (transform, segment) in &visited_query {
+        let exit_direction = get_exit_direction(segment.direction, segment.segment_type);
+        checkpoint.position = get_segment_center(transform, segment.segment_type);
+        checkpoint.rotation = get_rotation(exit_direction);
+    }
+}
+
+/// System to return the car to the last checkpoint after it stays off-road too long.
+/// Only active on difficulties that allow it; each respawn adds a time penalty.
+pub fn respawn_at_checkpoint(
+    time: Res<Time>,
+    mut checkpoint: ResMut<Checkpoint>,
+    mut race_state: ResMut<RaceState>,
+    car_query: Single<(&mut Transform, &mut Velocity, &mut LineCrossing), With<Car>>,
+) {
+    if !race_state.difficulty.respawns_off_road() || race_state.status != RaceStatus::Racing {
+        return;
+    }
+
+    if race_state.is_on_road {
+        checkpoint.off_road_time = 0.0;
+        return;
+    }
+
+    checkpoint.off_road_time += time.delta_secs();
+    if checkpoint.off_road_time < OFF_ROAD_RESPAWN_DELAY {
+        return;
+    }
+
+    let (mut transform, mut velocity, mut crossing) = car_query.into_inner();
+    respawn_car(&mut transform, &mut velocity, checkpoint.position, checkpoint.rotation);
+
+    // Teleporting must not register as crossing the start/finish line
+    crossing.last_position = checkpoint.position;
+    race_state.add_penalty(RESPAWN_TIME_PENALTY);
+    checkpoint.off_road_time = 0.0;
+}
+
+/// System to rewind the car to the last checkpoint in practice mode (Backspace).
+/// Works like the off-road respawn but without a time penalty.
+/// Before the race starts the key resets to the start instead (see `reset_to_start`).
+pub fn rewind_to_checkpoint(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    (practice, race_state): (Res<PracticeMode>, Res<RaceState>),
+    checkpoint: Res<Checkpoint>,
+    car_query: Single<(&mut Transform, &mut Velocity, &mut LineCrossing), With<Car>>,
+) {
+    if !practice.0
+        || race_state.status == RaceStatus::WaitingToStart
+        || !keyboard_input.just_pressed(RESET_TO_START_KEY)
+    {
+        return;
+    }
+
+    let (mut transform, mut velocity, mut crossing) = car_query.into_inner();
+    respawn_car(&mut transform, &mut velocity, checkpoint.position, checkpoint.rotation);
+
+    // Teleporting must not register as crossing the start/finish line
+    crossing.last_position = checkpoint.position;
+}
+
+/// System to return the car to the start with zero velocity (Backspace) while it's free to
+/// drive around before crossing the start line, e.g. to line up a launch.
+/// The timer only starts at the start line, so it stays at zero; the segments visited on
+/// the way are forgotten (see `StartReset`).
+pub fn reset_to_start(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut race_state: ResMut<RaceState>,
+    mut checkpoint: ResMut<Checkpoint>,
+    car_query: Single<(&mut Transform, &mut Velocity, &mut LineCrossing, &mut VisitOrder), With<Car>>,
+    mut start_reset: MessageWriter<StartReset>,
+) {
+    if race_state.status != RaceStatus::WaitingToStart || !keyboard_input.just_pressed(RESET_TO_START_KEY) {
+        return;
+    }
+
+    let (mut transform, mut velocity, mut crossing, mut visit_order) = car_query.into_inner();
+    respawn_car(&mut transform, &mut velocity, checkpoint.start_position, checkpoint.start_rotation);
+
+    // Teleporting must not register as crossing the start line
+    crossing.last_position = checkpoint.start_position;
+    // The checkpoint goes back to the start along with the visits
+    checkpoint.position = checkpoint.start_position;
+    checkpoint.rotation = checkpoint.start_rotation;
+    visit_order.indices.clear();
+    race_state.stopwatch.reset();
+    start_reset.write(StartReset);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+
+    use crate::car::components::{CarClass, PlayerId};
+    use crate::car::systems::spawn_car;
+    use crate::hud::systems::init_race_state;
+    use crate::road::components::Direction;
+    use crate::settings::components::{Difficulty, FinishCondition};
+
+    #[test]
+    fn test_reset_returns_car_to_start_before_race() {
+        let mut world = World::new();
+        world.init_resource::<Messages<StartReset>>();
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(RESET_TO_START_KEY);
+        world.insert_resource(keyboard);
+        let start = Vec2::new(10.0, 20.0);
+        init_checkpoint(&mut world.commands(), start, get_rotation(Direction::Down));
+        init_race_state(&mut world.commands(), None, Difficulty::default(), FinishCondition::default());
+        let stats = CarClass::default().stats();
+        let car = spawn_car(&mut world.commands(), start, 0.0, PlayerId::One, stats, Color::WHITE);
+        world.flush();
+        world.entity_mut(car).insert((
+            LineCrossing { last_position: start },
+            VisitOrder { indices: vec![0, 1], segment_count: 10 },
+            Velocity(Vec2::new(0.0, 50.0)),
+            Transform::from_xyz(40.0, 80.0, 1.0),
+        ));
+
+        world.run_system_once(reset_to_start).unwrap();
+
+        let car = world.entity(car);
+        assert_eq!(car.get::<Transform>().unwrap().translation.truncate(), start);
+        assert_eq!(car.get::<Velocity>().unwrap().0, Vec2::ZERO);
+        assert!(car.get::<VisitOrder>().unwrap().indices.is_empty());
+        assert_eq!(world.resource::<Messages<StartReset>>().len(), 1);
+    }
+}
@@ -1,4 +1,8 @@
 use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::road::tracks::level_seed;
 
 // -- Current Level -- //
 /// Resource to track the current level (1, 2, or 3)
@@ -16,6 +20,92 @@ impl Default for CurrentLevel {
 #[derive(Resource, Default)]
 pub struct ResumeFromPause(pub bool);
 
+// -- Previous State -- //
+/// Resource recording the screen the settings menu was opened from, so Back returns there
+/// (the start menu, or the pause menu with the race kept in place)
+#[derive(Resource)]
+pub struct PreviousState(pub GameState);
+
+impl Default for PreviousState {
+    fn default() -> Self {
+        PreviousState(GameState::StartMenu)
+    }
+}
+
+// -- Practice Mode -- //
+/// Resource flag for practice runs: the timer is off, R restarts and Backspace rewinds
+/// to the last checkpoint, and nothing is written to the save
+#[derive(Resource, Default)]
+pub struct PracticeMode(pub bool);
+
+// -- Two Player Mode -- //
+/// Resource flag for local two-player races: a second car driven with WASD races on
+/// the same track, the first to finish wins, and nothing is written to the save
+#[derive(Resource, Default)]
+pub struct TwoPlayerMode(pub bool);
+
+// -- Seed Override -- //
+/// Resource holding a seed picked on the track preview for a generated level, replacing
+/// the level's own seed as (level, seed). Runs on a replaced seed aren't recorded, since
+/// the track isn't the level's. Cleared on returning to the level menu.
+#[derive(Resource, Default)]
+pub struct SeedOverride(pub Option<(usize, u64)>);
+
+impl SeedOverride {
+    /// Seed the level's track is generated from (None for the fixed tracks, see `BUILTIN_LEVEL_COUNT`)
+    pub fn seed_for(&self, level: usize) -> Option<u64> {
+        match self.0 {
+            Some((override_level, seed)) if override_level == level => Some(seed),
+            _ => level_seed(level),
+        }
+    }
+
+    /// Whether the level is played on a seed other than its own
+    pub fn replaces(&self, level: usize) -> bool {
+        self.seed_for(level) != level_seed(level)
+    }
+}
+
+// -- Game RNG -- //
+/// Environment variable holding the global seed, to reproduce a playthrough's randomness
+pub const GAME_SEED_ENV_VAR: &str = "BEVY_DRIVER_SEED";
+
+/// Resource holding the random number generator for all gameplay randomness (such as
+/// the lane each powerup sits in, or the new seeds the track preview's "Regenerate" tries).
+/// Seeded once at startup from the global seed, so the same seed plays out the same. A level's
+/// own track keeps its per-level seed, so it is the same whatever the global seed (see
+/// `generate_random_track`).
+#[derive(Resource)]
+pub struct GameRng(pub ChaCha8Rng);
+
+impl GameRng {
+    /// Creates the generator for the given global seed
+    pub fn from_seed(seed: u64) -> Self {
+        GameRng(ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Creates the generator for the seed in `GAME_SEED_ENV_VAR`, or a random one if it
+    /// isn't set. The seed is logged either way, so any run can be replayed.
+    pub fn from_env() -> Self {
+        let seed = std::env::var(GAME_SEED_ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_else(rand::random);
+        info!("Global seed: {} (set {} to reuse it)", seed, GAME_SEED_ENV_VAR);
+        Self::from_seed(seed)
+    }
+
+    /// Draws a new track generator seed
+    pub fn track_seed(&mut self) -> u64 {
+        self.0.random()
+    }
+}
+
+// -- Main Camera -- //
+/// Marker for the main game camera (as opposed to the offscreen minimap cameras)
+#[derive(Component)]
+pub struct MainCamera;
+
 // -- Game State -- //
 /// Global game state enum for managing menu and gameplay transitions
 #[derive(Clone, Copy, Default, Eq, PartialEq, Debug, Hash, States)]
@@ -35,11 +125,21 @@ pub enum GameState {
     Paused,
     /// Level complete screen (shown when race finishes)
     LevelComplete,
+    /// Playback of the just-completed run (entered from the level complete screen)
+    Replay,
+    /// Settings screen (difficulty and other options)
+    Settings,
+    /// Combined-time results at the end of a time trial sequence
+    SequenceResults,
+    /// Preview of a generated level's track, where another seed can be picked before playing
+    TrackPreview,
+    /// Car class and color selection (entered from the level menu)
+    Garage,
 }
 
 // -- Bloom Settings -- //
-/// Bloom intensity for the glow effect on unvisited road segments
-/// Lower values for subtle localized glow
+/// Bloom intensity for the glow effect on road edges in the original night theme
+/// (other themes set their own, see `Theme`). Lower values for subtle localized glow
 pub const BLOOM_INTENSITY: f32 = 0.45;
 
 // -- Window Settings -- //
@@ -52,3 +152,31 @@ pub const BOTTOM_BOUNDARY: f32 = -(WINDOW_HEIGHT as f32) / 2.0;
 
 // -- Colors -- //
 pub const GAME_BACKGROUND_COLOR: Color = Color::srgb(0.05, 0.05, 0.05);
+/// Bars filling the window around the letterboxed playfield (see `letterbox_viewport`)
+pub const LETTERBOX_COLOR: Color = Color::BLACK;
+
+// -- Auto-Save -- //
+/// Seconds of gameplay between auto-saves of the current save
+pub const AUTO_SAVE_INTERVAL_SECS: f32 = 30.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::road::helpers::get_prop_positions;
+    use crate::road::tracks::get_builtin_track;
+
+    #[test]
+    fn test_same_global_seed_plays_out_the_same() {
+        let track = get_builtin_track(1);
+        let mut first = GameRng::from_seed(7);
+        let mut second = GameRng::from_seed(7);
+
+        for _ in 0..3 {
+            assert_eq!(
+                get_prop_positions(&track, &mut first.0),
+                get_prop_positions(&track, &mut second.0)
+            );
+        }
+        assert_eq!(first.track_seed(), second.track_seed());
+    }
+}
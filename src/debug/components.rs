@@ -0,0 +1,9 @@
+use bevy::prelude::*;
+
+/// Resource toggling the hitbox debug overlay
+#[derive(Resource, Default)]
+pub struct ShowHitboxes(pub bool);
+
+/// Resource toggling the generation grid overlay
+#[derive(Resource, Default)]
+pub struct ShowGrid(pub bool);
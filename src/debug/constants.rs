@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+/// Key that toggles the hitbox overlay
+pub const HITBOX_TOGGLE_KEY: KeyCode = KeyCode::F3;
+/// Outline color for the car's collision box
+pub const CAR_HITBOX_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+/// Outline color for road segments' drivable regions
+pub const ROAD_HITBOX_COLOR: Color = Color::srgb(0.2, 1.0, 0.2);
+/// Outline color for powerup collision boxes
+pub const POWERUP_HITBOX_COLOR: Color = Color::srgb(1.0, 1.0, 0.2);
+
+/// Key that toggles the generation grid overlay
+pub const GRID_TOGGLE_KEY: KeyCode = KeyCode::F4;
+/// Color of the lines between grid cells (faint, so the track stays readable)
+pub const GRID_LINE_COLOR: Color = Color::srgba(0.6, 0.6, 1.0, 0.35);
+/// Color of the outline of cell (0, 0) and the arrow of the start direction
+pub const GRID_ORIGIN_COLOR: Color = Color::srgb(1.0, 0.5, 1.0);
+/// Colors of the arrows from cell (0, 0) to cells (1, 0) and (0, 1)
+pub const GRID_X_AXIS_COLOR: Color = Color::srgb(1.0, 0.4, 0.4);
+pub const GRID_Y_AXIS_COLOR: Color = Color::srgb(0.4, 1.0, 0.4);
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+use crate::road::constants::ROAD_SEGMENT_LENGTH;
+
+/// World position of the center of a generation grid cell. Cell (0, 0) is the track's
+/// starting segment, and each cell is `ROAD_SEGMENT_LENGTH` across (see `finalize_track`).
+pub fn grid_cell_to_world(origin: Vec2, cell: IVec2) -> Vec2 {
+    origin + cell.as_vec2() * ROAD_SEGMENT_LENGTH
+}
+
+/// Positions along one axis of the grid lines between cells inside `min..=max`,
+/// for cells centered on `origin` (the starting segment's coordinate on that axis)
+pub fn grid_line_positions(origin: f32, min: f32, max: f32) -> Vec<f32> {
+    let first = ((min - origin) / ROAD_SEGMENT_LENGTH - 0.5).ceil() as i32;
+    let last = ((max - origin) / ROAD_SEGMENT_LENGTH - 0.5).floor() as i32;
+    (first..=last)
+        .map(|line| origin + (line as f32 + 0.5) * ROAD_SEGMENT_LENGTH)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_lines_fall_between_cell_centers() {
+        let origin = Vec2::new(25.0, -75.0);
+        let lines = grid_line_positions(origin.x, -200.0, 200.0);
+        assert!(!lines.is_empty());
+        assert!(lines.iter().all(|&x| (-200.0..=200.0).contains(&x)));
+        // Every line sits half a cell from the centers of the cells on either side
+        for &x in &lines {
+            let cell = ((x - origin.x) / ROAD_SEGMENT_LENGTH).floor() as i32;
+            let left = grid_cell_to_world(origin, IVec2::new(cell, 0)).x;
+            let right = grid_cell_to_world(origin, IVec2::new(cell + 1, 0)).x;
+            assert_eq!(x - left, ROAD_SEGMENT_LENGTH / 2.0);
+            assert_eq!(right - x, ROAD_SEGMENT_LENGTH / 2.0);
+        }
+        // No line is missed at either end of the range
+        assert!(lines[0] - ROAD_SEGMENT_LENGTH < -200.0);
+        assert!(lines[lines.len() - 1] + ROAD_SEGMENT_LENGTH > 200.0);
+    }
+}
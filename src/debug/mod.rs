@@ -0,0 +1,27 @@
+pub mod components;
+pub mod constants;
+pub mod helpers;
+pub mod systems;
+
+use bevy::prelude::*;
+use crate::constants::GameState;
+use components::{ShowGrid, ShowHitboxes};
+use systems::{draw_grid, draw_hitboxes, grid_shown, hitboxes_shown, toggle_grid, toggle_hitboxes};
+
+/// Debug-only tools. Only compiled into debug builds (see main.rs).
+pub struct DebugPlugin;
+
+impl Plugin for DebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ShowHitboxes>()
+            .init_resource::<ShowGrid>()
+            .add_systems(
+                Update,
+                (
+                    (toggle_hitboxes, draw_hitboxes.run_if(hitboxes_shown)).chain(),
+                    (toggle_grid, draw_grid.run_if(grid_shown)).chain(),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use crate::car::components::Car;
+use crate::car::helpers::get_car_corners;
+use crate::constants::MainCamera;
+use crate::debug::components::{ShowGrid, ShowHitboxes};
+use crate::debug::constants::{
+    CAR_HITBOX_COLOR, GRID_LINE_COLOR, GRID_ORIGIN_COLOR, GRID_TOGGLE_KEY, GRID_X_AXIS_COLOR,
+    GRID_Y_AXIS_COLOR, HITBOX_TOGGLE_KEY, POWERUP_HITBOX_COLOR, ROAD_HITBOX_COLOR,
+};
+use crate::debug::helpers::{grid_cell_to_world, grid_line_positions};
+use crate::props::components::{NosPowerUp, SlowMoPowerUp};
+use crate::props::constants::NOS_SIZE;
+use crate::road::components::{RoadSegment, RoadSegmentType, TrackStart};
+use crate::road::constants::{ROAD_SEGMENT_LENGTH, ROAD_WIDTH};
+use crate::road::helpers::get_rotation;
+
+/// Filter matching every collectible powerup kind
+type AnyPowerUp = Or<(With<NosPowerUp>, With<SlowMoPowerUp>)>;
+
+/// World area the main camera shows, from its projection (so it follows the window size,
+/// letterboxed or not, and the zoom)
+fn camera_view(transform: &Transform, projection: &Projection) -> Rect {
+    let Projection::Orthographic(orthographic) = projection else {
+        return Rect::from_center_size(transform.translation.truncate(), Vec2::ZERO);
+    };
+    let center = transform.translation.truncate();
+    Rect::from_corners(center + orthographic.area.min, center + orthographic.area.max)
+}
+
+/// Run condition: returns true while the hitbox overlay is enabled
+pub fn hitboxes_shown(show: Res<ShowHitboxes>) -> bool {
+    show.0
+}
+
+/// Toggles the hitbox overlay when the toggle key is pressed
+pub fn toggle_hitboxes(keyboard_input: Res<ButtonInput<KeyCode>>, mut show: ResMut<ShowHitboxes>) {
+    if keyboard_input.just_pressed(HITBOX_TOGGLE_KEY) {
+        show.0 = !show.0;
+    }
+}
+
+/// Draws the car's OBB, each road segment's drivable region and the powerup
+/// collision boxes, matching the shapes used by the collision checks.
+/// Shapes outside the camera's view are skipped.
+pub fn draw_hitboxes(
+    mut gizmos: Gizmos,
+    camera_query: Single<(&Transform, &Projection), With<MainCamera>>,
+    car_query: Query<&Transform, With<Car>>,
+    segment_query: Query<(&Transform, &RoadSegment)>,
+    powerup_query: Query<&Transform, AnyPowerUp>,
+) {
+    let (camera_transform, projection) = *camera_query;
+    let view = camera_view(camera_transform, projection);
+    let on_screen = |center: Vec2, extent: f32| {
+        !view.intersect(Rect::from_center_size(center, Vec2::splat(extent))).is_empty()
+    };
+
+    for (transform, segment) in &segment_query {
+        let position = transform.translation.truncate();
+        let (_, _, angle) = transform.rotation.to_euler(EulerRot::XYZ);
+
+        match segment.segment_type {
+            RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => {
+                let length = segment.segment_type.length_in_cells() as f32 * ROAD_SEGMENT_LENGTH;
+                if on_screen(position, length) {
+                    let isometry = Isometry2d::new(position, Rot2::radians(angle));
+                    gizmos.rect_2d(isometry, Vec2::new(ROAD_WIDTH, length), ROAD_HITBOX_COLOR);
+                }
+            }
+            // Corners are a quarter circle around the pivot, spanning 45-135 degrees locally
+            RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight => {
+                if on_screen(position, ROAD_WIDTH * 2.0) {
+                    let start = Rot2::radians(angle - FRAC_PI_4);
+                    gizmos.arc_2d(Isometry2d::new(position, start), FRAC_PI_2, ROAD_WIDTH, ROAD_HITBOX_COLOR);
+                    for local_angle in [FRAC_PI_4, FRAC_PI_2 + FRAC_PI_4] {
+                        let edge = Vec2::from_angle(angle + local_angle) * ROAD_WIDTH;
+                        gizmos.line_2d(position, position + edge, ROAD_HITBOX_COLOR);
+                    }
+                }
+            }
+        }
+    }
+
+    for transform in &powerup_query {
+        let position = transform.translation.truncate();
+        if on_screen(position, NOS_SIZE) {
+            let (_, _, angle) = transform.rotation.to_euler(EulerRot::XYZ);
+            gizmos.rect_2d(
+                Isometry2d::new(position, Rot2::radians(angle)),
+                Vec2::splat(NOS_SIZE),
+                POWERUP_HITBOX_COLOR,
+            );
+        }
+    }
+
+    for transform in &car_query {
+        let corners = get_car_corners(transform).map(|corner| corner.truncate());
+        gizmos.linestrip_2d(
+            [corners[0], corners[1], corners[2], corners[3], corners[0]],
+            CAR_HITBOX_COLOR,
+        );
+    }
+}
+
+/// Run condition: returns true while the grid overlay is enabled
+pub fn grid_shown(show: Res<ShowGrid>) -> bool {
+    show.0
+}
+
+/// Toggles the grid overlay when the toggle key is pressed
+pub fn toggle_grid(keyboard_input: Res<ButtonInput<KeyCode>>, mut show: ResMut<ShowGrid>) {
+    if keyboard_input.just_pressed(GRID_TOGGLE_KEY) {
+        show.0 = !show.0;
+    }
+}
+
+/// Draws the track generator's grid across the view: the lines between its
+/// `ROAD_SEGMENT_LENGTH` cells, cell (0, 0) (the starting segment) with an arrow in the
+/// start direction, and arrows to cells (1, 0) and (0, 1) showing which way the grid's
+/// axes run in the world
+pub fn draw_grid(
+    mut gizmos: Gizmos,
+    camera_query: Single<(&Transform, &Projection), With<MainCamera>>,
+    track_start: Option<Res<TrackStart>>,
+) {
+    let Some(track_start) = track_start else {
+        return;
+    };
+    let origin = track_start.position;
+    let start_rotation = get_rotation(track_start.direction);
+    let (camera_transform, projection) = *camera_query;
+    let view = camera_view(camera_transform, projection);
+
+    for x in grid_line_positions(origin.x, view.min.x, view.max.x) {
+        gizmos.line_2d(Vec2::new(x, view.min.y), Vec2::new(x, view.max.y), GRID_LINE_COLOR);
+    }
+    for y in grid_line_positions(origin.y, view.min.y, view.max.y) {
+        gizmos.line_2d(Vec2::new(view.min.x, y), Vec2::new(view.max.x, y), GRID_LINE_COLOR);
+    }
+
+    for (cell, color) in [(IVec2::X, GRID_X_AXIS_COLOR), (IVec2::Y, GRID_Y_AXIS_COLOR)] {
+        gizmos.arrow_2d(origin, grid_cell_to_world(origin, cell), color);
+    }
+    // Drawn after the axes, as generated tracks start heading along +Y
+    gizmos.rect_2d(origin, Vec2::splat(ROAD_SEGMENT_LENGTH), GRID_ORIGIN_COLOR);
+    let heading = Rot2::radians(start_rotation) * Vec2::Y;
+    gizmos.arrow_2d(origin, origin + heading * ROAD_SEGMENT_LENGTH / 2.0, GRID_ORIGIN_COLOR);
+}
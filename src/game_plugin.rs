@@ -1,98 +1,296 @@
 use bevy::prelude::*;
-use crate::car::constants::CAR_HEIGHT;
+use crate::car::components::PlayerId;
+use crate::car::constants::TWO_PLAYER_START_OFFSET;
 use crate::car::systems::spawn_car;
-use crate::constants::{CurrentLevel, GameState, ResumeFromPause};
+use crate::constants::{
+    CurrentLevel, GameRng, GameState, MainCamera, PracticeMode, ResumeFromPause, SeedOverride,
+    TwoPlayerMode, AUTO_SAVE_INTERVAL_SECS,
+};
+use crate::hud::components::LineCrossing;
 use crate::hud::systems::{
-    check_finish_line_crossing, handle_off_road_logic, init_race_state, spawn_controls_hint,
+    check_finish_line_crossing, handle_off_road_logic, init_race_state, spawn_coin_ui, spawn_controls_hint,
     spawn_level_text_ui, spawn_multiplier_ui, spawn_nos_boost_bar, spawn_nos_boost_bar_glow,
-    spawn_timer_ui,
+    spawn_finish_blocked_text, spawn_slow_mo_ui, spawn_speedometer_ui, spawn_start_gantry,
+    spawn_timer_ui, spawn_volume_overlay, spawn_wall_glow,
 };
-use crate::road::components::{Direction, Track};
+use crate::checkpoint::systems::init_checkpoint;
+use crate::replay::systems::init_ghost_recorder;
+use crate::road::components::{PlayedTrackInfo, TrackStart, VisitOrder};
+use crate::road::helpers::{bronze_target_time, get_car_spawns, get_start_and_finish_positions};
 use crate::road::systems::{
-    check_car_on_road, spawn_finish_line, spawn_start_line, spawn_track,
+    animate_visited_edges, check_car_on_road, clear_fog, clear_visits_on_start_reset, highlight_missed_segments,
+    reveal_track, spawn_race_course, spawn_track_coins, spawn_track_props,
     update_segment_visited_status,
 };
-use crate::props::systems::{rotate_powerups, check_powerup_collision};
-use crate::road::track_generator::{generate_random_track, TrackGeneratorConfig};
-use crate::road::tracks::get_track;
+use crate::props::components::Score;
+use crate::props::systems::{
+    check_collectible_collision, check_powerup_collision, check_slow_mo_powerup_collision,
+    init_score, rotate_powerups,
+};
+use crate::save::{save_or_report, CurrentSave, SaveResult, SaveStatus};
+use crate::split_screen::systems::spawn_split_screen_camera;
+use crate::start_menu::components::GameEntity;
+use crate::settings::components::{FinishCondition, GameSettings};
+use crate::styles::palette::Palette;
+use crate::styles::theme::{apply_level_theme, GlowSettings};
+use crate::road::tracks::{load_track, resolve};
+use crate::utils::despawn_all;
 
 pub struct GamePlugin;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.add_plugins(RaceCleanupPlugin)
+        .init_resource::<AutoSaveTimer>()
+        .init_resource::<Score>()
+        .init_resource::<PlayedTrackInfo>()
+        .add_systems(
             OnEnter(GameState::Playing),
-            (setup_game.run_if(not(resuming_from_pause)), clear_resume_flag).chain(),
+            (
+                setup_game.pipe(count_level_attempt).run_if(not(resuming_from_pause)),
+                clear_resume_flag,
+            )
+                .chain(),
         )
         .add_systems(
             Update,
             (
                 check_car_on_road.pipe(handle_off_road_logic),
-                (update_segment_visited_status, check_finish_line_crossing).chain(),
+                (
+                    clear_visits_on_start_reset,
+                    update_segment_visited_status,
+                    check_finish_line_crossing,
+                    highlight_missed_segments,
+                )
+                    .chain(),
+                animate_visited_edges.after(clear_visits_on_start_reset),
+                reveal_track.run_if(fog_of_war_enabled),
+                clear_fog.run_if(resource_changed::<GameSettings>.and(not(fog_of_war_enabled))),
                 rotate_powerups,
                 check_powerup_collision,
+                check_slow_mo_powerup_collision,
+                check_collectible_collision,
+                (accumulate_playtime, auto_save).chain(),
+                restart_practice_run,
             )
                 .run_if(in_state(GameState::Playing)),
         );
     }
 }
 
+/// Despawns a race abandoned to a menu. Part of `GamePlugin`, kept apart so the state tests
+/// can check it without building a race's track and cameras.
+pub struct RaceCleanupPlugin;
+
+impl Plugin for RaceCleanupPlugin {
+    fn build(&self, app: &mut App) {
+        // A paused race is kept through the options screen, so it can't be despawned on
+        // leaving Playing or Paused. The menus a race can be abandoned to clean it up instead
+        // (LevelComplete cleans up on exit, as Next Level goes straight back to Playing).
+        app.add_systems(OnEnter(GameState::LevelMenu), despawn_all::<GameEntity>)
+            .add_systems(OnEnter(GameState::StartMenu), despawn_all::<GameEntity>);
+    }
+}
+
+/// Adds real frame time to the save's total playtime while in gameplay.
+/// Uses the frame delta rather than the race stopwatch, which runs faster off-road.
+/// Practice runs never touch the save.
+fn accumulate_playtime(
+    time: Res<Time>,
+    practice: Res<PracticeMode>,
+    mut current_save: ResMut<CurrentSave>,
+) {
+    if practice.0 {
+        return;
+    }
+    if let Some(save_data) = current_save.get_mut() {
+        save_data.add_playtime(time.delta_secs());
+    }
+}
+
+/// Counts a fresh start of the current level in the save, once `setup_game` has built its
+/// track (a level whose track couldn't be generated was never raced).
+/// Only runs on entering Playing without a paused race to resume, so resumes aren't counted.
+/// Practice runs never touch the save.
+fn count_level_attempt(
+    In(track_built): In<bool>,
+    current_level: Res<CurrentLevel>,
+    practice: Res<PracticeMode>,
+    mut current_save: ResMut<CurrentSave>,
+) {
+    if !track_built || practice.0 {
+        return;
+    }
+    if let Some(save_data) = current_save.get_mut() {
+        save_data.record_level_attempt(current_level.0);
+    }
+}
+
+/// Resource timing the periodic auto-save during gameplay.
+/// Keeps running across levels, so long campaigns are saved at a steady interval.
+#[derive(Resource)]
+struct AutoSaveTimer(Timer);
+
+impl Default for AutoSaveTimer {
+    fn default() -> Self {
+        AutoSaveTimer(Timer::from_seconds(AUTO_SAVE_INTERVAL_SECS, TimerMode::Repeating))
+    }
+}
+
+/// Periodically writes the current save (playtime and progress) to disk, so a crash
+/// mid-session loses at most one interval. Uses the same atomic write as the other saves;
+/// the file is only a few hundred bytes, so writing it on the main thread doesn't stutter.
+/// Practice runs never touch the save, and once a write has failed the save is only kept
+/// in memory (see `SaveStatus`).
+fn auto_save(
+    time: Res<Time>,
+    practice: Res<PracticeMode>,
+    status: Res<SaveStatus>,
+    mut timer: ResMut<AutoSaveTimer>,
+    current_save: Res<CurrentSave>,
+    mut save_results: MessageWriter<SaveResult>,
+) {
+    if practice.0 || status.in_memory_only || !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+    if let Some(save_data) = current_save.get() {
+        save_or_report(save_data, &mut save_results);
+    }
+}
+
+/// Restarts the level in place when R is pressed in practice mode.
+/// Re-entering Playing wouldn't re-run setup (same-state transitions skip OnEnter),
+/// so the gameplay entities are despawned and `setup_game` is run directly.
+fn restart_practice_run(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    practice: Res<PracticeMode>,
+    game_entities: Query<Entity, With<GameEntity>>,
+) {
+    if !practice.0 || !keyboard_input.just_pressed(KeyCode::KeyR) {
+        return;
+    }
+
+    for entity in &game_entities {
+        commands.entity(entity).despawn();
+    }
+    commands.run_system_cached(setup_game.pipe(count_level_attempt));
+}
+
 /// Run condition: returns true if we're resuming from pause
 fn resuming_from_pause(resume_flag: Res<ResumeFromPause>) -> bool {
     resume_flag.0
 }
 
+/// Run condition: returns true if the fog of war is turned on in settings
+fn fog_of_war_enabled(settings: Res<GameSettings>) -> bool {
+    settings.fog_of_war
+}
+
 /// Clears the resume flag after entering Playing state
 fn clear_resume_flag(mut resume_flag: ResMut<ResumeFromPause>) {
     resume_flag.0 = false;
 }
 
+/// Builds the race for the current level: the track, the cars and the HUD.
+/// Returns whether the track could be built (on failure the game goes back to the level menu).
 fn setup_game(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
-    current_level: Res<CurrentLevel>,
-) {
-    // Use hardcoded tracks for levels 1-3, random tracks for level 4+
-    let track: Track = if current_level.0 <= 3 {
-        get_track(current_level.0)
-    } else {
-        // Generate random track with seed based on level number
-        let config = TrackGeneratorConfig {
-            min_segments: 50,
-            max_segments: 120,
-            target_difficulty: 0.5,
-            seed: current_level.0 as u64,
-        };
-        let generated = generate_random_track(&config)
-            .expect("Failed to generate random track");
-
-        Track {
-            layout: generated.layout,
-            starting_point: generated.starting_point,
-            prop_indices: generated.prop_indices,
-        }
+    (current_level, two_player, current_save, seed_override): (
+        Res<CurrentLevel>,
+        Res<TwoPlayerMode>,
+        Res<CurrentSave>,
+        Res<SeedOverride>,
+    ),
+    settings: Res<GameSettings>,
+    (palette, glow, mut game_rng): (Res<Palette>, Res<GlowSettings>, ResMut<GameRng>),
+    camera: Single<Entity, With<MainCamera>>,
+) -> bool {
+    // Powerup density follows the difficulty (props are placed after the layout,
+    // so the layout for a level is the same on every difficulty)
+    let (min_props, max_props) = settings.difficulty.powerup_count_range();
+    let source = resolve(current_level.0, &seed_override, min_props, max_props);
+    let Some((track, track_info)) = load_track(source) else {
+        // The menus check generation before starting a level, so this is a last resort.
+        // The race resources are still reset so the gameplay systems stay idle (and a
+        // finished previous race doesn't trigger LevelComplete) until the state changes.
+        error!("Could not generate a track for level {}, returning to level menu", current_level.0);
+        init_race_state(&mut commands, None, settings.difficulty, FinishCondition::default());
+        init_checkpoint(&mut commands, Vec2::ZERO, 0.0);
+        commands.set_state(GameState::LevelMenu);
+        return false;
     };
 
-    spawn_car(&mut commands, track.starting_point);
-    spawn_track(&mut commands, &mut meshes, &mut materials, &track);
+    // Kept for the level complete screen, so the track can be shared
+    commands.insert_resource(track_info);
+    commands.insert_resource(TrackStart {
+        position: track.starting_point,
+        direction: track.start_direction,
+    });
 
-    // Spawn start line at the track's starting point (car crosses going up)
-    spawn_start_line(&mut commands, track.starting_point, Direction::Up);
+    // In two-player mode the cars start side by side on the starting segment
+    let players: &[(PlayerId, f32)] = if two_player.0 {
+        &[(PlayerId::One, -TWO_PLAYER_START_OFFSET), (PlayerId::Two, TWO_PLAYER_START_OFFSET)]
+    } else {
+        &[(PlayerId::One, 0.0)]
+    };
+    // Both players drive the class picked in the level menu
+    let car_stats = current_save.get().map(|s| s.car_class).unwrap_or_default().stats();
+    let car_color = current_save.get().map(|s| s.car_color).unwrap_or_default();
+    let theme = apply_level_theme(&mut commands, current_level.0, &glow);
+    // Offset across the road (to the right of the start direction), kept on the road together
+    let offsets: Vec<f32> = players.iter().map(|&(_, x_offset)| x_offset).collect();
+    let spawns = get_car_spawns(&track, &offsets);
+    for (&(player, _), &(start, start_rotation)) in players.iter().zip(&spawns) {
+        let color = player.car_color(car_color);
+        let car = spawn_car(&mut commands, start, start_rotation, player, car_stats, color);
+        commands.entity(car).insert((
+            VisitOrder::for_track(&track),
+            LineCrossing { last_position: start },
+        ));
+        spawn_speedometer_ui(&mut commands, player);
 
-    // Place finish line just behind the car's initial position
-    // Car needs to complete the lap and cross this line from below
-    let finish_position = Vec2::new(
-        track.starting_point.x,
-        track.starting_point.y - CAR_HEIGHT, // Just behind the car
-    );
-    spawn_finish_line(&mut commands, finish_position, Direction::Up);
+        // In two-player mode each player gets half the window, following their own car,
+        // with their own NOS bar and slow-mo indicator in it
+        let view = two_player
+            .0
+            .then(|| spawn_split_screen_camera(&mut commands, player, &settings, theme.bloom_intensity));
+        spawn_nos_boost_bar(&mut commands, player, view);
+        spawn_nos_boost_bar_glow(&mut commands, player, *camera, view);
+        spawn_slow_mo_ui(&mut commands, player, view);
+    }
+    spawn_race_course(&mut commands, &mut meshes, &mut materials, &track, &theme, &palette);
+    spawn_track_props(&mut commands, &track, &mut game_rng);
+    // Coins are only placed for the scoring twist
+    let coin_total = if settings.collect_coins { spawn_track_coins(&mut commands, &track) } else { 0 };
+    let (start_line, _) = get_start_and_finish_positions(&track);
+    spawn_start_gantry(&mut commands, start_line, track.start_direction);
 
     spawn_timer_ui(&mut commands);
-    spawn_multiplier_ui(&mut commands);
-    spawn_nos_boost_bar(&mut commands);
-    spawn_nos_boost_bar_glow(&mut commands);
-    spawn_controls_hint(&mut commands);
+    spawn_multiplier_ui(&mut commands, &palette, settings.difficulty.off_road_multiplier());
+    spawn_volume_overlay(&mut commands);
+    spawn_wall_glow(&mut commands);
+    spawn_finish_blocked_text(&mut commands, track.starting_point, &palette);
+    // The hint only describes player one's keys, and can be turned off in the settings
+    if !two_player.0 && settings.show_controls_hint {
+        spawn_controls_hint(&mut commands);
+    }
     spawn_level_text_ui(&mut commands, &current_level);
-    init_race_state(&mut commands, track.starting_point.y);
+    init_score(&mut commands, coin_total);
+    if coin_total > 0 {
+        spawn_coin_ui(&mut commands, &Score { collected: 0, total: coin_total });
+    }
+    // With medal gating on, only a bronze time unlocks the next level
+    let unlock_target = settings
+        .require_medal_to_unlock
+        .then(|| bronze_target_time(&track.layout));
+    let finish_condition = settings.finish_condition(current_level.0);
+    init_race_state(&mut commands, unlock_target, settings.difficulty, finish_condition);
+    init_ghost_recorder(&mut commands);
+    // Resets and early respawns go to the single-player spawn, kept on the road like the cars
+    let (start_point, start_rotation) = get_car_spawns(&track, &[0.0])[0];
+    init_checkpoint(&mut commands, start_point, start_rotation);
+    true
 }
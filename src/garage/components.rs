@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use crate::car::components::{CarClass, CarColor};
+
+/// Marker component for entities that belong to the garage screen
+/// (its UI, and the camera and car of the preview)
+#[derive(Component)]
+pub struct OnGarageScreen;
+
+/// All actions that can be triggered from garage buttons
+#[derive(Component)]
+pub enum GarageButtonAction {
+    CycleClass,
+    CycleColor,
+    Back,
+}
+
+impl GarageButtonAction {
+    /// Returns the button label for the current selection (None for plain buttons)
+    pub fn label(&self, car_class: CarClass, car_color: CarColor) -> Option<String> {
+        match self {
+            GarageButtonAction::CycleClass => Some(format!("Class: {}", car_class.label())),
+            GarageButtonAction::CycleColor => Some(format!("Color: {}", car_color.label())),
+            GarageButtonAction::Back => None,
+        }
+    }
+}
+
+/// Marker for the car turning in the preview
+#[derive(Component)]
+pub struct GarageCar;
+
+/// Fill of a stat bar, by its index in `STAT_NAMES`
+#[derive(Component)]
+pub struct StatBarFill(pub usize);
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use crate::styles::menu::{MEDIUM_TEXT_FONT_SIZE, SMALL_MARGIN, STANDARD_MARGIN};
+
+// ============================================================================
+// Car Preview
+// ============================================================================
+
+/// Size of the rendered car preview, in pixels
+pub const GARAGE_PREVIEW_SIZE: u32 = 220;
+/// How many times its size on the track the car is drawn in the preview
+pub const GARAGE_CAR_SCALE: f32 = 6.0;
+/// Turn rate of the preview car (radians per second)
+pub const GARAGE_ROTATION_SPEED: f32 = 0.8;
+/// Render layer of the preview camera, apart from the minimap and result card layers
+pub const GARAGE_RENDER_LAYER: usize = 33;
+pub const GARAGE_PREVIEW_BACKGROUND: Color = Color::srgb(0.08, 0.08, 0.1);
+
+// ============================================================================
+// Stat Bars
+// ============================================================================
+
+/// Names of the stat bars, in the order `stat_bar_fractions` returns them
+pub const STAT_NAMES: [&str; 4] = ["Speed", "Accel", "Grip", "Handling"];
+
+// Each bar shows its stat as a fraction of the value that fills it, clamped to 0-1.
+// With these the balanced class (`CAR_MAX_SPEED` etc.) fills about three quarters of
+// each bar, leaving room either side for the other classes.
+/// Top speed that fills the speed bar
+pub const STAT_BAR_FULL_SPEED: f32 = 320.0;
+/// Acceleration that fills the acceleration bar
+pub const STAT_BAR_FULL_ACCELERATION: f32 = 1300.0;
+/// Turn speed that fills the handling bar
+pub const STAT_BAR_FULL_TURN_SPEED: f32 = 4.0;
+/// Drift factor that empties the grip bar (full grip, a drift factor of 0, fills it)
+pub const STAT_BAR_EMPTY_GRIP_DRIFT: f32 = 0.25;
+
+// ============================================================================
+// Layout
+// ============================================================================
+
+pub const STAT_LABEL_WIDTH: f32 = 130.0;
+pub const STAT_LABEL_FONT_SIZE: f32 = MEDIUM_TEXT_FONT_SIZE;
+pub const STAT_BAR_WIDTH: f32 = 220.0;
+pub const STAT_BAR_HEIGHT: f32 = 14.0;
+pub const STAT_ROW_GAP: f32 = SMALL_MARGIN;
+pub const STAT_BAR_BACKGROUND: Color = Color::srgb(0.25, 0.25, 0.3);
+pub const STAT_BAR_FILL_COLOR: Color = Color::srgb(0.3, 0.8, 1.0);
+/// Gap between the preview and the stats beside it
+pub const GARAGE_PANEL_GAP: f32 = STANDARD_MARGIN * 2.0;
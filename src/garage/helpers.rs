@@ -0,0 +1,38 @@
+use crate::car::components::CarStats;
+use crate::garage::constants::{
+    STAT_BAR_EMPTY_GRIP_DRIFT, STAT_BAR_FULL_ACCELERATION, STAT_BAR_FULL_SPEED,
+    STAT_BAR_FULL_TURN_SPEED,
+};
+
+/// Fill (0.0 to 1.0) of each stat bar for a car's handling, in `STAT_NAMES` order.
+/// See the `STAT_BAR_*` constants for the values that fill a bar.
+pub fn stat_bar_fractions(stats: &CarStats) -> [f32; 4] {
+    [
+        stats.max_speed / STAT_BAR_FULL_SPEED,
+        stats.acceleration / STAT_BAR_FULL_ACCELERATION,
+        1.0 - stats.lateral_grip / STAT_BAR_EMPTY_GRIP_DRIFT,
+        stats.turn_speed / STAT_BAR_FULL_TURN_SPEED,
+    ]
+    .map(|fraction| fraction.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::car::components::CarClass;
+
+    #[test]
+    fn test_bars_rank_the_classes() {
+        let [speedster, balanced, gripper] =
+            [CarClass::Speedster, CarClass::Balanced, CarClass::Gripper]
+                .map(|class| stat_bar_fractions(&class.stats()));
+
+        // No class maxes out or empties a bar
+        for bars in [speedster, balanced, gripper] {
+            assert!(bars.iter().all(|&fill| fill > 0.0 && fill < 1.0));
+        }
+        // Speed and grip trade off against each other
+        assert!(speedster[0] > balanced[0] && balanced[0] > gripper[0]);
+        assert!(gripper[2] > balanced[2] && balanced[2] > speedster[2]);
+    }
+}
@@ -0,0 +1,35 @@
+pub mod components;
+pub mod constants;
+pub mod helpers;
+pub mod systems;
+
+use bevy::prelude::*;
+use crate::constants::GameState;
+use crate::save::CurrentSave;
+use crate::styles::menu::standard_button_system;
+use crate::utils::despawn_all;
+use components::OnGarageScreen;
+use systems::{garage_action, rotate_garage_car, spawn_garage, update_garage};
+
+/// Garage screen, where the car class and color for the next races are picked
+/// while a preview of the car turns next to its stats
+pub struct GaragePlugin;
+
+impl Plugin for GaragePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(GameState::Garage), spawn_garage)
+            .add_systems(OnExit(GameState::Garage), despawn_all::<OnGarageScreen>)
+            .add_systems(
+                Update,
+                (
+                    standard_button_system,
+                    garage_action,
+                    update_garage.run_if(resource_changed::<CurrentSave>),
+                    rotate_garage_car,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Garage)),
+            );
+    }
+}
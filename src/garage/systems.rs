@@ -0,0 +1,245 @@
+use bevy::camera::{visibility::RenderLayers, RenderTarget};
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+
+use crate::car::components::{CarClass, CarColor, PlayerId};
+use crate::car::constants::{CAR_HEIGHT, CAR_WIDTH};
+use crate::constants::GameState;
+use crate::garage::components::{GarageButtonAction, GarageCar, OnGarageScreen, StatBarFill};
+use crate::garage::constants::*;
+use crate::garage::helpers::stat_bar_fractions;
+use crate::save::{save_or_report, CurrentSave, SaveResult};
+use crate::settings::components::GameSettings;
+use crate::styles::colors::{MENU_BACKGROUND_COLOR, MENU_TEXT_COLOR};
+use crate::styles::menu::{
+    button_row, column_centered, spawn_menu_container, spawn_standard_button, title_style,
+};
+
+// ============================================================================
+// Garage Spawning
+// ============================================================================
+
+/// Spawns the garage screen: the car preview with the chosen class's stat bars beside it,
+/// and buttons to change the class and color.
+/// The preview is a sprite of the car drawn by an off-screen camera into the image shown.
+pub fn spawn_garage(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    current_save: Res<CurrentSave>,
+) {
+    let car_class = current_save.get().map(|s| s.car_class).unwrap_or_default();
+    let car_color = current_save.get().map(|s| s.car_color).unwrap_or_default();
+    let preview_image = spawn_car_preview(&mut commands, &mut images, car_color);
+
+    spawn_menu_container(&mut commands, OnGarageScreen, MENU_BACKGROUND_COLOR)
+        .with_children(|parent| {
+            parent.spawn(column_centered()).with_children(|parent| {
+                parent.spawn((Text::new("Garage"), title_style()));
+
+                parent
+                    .spawn(Node {
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(GARAGE_PANEL_GAP),
+                        ..default()
+                    })
+                    .with_children(|parent| {
+                        parent.spawn((
+                            ImageNode::new(preview_image),
+                            Node {
+                                width: Val::Px(GARAGE_PREVIEW_SIZE as f32),
+                                height: Val::Px(GARAGE_PREVIEW_SIZE as f32),
+                                ..default()
+                            },
+                        ));
+                        spawn_stat_bars(parent, car_class);
+                    });
+
+                parent.spawn(button_row()).with_children(|parent| {
+                    for action in [GarageButtonAction::CycleClass, GarageButtonAction::CycleColor] {
+                        let label = action.label(car_class, car_color).unwrap_or_default();
+                        spawn_standard_button(parent, &label, action);
+                    }
+                    spawn_standard_button(parent, "Back", GarageButtonAction::Back);
+                });
+            });
+        });
+}
+
+/// Spawns the preview camera rendering to a new image, and the car it shows.
+/// Returns the image for the UI to display.
+fn spawn_car_preview(
+    commands: &mut Commands,
+    images: &mut Assets<Image>,
+    car_color: CarColor,
+) -> Handle<Image> {
+    let image = images.add(Image::new_target_texture(
+        GARAGE_PREVIEW_SIZE,
+        GARAGE_PREVIEW_SIZE,
+        TextureFormat::Rgba8UnormSrgb,
+    ));
+    let render_layer = RenderLayers::layer(GARAGE_RENDER_LAYER);
+
+    commands.spawn((
+        Camera2d,
+        Camera {
+            target: RenderTarget::from(image.clone()),
+            order: -1, // Render before the main camera
+            clear_color: ClearColorConfig::Custom(GARAGE_PREVIEW_BACKGROUND),
+            ..default()
+        },
+        render_layer.clone(),
+        OnGarageScreen,
+    ));
+    commands.spawn((
+        Sprite {
+            color: PlayerId::One.car_color(car_color),
+            custom_size: Some(Vec2::new(CAR_WIDTH, CAR_HEIGHT) * GARAGE_CAR_SCALE),
+            ..default()
+        },
+        render_layer,
+        GarageCar,
+        OnGarageScreen,
+    ));
+
+    image
+}
+
+/// Spawns a labeled bar for each stat of the car class
+fn spawn_stat_bars(parent: &mut ChildSpawnerCommands, car_class: CarClass) {
+    let fractions = stat_bar_fractions(&car_class.stats());
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(STAT_ROW_GAP),
+            ..default()
+        })
+        .with_children(|parent| {
+            for (index, (name, fraction)) in STAT_NAMES.iter().zip(fractions).enumerate() {
+                parent
+                    .spawn(Node {
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(*name),
+                            TextFont {
+                                font_size: STAT_LABEL_FONT_SIZE,
+                                ..default()
+                            },
+                            TextColor(MENU_TEXT_COLOR),
+                            Node {
+                                width: Val::Px(STAT_LABEL_WIDTH),
+                                ..default()
+                            },
+                        ));
+                        row.spawn((
+                            Node {
+                                width: Val::Px(STAT_BAR_WIDTH),
+                                height: Val::Px(STAT_BAR_HEIGHT),
+                                ..default()
+                            },
+                            BackgroundColor(STAT_BAR_BACKGROUND),
+                        ))
+                        .with_children(|bar| {
+                            bar.spawn((
+                                Node {
+                                    width: Val::Percent(fraction * 100.0),
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                BackgroundColor(STAT_BAR_FILL_COLOR),
+                                StatBarFill(index),
+                            ));
+                        });
+                    });
+            }
+        });
+}
+
+// ============================================================================
+// Button Actions
+// ============================================================================
+
+/// Handles garage button actions. The class and color are saved to the profile at once.
+pub fn garage_action(
+    interaction_query: Query<
+        (&Interaction, &GarageButtonAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut current_save: ResMut<CurrentSave>,
+    mut save_results: MessageWriter<SaveResult>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, button_action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(save_data) = current_save.get_mut() else {
+            if matches!(button_action, GarageButtonAction::Back) {
+                game_state.set(GameState::LevelMenu);
+            }
+            continue;
+        };
+        match button_action {
+            GarageButtonAction::CycleClass => {
+                save_data.car_class = save_data.car_class.next();
+            }
+            GarageButtonAction::CycleColor => {
+                save_data.car_color = save_data.car_color.next();
+            }
+            GarageButtonAction::Back => {
+                game_state.set(GameState::LevelMenu);
+                continue;
+            }
+        }
+        save_or_report(save_data, &mut save_results);
+    }
+}
+
+// ============================================================================
+// Preview Updates
+// ============================================================================
+
+/// Refreshes the button labels, stat bars and car color after the selection changes
+pub fn update_garage(
+    current_save: Res<CurrentSave>,
+    button_query: Query<(&GarageButtonAction, &Children)>,
+    mut text_query: Query<&mut Text>,
+    mut bar_query: Query<(&StatBarFill, &mut Node)>,
+    mut car: Single<&mut Sprite, With<GarageCar>>,
+) {
+    let car_class = current_save.get().map(|s| s.car_class).unwrap_or_default();
+    let car_color = current_save.get().map(|s| s.car_color).unwrap_or_default();
+
+    for (action, children) in &button_query {
+        let Some(label) = action.label(car_class, car_color) else {
+            continue;
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) {
+                **text = label.clone();
+            }
+        }
+    }
+
+    let fractions = stat_bar_fractions(&car_class.stats());
+    for (fill, mut node) in bar_query.iter_mut() {
+        node.width = Val::Percent(fractions[fill.0] * 100.0);
+    }
+
+    car.color = PlayerId::One.car_color(car_color);
+}
+
+/// Turns the preview car (held still when motion is reduced)
+pub fn rotate_garage_car(
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    mut car: Single<&mut Transform, With<GarageCar>>,
+) {
+    if settings.reduced_motion {
+        return;
+    }
+    car.rotate_z(GARAGE_ROTATION_SPEED * time.delta_secs());
+}
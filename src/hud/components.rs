@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 use bevy::time::Stopwatch;
 
+use crate::car::components::PlayerId;
+use crate::hud::constants::{START_LIGHT_COUNT, START_LIGHT_INTERVAL};
+use crate::settings::components::{Difficulty, FinishCondition};
+
 /// Marker component for the level text
 #[derive(Component)]
 pub struct LevelText;
@@ -13,22 +17,131 @@ pub struct TimerText;
 #[derive(Component)]
 pub struct MultiplierText;
 
-/// Marker component for the NOS boost availability bar container
+/// Marker component for the coin counter text (only spawned when coins are placed)
+#[derive(Component)]
+pub struct CoinText;
+
+/// Component for the slow-mo indicator text of the given player's car
+#[derive(Component)]
+pub struct SlowMoText(pub PlayerId);
+
+/// Component for a speedometer text, showing the speed of the given player's car
+#[derive(Component)]
+pub struct SpeedometerText(pub PlayerId);
+
+/// Component for the NOS boost availability bar container of the given player's car.
+/// The fill and label are its children.
+#[derive(Component)]
+pub struct NosBoostBarContainer(pub PlayerId);
+
+/// Component for the NOS boost availability bar fill.
+/// Holds the fraction currently drawn, which eases up to the boost's remaining fraction.
+#[derive(Component, Default)]
+pub struct NosBoostBarFill {
+    pub shown: f32,
+}
+
+/// Marker component for the remaining-seconds label drawn over the NOS bar
+#[derive(Component)]
+pub struct NosBoostBarText;
+
+/// Component for the "complete the whole track" message shown near the finish line.
+/// Counts down the seconds the message stays visible.
+#[derive(Component)]
+pub struct FinishBlockedText {
+    pub remaining: f32,
+}
+
+/// Message sent when the car crosses the finish line before visiting every segment
+#[derive(Message)]
+pub struct FinishBlocked;
+
+/// Component for the light gantry over the start line. Its lights follow `RaceState::start_lights`;
+/// the timer counts down the green lights before it's removed.
+#[derive(Component)]
+pub struct StartGantry {
+    pub go_timer: Timer,
+}
+
+/// Component for the lights on the start gantry, with their place along it (the first comes on first)
+#[derive(Component)]
+pub struct StartLight(pub usize);
+
+/// Component for the NOS bar glow sprites of the given player's car (world-space for bloom effect).
+/// The sprites are children of the camera, offset to line up with the UI bar.
+#[derive(Component)]
+pub struct NosBoostBarGlow(pub PlayerId);
+
+/// Screen edge a wall warning glow sits on
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WallSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Glow sprite along one window boundary, fading in as a car nears that invisible wall
+#[derive(Component)]
+pub struct WallProximityGlow(pub WallSide);
+
+/// Component for the volume overlay shown after a volume hotkey press.
+/// Fades out like the controls hint; the timer restarts on every press.
 #[derive(Component)]
-pub struct NosBoostBarContainer;
+pub struct VolumeOverlay {
+    /// Seconds since the last volume change
+    pub timer: f32,
+}
 
-/// Marker component for the NOS boost availability bar fill
+/// Marker component for the volume bar fill
 #[derive(Component)]
-pub struct NosBoostBarFill;
+pub struct VolumeBarFill;
+
+/// Marker component for the volume label drawn over the bar
+#[derive(Component)]
+pub struct VolumeText;
+
+/// Component for the live minimap container, holding how the track was fitted into it
+#[derive(Component, Clone, Copy)]
+pub struct LiveMinimap {
+    /// World-space corner of the track bounds drawn at the track's left edge
+    pub min: Vec2,
+    /// World-space corner of the track bounds drawn at the track's top edge
+    pub max: Vec2,
+    /// Minimap pixels per world unit
+    pub scale: f32,
+}
+
+impl LiveMinimap {
+    /// Size of the drawn track in minimap pixels
+    pub fn track_size(&self) -> Vec2 {
+        (self.max - self.min) * self.scale
+    }
+
+    /// Converts a world position to minimap pixels from the track's top-left
+    /// (UI coordinates grow downwards, so Y is flipped against the top edge)
+    pub fn map_position(&self, world: Vec2) -> Vec2 {
+        Vec2::new(world.x - self.min.x, self.max.y - world.y) * self.scale
+    }
+}
+
+/// The parts of the live minimap moved each frame to follow the car (see `orient_live_minimap`)
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MinimapPart {
+    /// Fills the container and turns the track around its center in heading-up mode
+    Pivot,
+    /// Holds the segment cells, shifted to bring the car to the center in heading-up mode
+    Track,
+    /// Shows where the car is and which way it faces
+    CarMarker,
+}
 
-/// Component for the NOS bar glow sprites (world-space for bloom effect).
-/// Stores the relative offset from the bar center for positioning.
+/// Component added to a road segment linking it to its cell on the live minimap.
+/// One cell is spawned per segment, so a newly visited segment recolors just its own
+/// cell instead of the whole minimap being redrawn.
 #[derive(Component)]
-pub struct NosBoostBarGlow {
-    /// Relative X offset from bar center
-    pub offset_x: f32,
-    /// Relative Y offset from bar center
-    pub offset_y: f32,
+pub struct MinimapLink {
+    pub cell: Entity,
 }
 
 /// Component for the controls hint that fades out
@@ -42,6 +155,31 @@ pub struct ControlsHint {
     pub fade_duration: f32,
 }
 
+/// Component on every HUD element, so the HUD toggle (see `HudVisible`) can hide them all.
+/// While the HUD is hidden, it keeps the visibility the element's own systems last gave it,
+/// which is restored when the HUD is shown again.
+#[derive(Component, Default)]
+pub struct HudElement {
+    pub restore: Option<Visibility>,
+}
+
+/// Resource tracking whether the HUD is shown, toggled with `HUD_TOGGLE_KEY` for clean screenshots
+#[derive(Resource)]
+pub struct HudVisible(pub bool);
+
+impl Default for HudVisible {
+    fn default() -> Self {
+        HudVisible(true)
+    }
+}
+
+/// Component on each racing car storing its position last frame, for line-crossing detection.
+/// Crossing = car moved from one side of a line to the other between frames.
+#[derive(Component)]
+pub struct LineCrossing {
+    pub last_position: Vec2,
+}
+
 /// The current status of the race
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
 pub enum RaceStatus {
@@ -63,13 +201,40 @@ pub struct RaceState {
     pub status: RaceStatus,
     /// The final recorded time (set when race finishes)
     pub final_time: Option<f32>,
-    /// Car's Y position last frame (for crossing detection)
-    pub car_last_y: f32,
     /// Whether the car is currently on the road (affects timer speed)
     pub is_on_road: bool,
+    /// Race time counted while on the road (seconds)
+    pub on_road_time: f32,
+    /// Race time counted while off the road, including the multiplier (seconds)
+    pub off_road_time: f32,
+    /// Time added by respawn penalties (seconds), see `add_penalty`
+    pub penalty_time: f32,
+    /// False if the finish failed visit-order validation (the time is flagged invalid)
+    pub is_valid: bool,
+    /// Time the run must beat to unlock the next level (None when any finish unlocks it)
+    pub unlock_target: Option<f32>,
+    /// The car that crossed the finish first (only set once the race is finished)
+    pub winner: Option<PlayerId>,
+    /// Difficulty the race was started on. Changing it in the pause menu's options only
+    /// applies from the next race, so it can't be lowered for part of a run.
+    pub difficulty: Difficulty,
+    /// Finish condition of the level when the race started (see `GameSettings::finish_condition`)
+    pub finish_condition: FinishCondition,
+    /// Times the start lights: the red ones come on one at a time, then they all turn green.
+    /// The cars are held on the grid until then (see `start_lights_green`).
+    pub start_lights: Timer,
 }
 
 impl RaceState {
+    /// Whether the start lights have turned green, letting the cars drive off
+    pub fn lights_green(&self) -> bool {
+        self.start_lights.is_finished()
+    }
+
+    /// Number of red start lights lit so far
+    pub fn red_lights(&self) -> usize {
+        ((self.start_lights.elapsed_secs() / START_LIGHT_INTERVAL) as usize).min(START_LIGHT_COUNT)
+    }
 
     pub fn start_race(&mut self) {
         self.status = RaceStatus::Racing;
@@ -77,16 +242,17 @@ impl RaceState {
         self.stopwatch.unpause();
     }
 
-    pub fn finish_race(&mut self) {
+    pub fn finish_race(&mut self, winner: PlayerId) {
         self.status = RaceStatus::Finished;
         self.stopwatch.pause();
         self.final_time = Some(self.stopwatch.elapsed_secs());
+        self.winner = Some(winner);
     }
 
-    /// Stores the car's Y position from this frame for next frame's crossing detection.
-    /// Crossing = car moved from one side of a line to the other between frames.
-    pub fn set_previous_car_y(&mut self, y: f32) {
-        self.car_last_y = y;
+    /// Adds a time penalty to the race timer (e.g. for a checkpoint respawn)
+    pub fn add_penalty(&mut self, seconds: f32) {
+        self.stopwatch.tick(std::time::Duration::from_secs_f32(seconds));
+        self.penalty_time += seconds;
     }
 
 }
@@ -4,8 +4,12 @@ use bevy::prelude::*;
 // Race/Timer Constants
 // ============================================================================
 
-/// Time multiplier applied when the car is off the road
+/// Time multiplier applied when the car is off the road (per difficulty)
+pub const CASUAL_OFF_ROAD_TIME_MULTIPLIER: f32 = 4.0;
 pub const OFF_ROAD_TIME_MULTIPLIER: f32 = 10.0;
+pub const HARD_OFF_ROAD_TIME_MULTIPLIER: f32 = 15.0;
+/// Characters the timer is padded to, so its width holds steady up to 999.99 seconds
+pub const TIMER_DISPLAY_WIDTH: usize = 6;
 
 // ============================================================================
 // HUD Layout Constants
@@ -19,6 +23,106 @@ pub const HUD_PADDING: f32 = 10.0;
 pub const MULTIPLIER_FONT_SIZE_RATIO: f32 = 0.7;
 /// Spacing between timer and multiplier text
 pub const MULTIPLIER_TOP_SPACING: f32 = 5.0;
+/// Coin counter font size ratio relative to HUD_FONT_SIZE
+pub const COIN_FONT_SIZE_RATIO: f32 = 0.7;
+/// Spacing between the level text and the coin counter
+pub const COIN_TOP_SPACING: f32 = 5.0;
+/// Slow-mo indicator font size ratio relative to HUD_FONT_SIZE
+pub const SLOW_MO_FONT_SIZE_RATIO: f32 = 0.6;
+/// Spacing between the NOS bar and the slow-mo indicator below it
+pub const SLOW_MO_TOP_SPACING: f32 = 8.0;
+
+// ============================================================================
+// Speedometer Constants
+// ============================================================================
+
+/// Scale from game units (pixels per second) to km/h. Tune this to change how fast
+/// the car feels; mph is derived from it.
+pub const GAME_UNITS_TO_KPH: f32 = 0.72;
+/// Miles per kilometer (fixed, converts the km/h value to mph)
+pub const MPH_PER_KPH: f32 = 0.621_371;
+/// Speedometer font size ratio relative to HUD_FONT_SIZE
+pub const SPEEDOMETER_FONT_SIZE_RATIO: f32 = 0.7;
+
+// ============================================================================
+// Volume Overlay Constants
+// ============================================================================
+
+/// Width of the volume bar
+pub const VOLUME_BAR_WIDTH: f32 = 200.0;
+/// Height of the volume bar
+pub const VOLUME_BAR_HEIGHT: f32 = 20.0;
+/// Distance of the volume bar from the bottom of the screen
+pub const VOLUME_BAR_BOTTOM: f32 = 40.0;
+/// Background color for the volume bar (matches the NOS bar)
+pub const VOLUME_BAR_BG_COLOR: Color = Color::srgba(0.1, 0.1, 0.1, 0.8);
+/// Fill color for the volume bar
+pub const VOLUME_BAR_FILL_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+/// Font size for the volume label
+pub const VOLUME_TEXT_FONT_SIZE: f32 = 14.0;
+/// Volume label color (readable over both the fill and the background)
+pub const VOLUME_TEXT_COLOR: Color = Color::srgb(0.4, 0.4, 0.4);
+/// Delay before the volume overlay starts fading (seconds)
+pub const VOLUME_FADE_DELAY: f32 = 1.0;
+/// Duration of the volume overlay fade (seconds)
+pub const VOLUME_FADE_DURATION: f32 = 0.5;
+
+// ============================================================================
+// Live Minimap Constants
+// ============================================================================
+
+/// Length of the live minimap's longer side (the track is scaled to fit)
+pub const LIVE_MINIMAP_SIZE: f32 = 160.0;
+/// Distance of the live minimap from the bottom of the screen (above the speedometer)
+pub const LIVE_MINIMAP_BOTTOM: f32 = HUD_PADDING * 2.0 + HUD_FONT_SIZE * SPEEDOMETER_FONT_SIZE_RATIO;
+/// Padding between the minimap background and the track
+pub const LIVE_MINIMAP_PADDING: f32 = 6.0;
+/// Background color behind the live minimap
+pub const LIVE_MINIMAP_BG_COLOR: Color = Color::srgba(0.1, 0.1, 0.1, 0.6);
+/// Minimap color for segments not yet driven
+pub const LIVE_MINIMAP_ROAD_COLOR: Color = Color::srgb(0.35, 0.35, 0.35);
+/// Minimap color for visited segments (matches the visited edge glow)
+pub const LIVE_MINIMAP_VISITED_COLOR: Color = Color::srgb(0.45, 0.45, 1.0);
+/// Size of the car marker (longer along the car, so it shows the heading)
+pub const LIVE_MINIMAP_MARKER_SIZE: Vec2 = Vec2::new(4.0, 8.0);
+/// Color of the car marker
+pub const LIVE_MINIMAP_MARKER_COLOR: Color = Color::WHITE;
+
+// ============================================================================
+// Finish Blocked Message Constants
+// ============================================================================
+
+/// Message shown when crossing the finish line with segments left to visit
+pub const FINISH_BLOCKED_MESSAGE: &str = "Complete the whole track first!";
+/// How long the message stays visible (seconds); it fades out over the last second
+pub const FINISH_BLOCKED_DURATION: f32 = 2.5;
+/// Font size of the message
+pub const FINISH_BLOCKED_FONT_SIZE: f32 = 22.0;
+/// Distance above the finish line the message is drawn (world units)
+pub const FINISH_BLOCKED_OFFSET_Y: f32 = 45.0;
+/// Z-index for the message (above the car)
+pub const FINISH_BLOCKED_Z: f32 = 5.0;
+
+// ============================================================================
+// Start Gantry Constants
+// ============================================================================
+
+/// Number of lights along the gantry
+pub const START_LIGHT_COUNT: usize = 3;
+/// Side length of each light (world units)
+pub const START_LIGHT_SIZE: f32 = 10.0;
+/// Thickness of the bar the lights hang from (it spans the road like the start line)
+pub const START_GANTRY_THICKNESS: f32 = 6.0;
+/// Color of the bar
+pub const START_GANTRY_COLOR: Color = Color::srgb(0.25, 0.25, 0.28);
+/// Time between the red lights coming on one at a time, and from the last one to green (seconds)
+pub const START_LIGHT_INTERVAL: f32 = 0.7;
+/// Color of a light that hasn't come on yet
+pub const START_LIGHT_OFF_COLOR: Color = Color::srgb(0.12, 0.12, 0.14);
+/// How long the lights stay green before the gantry is removed (seconds)
+pub const START_GANTRY_GO_DURATION: f32 = 1.0;
+/// Z-index for the gantry (overhead, above the car)
+pub const START_GANTRY_Z: f32 = 6.0;
 
 // ============================================================================
 // Controls Hint Constants
@@ -48,6 +152,10 @@ pub const CONTROLS_HINT_COLOR: Color = Color::srgba(
 pub const CONTROLS_FADE_DELAY: f32 = 3.0;
 /// Duration of the fade animation (seconds)
 pub const CONTROLS_FADE_DURATION: f32 = 1.0;
+/// Keys that dismiss the controls hint at once
+pub const CONTROLS_HINT_DISMISS_KEYS: [KeyCode; 2] = [KeyCode::Enter, KeyCode::NumpadEnter];
+/// Key that hides and shows the whole HUD
+pub const HUD_TOGGLE_KEY: KeyCode = KeyCode::F1;
 
 // ============================================================================
 // Controls Hint Arrow Gizmo Constants
@@ -66,6 +174,19 @@ pub const ARROW_VERTICAL_OFFSET: f32 = 5.0;
 /// Horizontal offset for left/right steer arrows from center
 pub const ARROW_STEER_OFFSET: f32 = 6.0;
 
+// ============================================================================
+// Guide Arrow Gizmo Constants
+// ============================================================================
+
+/// Distance from the car's center to the start of the guide arrow
+pub const GUIDE_ARROW_OFFSET: f32 = 25.0;
+/// Length of the guide arrow
+pub const GUIDE_ARROW_LENGTH: f32 = 30.0;
+/// Size of the guide arrow head
+pub const GUIDE_ARROW_HEAD_SIZE: f32 = 10.0;
+/// Guide arrow color (slightly emissive so it glows)
+pub const GUIDE_ARROW_COLOR: Color = Color::srgba(1.6, 1.3, 0.3, 0.8);
+
 /// Velocity threshold squared to detect if player has moved
 pub const PLAYER_MOVED_VELOCITY_THRESHOLD: f32 = 10.0;
 
@@ -99,6 +220,12 @@ pub const NOS_BAR_BG_COLOR: Color = Color::srgba(0.1, 0.1, 0.1, 0.8);
 pub const NOS_BAR_FILL_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
 /// Border color for the bar (non-glowing UI border - dark to not interfere with glow)
 pub const NOS_BAR_BORDER_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.0);
+/// Seconds the fill takes to rise from empty to full when a boost is picked up
+pub const NOS_BAR_FILL_EASE_DURATION: f32 = 0.1;
+/// Font size for the remaining-seconds label on the bar
+pub const NOS_BAR_TEXT_FONT_SIZE: f32 = 16.0;
+/// Label color (dark cyan, readable over both the white fill and the dark background)
+pub const NOS_BAR_TEXT_COLOR: Color = Color::srgb(0.0, 0.55, 0.55);
 
 // -- NOS Boost Bar Glow (Sprite-based for bloom effect) -- //
 /// Glow edge thickness (matches powerup thickness)
@@ -107,3 +234,18 @@ pub const NOS_BAR_GLOW_THICKNESS: f32 = 2.0;
 pub const NOS_BAR_GLOW_COLOR: Color = Color::srgb(0.0, 1.7, 1.7);
 /// Z-index for glow sprites (above most game elements)
 pub const NOS_BAR_GLOW_Z: f32 = 100.0;
+
+// ============================================================================
+// Wall Proximity Warning Constants
+// ============================================================================
+
+/// Distance from a window boundary at which its glow starts to fade in
+pub const WALL_WARNING_DISTANCE: f32 = 60.0;
+/// Thickness of each edge glow sprite
+pub const WALL_GLOW_THICKNESS: f32 = 14.0;
+/// Glow alpha when the car touches the boundary
+pub const WALL_GLOW_MAX_ALPHA: f32 = 0.6;
+/// Edge glow color (alpha is set from the car's proximity)
+pub const WALL_GLOW_COLOR: Color = Color::srgb(1.0, 0.35, 0.2);
+/// Z-index for the edge glow (above the track and cars)
+pub const WALL_GLOW_Z: f32 = 50.0;
@@ -1,33 +1,209 @@
-use crate::road::components::Direction;
-use crate::road::constants::ROAD_WIDTH;
+use bevy::prelude::*;
 
-/// Horizontal tolerance for detecting line crossing (half the road width)
-pub const LINE_X_TOLERANCE: f32 = ROAD_WIDTH / 2.0;
+use crate::hud::constants::{
+    GAME_UNITS_TO_KPH, MPH_PER_KPH, NOS_BAR_FILL_EASE_DURATION, TIMER_DISPLAY_WIDTH,
+    WALL_GLOW_MAX_ALPHA, WALL_WARNING_DISTANCE,
+};
+use crate::props::components::Score;
+use crate::road::components::{Direction, RoadSegment, RoadSegmentType};
+use crate::road::constants::{ROAD_SEGMENT_LENGTH, ROAD_WIDTH};
+use crate::road::helpers::{get_direction_vector, get_exit_direction};
+use crate::settings::components::SpeedUnit;
 
-/// Check if the car is within the horizontal bounds of a line
-pub fn is_within_line_x_bounds(car_x: f32, line_x: f32) -> bool {
-    (car_x - line_x).abs() < LINE_X_TOLERANCE
+/// Tolerance across the road for detecting line crossing (half the road width)
+pub const LINE_HALF_WIDTH: f32 = ROAD_WIDTH / 2.0;
+
+/// Check if the car is within the bounds of a line crossed in the given direction
+/// (the line spans the road, perpendicular to the direction)
+pub fn is_within_line_bounds(car_pos: Vec2, line_pos: Vec2, direction: Direction) -> bool {
+    let across = get_direction_vector(direction).perp();
+    (car_pos - line_pos).dot(across).abs() < LINE_HALF_WIDTH
 }
 
 /// Check if the car has crossed a line in the specified direction.
 /// Returns true if the car was on one side of the line last frame and is now on the other side.
 ///
 /// # Arguments
-/// * `car_y` - Current Y position of the car
-/// * `last_y` - Y position of the car last frame
-/// * `line_y` - Y position of the line
+/// * `car_pos` - Current position of the car
+/// * `last_pos` - Position of the car last frame
+/// * `line_pos` - Position of the line
 /// * `direction` - The direction the car must be moving to trigger the crossing
-pub fn has_crossed_line(car_y: f32, last_y: f32, line_y: f32, direction: Direction) -> bool {
-    match direction {
-        Direction::Up => last_y <= line_y && car_y > line_y,
-        Direction::Down => last_y >= line_y && car_y < line_y,
-        // For horizontal movement, we'd check X instead of Y
-        // These can be implemented when needed
-        Direction::Left | Direction::Right => false,
+pub fn has_crossed_line(car_pos: Vec2, last_pos: Vec2, line_pos: Vec2, direction: Direction) -> bool {
+    // Distances past the line, measured along the crossing direction
+    let along = get_direction_vector(direction);
+    let last_distance = (last_pos - line_pos).dot(along);
+    let distance = (car_pos - line_pos).dot(along);
+    last_distance <= 0.0 && distance > 0.0
+}
+
+/// Check if the car's path from `last_pos` to `car_pos` crossed the line in the specified
+/// direction, within the line's bounds. The path is swept rather than sampled: the bounds are
+/// checked where the path meets the line, so a car crossing diagonally at speed counts
+/// whether or not it's within the bounds at either end, and one passing beside the line
+/// doesn't count for ending up in front of it.
+pub fn has_swept_across_line(car_pos: Vec2, last_pos: Vec2, line_pos: Vec2, direction: Direction) -> bool {
+    if !has_crossed_line(car_pos, last_pos, line_pos, direction) {
+        return false;
     }
+    let along = get_direction_vector(direction);
+    let last_distance = (last_pos - line_pos).dot(along);
+    let distance = (car_pos - line_pos).dot(along);
+    // How far along the path it meets the line (the distances have opposite signs)
+    let fraction = last_distance / (last_distance - distance);
+    is_within_line_bounds(last_pos.lerp(car_pos, fraction), line_pos, direction)
+}
+
+/// Text of the coin counter, also shown on the level complete screen
+pub fn coin_label(score: &Score) -> String {
+    format!("Coins: {}/{}", score.collected, score.total)
 }
 
-/// Format elapsed time as a string with 2 decimal places
+/// Text of the off-road warning for the given timer multiplier
+pub fn multiplier_label(multiplier: f32) -> String {
+    format!("Off the road! (x{})", multiplier)
+}
+
+/// Alpha of a wall warning glow for a car the given distance from that wall:
+/// 0 beyond `WALL_WARNING_DISTANCE`, rising linearly to `WALL_GLOW_MAX_ALPHA` at the wall
+pub fn wall_glow_alpha(distance: f32) -> f32 {
+    (1.0 - distance / WALL_WARNING_DISTANCE).clamp(0.0, 1.0) * WALL_GLOW_MAX_ALPHA
+}
+
+/// Fraction of the NOS bar to draw this frame, moving from `shown` towards the boost's `target`.
+/// Rises at a rate that fills an empty bar in `NOS_BAR_FILL_EASE_DURATION`, but drops straight
+/// to the target, so the bar never shows more time than is left. Snaps when motion is reduced.
+pub fn ease_bar_fill(shown: f32, target: f32, delta_secs: f32, reduced_motion: bool) -> f32 {
+    if reduced_motion || target <= shown {
+        return target;
+    }
+    (shown + delta_secs / NOS_BAR_FILL_EASE_DURATION).min(target)
+}
+
+/// Format elapsed time as a string with 2 decimal places, left-padded to `TIMER_DISPLAY_WIDTH`.
+/// The default font is monospaced, so with the padding no glyph moves as the digits change
+/// (longer times still print in full). The padding is always on rather than a setting: the
+/// timer is right-aligned, so the leading spaces never show and there's nothing to opt out of.
 pub fn format_elapsed_time(elapsed_secs: f32) -> String {
-    format!("{:.2}", elapsed_secs)
+    format!("{:>width$.2}", elapsed_secs, width = TIMER_DISPLAY_WIDTH)
+}
+
+/// Converts a speed in game units (pixels per second) to the given display unit
+pub fn convert_speed(speed: f32, unit: SpeedUnit) -> f32 {
+    match unit {
+        SpeedUnit::GameUnits => speed,
+        SpeedUnit::Kph => speed * GAME_UNITS_TO_KPH,
+        SpeedUnit::Mph => speed * GAME_UNITS_TO_KPH * MPH_PER_KPH,
+    }
+}
+
+/// Returns the world-space (center, size) of the grid cells a road segment covers.
+/// Straights are centered on their transform; corner transforms sit on the inner
+/// pivot, so the cell center is half a cell back along the exit direction and half
+/// a cell forward along the entry direction.
+pub fn segment_cell_rect(segment: &RoadSegment, transform: &Transform) -> (Vec2, Vec2) {
+    let position = transform.translation.truncate();
+    match segment.segment_type {
+        RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => {
+            let length = segment.segment_type.length_in_cells() as f32 * ROAD_SEGMENT_LENGTH;
+            let size = match segment.direction {
+                Direction::Up | Direction::Down => Vec2::new(ROAD_WIDTH, length),
+                Direction::Left | Direction::Right => Vec2::new(length, ROAD_WIDTH),
+            };
+            (position, size)
+        }
+        RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight => {
+            let entry_vec = get_direction_vector(segment.direction);
+            let exit_vec = get_direction_vector(get_exit_direction(segment.direction, segment.segment_type));
+            let center = position + (entry_vec - exit_vec) * (ROAD_SEGMENT_LENGTH / 2.0);
+            (center, Vec2::splat(ROAD_SEGMENT_LENGTH))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::road::helpers::get_start_and_finish_positions;
+    use crate::road::tracks::get_builtin_track;
+
+    /// Level 1's track turned to start heading down
+    fn down_starting_track() -> (Vec2, Vec2) {
+        let mut track = get_builtin_track(1);
+        track.start_direction = Direction::Down;
+        get_start_and_finish_positions(&track)
+    }
+
+    #[test]
+    fn test_down_starting_track_crosses_lines_heading_down() {
+        let (start, finish) = down_starting_track();
+        // The start line is ahead of the starting point, the finish line behind it
+        assert!(start.y < finish.y);
+
+        let step = Vec2::new(0.0, -2.0);
+        assert!(has_crossed_line(start + step, start - step, start, Direction::Down));
+        assert!(has_crossed_line(finish + step, finish - step, finish, Direction::Down));
+        // Driving back up through a line doesn't count
+        assert!(!has_crossed_line(start - step, start + step, start, Direction::Down));
+    }
+
+    #[test]
+    fn test_line_bounds_span_the_road() {
+        let (start, _) = down_starting_track();
+        let across = Vec2::new(LINE_HALF_WIDTH - 1.0, 0.0);
+        assert!(is_within_line_bounds(start + across, start, Direction::Down));
+        assert!(!is_within_line_bounds(start + across * 2.0, start, Direction::Down));
+        // Distance along the direction of travel doesn't matter
+        assert!(is_within_line_bounds(start + Vec2::new(0.0, -500.0), start, Direction::Down));
+    }
+
+    #[test]
+    fn test_horizontal_crossing() {
+        let line = Vec2::new(100.0, 0.0);
+        assert!(has_crossed_line(Vec2::new(101.0, 0.0), Vec2::new(99.0, 0.0), line, Direction::Right));
+        assert!(!has_crossed_line(Vec2::new(101.0, 0.0), Vec2::new(99.0, 0.0), line, Direction::Left));
+        assert!(has_crossed_line(Vec2::new(99.0, 0.0), Vec2::new(101.0, 0.0), line, Direction::Left));
+        assert!(is_within_line_bounds(Vec2::new(100.0, LINE_HALF_WIDTH - 1.0), line, Direction::Right));
+        assert!(!is_within_line_bounds(Vec2::new(100.0, LINE_HALF_WIDTH + 1.0), line, Direction::Right));
+    }
+
+    #[test]
+    fn test_diagonal_crossing_is_checked_where_it_meets_the_line() {
+        let line = Vec2::ZERO;
+        // Past the bounds at both ends, but through the middle of the line
+        let (last, car) = (Vec2::new(-60.0, -60.0), Vec2::new(60.0, 60.0));
+        assert!(!is_within_line_bounds(last, line, Direction::Up));
+        assert!(!is_within_line_bounds(car, line, Direction::Up));
+        assert!(has_swept_across_line(car, last, line, Direction::Up));
+
+        // Within the bounds at the end, but passed beside the line
+        let (last, car) = (Vec2::new(-60.0, -10.0), Vec2::new(20.0, 70.0));
+        assert!(is_within_line_bounds(car, line, Direction::Up));
+        assert!(!has_swept_across_line(car, last, line, Direction::Up));
+
+        // Straight through still counts, and only in the line's direction
+        let (last, car) = (Vec2::new(10.0, -300.0), Vec2::new(10.0, 300.0));
+        assert!(has_swept_across_line(car, last, line, Direction::Up));
+        assert!(!has_swept_across_line(last, car, line, Direction::Up));
+    }
+
+    #[test]
+    fn test_elapsed_time_keeps_a_fixed_width() {
+        assert_eq!(format_elapsed_time(0.0), "  0.00");
+        assert_eq!(format_elapsed_time(9.999), " 10.00");
+        assert_eq!(format_elapsed_time(999.5), "999.50");
+        // Past the padded width the time still prints in full
+        assert_eq!(format_elapsed_time(12345.5), "12345.50");
+    }
+
+    #[test]
+    fn test_bar_fill_eases_up_but_drops_at_once() {
+        let half_ease = NOS_BAR_FILL_EASE_DURATION / 2.0;
+        // A pickup fills the bar over the ease duration
+        assert_eq!(ease_bar_fill(0.0, 1.0, half_ease, false), 0.5);
+        assert_eq!(ease_bar_fill(0.5, 1.0, NOS_BAR_FILL_EASE_DURATION, false), 1.0);
+        // Counting down is never behind the real time left
+        assert_eq!(ease_bar_fill(0.8, 0.7, half_ease, false), 0.7);
+        // Reduced motion snaps straight to full
+        assert_eq!(ease_bar_fill(0.0, 1.0, half_ease, true), 1.0);
+    }
 }
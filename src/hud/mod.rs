@@ -3,32 +3,62 @@ pub mod constants;
 pub mod helpers;
 pub mod systems;
 
+use bevy::camera::visibility::VisibilitySystems;
 use bevy::prelude::*;
 use crate::constants::GameState;
+use crate::props::components::Score;
+use components::{FinishBlocked, HudVisible};
 use systems::{
-    check_race_finished, check_start_line_crossing, render_controls_hint_arrows, tick_race_timer,
-    update_controls_hint, update_multiplier_display, update_nos_boost_bar,
-    update_nos_boost_bar_glow, update_timer_display,
+    apply_hud_visibility, check_race_finished, check_start_line_crossing, orient_live_minimap,
+    render_controls_hint_arrows, render_guide_arrow, spawn_live_minimap, tick_race_timer, toggle_hud, update_coin_display, update_controls_hint,
+    update_finish_blocked_text, update_live_minimap, update_multiplier_display,
+    update_nos_boost_bar, update_nos_boost_bar_glow, update_slow_mo_display, update_speedometer,
+    update_start_gantry, update_timer_display, update_volume_overlay, update_wall_glow,
 };
 
 pub struct HudPlugin;
 
 impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                check_start_line_crossing,
-                tick_race_timer,
-                update_timer_display,
-                update_multiplier_display,
-                update_nos_boost_bar,
-                update_nos_boost_bar_glow,
-                update_controls_hint,
-                render_controls_hint_arrows,
-                check_race_finished,
+        app.add_message::<FinishBlocked>()
+            .init_resource::<HudVisible>()
+            .add_systems(
+                Update,
+                toggle_hud.run_if(in_state(GameState::Playing).or(in_state(GameState::Replay))),
             )
-                .run_if(in_state(GameState::Playing)),
-        );
+            // After every Update system that shows or hides a HUD element
+            .add_systems(
+                PostUpdate,
+                apply_hud_visibility.before(VisibilitySystems::VisibilityPropagate),
+            )
+            .add_systems(
+                Update,
+                (
+                    check_start_line_crossing,
+                    update_start_gantry,
+                    tick_race_timer,
+                    update_timer_display,
+                    update_multiplier_display,
+                    update_nos_boost_bar,
+                    update_nos_boost_bar_glow,
+                    update_wall_glow,
+                    update_slow_mo_display,
+                    update_coin_display.run_if(resource_exists_and_changed::<Score>),
+                    update_speedometer,
+                    update_volume_overlay,
+                    (spawn_live_minimap, update_live_minimap, orient_live_minimap).chain(),
+                    update_finish_blocked_text,
+                    update_controls_hint,
+                    // Gizmos aren't entities, so the HUD toggle skips drawing them instead
+                    (render_controls_hint_arrows, render_guide_arrow).run_if(hud_is_visible),
+                    check_race_finished,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
     }
 }
+
+/// Run condition: returns true unless the HUD is toggled off
+fn hud_is_visible(hud_visible: Res<HudVisible>) -> bool {
+    hud_visible.0
+}
@@ -1,29 +1,60 @@
 use bevy::prelude::*;
 use bevy::time::Stopwatch;
 
-use crate::car::components::Car;
+use crate::car::components::{Car, PlayerId, SubstepPath};
 use crate::car::components::NosBoostAvailable;
+use crate::car::components::SlowMotion;
 use crate::car::components::Velocity;
-use crate::constants::{CurrentLevel, GameState, WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::car::helpers::{get_car_corners, substep_positions};
+use crate::constants::{
+    CurrentLevel, GameState, PracticeMode, TwoPlayerMode, BOTTOM_BOUNDARY, LEFT_BOUNDARY,
+    RIGHT_BOUNDARY, TOP_BOUNDARY, WINDOW_HEIGHT, WINDOW_WIDTH,
+};
 use crate::hud::components::{
-    ControlsHint, LevelText, MultiplierText, NosBoostBarContainer, NosBoostBarFill,
-    NosBoostBarGlow, RaceState, RaceStatus, TimerText,
+    CoinText, ControlsHint, FinishBlocked, FinishBlockedText, HudElement, HudVisible, LevelText, LineCrossing, LiveMinimap, MinimapLink, MinimapPart, MultiplierText, NosBoostBarContainer, NosBoostBarFill,
+    NosBoostBarGlow, NosBoostBarText, RaceState, RaceStatus, SlowMoText, SpeedometerText, StartGantry,
+    StartLight, TimerText,
+    VolumeBarFill, VolumeOverlay, VolumeText, WallProximityGlow, WallSide,
 };
 use crate::hud::constants::{
     ARROW_BASE_X_OFFSET, ARROW_BASE_Y_OFFSET, ARROW_HEAD_SIZE, ARROW_SIZE, ARROW_STEER_OFFSET,
     ARROW_VERTICAL_OFFSET, CONTROLS_FADE_DELAY, CONTROLS_FADE_DURATION, CONTROLS_HINT_ALPHA,
-    CONTROLS_HINT_LINE_HEIGHT, CONTROLS_HINT_PADDING, CONTROLS_HINT_RGB, CONTROL_LABELS,
+    CONTROLS_HINT_DISMISS_KEYS, CONTROLS_HINT_LINE_HEIGHT, CONTROLS_HINT_PADDING, CONTROLS_HINT_RGB,
+    CONTROL_LABELS,
+    FINISH_BLOCKED_DURATION, FINISH_BLOCKED_MESSAGE, FINISH_BLOCKED_OFFSET_Y, FINISH_BLOCKED_Z,
+    GUIDE_ARROW_COLOR, GUIDE_ARROW_HEAD_SIZE, GUIDE_ARROW_LENGTH, GUIDE_ARROW_OFFSET, HUD_TOGGLE_KEY,
+    LIVE_MINIMAP_PADDING, LIVE_MINIMAP_ROAD_COLOR, LIVE_MINIMAP_SIZE, LIVE_MINIMAP_VISITED_COLOR,
     NOS_BAR_GLOW_COLOR, NOS_BAR_GLOW_THICKNESS, NOS_BAR_GLOW_Z, NOS_BAR_HEIGHT, NOS_BAR_TOP,
-    NOS_BAR_WIDTH, OFF_ROAD_TIME_MULTIPLIER, PLAYER_MOVED_VELOCITY_THRESHOLD,
+    NOS_BAR_WIDTH, PLAYER_MOVED_VELOCITY_THRESHOLD, START_GANTRY_COLOR, START_GANTRY_GO_DURATION,
+    START_GANTRY_THICKNESS, START_GANTRY_Z, START_LIGHT_COUNT, START_LIGHT_INTERVAL, START_LIGHT_OFF_COLOR,
+    START_LIGHT_SIZE, VOLUME_BAR_BG_COLOR,
+    VOLUME_BAR_FILL_COLOR, VOLUME_FADE_DELAY, VOLUME_FADE_DURATION, VOLUME_TEXT_COLOR,
+    WALL_GLOW_COLOR, WALL_GLOW_THICKNESS, WALL_GLOW_Z,
+};
+use crate::hud::helpers::{
+    coin_label, convert_speed, ease_bar_fill, format_elapsed_time, has_swept_across_line,
+    multiplier_label, segment_cell_rect, wall_glow_alpha,
+};
+use crate::props::components::Score;
+use crate::road::components::{
+    Direction, FinishLine, RoadSegment, StartLine, VisitOrder, Visited,
 };
-use crate::hud::helpers::{format_elapsed_time, has_crossed_line, is_within_line_x_bounds};
-use crate::road::components::{Direction, FinishLine, RoadSegment, StartLine, Visited};
+use crate::road::constants::{MAX_VISIT_GAP, STARTING_LINE_WIDTH};
+use crate::road::helpers::{get_rotation, get_segment_center, is_contiguous_traversal};
+use crate::settings::components::{Difficulty, FinishCondition, GameSettings, VolumeChanged};
 use crate::start_menu::components::GameEntity;
 use crate::styles::hud::{
-    controls_hint_line_style, level_text_style, multiplier_style, nos_bar_container_colors,
-    nos_bar_container_style, nos_bar_fill_color, nos_bar_fill_style, timer_color, timer_style,
+    coin_style, controls_hint_line_style, finish_blocked_style, level_text_style, live_minimap_cell_style,
+    live_minimap_container_style, live_minimap_marker_style, live_minimap_pivot_style,
+    live_minimap_track_style, multiplier_style, nos_bar_container_colors,
+    nos_bar_container_style, nos_bar_fill_color, nos_bar_fill_style, nos_bar_text_style,
+    slow_mo_style, speedometer_style, timer_color, timer_style, volume_bar_container_style,
+    volume_bar_fill_style, volume_text_style,
 };
 
+use crate::split_screen::helpers::split_view_layer;
+use crate::styles::palette::Palette;
+use crate::styles::theme::{GlowSettings, Glowing};
 use crate::utils::spawn_hud_element;
 
 /// Spawns the off the road level text UI element
@@ -41,50 +72,162 @@ pub fn spawn_level_text_ui(commands: &mut Commands, current_level: &CurrentLevel
 pub fn spawn_timer_ui(commands: &mut Commands) {
     spawn_hud_element(
         commands,
-        "0.00".to_string(),
+        format_elapsed_time(0.0),
         timer_style(),
         TimerText,
         Visibility::Inherited,
     );
 }
 
-/// Spawns the multiplier indicator UI element below the timer
-pub fn spawn_multiplier_ui(commands: &mut Commands) {
+/// Spawns the multiplier indicator UI element below the timer, showing the off-road multiplier
+pub fn spawn_multiplier_ui(commands: &mut Commands, palette: &Palette, multiplier: f32) {
     spawn_hud_element(
         commands,
-        format!("Off the road! (x{})", OFF_ROAD_TIME_MULTIPLIER as i32),
-        multiplier_style(),
+        multiplier_label(multiplier),
+        multiplier_style(palette),
         MultiplierText,
         Visibility::Hidden,
     );
 }
 
+/// Spawns the coin counter UI element below the level text
+pub fn spawn_coin_ui(commands: &mut Commands, score: &Score) {
+    spawn_hud_element(commands, coin_label(score), coin_style(), CoinText, Visibility::Inherited);
+}
+
+/// Spawns the slow-mo indicator UI element for the given player's car below their NOS bar.
+/// In a split-screen race it's drawn in the player's own view.
+pub fn spawn_slow_mo_ui(commands: &mut Commands, player: PlayerId, view: Option<Entity>) {
+    let text = spawn_hud_element(
+        commands,
+        String::new(),
+        slow_mo_style(),
+        SlowMoText(player),
+        Visibility::Hidden,
+    );
+    if let Some(view) = view {
+        commands.entity(text).insert(UiTargetCamera(view));
+    }
+}
+
+/// Spawns a speedometer UI element for the given player's car in a bottom corner
+pub fn spawn_speedometer_ui(commands: &mut Commands, player: PlayerId) {
+    spawn_hud_element(
+        commands,
+        String::new(),
+        speedometer_style(player),
+        SpeedometerText(player),
+        Visibility::Inherited,
+    );
+}
+
+/// Spawns the hidden "complete the whole track" message just above the track's starting point,
+/// which lies between the start and finish lines
+pub fn spawn_finish_blocked_text(commands: &mut Commands, starting_point: Vec2, palette: &Palette) {
+    commands.spawn((
+        Text2d::new(FINISH_BLOCKED_MESSAGE),
+        finish_blocked_style(palette),
+        Transform::from_xyz(
+            starting_point.x,
+            starting_point.y + FINISH_BLOCKED_OFFSET_Y,
+            FINISH_BLOCKED_Z,
+        ),
+        Visibility::Hidden,
+        FinishBlockedText { remaining: 0.0 },
+        HudElement::default(),
+        GameEntity,
+    ));
+}
+
+/// Spawns the start light gantry across the road over the start line, with its lights off.
+/// The lights are children of the gantry, so they go with it when it's despawned.
+pub fn spawn_start_gantry(
+    commands: &mut Commands,
+    position: Vec2,
+    direction: Direction,
+) {
+    let transform = Transform::from_xyz(position.x, position.y, START_GANTRY_Z)
+        .with_rotation(Quat::from_rotation_z(get_rotation(direction)));
+    let light_spacing = STARTING_LINE_WIDTH / (START_LIGHT_COUNT + 1) as f32;
+
+    commands
+        .spawn((
+            Sprite {
+                color: START_GANTRY_COLOR,
+                custom_size: Some(Vec2::new(STARTING_LINE_WIDTH, START_GANTRY_THICKNESS)),
+                ..default()
+            },
+            transform,
+            StartGantry {
+                go_timer: Timer::from_seconds(START_GANTRY_GO_DURATION, TimerMode::Once),
+            },
+            GameEntity,
+        ))
+        .with_children(|gantry| {
+            for i in 0..START_LIGHT_COUNT {
+                // Evenly spaced across the bar, drawn on top of it
+                let x = (i + 1) as f32 * light_spacing - STARTING_LINE_WIDTH / 2.0;
+                gantry.spawn((
+                    Sprite {
+                        color: START_LIGHT_OFF_COLOR,
+                        custom_size: Some(Vec2::splat(START_LIGHT_SIZE)),
+                        ..default()
+                    },
+                    Transform::from_xyz(x, 0.0, 0.1),
+                    StartLight(i),
+                ));
+            }
+        });
+}
+
 /// Initialize the race state resource
-pub fn init_race_state(commands: &mut Commands, car_start_y: f32) {
+pub fn init_race_state(
+    commands: &mut Commands,
+    unlock_target: Option<f32>,
+    difficulty: Difficulty,
+    finish_condition: FinishCondition,
+) {
     commands.insert_resource(RaceState {
         stopwatch: Stopwatch::new(),
         status: RaceStatus::WaitingToStart,
         final_time: None,
-        car_last_y: car_start_y,
         is_on_road: true,
+        on_road_time: 0.0,
+        off_road_time: 0.0,
+        penalty_time: 0.0,
+        is_valid: true,
+        unlock_target,
+        winner: None,
+        difficulty,
+        finish_condition,
+        start_lights: Timer::from_seconds(
+            START_LIGHT_INTERVAL * (START_LIGHT_COUNT + 1) as f32,
+            TimerMode::Once,
+        ),
     });
 }
 
-/// Checks if the car crossed a line (used for start/finish detection)
+/// Checks if the car crossed a line (used for start/finish detection) on any of its physics
+/// substeps since `car_last_pos`, see `has_swept_across_line`
 fn has_crossed_line_at(
-    car_pos: Vec2,
-    car_last_y: f32,
+    car_last_pos: Vec2,
+    path: &[Vec2],
     line_pos: Vec2,
     direction: Direction,
 ) -> bool {
-    let within_x_bounds = is_within_line_x_bounds(car_pos.x, line_pos.x);
-    let crossed = has_crossed_line(car_pos.y, car_last_y, line_pos.y, direction);
-    return within_x_bounds && crossed;
+    let mut last_pos = car_last_pos;
+    for &car_pos in path {
+        if has_swept_across_line(car_pos, last_pos, line_pos, direction) {
+            return true;
+        }
+        last_pos = car_pos;
+    }
+    return false;
 }
 
-/// System to check if the car crosses the start line and start the timer
+/// System to check if any car crosses the start line and start the timer
 pub fn check_start_line_crossing(
-    car_query: Single<&Transform, With<Car>>,
+    mut car_query: Query<(&Transform, &mut LineCrossing, Option<&SubstepPath>), With<Car>>,
     start_line_query: Single<(&Transform, &StartLine)>,
     mut race_state: ResMut<RaceState>,
 ) {
@@ -93,72 +236,122 @@ pub fn check_start_line_crossing(
         return;
     }
 
-    let car_pos = car_query.translation.truncate();
     let (start_transform, start_line) = *start_line_query;
     let start_pos = start_transform.translation.truncate();
 
-    // Check if car crossed the start line
-    if has_crossed_line_at(car_pos, race_state.car_last_y, start_pos, start_line.direction) {
-        race_state.start_race();
-    }
+    for (car_transform, mut crossing, path) in car_query.iter_mut() {
+        let car_pos = car_transform.translation.truncate();
+        let path = substep_positions(path, car_pos);
+
+        // Check if car crossed the start line
+        if has_crossed_line_at(crossing.last_position, &path, start_pos, start_line.direction) {
+            race_state.start_race();
+        }
 
-    // Update last Y position for next frame's crossing detection
-    race_state.set_previous_car_y(car_pos.y);
+        // Update last position for next frame's crossing detection
+        crossing.last_position = car_pos;
+    }
 }
 
-/// System to check if the car crosses the finish line and stop the timer
+/// Cars checked against the finish line, with what decides whether they may finish
+type FinishingCarQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Transform,
+        &'static mut LineCrossing,
+        Option<&'static SubstepPath>,
+        &'static VisitOrder,
+        &'static PlayerId,
+    ),
+    With<Car>,
+>;
+
+/// System to check if a car crosses the finish line and stop the timer.
+/// The first car to cross having met the level's finish condition (by default, visiting every
+/// segment) wins the race.
 pub fn check_finish_line_crossing(
-    car_query: Single<&Transform, With<Car>>,
+    mut car_query: FinishingCarQuery,
     finish_line_query: Single<(&Transform, &FinishLine)>,
-    unvisited_query: Query<(), (With<RoadSegment>, Without<Visited>)>,
+    settings: Res<GameSettings>,
     mut race_state: ResMut<RaceState>,
+    mut finish_blocked: MessageWriter<FinishBlocked>,
 ) {
     // Only check for finish crossing while actively racing
     if race_state.status != RaceStatus::Racing {
         return;
     }
 
-    let car_pos = car_query.translation.truncate();
     let (finish_transform, finish_line) = *finish_line_query;
     let finish_pos = finish_transform.translation.truncate();
 
-    // Check if car crossed the finish line AND all segments have been visited
-    if has_crossed_line_at(car_pos, race_state.car_last_y, finish_pos, finish_line.direction) {
-        // All segments visited = no unvisited segments remain (O(1) check)
-        let all_visited = unvisited_query.is_empty();
-        if all_visited {
-            race_state.finish_race();
+    for (car_transform, mut crossing, path, visit_order, player) in car_query.iter_mut() {
+        let car_pos = car_transform.translation.truncate();
+        let path = substep_positions(path, car_pos);
+        let crossed = has_crossed_line_at(crossing.last_position, &path, finish_pos, finish_line.direction);
+
+        // Update last position for next frame's crossing detection
+        crossing.last_position = car_pos;
+
+        if !crossed || race_state.status != RaceStatus::Racing {
+            continue;
         }
-    }
 
-    // Update last Y position for next frame's crossing detection
-    race_state.set_previous_car_y(car_pos.y);
+        if race_state.finish_condition.is_met(visit_order) {
+            // Flag the time if segments weren't visited as one continuous lap
+            // (a lap-only finish doesn't visit every segment, so there's no order to check)
+            if settings.validate_visit_order
+                && race_state.finish_condition == FinishCondition::VisitAll
+                && !is_contiguous_traversal(&visit_order.indices, visit_order.segment_count, MAX_VISIT_GAP)
+            {
+                race_state.is_valid = false;
+            }
+            race_state.finish_race(*player);
+        } else {
+            // Crossed too early - tell the player why the race didn't end
+            finish_blocked.write(FinishBlocked);
+        }
+    }
 }
 
-/// System to tick the race timer
-pub fn tick_race_timer(mut race_state: ResMut<RaceState>, time: Res<Time>) {
-    if race_state.status == RaceStatus::Racing {
-        let multiplier = if race_state.is_on_road {
-            1.0
+/// System to tick the race timer (stopped in practice mode), splitting the time into on-road and off-road.
+/// Off the road the timer runs faster by the multiplier of the difficulty the race started on.
+/// Always uses the real frame delta - slow-mo only scales car physics, so it can't be used to save time.
+pub fn tick_race_timer(mut race_state: ResMut<RaceState>, time: Res<Time>, practice: Res<PracticeMode>) {
+    if race_state.status == RaceStatus::Racing && !practice.0 {
+        let delta = if race_state.is_on_road {
+            time.delta()
         } else {
-            OFF_ROAD_TIME_MULTIPLIER
+            time.delta().mul_f32(race_state.difficulty.off_road_multiplier())
         };
-        race_state.stopwatch.tick(time.delta().mul_f32(multiplier));
+        race_state.stopwatch.tick(delta);
+        if race_state.is_on_road {
+            race_state.on_road_time += delta.as_secs_f32();
+        } else {
+            race_state.off_road_time += delta.as_secs_f32();
+        }
     }
 }
 
 /// System to update the timer display
 pub fn update_timer_display(
     race_state: Res<RaceState>,
+    palette: Res<Palette>,
+    practice: Res<PracticeMode>,
     mut query: Query<(&mut Text, &mut TextColor), With<TimerText>>,
 ) {
     if let Ok((mut text, mut color)) = query.single_mut() {
+        if practice.0 {
+            **text = "PRACTICE".to_string();
+            *color = timer_color(&race_state.status, &palette);
+            return;
+        }
         let elapsed = match race_state.status {
             RaceStatus::Finished => race_state.final_time.unwrap_or(0.0),
             _ => race_state.stopwatch.elapsed_secs(),
         };
         **text = format_elapsed_time(elapsed);
-        *color = timer_color(&race_state.status);
+        *color = timer_color(&race_state.status, &palette);
     }
 }
 
@@ -182,6 +375,122 @@ pub fn update_multiplier_display(
     }
 }
 
+/// System to show each car's remaining slow-mo time while the effect is active
+pub fn update_slow_mo_display(
+    car_query: Query<(&SlowMotion, &PlayerId), With<Car>>,
+    mut query: Query<(&mut Text, &mut Visibility, &SlowMoText)>,
+) {
+    for (mut text, mut visibility, owner) in query.iter_mut() {
+        match player_component(&car_query, owner.0) {
+            Some(slow_mo) => {
+                **text = format!("SLOW-MO {:.1}s", slow_mo.remaining_secs());
+                *visibility = Visibility::Visible;
+            }
+            None => {
+                *visibility = Visibility::Hidden;
+            }
+        }
+    }
+}
+
+/// System to update the coin counter as coins are collected
+pub fn update_coin_display(score: Res<Score>, mut query: Query<&mut Text, With<CoinText>>) {
+    for mut text in &mut query {
+        **text = coin_label(&score);
+    }
+}
+
+/// System to show each car's speed in the unit chosen in settings.
+/// In two-player mode the readings are prefixed with the player label.
+pub fn update_speedometer(
+    settings: Res<GameSettings>,
+    two_player: Res<TwoPlayerMode>,
+    car_query: Query<(&Velocity, &PlayerId), With<Car>>,
+    mut text_query: Query<(&mut Text, &SpeedometerText)>,
+) {
+    for (velocity, player) in &car_query {
+        let Some((mut text, _)) = text_query.iter_mut().find(|(_, owner)| owner.0 == *player) else {
+            continue;
+        };
+        let speed = convert_speed(velocity.0.length(), settings.speed_unit);
+        let reading = format!("{:.0} {}", speed, settings.speed_unit.label());
+        **text = if two_player.0 {
+            format!("{} {}", player.label(), reading)
+        } else {
+            reading
+        };
+    }
+}
+
+/// System to flash the "complete the whole track" message after a blocked finish attempt.
+/// The message fades out over its last second (unless motion is reduced) and is hidden
+/// when the countdown ends.
+pub fn update_finish_blocked_text(
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    mut finish_blocked: MessageReader<FinishBlocked>,
+    mut query: Query<(&mut FinishBlockedText, &mut Visibility, &mut TextColor)>,
+) {
+    let Ok((mut message, mut visibility, mut color)) = query.single_mut() else {
+        return;
+    };
+
+    if finish_blocked.read().count() > 0 {
+        message.remaining = FINISH_BLOCKED_DURATION;
+        *visibility = Visibility::Visible;
+    }
+
+    if *visibility == Visibility::Hidden {
+        return;
+    }
+
+    message.remaining -= time.delta_secs();
+    if message.remaining <= 0.0 {
+        *visibility = Visibility::Hidden;
+    } else if !settings.reduced_motion {
+        color.0.set_alpha(message.remaining.min(1.0));
+    }
+}
+
+/// System to run the start lights: they come on red one at a time, then all turn green and the
+/// cars may drive off. The gantry is removed once they've been green for a moment.
+pub fn update_start_gantry(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut race_state: ResMut<RaceState>,
+    palette: Res<Palette>,
+    mut gantry_query: Query<(Entity, &mut StartGantry)>,
+    mut light_query: Query<(&mut Sprite, &StartLight)>,
+) {
+    // The green lights are counted from the frame after they come on, so they show for the full time
+    let was_green = race_state.lights_green();
+    race_state.start_lights.tick(time.delta());
+    let Ok((gantry, mut start_gantry)) = gantry_query.single_mut() else {
+        return;
+    };
+
+    let green = race_state.lights_green();
+    let red_lights = race_state.red_lights();
+    for (mut sprite, light) in light_query.iter_mut() {
+        sprite.color = if green {
+            palette.start_line
+        } else if light.0 < red_lights {
+            palette.error_text
+        } else {
+            START_LIGHT_OFF_COLOR
+        };
+    }
+
+    if was_green && start_gantry.go_timer.tick(time.delta()).is_finished() {
+        commands.entity(gantry).despawn();
+    }
+}
+
+/// Run condition holding the cars on the grid until the start lights turn green
+pub fn start_lights_green(race_state: Option<Res<RaceState>>) -> bool {
+    race_state.is_some_and(|race_state| race_state.lights_green())
+}
+
 /// System to detect when race finishes and transition to LevelComplete state
 pub fn check_race_finished(
     race_state: Res<RaceState>,
@@ -207,13 +516,21 @@ pub fn spawn_controls_hint(commands: &mut Commands) {
                 fade_delay: CONTROLS_FADE_DELAY,
                 fade_duration: CONTROLS_FADE_DURATION,
             },
+            HudElement::default(),
             GameEntity,
         ));
     }
 }
 
 /// Draws a 2D arrow using gizmos
-fn draw_arrow_2d(gizmos: &mut Gizmos, start: Vec2, direction: Vec2, size: f32, color: Color) {
+fn draw_arrow_2d(
+    gizmos: &mut Gizmos,
+    start: Vec2,
+    direction: Vec2,
+    size: f32,
+    head_size: f32,
+    color: Color,
+) {
     let end = start + direction.normalize() * size;
 
     // Main line
@@ -221,15 +538,15 @@ fn draw_arrow_2d(gizmos: &mut Gizmos, start: Vec2, direction: Vec2, size: f32, c
 
     // Arrow head (two lines forming a V)
     let perp = Vec2::new(-direction.y, direction.x).normalize();
-    let head_back = end - direction.normalize() * ARROW_HEAD_SIZE;
-    let head_left = head_back + perp * (ARROW_HEAD_SIZE * 0.5);
-    let head_right = head_back - perp * (ARROW_HEAD_SIZE * 0.5);
+    let head_back = end - direction.normalize() * head_size;
+    let head_left = head_back + perp * (head_size * 0.5);
+    let head_right = head_back - perp * (head_size * 0.5);
 
     gizmos.line_2d(end, head_left, color);
     gizmos.line_2d(end, head_right, color);
 }
 
-/// Renders the arrow gizmos for controls hint
+/// Renders the arrow gizmos for controls hint (nothing once it's gone, or if it's turned off)
 pub fn render_controls_hint_arrows(mut gizmos: Gizmos, hint_query: Query<&ControlsHint>) {
     // Check if any hints exist
     let Ok(hint) = hint_query.iter().next().ok_or(()) else {
@@ -261,6 +578,7 @@ pub fn render_controls_hint_arrows(mut gizmos: Gizmos, hint_query: Query<&Contro
         Vec2::new(base_x, line0_y - ARROW_VERTICAL_OFFSET),
         Vec2::Y,
         ARROW_SIZE,
+        ARROW_HEAD_SIZE,
         color,
     );
 
@@ -271,6 +589,7 @@ pub fn render_controls_hint_arrows(mut gizmos: Gizmos, hint_query: Query<&Contro
         Vec2::new(base_x - ARROW_STEER_OFFSET, line1_y),
         -Vec2::X,
         ARROW_SIZE,
+        ARROW_HEAD_SIZE,
         color,
     );
     draw_arrow_2d(
@@ -278,6 +597,7 @@ pub fn render_controls_hint_arrows(mut gizmos: Gizmos, hint_query: Query<&Contro
         Vec2::new(base_x + ARROW_STEER_OFFSET, line1_y),
         Vec2::X,
         ARROW_SIZE,
+        ARROW_HEAD_SIZE,
         color,
     );
 
@@ -288,16 +608,54 @@ pub fn render_controls_hint_arrows(mut gizmos: Gizmos, hint_query: Query<&Contro
         Vec2::new(base_x, line2_y + ARROW_VERTICAL_OFFSET),
         -Vec2::Y,
         ARROW_SIZE,
+        ARROW_HEAD_SIZE,
         color,
     );
 
     // Lines 3-4: ESC and SPACE text (no arrows needed)
 }
 
-/// Updates the controls hint - fades out after delay or when player moves
+/// Draws an arrow next to each car pointing at the nearest unvisited road segment (when enabled in settings).
+/// Nothing is drawn once every segment has been visited.
+pub fn render_guide_arrow(
+    mut gizmos: Gizmos,
+    settings: Res<GameSettings>,
+    car_query: Query<&Transform, With<Car>>,
+    unvisited_query: Query<(&Transform, &RoadSegment), Without<Visited>>,
+) {
+    if !settings.show_guide_arrow {
+        return;
+    }
+
+    for car_transform in &car_query {
+        let car_pos = car_transform.translation.truncate();
+        let nearest = unvisited_query
+            .iter()
+            .map(|(transform, segment)| get_segment_center(transform, segment.segment_type))
+            .min_by(|a, b| a.distance_squared(car_pos).total_cmp(&b.distance_squared(car_pos)));
+        let Some(target) = nearest else {
+            return;
+        };
+
+        let Some(direction) = (target - car_pos).try_normalize() else {
+            continue;
+        };
+        draw_arrow_2d(
+            &mut gizmos,
+            car_pos + direction * GUIDE_ARROW_OFFSET,
+            direction,
+            GUIDE_ARROW_LENGTH,
+            GUIDE_ARROW_HEAD_SIZE,
+            GUIDE_ARROW_COLOR,
+        );
+    }
+}
+
+/// Updates the controls hint - fades out after delay or when player moves (or disappears
+/// then with reduced motion), and disappears at once when dismissed (see `CONTROLS_HINT_DISMISS_KEYS`)
 pub fn update_controls_hint(
     mut commands: Commands,
-    time: Res<Time>,
+    (time, settings): (Res<Time>, Res<GameSettings>),
     keyboard: Res<ButtonInput<KeyCode>>,
     car_query: Query<&Velocity, With<Car>>,
     mut hint_query: Query<(Entity, &mut ControlsHint, &mut TextColor)>,
@@ -306,6 +664,13 @@ pub fn update_controls_hint(
         return;
     }
 
+    if keyboard.any_just_pressed(CONTROLS_HINT_DISMISS_KEYS) {
+        for (entity, _, _) in &hint_query {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
     // Check if player has started moving (any arrow key or car has velocity)
     let player_moved = keyboard.any_pressed([
         KeyCode::ArrowUp,
@@ -329,8 +694,8 @@ pub fn update_controls_hint(
         // Calculate alpha based on fade progress
         if hint.timer >= hint.fade_delay {
             let fade_progress = (hint.timer - hint.fade_delay) / hint.fade_duration;
-            if fade_progress >= 1.0 {
-                // Fully faded - despawn the hint
+            if fade_progress >= 1.0 || settings.reduced_motion {
+                // Fully faded (or not faded at all) - despawn the hint
                 commands.entity(entity).despawn();
             } else {
                 // Update alpha
@@ -346,166 +711,731 @@ pub fn update_controls_hint(
 // NOS Boost Bar HUD
 // ============================================================================
 
-/// Spawns the NOS boost bar container (initially hidden, shown when boost is available)
-pub fn spawn_nos_boost_bar(commands: &mut Commands) {
+/// Spawns the NOS boost bar container for the given player's car (initially hidden, shown
+/// when boost is available). In a split-screen race it's drawn in the player's own view.
+pub fn spawn_nos_boost_bar(commands: &mut Commands, player: PlayerId, view: Option<Entity>) {
     let (bg_color, border_color) = nos_bar_container_colors();
 
-    commands
-        .spawn((
-            nos_bar_container_style(),
-            bg_color,
-            border_color,
-            Visibility::Hidden,
-            NosBoostBarContainer,
-            GameEntity,
-        ))
+    let mut container = commands.spawn((
+        nos_bar_container_style(),
+        bg_color,
+        border_color,
+        Visibility::Hidden,
+        NosBoostBarContainer(player),
+        HudElement::default(),
+        GameEntity,
+    ));
+    if let Some(view) = view {
+        container.insert(UiTargetCamera(view));
+    }
+    container
         .with_children(|parent| {
             parent.spawn((
                 nos_bar_fill_style(),
                 nos_bar_fill_color(),
-                NosBoostBarFill,
+                NosBoostBarFill::default(),
+            ));
+            // Spawned after the fill so it draws on top of it
+            parent.spawn((
+                Text::new(""),
+                nos_bar_text_style(),
+                NosBoostBarText,
             ));
         });
 }
 
 /// Spawns the NOS boost bar glow sprites (world-space for bloom effect).
 /// Creates 4 sprite edges around the bar that will bloom like the powerup.
-pub fn spawn_nos_boost_bar_glow(commands: &mut Commands) {
+/// The edges are children of the camera, so they move with it during transform
+/// propagation and stay aligned with the screen-space bar even while the camera pans.
+/// In a split-screen race they follow the player's view camera, on a layer only it draws.
+pub fn spawn_nos_boost_bar_glow(
+    commands: &mut Commands,
+    player: PlayerId,
+    main_camera: Entity,
+    view: Option<Entity>,
+) {
     // Calculate total bar dimensions including the glow border
     let total_width = NOS_BAR_WIDTH + NOS_BAR_GLOW_THICKNESS;
     let total_height = NOS_BAR_HEIGHT + NOS_BAR_GLOW_THICKNESS;
 
+    // Bar center relative to the camera (the bar is centered horizontally, positioned from the top)
+    let bar_center_y = (WINDOW_HEIGHT as f32) / 2.0 - NOS_BAR_TOP - NOS_BAR_HEIGHT / 2.0;
+
     // Calculate offsets for each edge
     let top_offset_y = total_height / 2.0 - NOS_BAR_GLOW_THICKNESS / 2.0;
     let bottom_offset_y = -total_height / 2.0 + NOS_BAR_GLOW_THICKNESS / 2.0;
     let left_offset_x = -total_width / 2.0 + NOS_BAR_GLOW_THICKNESS / 2.0;
     let right_offset_x = total_width / 2.0 - NOS_BAR_GLOW_THICKNESS / 2.0;
 
-    // Top edge
-    commands.spawn((
-        Sprite {
-            color: NOS_BAR_GLOW_COLOR,
-            custom_size: Some(Vec2::new(total_width, NOS_BAR_GLOW_THICKNESS)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, 0.0, NOS_BAR_GLOW_Z),
-        Visibility::Hidden,
-        NosBoostBarGlow {
-            offset_x: 0.0,
-            offset_y: top_offset_y,
-        },
-        GameEntity,
-    ));
+    let horizontal_edge = Vec2::new(total_width, NOS_BAR_GLOW_THICKNESS);
+    let vertical_edge = Vec2::new(NOS_BAR_GLOW_THICKNESS, total_height);
 
-    // Bottom edge
-    commands.spawn((
-        Sprite {
-            color: NOS_BAR_GLOW_COLOR,
-            custom_size: Some(Vec2::new(total_width, NOS_BAR_GLOW_THICKNESS)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, 0.0, NOS_BAR_GLOW_Z),
-        Visibility::Hidden,
-        NosBoostBarGlow {
-            offset_x: 0.0,
-            offset_y: bottom_offset_y,
-        },
-        GameEntity,
-    ));
+    // (size, offset from bar center) for the top, bottom, left and right edges
+    let edges = [
+        (horizontal_edge, Vec2::new(0.0, top_offset_y)),
+        (horizontal_edge, Vec2::new(0.0, bottom_offset_y)),
+        (vertical_edge, Vec2::new(left_offset_x, 0.0)),
+        (vertical_edge, Vec2::new(right_offset_x, 0.0)),
+    ];
 
-    // Left edge
-    commands.spawn((
-        Sprite {
-            color: NOS_BAR_GLOW_COLOR,
-            custom_size: Some(Vec2::new(NOS_BAR_GLOW_THICKNESS, total_height)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, 0.0, NOS_BAR_GLOW_Z),
-        Visibility::Hidden,
-        NosBoostBarGlow {
-            offset_x: left_offset_x,
-            offset_y: 0.0,
-        },
-        GameEntity,
-    ));
+    let camera = view.unwrap_or(main_camera);
+    commands.entity(camera).with_children(|parent| {
+        for (size, offset) in edges {
+            let mut edge = parent.spawn((
+                Glowing(NOS_BAR_GLOW_COLOR),
+                Sprite {
+                    color: NOS_BAR_GLOW_COLOR,
+                    custom_size: Some(size),
+                    ..default()
+                },
+                Transform::from_xyz(offset.x, bar_center_y + offset.y, NOS_BAR_GLOW_Z),
+                Visibility::Hidden,
+                NosBoostBarGlow(player),
+                HudElement::default(),
+                GameEntity,
+            ));
+            if view.is_some() {
+                edge.insert(split_view_layer(player));
+            }
+        }
+    });
+}
 
-    // Right edge
-    commands.spawn((
-        Sprite {
-            color: NOS_BAR_GLOW_COLOR,
-            custom_size: Some(Vec2::new(NOS_BAR_GLOW_THICKNESS, total_height)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, 0.0, NOS_BAR_GLOW_Z),
-        Visibility::Hidden,
-        NosBoostBarGlow {
-            offset_x: right_offset_x,
-            offset_y: 0.0,
-        },
-        GameEntity,
-    ));
+// ============================================================================
+// Wall Proximity Warning
+// ============================================================================
+
+/// Spawns a glow sprite along each window boundary, hidden until a car gets close to it.
+/// The car is clamped to these boundaries, so the glow shows the otherwise invisible walls.
+pub fn spawn_wall_glow(commands: &mut Commands) {
+    let width = WINDOW_WIDTH as f32;
+    let height = WINDOW_HEIGHT as f32;
+    let half_thickness = WALL_GLOW_THICKNESS / 2.0;
+
+    let horizontal_edge = Vec2::new(width, WALL_GLOW_THICKNESS);
+    let vertical_edge = Vec2::new(WALL_GLOW_THICKNESS, height);
+
+    // (side, size, center) for each edge, inset so the whole sprite is on screen
+    let edges = [
+        (WallSide::Left, vertical_edge, Vec2::new(LEFT_BOUNDARY + half_thickness, 0.0)),
+        (WallSide::Right, vertical_edge, Vec2::new(RIGHT_BOUNDARY - half_thickness, 0.0)),
+        (WallSide::Top, horizontal_edge, Vec2::new(0.0, TOP_BOUNDARY - half_thickness)),
+        (WallSide::Bottom, horizontal_edge, Vec2::new(0.0, BOTTOM_BOUNDARY + half_thickness)),
+    ];
+
+    for (side, size, center) in edges {
+        commands.spawn((
+            Sprite {
+                color: WALL_GLOW_COLOR.with_alpha(0.0),
+                custom_size: Some(size),
+                ..default()
+            },
+            Transform::from_xyz(center.x, center.y, WALL_GLOW_Z),
+            WallProximityGlow(side),
+            HudElement::default(),
+            GameEntity,
+        ));
+    }
 }
 
-/// Updates the NOS boost bar glow sprites to follow the camera and match visibility.
-/// The glow sprites are positioned in world-space relative to the camera center.
-pub fn update_nos_boost_bar_glow(
-    car_query: Query<&NosBoostAvailable, With<Car>>,
-    camera_query: Query<&Transform, With<Camera2d>>,
-    mut glow_query: Query<(&mut Transform, &mut Visibility, &NosBoostBarGlow), Without<Camera2d>>,
-) {
-    let Ok(camera_transform) = camera_query.single() else {
-        return;
-    };
+/// Distance from the car's nearest corner to the given window boundary
+fn distance_to_wall(corners: &[Vec3; 4], side: WallSide) -> f32 {
+    let distances = corners.iter().map(|corner| match side {
+        WallSide::Left => corner.x - LEFT_BOUNDARY,
+        WallSide::Right => RIGHT_BOUNDARY - corner.x,
+        WallSide::Top => TOP_BOUNDARY - corner.y,
+        WallSide::Bottom => corner.y - BOTTOM_BOUNDARY,
+    });
+    distances.fold(f32::INFINITY, f32::min)
+}
 
-    let camera_pos = camera_transform.translation.truncate();
+/// Fades each edge glow in as the closest car nears that boundary (fainter with reduced glow)
+pub fn update_wall_glow(
+    glow_settings: Res<GlowSettings>,
+    car_query: Query<&Transform, With<Car>>,
+    mut glow_query: Query<(&WallProximityGlow, &mut Sprite)>,
+) {
+    let car_corners: Vec<[Vec3; 4]> = car_query.iter().map(get_car_corners).collect();
 
-    // Calculate bar center position relative to camera (top center of screen)
-    // The bar is centered horizontally and positioned from the top
-    let bar_center_x = camera_pos.x;
-    let bar_center_y = camera_pos.y + (WINDOW_HEIGHT as f32) / 2.0 - NOS_BAR_TOP - NOS_BAR_HEIGHT / 2.0;
+    for (glow, mut sprite) in glow_query.iter_mut() {
+        let distance = car_corners
+            .iter()
+            .map(|corners| distance_to_wall(corners, glow.0))
+            .fold(f32::INFINITY, f32::min);
+        sprite.color.set_alpha(wall_glow_alpha(distance) * glow_settings.warning_scale);
+    }
+}
 
-    // Determine if boost is available (for visibility)
-    let boost_visible = car_query.iter().next().is_some();
+/// Returns the component from the given player's car, if it has one
+fn player_component<'a, T: Component>(
+    car_query: &'a Query<(&T, &PlayerId), With<Car>>,
+    player: PlayerId,
+) -> Option<&'a T> {
+    car_query
+        .iter()
+        .find(|(_, owner)| **owner == player)
+        .map(|(component, _)| component)
+}
 
-    for (mut transform, mut visibility, glow) in glow_query.iter_mut() {
-        // Update visibility
+/// Updates each NOS boost bar glow's visibility to match its player's boost availability.
+/// Positioning is handled by parenting the glow sprites to the camera.
+pub fn update_nos_boost_bar_glow(
+    car_query: Query<(&NosBoostAvailable, &PlayerId), With<Car>>,
+    mut glow_query: Query<(&mut Visibility, &NosBoostBarGlow)>,
+) {
+    for (mut visibility, glow) in glow_query.iter_mut() {
+        let boost_visible = player_component(&car_query, glow.0).is_some();
         *visibility = if boost_visible {
             Visibility::Visible
         } else {
             Visibility::Hidden
         };
-
-        // Update position based on stored offsets
-        transform.translation.x = bar_center_x + glow.offset_x;
-        transform.translation.y = bar_center_y + glow.offset_y;
     }
 }
 
-/// Updates the NOS boost bar visibility and fill width based on boost availability.
-/// Shows the bar when NosBoostAvailable exists on the car, hides it otherwise.
-/// The fill width shrinks as the availability timer counts down.
+/// Updates each NOS boost bar's visibility, fill width and remaining-seconds label based on its
+/// player's boost availability. Shows the bar when NosBoostAvailable exists on the car, hides it otherwise.
+/// The fill width shrinks as the availability timer counts down, or as the burst
+/// runs out once one has been triggered in tap mode. A refill eases up rather than snapping
+/// (see `ease_bar_fill`).
 pub fn update_nos_boost_bar(
-    car_query: Query<&NosBoostAvailable, With<Car>>,
-    mut container_query: Query<&mut Visibility, With<NosBoostBarContainer>>,
-    mut fill_query: Query<&mut Node, With<NosBoostBarFill>>,
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    car_query: Query<(&NosBoostAvailable, &PlayerId), With<Car>>,
+    mut container_query: Query<(&mut Visibility, &NosBoostBarContainer, &Children)>,
+    mut fill_query: Query<(&mut Node, &mut NosBoostBarFill)>,
+    mut text_query: Query<&mut Text, With<NosBoostBarText>>,
+) {
+    for (mut container_visibility, container, children) in container_query.iter_mut() {
+        match player_component(&car_query, container.0) {
+            Some(boost) => {
+                // Show the bar
+                *container_visibility = Visibility::Visible;
+
+                for child in children.iter() {
+                    // Update fill width based on remaining time
+                    if let Ok((mut fill_node, mut fill)) = fill_query.get_mut(child) {
+                        fill.shown = ease_bar_fill(
+                            fill.shown,
+                            boost.remaining_fraction(),
+                            time.delta_secs(),
+                            settings.reduced_motion,
+                        );
+                        fill_node.width = Val::Percent(fill.shown * 100.0);
+                    }
+
+                    // Show the exact time left to hit the boost key, or of the burst (hidden along with the container)
+                    if let Ok(mut text) = text_query.get_mut(child) {
+                        **text = format!("{:.1}s", boost.remaining_secs());
+                    }
+                }
+            }
+            None => {
+                // Hide the bar when no boost is available, emptied so the next pickup fills it up
+                *container_visibility = Visibility::Hidden;
+                for child in children.iter() {
+                    if let Ok((_, mut fill)) = fill_query.get_mut(child) {
+                        fill.shown = 0.0;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// HUD Toggle
+// ============================================================================
+
+/// Hides or shows the whole HUD when `HUD_TOGGLE_KEY` is pressed
+pub fn toggle_hud(keyboard_input: Res<ButtonInput<KeyCode>>, mut hud_visible: ResMut<HudVisible>) {
+    if keyboard_input.just_pressed(HUD_TOGGLE_KEY) {
+        hud_visible.0 = !hud_visible.0;
+    }
+}
+
+/// Keeps every HUD element hidden while the HUD is toggled off, then restores them.
+/// Runs after the Update systems that show and hide elements themselves (the NOS bar,
+/// multiplier, ...), so it overrides them; a visibility they set while the HUD is off
+/// is remembered and is the one restored.
+pub fn apply_hud_visibility(
+    hud_visible: Res<HudVisible>,
+    mut query: Query<(&mut HudElement, &mut Visibility)>,
 ) {
-    let Ok(mut container_visibility) = container_query.single_mut() else {
+    for (mut element, mut visibility) in query.iter_mut() {
+        if hud_visible.0 {
+            if let Some(restore) = element.restore.take() {
+                // A visibility set this frame is newer than the remembered one
+                if !visibility.is_changed() {
+                    *visibility = restore;
+                }
+            }
+        } else {
+            // Only a change by another system is newer than our own Hidden
+            if element.restore.is_none() || visibility.is_changed() {
+                element.restore = Some(*visibility);
+            }
+            visibility.set_if_neq(Visibility::Hidden);
+        }
+    }
+}
+
+// ============================================================================
+// Volume Overlay
+// ============================================================================
+
+/// Spawns the hidden volume overlay (bar with a label) near the bottom of the screen
+pub fn spawn_volume_overlay(commands: &mut Commands) {
+    commands
+        .spawn((
+            volume_bar_container_style(),
+            Visibility::Hidden,
+            VolumeOverlay {
+                timer: VOLUME_FADE_DELAY + VOLUME_FADE_DURATION,
+            },
+            HudElement::default(),
+            GameEntity,
+        ))
+        .with_children(|parent| {
+            parent.spawn((volume_bar_fill_style(), VolumeBarFill));
+            // Spawned after the fill so it draws on top of it
+            parent.spawn((Text::new(""), volume_text_style(), VolumeText));
+        });
+}
+
+/// System to show the volume overlay after a volume hotkey and fade it out afterwards.
+/// Uses the same delay-then-fade timing as the controls hint, and the same instant hide
+/// with reduced motion.
+pub fn update_volume_overlay(
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    mut volume_changed: MessageReader<VolumeChanged>,
+    mut overlay_query: Single<(&mut VolumeOverlay, &mut Visibility, &mut BackgroundColor), Without<VolumeBarFill>>,
+    mut fill_query: Single<(&mut Node, &mut BackgroundColor), With<VolumeBarFill>>,
+    mut text_query: Single<(&mut Text, &mut TextColor), With<VolumeText>>,
+) {
+    let (overlay, visibility, background) = &mut *overlay_query;
+    let (fill_node, fill_color) = &mut *fill_query;
+    let (text, text_color) = &mut *text_query;
+
+    if volume_changed.read().count() > 0 {
+        overlay.timer = 0.0;
+        fill_node.width = Val::Percent(settings.effective_volume() * 100.0);
+        ***text = if settings.muted {
+            "Muted".to_string()
+        } else {
+            format!("Volume {:.0}%", settings.master_volume * 100.0)
+        };
+    }
+
+    let total_duration = VOLUME_FADE_DELAY + VOLUME_FADE_DURATION;
+    if overlay.timer >= total_duration {
+        **visibility = Visibility::Hidden;
+        return;
+    }
+
+    overlay.timer += time.delta_secs();
+    if settings.reduced_motion && overlay.timer >= VOLUME_FADE_DELAY {
+        overlay.timer = total_duration;
+        **visibility = Visibility::Hidden;
+        return;
+    }
+    let fade_progress = ((overlay.timer - VOLUME_FADE_DELAY) / VOLUME_FADE_DURATION).clamp(0.0, 1.0);
+    let alpha = 1.0 - fade_progress;
+
+    **visibility = Visibility::Visible;
+    background.0 = VOLUME_BAR_BG_COLOR.with_alpha(VOLUME_BAR_BG_COLOR.alpha() * alpha);
+    fill_color.0 = VOLUME_BAR_FILL_COLOR.with_alpha(alpha);
+    text_color.0 = VOLUME_TEXT_COLOR.with_alpha(alpha);
+}
+
+// ============================================================================
+// Live Minimap
+// ============================================================================
+
+/// System to build the live minimap once the track's road segments are spawned.
+/// Each segment gets one cell, and a `MinimapLink` pointing at it so visits can
+/// recolor that cell alone. Runs again after a practice restart respawns the track.
+/// Under the fog of war, cells stay blank until their segment is visited.
+pub fn spawn_live_minimap(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    segment_query: Query<(Entity, &RoadSegment, &Transform), Added<RoadSegment>>,
+) {
+    let rects: Vec<(Entity, Vec2, Vec2)> = segment_query
+        .iter()
+        .map(|(entity, segment, transform)| {
+            let (center, size) = segment_cell_rect(segment, transform);
+            (entity, center, size)
+        })
+        .collect();
+    if rects.is_empty() {
+        return;
+    }
+
+    let (min, max) = rects.iter().fold(
+        (Vec2::splat(f32::MAX), Vec2::splat(f32::MIN)),
+        |(min, max), &(_, center, size)| (min.min(center - size / 2.0), max.max(center + size / 2.0)),
+    );
+    let minimap = LiveMinimap { min, max, scale: LIVE_MINIMAP_SIZE / (max - min).max_element() };
+    let track_size = minimap.track_size();
+    let road_color = live_minimap_road_color(&settings);
+
+    commands
+        .spawn((
+            live_minimap_container_style(track_size),
+            minimap,
+            HudElement::default(),
+            GameEntity,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((live_minimap_pivot_style(), MinimapPart::Pivot))
+                .with_children(|pivot| {
+                    pivot
+                        .spawn((live_minimap_track_style(track_size), MinimapPart::Track))
+                        .with_children(|track| {
+                            for &(segment_entity, center, size) in &rects {
+                                let top_left_corner = center + Vec2::new(-size.x, size.y) / 2.0;
+                                let top_left = minimap.map_position(top_left_corner);
+                                let cell = track
+                                    .spawn((
+                                        live_minimap_cell_style(top_left, size * minimap.scale),
+                                        BackgroundColor(road_color),
+                                    ))
+                                    .id();
+                                track
+                                    .commands()
+                                    .entity(segment_entity)
+                                    .insert(MinimapLink { cell });
+                            }
+                        });
+                });
+            parent.spawn((live_minimap_marker_style(), MinimapPart::CarMarker));
+        });
+}
+
+/// System to follow player one's car on the live minimap. North up, the car marker moves
+/// over the track and turns with the car. Heading up (see `GameSettings::rotate_minimap`),
+/// the marker stays in the center pointing up and the track turns around it instead,
+/// shown through a square window so the view doesn't change size as it turns.
+pub fn orient_live_minimap(
+    settings: Res<GameSettings>,
+    car_query: Query<(&Transform, &PlayerId), With<Car>>,
+    minimap: Single<(&LiveMinimap, &mut Node)>,
+    mut part_query: Query<(&MinimapPart, &mut UiTransform)>,
+) {
+    let player_one = car_query.iter().find(|(_, player)| **player == PlayerId::One);
+    let Some((car_transform, _)) = player_one else {
         return;
     };
+    let (minimap, mut node) = minimap.into_inner();
+    let car_position = minimap.map_position(car_transform.translation.xy());
+    let (_, _, heading) = car_transform.rotation.to_euler(EulerRot::XYZ);
 
-    match car_query.iter().next() {
-        Some(boost) => {
-            // Show the bar
-            *container_visibility = Visibility::Visible;
+    let view_size = if settings.rotate_minimap {
+        Vec2::splat(LIVE_MINIMAP_SIZE)
+    } else {
+        minimap.track_size()
+    };
+    node.set_if_neq(Node {
+        width: Val::Px(view_size.x + LIVE_MINIMAP_PADDING * 2.0),
+        height: Val::Px(view_size.y + LIVE_MINIMAP_PADDING * 2.0),
+        ..node.clone()
+    });
 
-            // Update fill width based on remaining time
-            if let Ok(mut fill_node) = fill_query.single_mut() {
-                fill_node.width = Val::Percent(boost.remaining_fraction() * 100.0);
-            }
+    // UI rotations turn clockwise, the car's turn counter-clockwise
+    let view_center = view_size / 2.0;
+    for (part, mut transform) in part_query.iter_mut() {
+        let (translation, rotation) = match (part, settings.rotate_minimap) {
+            (MinimapPart::Pivot, true) => (Vec2::ZERO, heading),
+            (MinimapPart::Track, true) => (view_center - car_position, 0.0),
+            (MinimapPart::CarMarker, true) => (view_center, 0.0),
+            (MinimapPart::CarMarker, false) => (car_position, -heading),
+            (_, false) => (Vec2::ZERO, 0.0),
+        };
+        transform.set_if_neq(UiTransform {
+            translation: Val2::px(translation.x, translation.y),
+            rotation: Rot2::radians(rotation),
+            ..UiTransform::IDENTITY
+        });
+    }
+}
+
+/// Color of the live minimap cells of unvisited segments (hidden under the fog of war)
+fn live_minimap_road_color(settings: &GameSettings) -> Color {
+    if settings.fog_of_war { Color::NONE } else { LIVE_MINIMAP_ROAD_COLOR }
+}
+
+/// System to light up a segment's minimap cell when the segment is first visited,
+/// and to turn it back off when the visit is forgotten (see `StartReset`)
+pub fn update_live_minimap(
+    visited_query: Query<&MinimapLink, Added<Visited>>,
+    link_query: Query<&MinimapLink>,
+    mut unvisited: RemovedComponents<Visited>,
+    mut cell_query: Query<&mut BackgroundColor>,
+    settings: Res<GameSettings>,
+) {
+    for link in &visited_query {
+        if let Ok(mut background) = cell_query.get_mut(link.cell) {
+            background.0 = LIVE_MINIMAP_VISITED_COLOR;
+        }
+    }
+    for segment in unvisited.read() {
+        let Ok(link) = link_query.get(segment) else {
+            continue;
+        };
+        if let Ok(mut background) = cell_query.get_mut(link.cell) {
+            background.0 = live_minimap_road_color(&settings);
         }
-        None => {
-            // Hide the bar when no boost is available
-            *container_visibility = Visibility::Hidden;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    use crate::car::components::{CarClass, CarStats};
+    use crate::car::systems::move_car;
+    use crate::settings::constants::DEFAULT_PHYSICS_SUBSTEPS;
+
+    /// Ticks the race timer once for a second of real time spent off the road.
+    /// Returns the (elapsed race time, off-road time) it accrued.
+    fn tick_off_road_second(difficulty: Difficulty) -> (f32, f32) {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs(1));
+        world.insert_resource(time);
+        world.insert_resource(PracticeMode(false));
+        init_race_state(&mut world.commands(), None, difficulty, FinishCondition::default());
+        world.flush();
+        let mut race_state = world.resource_mut::<RaceState>();
+        race_state.start_race();
+        race_state.is_on_road = false;
+
+        world.run_system_once(tick_race_timer).unwrap();
+
+        let race_state = world.resource::<RaceState>();
+        (race_state.stopwatch.elapsed_secs(), race_state.off_road_time)
+    }
+
+    #[test]
+    fn test_off_road_timer_accrues_at_difficulty_multiplier() {
+        for difficulty in [Difficulty::Casual, Difficulty::Normal, Difficulty::Hard] {
+            let multiplier = difficulty.off_road_multiplier();
+            let (elapsed, off_road) = tick_off_road_second(difficulty);
+
+            assert!((elapsed - multiplier).abs() < 1e-4);
+            assert!((off_road - multiplier).abs() < 1e-4);
+        }
+        assert!(Difficulty::Casual.off_road_multiplier() < Difficulty::Normal.off_road_multiplier());
+    }
+
+    #[test]
+    fn test_dismiss_key_removes_controls_hint() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<GameSettings>();
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+        keyboard.press(KeyCode::Enter);
+        world.insert_resource(keyboard);
+        spawn_controls_hint(&mut world.commands());
+        world.flush();
+
+        world.run_system_once(update_controls_hint).unwrap();
+
+        let mut hints = world.query::<&ControlsHint>();
+        assert_eq!(hints.iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn test_reduced_motion_removes_controls_hint_without_fading() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.init_resource::<ButtonInput<KeyCode>>();
+        world.insert_resource(GameSettings { reduced_motion: true, ..default() });
+        spawn_controls_hint(&mut world.commands());
+        world.flush();
+
+        // Still shown before the fade would start
+        world.run_system_once(update_controls_hint).unwrap();
+        let mut hints = world.query::<&mut ControlsHint>();
+        assert!(hints.iter(&world).count() > 0);
+
+        for mut hint in hints.iter_mut(&mut world) {
+            hint.timer = hint.fade_delay;
         }
+        world.run_system_once(update_controls_hint).unwrap();
+        assert_eq!(hints.iter(&world).count(), 0);
+    }
+
+    /// Drives a car over a finish line heading up, having visited `visited` of a
+    /// 10-segment track. Returns whether that finished the race.
+    fn cross_finish_line(finish_condition: FinishCondition, visited: Vec<usize>) -> bool {
+        let mut world = World::new();
+        world.init_resource::<Messages<FinishBlocked>>();
+        world.init_resource::<GameSettings>();
+        init_race_state(&mut world.commands(), None, Difficulty::default(), finish_condition);
+        world.flush();
+        world.resource_mut::<RaceState>().start_race();
+
+        world.spawn((Transform::default(), FinishLine { direction: Direction::Up }));
+        world.spawn((
+            Car,
+            PlayerId::One,
+            Transform::from_xyz(0.0, 5.0, 0.0),
+            LineCrossing { last_position: Vec2::new(0.0, -5.0) },
+            VisitOrder { indices: visited, segment_count: 10 },
+        ));
+
+        world.run_system_once(check_finish_line_crossing).unwrap();
+        world.resource::<RaceState>().status == RaceStatus::Finished
+    }
+
+    #[test]
+    fn test_visit_all_needs_every_segment() {
+        assert!(!cross_finish_line(FinishCondition::VisitAll, (0..9).collect()));
+        assert!(cross_finish_line(FinishCondition::VisitAll, (0..10).collect()));
+    }
+
+    #[test]
+    fn test_lap_only_needs_the_far_side_of_the_loop() {
+        // Backing over the line straight after the start isn't a lap
+        assert!(!cross_finish_line(FinishCondition::LapOnly, vec![0, 9]));
+        assert!(cross_finish_line(FinishCondition::LapOnly, vec![0, 1, 2, 4, 5, 7, 9]));
+    }
+
+    /// Moves a car across the finish line in one slow frame (half a second) at top speed,
+    /// heading diagonally so it ends the frame beyond the line's edge, then checks the
+    /// finish. Returns whether that finished the race.
+    fn cross_finish_line_in_one_frame(physics_substeps: u32) -> bool {
+        let mut world = World::new();
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_millis(500));
+        world.insert_resource(time);
+        world.init_resource::<Messages<FinishBlocked>>();
+        world.insert_resource(GameSettings { physics_substeps, ..default() });
+        init_race_state(&mut world.commands(), None, Difficulty::default(), FinishCondition::default());
+        world.flush();
+        world.resource_mut::<RaceState>().start_race();
+
+        // Friction is left out so the distance covered is exact
+        let stats = CarStats { friction: 0.0, ..CarClass::default().stats() };
+        let heading = Vec2::new(1.0, 1.0).normalize();
+        world.spawn((FinishLine { direction: Direction::Up }, Transform::default()));
+        world.spawn((
+            Car,
+            PlayerId::One,
+            stats,
+            Transform::from_xyz(-44.0, -44.0, 0.0)
+                .with_rotation(Quat::from_rotation_z(-std::f32::consts::FRAC_PI_4)),
+            Velocity(heading * stats.max_speed),
+            SubstepPath::default(),
+            LineCrossing { last_position: Vec2::new(-44.0, -44.0) },
+            VisitOrder { indices: (0..10).collect(), segment_count: 10 },
+        ));
+
+        world.run_system_once(move_car).unwrap();
+        world.run_system_once(check_finish_line_crossing).unwrap();
+        world.resource::<RaceState>().status == RaceStatus::Finished
+    }
+
+    #[test]
+    fn test_fast_car_crossing_the_finish_line_finishes() {
+        // The car is only seen before the line and past its edge when the frame isn't split,
+        // but the crossing is swept
+        assert!(cross_finish_line_in_one_frame(DEFAULT_PHYSICS_SUBSTEPS));
+        assert!(cross_finish_line_in_one_frame(1));
+    }
+
+    #[test]
+    fn test_hud_toggle_restores_visibility_set_while_hidden() {
+        let mut world = World::new();
+        world.insert_resource(HudVisible(false));
+        let apply = world.register_system(apply_hud_visibility);
+        // Like the NOS bar, hidden until its own system shows it
+        let element = world.spawn((HudElement::default(), Visibility::Hidden)).id();
+        world.run_system(apply).unwrap();
+
+        // Shown by its own system while the HUD is off
+        *world.get_mut::<Visibility>(element).unwrap() = Visibility::Visible;
+        world.run_system(apply).unwrap();
+        assert_eq!(world.get::<Visibility>(element), Some(&Visibility::Hidden));
+
+        world.resource_mut::<HudVisible>().0 = true;
+        world.run_system(apply).unwrap();
+        assert_eq!(world.get::<Visibility>(element), Some(&Visibility::Visible));
+    }
+
+    #[test]
+    fn test_heading_up_minimap_keeps_the_car_centered() {
+        let mut world = World::new();
+        world.insert_resource(GameSettings { rotate_minimap: true, ..default() });
+        let heading = 0.7;
+        let car_position = Vec2::new(30.0, -20.0);
+        world.spawn((
+            Car,
+            PlayerId::One,
+            Transform::from_translation(car_position.extend(0.0))
+                .with_rotation(Quat::from_rotation_z(heading)),
+        ));
+        let minimap = LiveMinimap { min: Vec2::splat(-100.0), max: Vec2::splat(100.0), scale: 0.5 };
+        world.spawn((minimap, Node::default()));
+        let parts = [MinimapPart::Pivot, MinimapPart::Track, MinimapPart::CarMarker]
+            .map(|part| world.spawn((part, UiTransform::IDENTITY)).id());
+
+        world.run_system_once(orient_live_minimap).unwrap();
+
+        let view_center = Vec2::splat(LIVE_MINIMAP_SIZE / 2.0);
+        let transform = |entity: Entity| *world.get::<UiTransform>(entity).unwrap();
+        // The track turns by the car's heading, with the car moved under the centered marker
+        assert!((transform(parts[0]).rotation.as_radians() - heading).abs() < 1e-5);
+        let track_offset = view_center - minimap.map_position(car_position);
+        assert_eq!(transform(parts[1]).translation, Val2::px(track_offset.x, track_offset.y));
+        assert_eq!(transform(parts[2]).translation, Val2::px(view_center.x, view_center.y));
+        assert_eq!(transform(parts[2]).rotation, Rot2::IDENTITY);
+    }
+
+    #[test]
+    fn test_start_gantry_counts_down_then_goes_green_and_leaves() {
+        let mut world = World::new();
+        let palette = Palette::default();
+        world.insert_resource(palette);
+        world.init_resource::<Time>();
+        init_race_state(&mut world.commands(), None, Difficulty::default(), FinishCondition::default());
+        spawn_start_gantry(&mut world.commands(), Vec2::ZERO, Direction::Up);
+        world.flush();
+        let light_colors = |world: &mut World| {
+            let mut lights = world.query::<(&Sprite, &StartLight)>();
+            let mut lights: Vec<_> = lights.iter(world).map(|(sprite, light)| (light.0, sprite.color)).collect();
+            lights.sort_by_key(|&(index, _)| index);
+            lights.into_iter().map(|(_, color)| color).collect::<Vec<_>>()
+        };
+        let advance = |world: &mut World, secs: f32| {
+            world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(secs));
+            world.run_system_once(update_start_gantry).unwrap();
+        };
+
+        // The lights come on red one at a time, holding the cars on the grid
+        advance(&mut world, 0.0);
+        assert_eq!(light_colors(&mut world), vec![START_LIGHT_OFF_COLOR; START_LIGHT_COUNT]);
+        advance(&mut world, START_LIGHT_INTERVAL);
+        let mut one_lit = vec![START_LIGHT_OFF_COLOR; START_LIGHT_COUNT];
+        one_lit[0] = palette.error_text;
+        assert_eq!(light_colors(&mut world), one_lit);
+        assert!(!world.run_system_once(start_lights_green).unwrap());
+
+        // After the last red one they all turn green, and the cars may go
+        advance(&mut world, START_LIGHT_INTERVAL * (START_LIGHT_COUNT + 1) as f32);
+        assert_eq!(light_colors(&mut world), vec![palette.start_line; START_LIGHT_COUNT]);
+        assert!(world.run_system_once(start_lights_green).unwrap());
+
+        // The lights go with the gantry once they've been green for a moment
+        advance(&mut world, START_GANTRY_GO_DURATION);
+        assert_eq!(world.query::<&StartGantry>().iter(&world).count(), 0);
+        assert!(light_colors(&mut world).is_empty());
     }
 }
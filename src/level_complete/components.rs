@@ -1,4 +1,7 @@
 use bevy::prelude::*;
+use std::path::PathBuf;
+
+use crate::save::RunSetup;
 
 /// Marker component for entities that belong to the level complete screen
 #[derive(Component)]
@@ -8,7 +11,64 @@ pub struct OnLevelCompleteScreen;
 #[derive(Component)]
 pub enum LevelCompleteButtonAction {
     RestartLevel,
-    NextLevel,
+    /// Moves on to the next level, or shows the notice it holds while that level is still locked
+    NextLevel(Option<String>),
+    WatchReplay,
+    /// Shows the track tinted by where the player's runs slow down or leave the road,
+    /// see `show_heatmap`
+    ShowHeatmap,
+    /// Copies (or shows) the run's result line, see `share_text`
+    ShareResult(String),
+    /// Renders the run's result card and saves it as a PNG, see `start_result_card_export`
+    ExportCard(ResultCard),
     MainMenu,
     Quit,
 }
+
+/// What an exported result card shows
+#[derive(Clone)]
+pub struct ResultCard {
+    /// `CurrentSave`'s player name
+    pub player_name: String,
+    pub level: usize,
+    /// `RaceState.final_time`
+    pub time: f32,
+    /// `RaceState.difficulty` and the save's car class
+    pub setup: RunSetup,
+    /// See `medal_for`
+    pub medal: Option<Medal>,
+}
+
+/// Medal a finish time can earn against the level's target time (see `medal_for`)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Medal {
+    /// Within the bronze target time (see `bronze_target_time`)
+    Bronze,
+}
+
+impl Medal {
+    /// Display name shown on the result screens and level cards
+    pub fn label(&self) -> &'static str {
+        match self {
+            Medal::Bronze => "Bronze",
+        }
+    }
+}
+
+/// Marker for the result card's camera and UI, despawned once the card is saved
+#[derive(Component)]
+pub struct ResultCardEntity;
+
+/// On the result card's camera: the card is captured to `path` once its minimap is in
+/// and a few frames have rendered, then the card is despawned.
+#[derive(Component)]
+pub struct ResultCardExport {
+    pub image: Handle<Image>,
+    pub path: PathBuf,
+    pub frames_remaining: u32,
+    pub captured: bool,
+}
+
+/// On the screenshot of a result card: the file the captured card is written to
+#[derive(Component)]
+pub struct ResultCardCapture(pub PathBuf);
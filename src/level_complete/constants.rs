@@ -1,4 +1,7 @@
-use crate::styles::menu::{LARGE_TEXT_FONT_SIZE, MEDIUM_TEXT_FONT_SIZE, SMALL_MARGIN, STANDARD_MARGIN};
+use crate::level_menu::constants::{MINI_MAP_HEIGHT, MINI_MAP_WIDTH};
+use crate::styles::menu::{
+    LARGE_TEXT_FONT_SIZE, MEDIUM_TEXT_FONT_SIZE, SMALL_MARGIN, SMALL_TEXT_FONT_SIZE, STANDARD_MARGIN,
+};
 
 // ============================================================================
 // Font Sizes (re-exported from shared styles for convenience)
@@ -6,6 +9,7 @@ use crate::styles::menu::{LARGE_TEXT_FONT_SIZE, MEDIUM_TEXT_FONT_SIZE, SMALL_MAR
 
 pub const TIME_DISPLAY_FONT_SIZE: f32 = LARGE_TEXT_FONT_SIZE;
 pub const NEW_BEST_FONT_SIZE: f32 = MEDIUM_TEXT_FONT_SIZE;
+pub const BREAKDOWN_FONT_SIZE: f32 = SMALL_TEXT_FONT_SIZE;
 
 // ============================================================================
 // Layout Constants
@@ -14,3 +18,24 @@ pub const NEW_BEST_FONT_SIZE: f32 = MEDIUM_TEXT_FONT_SIZE;
 pub const TIME_DISPLAY_MARGIN: f32 = SMALL_MARGIN;
 pub const NEW_BEST_MARGIN: f32 = STANDARD_MARGIN;
 pub const PLACEHOLDER_HEIGHT: f32 = MEDIUM_TEXT_FONT_SIZE;
+
+// ============================================================================
+// Result Card
+// ============================================================================
+
+/// Size of the exported result card image, in pixels
+pub const RESULT_CARD_WIDTH: u32 = 800;
+pub const RESULT_CARD_HEIGHT: u32 = 400;
+pub const RESULT_CARD_PADDING: f32 = 30.0;
+/// The card's minimap keeps the level card previews' aspect ratio (it's the same image)
+pub const RESULT_CARD_MINIMAP_HEIGHT: f32 = RESULT_CARD_HEIGHT as f32 - 2.0 * RESULT_CARD_PADDING;
+pub const RESULT_CARD_MINIMAP_WIDTH: f32 = RESULT_CARD_MINIMAP_HEIGHT * MINI_MAP_WIDTH / MINI_MAP_HEIGHT;
+pub const RESULT_CARD_HEADER_FONT_SIZE: f32 = SMALL_TEXT_FONT_SIZE;
+pub const RESULT_CARD_NAME_FONT_SIZE: f32 = 48.0;
+pub const RESULT_CARD_TIME_FONT_SIZE: f32 = 56.0;
+pub const RESULT_CARD_DETAIL_FONT_SIZE: f32 = MEDIUM_TEXT_FONT_SIZE;
+/// Frames the card renders (for its layout and text) before it's captured, and after,
+/// so the capture completes before the camera is despawned
+pub const RESULT_CARD_SETTLE_FRAMES: u32 = 3;
+/// Render layer of the card's camera, above the minimap layers, so it draws none of the race
+pub const RESULT_CARD_RENDER_LAYER: usize = 32;
@@ -0,0 +1,125 @@
+use crate::car::components::CarClass;
+use crate::level_complete::components::Medal;
+use crate::save::{sanitize_filename, RunSetup};
+use crate::settings::components::Difficulty;
+use crate::utils::format_time;
+
+/// Builds the shareable result line for a finished run, e.g.
+/// `BevyDriver L7 seed=7 time=00:42.18 medal=Bronze difficulty=Hard car=Speedster`.
+/// The seed is left out for the fixed tracks (None), the difficulty on Normal, the car in the
+/// Balanced class, and the medal is `None` when the bronze target (if known) wasn't beaten.
+pub fn share_text(
+    level: usize,
+    seed: Option<u64>,
+    time: f32,
+    setup: RunSetup,
+    bronze_target: Option<f32>,
+) -> String {
+    let seed = seed.map(|seed| format!(" seed={}", seed)).unwrap_or_default();
+    let medal = medal_label(medal_for(time, bronze_target));
+    let difficulty = match setup.difficulty {
+        Difficulty::Normal => String::new(),
+        difficulty => format!(" difficulty={}", difficulty.label()),
+    };
+    let car = match setup.car_class {
+        CarClass::Balanced => String::new(),
+        car_class => format!(" car={}", car_class.label()),
+    };
+    format!("BevyDriver L{}{} time={} medal={}{}{}", level, seed, format_time(time), medal, difficulty, car)
+}
+
+/// Medal earned by a time: bronze if it beat the bronze target, none otherwise
+/// (or when the target isn't known)
+pub fn medal_for(time: f32, bronze_target: Option<f32>) -> Option<Medal> {
+    match bronze_target {
+        Some(target) if time <= target => Some(Medal::Bronze),
+        _ => None,
+    }
+}
+
+/// Label of the medal earned by a time (see `medal_for`), "None" without one
+pub fn medal_label(medal: Option<Medal>) -> &'static str {
+    medal.map_or("None", |medal| medal.label())
+}
+
+/// Ranks a time against the best times of the other profiles on this machine, e.g.
+/// "2nd of 4 on this PC". Ties go to the time already set. Returns None when no other
+/// profile has a time for the level, as there's nobody to rank against.
+pub fn local_rank_text(time: f32, other_times: &[f32]) -> Option<String> {
+    if other_times.is_empty() {
+        return None;
+    }
+    let rank = 1 + other_times.iter().filter(|&&other| other <= time).count();
+    Some(format!("{} of {} on this PC", ordinal(rank), other_times.len() + 1))
+}
+
+/// Formats a position as "1st", "2nd", "3rd", "4th", ..., "11th", ..., "21st"
+fn ordinal(n: usize) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// File name of an exported result card, e.g. `Alice_level_7_20250101_120000.png`.
+/// The timestamp keeps cards of the same level from overwriting each other.
+pub fn card_file_name(player_name: &str, level: usize, timestamp: &str) -> String {
+    format!("{}_level_{}_{}.png", sanitize_filename(player_name), level, timestamp)
+}
+
+/// Places text on the system clipboard
+#[cfg(feature = "clipboard")]
+pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|e| e.to_string())
+}
+
+/// Clipboard support is compiled out; the caller shows the text instead
+#[cfg(not(feature = "clipboard"))]
+pub fn copy_to_clipboard(_text: &str) -> Result<(), String> {
+    Err("clipboard support isn't enabled in this build".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_share_text_includes_seed_and_medal() {
+        assert_eq!(
+            share_text(7, Some(7), 42.18, RunSetup::default(), Some(50.0)),
+            "BevyDriver L7 seed=7 time=00:42.18 medal=Bronze"
+        );
+        // Fixed tracks have no seed, a slow time earns no medal, and other setups are named
+        let setup = RunSetup { difficulty: Difficulty::Hard, car_class: CarClass::Speedster };
+        assert_eq!(
+            share_text(2, None, 75.5, setup, Some(60.0)),
+            "BevyDriver L2 time=01:15.50 medal=None difficulty=Hard car=Speedster"
+        );
+    }
+
+    #[test]
+    fn test_local_rank_counts_faster_and_tied_profiles() {
+        assert_eq!(local_rank_text(40.0, &[]), None);
+        assert_eq!(
+            local_rank_text(40.0, &[35.0, 40.0, 52.0]).as_deref(),
+            Some("3rd of 4 on this PC")
+        );
+        assert_eq!(local_rank_text(30.0, &[35.0]).as_deref(), Some("1st of 2 on this PC"));
+        assert_eq!(ordinal(12), "12th");
+        assert_eq!(ordinal(22), "22nd");
+    }
+
+    #[test]
+    fn test_card_file_name_is_sanitized() {
+        assert_eq!(
+            card_file_name("Al/ice", 7, "20250101_120000"),
+            "Al_ice_level_7_20250101_120000.png"
+        );
+    }
+}
@@ -1,14 +1,19 @@
 pub mod components;
 pub mod constants;
+pub mod helpers;
 pub mod systems;
 
 use bevy::prelude::*;
 use crate::constants::GameState;
 use crate::utils::despawn_all;
 use crate::start_menu::components::GameEntity;
-use components::OnLevelCompleteScreen;
-use systems::{level_complete_action, spawn_level_complete_menu};
-use crate::styles::menu::standard_button_system;
+use components::{OnLevelCompleteScreen, ResultCardEntity};
+use systems::{
+    export_result_card, level_complete_action, spawn_level_complete_menu, start_result_card_export,
+};
+use crate::level_menu::minimap::{capture_minimaps, cleanup_minimap_rendering, MinimapCache};
+use crate::level_menu::systems::update_minimap_previews;
+use crate::styles::menu::{dismiss_notice_system, standard_button_system, NoticeOverlay};
 
 pub struct LevelCompletePlugin;
 
@@ -16,11 +21,27 @@ impl Plugin for LevelCompletePlugin {
     fn build(&self, app: &mut App) {
         app
             .add_systems(OnEnter(GameState::LevelComplete), spawn_level_complete_menu)
-            .add_systems(OnExit(GameState::LevelComplete), (despawn_all::<OnLevelCompleteScreen>, despawn_all::<GameEntity>))
+            .add_systems(
+                OnExit(GameState::LevelComplete),
+                (
+                    despawn_all::<OnLevelCompleteScreen>,
+                    despawn_all::<GameEntity>,
+                    despawn_all::<NoticeOverlay>,
+                    despawn_all::<ResultCardEntity>,
+                    cleanup_minimap_rendering,
+                ),
+            )
             .add_systems(
                 Update,
-                (standard_button_system, level_complete_action)
+                (standard_button_system, level_complete_action, dismiss_notice_system)
                     .run_if(in_state(GameState::LevelComplete)),
+            )
+            // The result card reuses the level menu's minimaps (and its cache)
+            .add_systems(
+                Update,
+                (start_result_card_export, capture_minimaps, update_minimap_previews, export_result_card)
+                    .run_if(in_state(GameState::LevelComplete))
+                    .run_if(resource_exists::<MinimapCache>),
             );
     }
 }
@@ -1,60 +1,289 @@
 use bevy::app::AppExit;
+use bevy::camera::{visibility::RenderLayers, RenderTarget};
 use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use std::path::PathBuf;
 
-use crate::constants::{CurrentLevel, GameState};
+use crate::analysis::components::CurrentAnalysis;
+use crate::constants::{CurrentLevel, GameState, PracticeMode, SeedOverride, TwoPlayerMode};
 use crate::hud::components::RaceState;
-use crate::level_complete::components::{LevelCompleteButtonAction, OnLevelCompleteScreen};
-use crate::level_complete::constants::{
-    NEW_BEST_FONT_SIZE, NEW_BEST_MARGIN, PLACEHOLDER_HEIGHT, TIME_DISPLAY_FONT_SIZE, TIME_DISPLAY_MARGIN,
+use crate::level_complete::components::{
+    LevelCompleteButtonAction, OnLevelCompleteScreen, ResultCard, ResultCardCapture, ResultCardEntity,
+    ResultCardExport,
 };
-use crate::save::{save_to_file, CurrentSave};
+use crate::level_complete::constants::*;
+use crate::level_complete::helpers::{
+    card_file_name, copy_to_clipboard, local_rank_text, medal_for, medal_label, share_text,
+};
+use crate::level_menu::components::LevelMiniMapPreview;
+use crate::level_menu::minimap::{render_level_minimaps, MinimapCache, MinimapLayers};
+use crate::level_menu::systems::MinimapImageAdded;
+use crate::props::components::Score;
+use crate::replay::components::GhostRecorder;
+use crate::road::components::PlayedTrackInfo;
+use crate::road::helpers::bronze_target_time;
+use crate::road::tracks::{get_level_track, get_played_track};
+use crate::save::{
+    get_cards_dir, save_or_report, show_toast, CompletionResult, CurrentSave, RunSetup, SaveCache,
+    SaveResult, SaveToast,
+};
+use crate::sequence::components::SequenceState;
+use crate::settings::components::{FinishCondition, GameSettings};
 use crate::styles::colors::{
-    MENU_TEXT_COLOR, OVERLAY_BACKGROUND_COLOR, SUCCESS_TEXT_COLOR,
+    MENU_BACKGROUND_COLOR, MENU_TEXT_COLOR, OVERLAY_BACKGROUND_COLOR, SECONDARY_TEXT_COLOR,
 };
 use crate::styles::menu::{
-    column_centered, spawn_menu_container, spawn_button_with_width, title_style, LARGE_BUTTON_WIDTH,
+    button_row, column_centered, spawn_menu_container, spawn_button_with_width,
+    spawn_notice_overlay, title_style, LARGE_BUTTON_WIDTH,
 };
+use crate::styles::palette::Palette;
+use crate::styles::theme::GlowSettings;
+use crate::utils::format_time;
 
 // ============================================================================
 // Level Complete Menu Spawning
 // ============================================================================
 
-/// Spawns the level complete menu UI and auto-saves progress
+/// Spawns the level complete menu UI and auto-saves progress (except for practice, two-player
+/// and replaced-seed runs).
+/// During a time trial only the progress is saved; the sequence moves on without the menu.
 pub fn spawn_level_complete_menu(
     mut commands: Commands,
     race_state: Res<RaceState>,
     current_level: Res<CurrentLevel>,
-    mut current_save: ResMut<CurrentSave>,
+    (mut current_save, mut save_results): (ResMut<CurrentSave>, MessageWriter<SaveResult>),
+    (recorder, analysis, score, track_info): (
+        Res<GhostRecorder>,
+        Res<CurrentAnalysis>,
+        Res<Score>,
+        Res<PlayedTrackInfo>,
+    ),
+    (palette, settings, mut save_cache): (Res<Palette>, Res<GameSettings>, ResMut<SaveCache>),
+    (practice, two_player, sequence, seed_override): (
+        Res<PracticeMode>,
+        Res<TwoPlayerMode>,
+        Res<SequenceState>,
+        Res<SeedOverride>,
+    ),
 ) {
-    // Auto-save progress if we have an active save
-    let mut new_best = false;
+    // Auto-save progress if we have an active save (invalid, practice and two-player runs are never
+    // recorded, nor are runs on a seed picked on the track preview, as that isn't the level's track,
+    // or lap-only finishes, which skip part of it).
+    // Best times keep the difficulty and car class they were set with, which are shown next to them.
+    let mut completion = None;
+    let mut previous_coins = None;
+    let difficulty = race_state.difficulty;
+    let setup = RunSetup {
+        difficulty,
+        car_class: current_save.get().map(|s| s.car_class).unwrap_or_default(),
+    };
+    let solo_time = race_state
+        .final_time
+        .filter(|_| race_state.is_valid && !practice.0 && !two_player.0);
+    let replaced_seed = seed_override.replaces(current_level.0);
+    let lap_only = race_state.finish_condition == FinishCondition::LapOnly;
+    let recorded_time = solo_time.filter(|_| !replaced_seed && !lap_only);
     if let Some(save_data) = current_save.get_mut() {
-        if let Some(final_time) = race_state.final_time {
-            new_best = save_data.record_level_completion(current_level.0, final_time);
+        if let Some(final_time) = recorded_time {
+            let previous_setup = save_data.get_best_run(current_level.0).map(|(_, setup)| setup);
+            let result = save_data.record_level_completion(
+                current_level.0,
+                final_time,
+                difficulty,
+                race_state.unlock_target,
+            );
+            completion = Some((result, previous_setup.unwrap_or_default()));
+            if score.total > 0 {
+                previous_coins = Some(save_data.record_coins(current_level.0, score.collected));
+            }
             // Save to file
-            let _ = save_to_file(save_data);
+            save_or_report(save_data, &mut save_results);
         }
     }
 
-    let final_time_str = race_state
+    // Time trials move straight on to the next level (see advance_sequence)
+    if sequence.is_active() {
+        return;
+    }
+
+    // The bronze time is only named when medal gating is what held back a recorded run; other
+    // runs (practice, two-player, invalid, ...) can't unlock the next level at all
+    let next_level = current_level.0 + 1;
+    let locked_notice = current_save
+        .get()
+        .filter(|save| next_level > save.highest_level_unlocked)
+        .map(|_| {
+            if recorded_time.is_some() && race_state.unlock_target.is_some() {
+                format!("Beat the bronze time to unlock level {}.", next_level)
+            } else {
+                format!("Level {} is still locked.", next_level)
+            }
+        });
+
+    let time_str = race_state
         .final_time
         .map(|t| format!("{:.2}s", t))
         .unwrap_or_else(|| "N/A".to_string());
+    let final_time_str = if practice.0 {
+        "Practice — not saved".to_string()
+    } else if two_player.0 {
+        let winner = race_state.winner.map_or("Nobody", |player| player.label());
+        format!("{} — {} wins, not saved", time_str, winner)
+    } else if replaced_seed {
+        format!("{} — new seed, not saved", time_str)
+    } else if lap_only {
+        format!("{} — lap only, not saved", time_str)
+    } else {
+        let time_str = format!("{}{}", time_str, setup.time_suffix());
+        match race_state.unlock_target {
+            Some(target) => format!("{} (Bronze {:.2}s)", time_str, target),
+            None => time_str,
+        }
+    };
+
+    // Valid solo runs of the whole track can be shared, with the seed they were played on.
+    // The layout doesn't depend on the props, so the track is regenerated without them just to get
+    // its bronze target.
+    let shared_time = solo_time.filter(|_| !lap_only);
+    let bronze_target = shared_time
+        .and_then(|_| get_played_track(current_level.0, &seed_override, 0, 0))
+        .map(|track| bronze_target_time(&track.layout));
+    let share = shared_time.map(|time| {
+        share_text(current_level.0, seed_override.seed_for(current_level.0), time, setup, bronze_target)
+    });
+    // Recorded runs can also be exported as a result card with the player's name
+    let card = recorded_time.zip(current_save.get()).map(|(time, save)| ResultCard {
+        player_name: save.player_name.clone(),
+        level: current_level.0,
+        time,
+        setup,
+        medal: medal_for(time, bronze_target),
+    });
+    // Recorded runs are ranked against the other profiles' best times set on the same difficulty
+    // in the same car class
+    let local_rank = recorded_time
+        .zip(current_save.get())
+        .filter(|_| settings.local_ranking)
+        .and_then(|(time, save)| {
+            let other_times: Vec<f32> = save_cache
+                .saves()
+                .iter()
+                .filter(|other| other.player_name != save.player_name)
+                .filter_map(|other| other.get_best_run(current_level.0))
+                .filter(|&(_, other_setup)| other_setup == setup)
+                .map(|(time, _)| time)
+                .collect();
+            local_rank_text(time, &other_times).map(|rank| format!("{}{}", rank, setup.time_suffix()))
+        });
 
     spawn_menu_container(&mut commands, OnLevelCompleteScreen, OVERLAY_BACKGROUND_COLOR)
         .with_children(|parent| {
             parent.spawn(column_centered()).with_children(|parent| {
                 parent.spawn((Text::new("Level Complete!"), title_style()));
-                spawn_time_display(parent, &final_time_str, new_best);
-                spawn_button_with_width(parent, "Restart Level", LevelCompleteButtonAction::RestartLevel, LARGE_BUTTON_WIDTH);
-                spawn_button_with_width(parent, "Next Level", LevelCompleteButtonAction::NextLevel, LARGE_BUTTON_WIDTH);
+                // Practice runs don't tick the timer, so there's nothing to break down
+                let breakdown = (!practice.0).then_some((
+                    race_state.on_road_time,
+                    race_state.off_road_time,
+                    race_state.penalty_time,
+                ));
+                spawn_time_display(
+                    parent,
+                    &final_time_str,
+                    breakdown,
+                    recorded_time.zip(completion),
+                    race_state.is_valid,
+                    &palette,
+                );
+                if let Some(rank) = local_rank {
+                    parent.spawn((
+                        Text::new(rank),
+                        TextFont {
+                            font_size: BREAKDOWN_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(SECONDARY_TEXT_COLOR),
+                        Node {
+                            margin: UiRect::bottom(Val::Px(TIME_DISPLAY_MARGIN)),
+                            ..default()
+                        },
+                    ));
+                }
+                if score.total > 0 {
+                    spawn_coin_display(parent, &score, previous_coins);
+                }
+                // The seed (with the generator settings) to replay the same track on the track preview
+                parent.spawn((
+                    Text::new(format!("Track: {}", track_info.label())),
+                    TextFont {
+                        font_size: BREAKDOWN_FONT_SIZE,
+                        ..default()
+                    },
+                    TextColor(SECONDARY_TEXT_COLOR),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(TIME_DISPLAY_MARGIN)),
+                        ..default()
+                    },
+                ));
+                // Restart, replay and the heatmap share a row to keep the menu within the window height
+                parent.spawn(button_row()).with_children(|parent| {
+                    spawn_button_with_width(parent, "Restart Level", LevelCompleteButtonAction::RestartLevel, LARGE_BUTTON_WIDTH);
+                    if recorder.has_samples() {
+                        spawn_button_with_width(parent, "Watch Replay", LevelCompleteButtonAction::WatchReplay, LARGE_BUTTON_WIDTH);
+                    }
+                    if analysis.0.is_some() {
+                        spawn_button_with_width(parent, "Heatmap", LevelCompleteButtonAction::ShowHeatmap, LARGE_BUTTON_WIDTH);
+                    }
+                });
+                // As do next level and sharing
+                parent.spawn(button_row()).with_children(|parent| {
+                    spawn_button_with_width(parent, "Next Level", LevelCompleteButtonAction::NextLevel(locked_notice), LARGE_BUTTON_WIDTH);
+                    if let Some(text) = share {
+                        spawn_button_with_width(parent, "Share Result", LevelCompleteButtonAction::ShareResult(text), LARGE_BUTTON_WIDTH);
+                    }
+                    if let Some(card) = card {
+                        spawn_button_with_width(parent, "Export Card", LevelCompleteButtonAction::ExportCard(card), LARGE_BUTTON_WIDTH);
+                    }
+                });
                 spawn_button_with_width(parent, "Main Menu", LevelCompleteButtonAction::MainMenu, LARGE_BUTTON_WIDTH);
                 spawn_button_with_width(parent, "Quit", LevelCompleteButtonAction::Quit, LARGE_BUTTON_WIDTH);
             });
         });
 }
 
-fn spawn_time_display(parent: &mut ChildSpawnerCommands, time_str: &str, is_new_best: bool) {
+/// Spawns the coins collected out of the track's total. `previous_coins` is the previous
+/// best when the run was recorded (None inside it if no run collected any before).
+fn spawn_coin_display(parent: &mut ChildSpawnerCommands, score: &Score, previous_coins: Option<Option<u32>>) {
+    let best = match previous_coins {
+        Some(Some(best)) if score.collected <= best => format!(" (Best {})", best),
+        Some(_) if score.collected > 0 => " (New best!)".to_string(),
+        _ => String::new(),
+    };
+    parent.spawn((
+        Text::new(format!("Coins: {}/{}{}", score.collected, score.total, best)),
+        TextFont {
+            font_size: BREAKDOWN_FONT_SIZE,
+            ..default()
+        },
+        TextColor(SECONDARY_TEXT_COLOR),
+        Node {
+            margin: UiRect::bottom(Val::Px(TIME_DISPLAY_MARGIN)),
+            ..default()
+        },
+    ));
+}
+
+/// Spawns the final time and its (on road, off road, respawn penalties) breakdown, followed by a line comparing
+/// it to the previous best (or flagging an invalid run). `completion` is the recorded time, its result
+/// and the setup the previous best was set with.
+fn spawn_time_display(
+    parent: &mut ChildSpawnerCommands,
+    time_str: &str,
+    breakdown: Option<(f32, f32, f32)>,
+    completion: Option<(f32, (CompletionResult, RunSetup))>,
+    is_valid: bool,
+    palette: &Palette,
+) {
     parent.spawn((
         Text::new(format!("Time: {}", time_str)),
         TextFont {
@@ -68,14 +297,54 @@ fn spawn_time_display(parent: &mut ChildSpawnerCommands, time_str: &str, is_new_
         },
     ));
 
-    if is_new_best {
+    // The parts add up to the final time
+    if let Some((on_road, off_road, penalties)) = breakdown {
+        parent.spawn((
+            Text::new(format!(
+                "On road: {:.1}s, Off road penalty: {:.1}s\nRespawn penalties: {:.1}s",
+                on_road, off_road, penalties
+            )),
+            TextLayout::new_with_justify(Justify::Center),
+            TextFont {
+                font_size: BREAKDOWN_FONT_SIZE,
+                ..default()
+            },
+            TextColor(SECONDARY_TEXT_COLOR),
+            Node {
+                margin: UiRect::bottom(Val::Px(TIME_DISPLAY_MARGIN)),
+                ..default()
+            },
+        ));
+    }
+
+    let status = if !is_valid {
+        Some((
+            "Invalid run - track not driven as one lap".to_string(),
+            palette.error_text,
+        ))
+    } else {
+        completion.map(|(time, (result, previous_setup))| match result.previous_best {
+            None => ("First completion!".to_string(), palette.success_text),
+            Some(previous) if result.is_new_best => (
+                format!("New Best Time!\n{}", format_time_comparison(previous, previous_setup, time)),
+                palette.success_text,
+            ),
+            Some(previous) => (
+                format_time_comparison(previous, previous_setup, time),
+                SECONDARY_TEXT_COLOR,
+            ),
+        })
+    };
+
+    if let Some((text, color)) = status {
         parent.spawn((
-            Text::new("New Best Time!"),
+            Text::new(text),
             TextFont {
                 font_size: NEW_BEST_FONT_SIZE,
                 ..default()
             },
-            TextColor(SUCCESS_TEXT_COLOR),
+            TextColor(color),
+            TextLayout::new_with_justify(Justify::Center),
             Node {
                 margin: UiRect::bottom(Val::Px(NEW_BEST_MARGIN)),
                 ..default()
@@ -92,19 +361,38 @@ fn spawn_time_display(parent: &mut ChildSpawnerCommands, time_str: &str, is_new_
     }
 }
 
+/// Formats "Previous: 00:45.21 (Hard)  →  New: 00:43.88 (-1.33)", naming the previous best's
+/// setup when it wasn't the default (see `RunSetup::time_suffix`)
+fn format_time_comparison(previous: f32, previous_setup: RunSetup, new: f32) -> String {
+    format!(
+        "Previous: {}{}  →  New: {} ({:+.2})",
+        format_time(previous),
+        previous_setup.time_suffix(),
+        format_time(new),
+        new - previous
+    )
+}
+
 // ============================================================================
 // Button Actions
 // ============================================================================
 
+/// Level complete menu buttons whose interaction changed this frame
+pub type LevelCompleteInteractionQuery<'w, 's> = Query<
+    'w,
+    's,
+    (&'static Interaction, &'static LevelCompleteButtonAction),
+    (Changed<Interaction>, With<Button>),
+>;
+
 /// Handles level complete menu button actions
 pub fn level_complete_action(
-    interaction_query: Query<
-        (&Interaction, &LevelCompleteButtonAction),
-        (Changed<Interaction>, With<Button>),
-    >,
+    interaction_query: LevelCompleteInteractionQuery,
+    mut commands: Commands,
     mut app_exit_writer: MessageWriter<AppExit>,
     mut game_state: ResMut<NextState<GameState>>,
     mut current_level: ResMut<CurrentLevel>,
+    settings: Res<GameSettings>,
 ) {
     for (interaction, button_action) in &interaction_query {
         if *interaction == Interaction::Pressed {
@@ -113,11 +401,42 @@ pub fn level_complete_action(
                     // Restart the current level (level stays the same)
                     game_state.set(GameState::Playing);
                 }
-                LevelCompleteButtonAction::NextLevel => {
-                    // Levels 1-3 are hardcoded, levels 4+ are randomly generated
-                    current_level.0 += 1;
+                LevelCompleteButtonAction::NextLevel(locked_notice) => {
+                    if let Some(notice) = locked_notice {
+                        spawn_notice_overlay(&mut commands, notice);
+                        continue;
+                    }
+                    // Levels up to `BUILTIN_LEVEL_COUNT` are hardcoded, later ones are generated
+                    let next_level = current_level.0 + 1;
+                    let (min_props, max_props) = settings.difficulty.powerup_count_range();
+                    if get_level_track(next_level, min_props, max_props).is_none() {
+                        spawn_notice_overlay(
+                            &mut commands,
+                            &format!("Level {} couldn't be generated.", next_level),
+                        );
+                        continue;
+                    }
+                    current_level.0 = next_level;
                     game_state.set(GameState::Playing);
                 }
+                LevelCompleteButtonAction::WatchReplay => {
+                    game_state.set(GameState::Replay);
+                }
+                // Handled by show_heatmap, which tints the road
+                LevelCompleteButtonAction::ShowHeatmap => {}
+                LevelCompleteButtonAction::ShareResult(text) => {
+                    // Without a clipboard the line is shown so it can be copied or screenshotted
+                    let message = match copy_to_clipboard(text) {
+                        Ok(()) => format!("Copied to clipboard:\n{}", text),
+                        Err(e) => {
+                            info!("Result not copied ({}), showing it instead", e);
+                            format!("Share your result:\n{}", text)
+                        }
+                    };
+                    spawn_notice_overlay(&mut commands, &message);
+                }
+                // Handled by start_result_card_export, which needs the render assets
+                LevelCompleteButtonAction::ExportCard(_) => {}
                 LevelCompleteButtonAction::MainMenu => {
                     game_state.set(GameState::StartMenu);
                 }
@@ -128,3 +447,226 @@ pub fn level_complete_action(
         }
     }
 }
+
+// ============================================================================
+// Result Card Export
+// ============================================================================
+
+/// Starts exporting the result card when its button is pressed: spawns the card on an
+/// off-screen camera and starts rendering the level's minimap if it isn't cached.
+/// The card is saved by `export_result_card`.
+pub fn start_result_card_export(
+    interaction_query: LevelCompleteInteractionQuery,
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    (mut minimap_cache, mut layers): (ResMut<MinimapCache>, ResMut<MinimapLayers>),
+    glow: Res<GlowSettings>,
+) {
+    for (interaction, button_action) in &interaction_query {
+        let (Interaction::Pressed, LevelCompleteButtonAction::ExportCard(card)) = (interaction, button_action) else {
+            continue;
+        };
+
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S").to_string();
+        let path = match get_cards_dir() {
+            Ok(dir) => dir.join(card_file_name(&card.player_name, card.level, &timestamp)),
+            Err(e) => {
+                spawn_notice_overlay(&mut commands, &format!("Couldn't export the card: {}", e));
+                continue;
+            }
+        };
+
+        render_level_minimaps(
+            &mut commands,
+            (&mut images, &mut meshes, &mut materials),
+            &mut minimap_cache,
+            &mut layers,
+            &glow,
+            [card.level],
+            Vec::new(),
+        );
+        spawn_result_card(&mut commands, &mut images, card, path);
+    }
+}
+
+/// Spawns the result card: an off-screen camera and the card's UI targeting it.
+/// The details are on the left and the level's minimap (filled in by
+/// `update_minimap_previews`) on the right:
+///
+/// ```text
+/// BevyDriver        +---------+
+/// <player name>     |         |
+/// Level <n>         | minimap |
+/// <time>            |         |
+/// Medal: <medal>    +---------+
+/// ```
+fn spawn_result_card(commands: &mut Commands, images: &mut Assets<Image>, card: &ResultCard, path: PathBuf) {
+    let image = images.add(Image::new_target_texture(
+        RESULT_CARD_WIDTH,
+        RESULT_CARD_HEIGHT,
+        TextureFormat::Rgba8UnormSrgb,
+    ));
+
+    let camera = commands
+        .spawn((
+            Camera2d,
+            Camera {
+                target: RenderTarget::from(image.clone()),
+                order: -2, // Render before the minimaps and the main camera
+                clear_color: ClearColorConfig::Custom(MENU_BACKGROUND_COLOR),
+                ..default()
+            },
+            RenderLayers::layer(RESULT_CARD_RENDER_LAYER),
+            ResultCardExport {
+                image,
+                path,
+                frames_remaining: RESULT_CARD_SETTLE_FRAMES,
+                captured: false,
+            },
+            ResultCardEntity,
+        ))
+        .id();
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                padding: UiRect::all(Val::Px(RESULT_CARD_PADDING)),
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(MENU_BACKGROUND_COLOR),
+            UiTargetCamera(camera),
+            ResultCardEntity,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(TIME_DISPLAY_MARGIN),
+                    ..default()
+                })
+                .with_children(|details| {
+                    details.spawn(card_text("BevyDriver", RESULT_CARD_HEADER_FONT_SIZE, SECONDARY_TEXT_COLOR));
+                    details.spawn(card_text(&card.player_name, RESULT_CARD_NAME_FONT_SIZE, MENU_TEXT_COLOR));
+                    details.spawn(card_text(
+                        &format!("Level {}", card.level),
+                        RESULT_CARD_DETAIL_FONT_SIZE,
+                        SECONDARY_TEXT_COLOR,
+                    ));
+                    details.spawn(card_text(&format_time(card.time), RESULT_CARD_TIME_FONT_SIZE, MENU_TEXT_COLOR));
+                    details.spawn(card_text(
+                        &format!("Difficulty: {}", card.setup.difficulty.label()),
+                        RESULT_CARD_DETAIL_FONT_SIZE,
+                        SECONDARY_TEXT_COLOR,
+                    ));
+                    details.spawn(card_text(
+                        &format!("Car: {}", card.setup.car_class.label()),
+                        RESULT_CARD_DETAIL_FONT_SIZE,
+                        SECONDARY_TEXT_COLOR,
+                    ));
+                    details.spawn(card_text(
+                        &format!("Medal: {}", medal_label(card.medal)),
+                        RESULT_CARD_DETAIL_FONT_SIZE,
+                        SECONDARY_TEXT_COLOR,
+                    ));
+                });
+
+            parent.spawn((
+                Node {
+                    width: Val::Px(RESULT_CARD_MINIMAP_WIDTH),
+                    height: Val::Px(RESULT_CARD_MINIMAP_HEIGHT),
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BorderColor::all(SECONDARY_TEXT_COLOR),
+                BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.5)),
+                LevelMiniMapPreview(card.level),
+            ));
+        });
+}
+
+/// Writes a captured result card to its file, then tells the player where it went,
+/// or shows the save toast when it couldn't be written
+fn write_result_card(
+    captured: On<ScreenshotCaptured>,
+    mut commands: Commands,
+    captures: Query<&ResultCardCapture>,
+    toast_query: Query<Entity, With<SaveToast>>,
+) {
+    let Ok(ResultCardCapture(path)) = captures.get(captured.entity) else {
+        return;
+    };
+    let written = captured
+        .image
+        .clone()
+        .try_into_dynamic()
+        .map_err(|e| e.to_string())
+        .and_then(|image| image.to_rgb8().save(path).map_err(|e| e.to_string()));
+    match written {
+        Ok(()) => spawn_notice_overlay(
+            &mut commands,
+            &format!("Result card saved to:\n{}", path.display()),
+        ),
+        Err(e) => {
+            warn!("Failed to save result card to {}: {}", path.display(), e);
+            show_toast(&mut commands, &toast_query, "Couldn't save the result card");
+        }
+    }
+}
+
+/// A line of text on the result card
+fn card_text(text: &str, font_size: f32, color: Color) -> impl Bundle {
+    (
+        Text::new(text),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(color),
+    )
+}
+
+/// Saves each result card once its minimap is in (or couldn't be generated) and it has
+/// rendered a few frames, then despawns the card after the capture has had time to complete
+pub fn export_result_card(
+    mut commands: Commands,
+    mut exports: Query<(Entity, &mut ResultCardExport)>,
+    pending_minimaps: Query<&LevelMiniMapPreview, Without<MinimapImageAdded>>,
+    minimap_cache: Res<MinimapCache>,
+    card_roots: Query<(Entity, &UiTargetCamera), With<ResultCardEntity>>,
+) {
+    let minimap_pending = pending_minimaps
+        .iter()
+        .any(|preview| !minimap_cache.failed_levels.contains(&preview.0));
+    if minimap_pending {
+        return;
+    }
+
+    for (camera, mut export) in &mut exports {
+        if export.frames_remaining > 0 {
+            export.frames_remaining -= 1;
+            continue;
+        }
+
+        if !export.captured {
+            commands
+                .spawn((Screenshot::image(export.image.clone()), ResultCardCapture(export.path.clone())))
+                .observe(write_result_card);
+            export.captured = true;
+            export.frames_remaining = RESULT_CARD_SETTLE_FRAMES;
+            continue;
+        }
+
+        commands.entity(camera).despawn();
+        for (root, target) in &card_roots {
+            if target.entity() == camera {
+                commands.entity(root).despawn();
+            }
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+use crate::level_complete::components::Medal;
+use crate::level_complete::helpers::medal_for;
+
 /// Marker component for entities that belong to the level menu screen
 #[derive(Component)]
 pub struct OnLevelMenuScreen;
@@ -9,10 +12,56 @@ pub struct OnLevelMenuScreen;
 pub enum LevelMenuButtonAction {
     /// Play the selected level
     PlayLevel(usize),
+    /// Pick the car class and color in the garage
+    Garage,
+    /// Ask to wipe this profile's level progress
+    ResetProgress,
     /// Return to start menu
     MainMenu,
 }
 
+/// Marker for the button toggling practice mode for the next level started
+#[derive(Component)]
+pub struct PracticeToggleButton;
+
+/// Marker for the button toggling two-player mode for the next level started
+#[derive(Component)]
+pub struct TwoPlayerToggleButton;
+
+/// Marker for the button switching the level list's order (see `LevelSortOrder`)
+#[derive(Component)]
+pub struct LevelSortButton;
+
+/// Order of the level menu's list, kept across menu visits
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LevelSortOrder {
+    /// By level number
+    #[default]
+    Level,
+    /// Easiest first, by difficulty rating
+    Difficulty,
+}
+
+/// Button on a level card switching what finishing that level takes (see `FinishCondition`).
+/// Its label is filled in by `update_finish_condition_labels`.
+#[derive(Component)]
+pub struct FinishConditionButton(pub usize);
+
+/// Marker for the button starting a time trial sequence
+#[derive(Component)]
+pub struct TimeTrialButton;
+
+/// Actions for the reset progress confirmation dialog
+#[derive(Component)]
+pub enum ResetConfirmButtonAction {
+    ConfirmReset,
+    CancelReset,
+}
+
+/// Marker for the reset progress confirmation overlay
+#[derive(Component)]
+pub struct ResetConfirmationOverlay;
+
 /// Marker for the scrollable level list container
 #[derive(Component)]
 pub struct LevelListContainer;
@@ -21,10 +70,54 @@ pub struct LevelListContainer;
 #[derive(Component)]
 pub struct LevelCard(pub usize);
 
-/// Marker for the mini-map preview area within a level card (for future use)
+/// Marker for a mini-map preview area (within a level card, or a load menu save slot)
 #[derive(Component)]
 pub struct LevelMiniMapPreview(pub usize);
 
 /// Marker for the time display text within a level card
 #[derive(Component)]
 pub struct LevelTimeDisplay(pub usize);
+
+/// How far the player has got with a level, shown over its mini-map
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LevelProgress {
+    /// Never raced
+    Untried,
+    /// Raced without finishing yet
+    Attempted,
+    /// Finished, slower than the bronze target time
+    Completed,
+    /// Finished within the bronze target time (see `bronze_target_time`)
+    Bronze,
+}
+
+impl LevelProgress {
+    /// Progress from the level's best time and attempts in the save, with the medal the
+    /// level complete screen gives (see `medal_for`).
+    /// Without a known bronze target a finished level counts as completed.
+    pub fn new(best_time: Option<f32>, attempts: u32, bronze_target: Option<f32>) -> Self {
+        match best_time {
+            Some(time) => match medal_for(time, bronze_target) {
+                Some(Medal::Bronze) => LevelProgress::Bronze,
+                None => LevelProgress::Completed,
+            },
+            None if attempts > 0 => LevelProgress::Attempted,
+            None => LevelProgress::Untried,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_shows_medal_only_within_target() {
+        assert_eq!(LevelProgress::new(None, 0, Some(30.0)), LevelProgress::Untried);
+        assert_eq!(LevelProgress::new(None, 2, Some(30.0)), LevelProgress::Attempted);
+        assert_eq!(LevelProgress::new(Some(35.0), 2, Some(30.0)), LevelProgress::Completed);
+        assert_eq!(LevelProgress::new(Some(30.0), 2, Some(30.0)), LevelProgress::Bronze);
+        // A level whose track couldn't be generated has no target to beat
+        assert_eq!(LevelProgress::new(Some(10.0), 1, None), LevelProgress::Completed);
+    }
+}
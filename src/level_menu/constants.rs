@@ -1,9 +1,11 @@
+use bevy::prelude::*;
+
 // ============================================================================
 // Level Menu Layout Constants
 // ============================================================================
 
 /// Height of the scrollable level list container
-pub const LEVEL_LIST_HEIGHT: f32 = 450.0;
+pub const LEVEL_LIST_HEIGHT: f32 = 380.0;
 
 /// Vertical margin around the level list
 pub const LEVEL_LIST_MARGIN: f32 = 20.0;
@@ -26,6 +28,24 @@ pub const MINI_MAP_WIDTH: f32 = 80.0;
 /// Height of the mini-map preview area
 pub const MINI_MAP_HEIGHT: f32 = 70.0;
 
+/// Border of the mini-map of a level not yet raced
+pub const MINI_MAP_BORDER: f32 = 1.0;
+
+/// Border of the mini-map of a level raced, ringed in its progress color
+pub const PROGRESS_RING_WIDTH: f32 = 2.0;
+
+/// Font size of the badge over a completed level's mini-map
+pub const PROGRESS_BADGE_FONT_SIZE: f32 = 11.0;
+
+/// Padding around the badge text
+pub const PROGRESS_BADGE_PADDING: f32 = 2.0;
+
+/// Background of the badge, dark so it reads over the track
+pub const PROGRESS_BADGE_BACKGROUND: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
+
+/// Color of the bronze medal badge and ring
+pub const BRONZE_MEDAL_COLOR: Color = Color::srgb(0.8, 0.5, 0.2);
+
 /// Font size for level number
 pub const LEVEL_NUMBER_FONT_SIZE: f32 = 28.0;
 
@@ -46,3 +66,15 @@ pub const LEVEL_STATUS_FONT_SIZE: f32 = 16.0;
 
 /// Width of the level number column
 pub const LEVEL_NUMBER_WIDTH: f32 = 140.0;
+
+/// Font size for the finish condition button next to the difficulty stars
+pub const FINISH_BUTTON_FONT_SIZE: f32 = 12.0;
+
+/// Horizontal padding inside the finish condition button
+pub const FINISH_BUTTON_PADDING: f32 = 4.0;
+
+/// Gap between the difficulty stars and the finish condition button
+pub const FINISH_BUTTON_MARGIN_LEFT: f32 = 6.0;
+
+/// Gap between the title and the player stats next to it
+pub const STATS_MARGIN_LEFT: f32 = 30.0;
@@ -1,35 +1,153 @@
-//! Minimap rendering for the level menu.
+//! Minimap rendering for the level menu (and the load menu's save thumbnails).
 //! Renders scaled-down track previews with full bloom/glow effects.
 
 use bevy::camera::{visibility::RenderLayers, RenderTarget};
 use bevy::core_pipeline::tonemapping::{DebandDither, Tonemapping};
 use bevy::image::{ImageSampler, ImageSamplerDescriptor};
 use bevy::math::primitives::CircularSector;
-use bevy::post_process::bloom::{Bloom, BloomCompositeMode, BloomPrefilter};
 use bevy::prelude::*;
 use bevy::render::render_resource::{
     Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::RangeInclusive;
 
-use crate::constants::{BLOOM_INTENSITY, GAME_BACKGROUND_COLOR};
+use crate::level_menu::components::LevelMiniMapPreview;
 use crate::level_menu::constants::{MINI_MAP_HEIGHT, MINI_MAP_WIDTH};
+use crate::level_menu::systems::MinimapImageAdded;
 use crate::road::components::{Direction, RoadSegmentType, Track};
-use crate::road::constants::{
-    ROAD_EDGE_WIDTH, ROAD_SEGMENT_COLOR, ROAD_SEGMENT_LENGTH, ROAD_WIDTH, VISITED_EDGE_COLOR,
+use crate::road::constants::{ROAD_EDGE_WIDTH, ROAD_SEGMENT_LENGTH, ROAD_WIDTH};
+use crate::road::helpers::{
+    bronze_target_time, compute_track_bounds, get_exit_direction, get_position_offset, get_rotation,
 };
-use crate::road::helpers::{compute_track_bounds, get_exit_direction, get_position_offset, get_rotation};
-use crate::road::track_generator::{generate_random_track, TrackGeneratorConfig};
-use crate::road::tracks::get_track;
+use crate::road::track_generator::{rate_track_difficulty, TrackGeneratorConfig};
+use crate::road::track_loader::{user_tracks_stamp, TracksStamp};
+use crate::road::tracks;
+use crate::styles::theme::{camera_bloom, GlowSettings, Theme};
 
 // ============================================================================
 // Resources
 // ============================================================================
 
 /// Cache of rendered minimap images, keyed by level number.
+/// Holds at most `MINIMAP_CACHE_CAPACITY` images, evicting the least recently shown.
+/// An evicted image is only freed once no preview shows it any more, as each preview's
+/// `ImageNode` holds a handle of its own.
 #[derive(Resource, Default)]
 pub struct MinimapCache {
-    pub images: HashMap<usize, Handle<Image>>,
+    images: HashMap<usize, Handle<Image>>,
+    /// Cached levels ordered from least to most recently shown
+    recently_shown: VecDeque<usize>,
+    /// Levels whose random track couldn't be generated (no preview, can't be played)
+    pub failed_levels: HashSet<usize>,
+    /// What the level cards show of each level's track, kept across menu visits
+    summaries: HashMap<usize, TrackSummary>,
+    /// The user track files' stamp when the summaries were taken
+    summaries_stamp: TracksStamp,
+}
+
+/// A level's track as its card shows it, so the track needn't be rebuilt to list the level
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TrackSummary {
+    /// See `rate_track_difficulty`
+    pub difficulty: f32,
+    /// See `bronze_target_time`
+    pub bronze_target: f32,
+}
+
+impl MinimapCache {
+    pub fn get(&self, level: usize) -> Option<&Handle<Image>> {
+        self.images.get(&level)
+    }
+
+    pub fn contains(&self, level: usize) -> bool {
+        self.images.contains_key(&level)
+    }
+
+    /// Marks a level's minimap as just shown, so it's the last to be evicted
+    pub fn mark_shown(&mut self, level: usize) {
+        if let Some(index) = self.recently_shown.iter().position(|&l| l == level) {
+            self.recently_shown.remove(index);
+            self.recently_shown.push_back(level);
+        }
+    }
+
+    /// Caches a level's minimap, dropping the least recently shown beyond capacity
+    pub fn insert(&mut self, level: usize, image: Handle<Image>) {
+        self.images.insert(level, image);
+        self.mark_shown(level);
+        if !self.recently_shown.contains(&level) {
+            self.recently_shown.push_back(level);
+        }
+
+        while self.recently_shown.len() > MINIMAP_CACHE_CAPACITY {
+            let Some(oldest) = self.recently_shown.pop_front() else {
+                break;
+            };
+            self.images.remove(&oldest);
+        }
+    }
+
+    /// The level's track summary, building the track only the first time it's asked for
+    /// (or again once a user track file changed). None if the track couldn't be generated.
+    pub fn summary(&mut self, level: usize) -> Option<TrackSummary> {
+        let stamp = user_tracks_stamp();
+        if stamp != self.summaries_stamp {
+            self.summaries.clear();
+            self.summaries_stamp = stamp;
+        }
+        if let Some(summary) = self.summaries.get(&level) {
+            return Some(*summary);
+        }
+        if self.failed_levels.contains(&level) {
+            return None;
+        }
+
+        let Some(track) = get_level_track(level) else {
+            self.failed_levels.insert(level);
+            return None;
+        };
+        let summary = TrackSummary {
+            difficulty: rate_track_difficulty(&track.layout),
+            bronze_target: bronze_target_time(&track.layout),
+        };
+        self.summaries.insert(level, summary);
+        Some(summary)
+    }
+
+    /// Empties the cache of images (keeping the failed levels and track summaries)
+    pub fn clear(&mut self) {
+        self.recently_shown.clear();
+        self.images.clear();
+    }
+}
+
+/// Pool of the render layers free for minimap scenes. Each scene being rendered holds
+/// one layer until its camera is despawned, so scenes never draw into each other's images.
+#[derive(Resource)]
+pub struct MinimapLayers {
+    free: Vec<usize>,
+}
+
+impl Default for MinimapLayers {
+    fn default() -> Self {
+        // Reversed so the lowest layers are handed out first
+        Self { free: MINIMAP_RENDER_LAYERS.rev().collect() }
+    }
+}
+
+impl MinimapLayers {
+    /// Takes a free layer, or None while every layer is in use
+    pub fn take(&mut self) -> Option<usize> {
+        self.free.pop()
+    }
+
+    /// Returns a layer to the pool once its scene is despawned
+    pub fn give_back(&mut self, layer: usize) {
+        if !self.free.contains(&layer) {
+            self.free.push(layer);
+        }
+    }
 }
 
 // ============================================================================
@@ -40,12 +158,15 @@ pub struct MinimapCache {
 #[derive(Component)]
 pub struct MinimapCamera {
     pub level: usize,
+    /// Render layer taken from `MinimapLayers` for this camera's scene
+    pub layer: usize,
 }
 
 /// Marker for entities that are part of a minimap scene (to be despawned after rendering).
+/// Scenes are told apart by their render layer, which no two live scenes share.
 #[derive(Component)]
 pub struct MinimapSceneEntity {
-    pub level: usize,
+    pub layer: usize,
 }
 
 /// Marker for tracking when a minimap has been rendered.
@@ -63,37 +184,44 @@ pub struct MinimapRendered {
 /// Resolution multiplier for minimap rendering (higher = sharper but more memory).
 const MINIMAP_RESOLUTION_SCALE: f32 = 2.0;
 
+/// Maximum number of minimap images kept in the cache, on top of those shown on screen.
+const MINIMAP_CACHE_CAPACITY: usize = 24;
+
 /// Number of frames to wait for bloom to stabilize before capturing.
 const FRAMES_BEFORE_CAPTURE: u32 = 3;
 
-/// Padding around the track in the minimap (as fraction of track size).
-const MINIMAP_PADDING: f32 = 0.25;
+/// Margin between the track and the image edge, in preview pixels.
+/// Leaves room for the frame and the glow around the edges.
+const MINIMAP_MARGIN: f32 = 5.0;
 
-/// Base render layer for minimap rendering (layers 1-31 are available, 0 is default).
-/// We use layers 1+ for minimaps to isolate each level's track.
-const MINIMAP_RENDER_LAYER_BASE: u8 = 1;
+/// Width of the frame drawn around the rendered track, in preview pixels (0.0 for no frame).
+const MINIMAP_FRAME_WIDTH: f32 = 1.5;
 
-/// Get the render layer for a specific level's minimap.
-/// Each level gets its own render layer to isolate its track from others.
-fn get_minimap_render_layer(level: usize) -> RenderLayers {
-    // Use layers 1-31 (0 is the default layer used by the main game)
-    // Wrap around if we have more than 31 levels (unlikely but safe)
-    let layer = MINIMAP_RENDER_LAYER_BASE as usize + ((level - 1) % 31);
-    RenderLayers::layer(layer)
-}
+/// Size of the level card previews, in UI pixels
+pub const LEVEL_CARD_PREVIEW_SIZE: Vec2 = Vec2::new(MINI_MAP_WIDTH, MINI_MAP_HEIGHT);
+
+/// Render layers shared out to minimap scenes (see `MinimapLayers`).
+/// 0 is the main game's, and 32 up are the result card's, the garage's and the split views'.
+const MINIMAP_RENDER_LAYERS: RangeInclusive<usize> = 1..=31;
 
 // ============================================================================
 // Minimap Rendering
 // ============================================================================
 
-/// Creates a render target image for minimap rendering.
-pub fn create_minimap_image(images: &mut Assets<Image>) -> Handle<Image> {
-    let width = (MINI_MAP_WIDTH * MINIMAP_RESOLUTION_SCALE) as u32;
-    let height = (MINI_MAP_HEIGHT * MINIMAP_RESOLUTION_SCALE) as u32;
+/// Size of the render target for a preview of the given size (in UI pixels)
+fn minimap_image_size(preview_size: Vec2) -> Vec2 {
+    preview_size * MINIMAP_RESOLUTION_SCALE
+}
+
+/// Creates a render target image for a minimap preview of the given size (in UI pixels).
+/// The image stays single-sampled whatever the MSAA setting: only the main camera is
+/// multisampled, and a texture shown in the UI can't have more than one sample.
+pub fn create_minimap_image(images: &mut Assets<Image>, preview_size: Vec2) -> Handle<Image> {
+    let image_size = minimap_image_size(preview_size);
 
     let size = Extent3d {
-        width,
-        height,
+        width: image_size.x as u32,
+        height: image_size.y as u32,
         depth_or_array_layers: 1,
     };
 
@@ -106,6 +234,7 @@ pub fn create_minimap_image(images: &mut Assets<Image>) -> Handle<Image> {
             // This ensures tonemapping is applied correctly and stored as sRGB
             format: TextureFormat::Rgba8UnormSrgb,
             mip_level_count: 1,
+            // Always 1, see above
             sample_count: 1,
             usage: TextureUsages::TEXTURE_BINDING
                 | TextureUsages::COPY_DST
@@ -128,54 +257,40 @@ pub fn create_minimap_image(images: &mut Assets<Image>) -> Handle<Image> {
 }
 
 /// Gets or generates the track for a given level.
-pub fn get_level_track(level: usize) -> Track {
-    if level <= 3 {
-        get_track(level)
-    } else {
-        let config = TrackGeneratorConfig {
-            min_segments: 50,
-            max_segments: 120,
-            target_difficulty: 0.5,
-            seed: level as u64,
-        };
-        let generated = generate_random_track(&config).expect("Failed to generate random track");
-        Track {
-            layout: generated.layout,
-            starting_point: generated.starting_point,
-            prop_indices: generated.prop_indices,
-        }
-    }
+/// Props aren't drawn on the minimap, so the default prop range is used.
+pub fn get_level_track(level: usize) -> Option<Track> {
+    let config = TrackGeneratorConfig::default();
+    tracks::get_level_track(level, config.min_props, config.max_props)
 }
 
-/// Calculates the scale and offset needed to fit a track into the minimap bounds.
-pub fn calculate_minimap_transform(track: &Track) -> (f32, Vec2) {
-    let (min, max) = compute_track_bounds(track.starting_point, &track.layout);
+/// Calculates the scale and offset needed to fit a track into a preview of the given size.
+/// The track is centered and scaled up until its longer side (relative to the
+/// preview's aspect) reaches the margin, so wide and tall tracks both fill the preview.
+pub fn calculate_minimap_transform(track: &Track, preview_size: Vec2) -> (f32, Vec2) {
+    let (min, max) = compute_track_bounds(track.starting_point, track.start_direction, &track.layout);
 
     // Add road width padding to bounds
     let padding = ROAD_WIDTH / 2.0 + ROAD_EDGE_WIDTH;
     let min = min - Vec2::splat(padding);
     let max = max + Vec2::splat(padding);
 
-    let track_width = max.x - min.x;
-    let track_height = max.y - min.y;
+    let track_size = max - min;
     let track_center = (min + max) / 2.0;
 
-    // Calculate scale to fit in minimap with padding
-    let available_width = MINI_MAP_WIDTH * MINIMAP_RESOLUTION_SCALE * (1.0 - MINIMAP_PADDING);
-    let available_height = MINI_MAP_HEIGHT * MINIMAP_RESOLUTION_SCALE * (1.0 - MINIMAP_PADDING);
-
-    let scale_x = available_width / track_width;
-    let scale_y = available_height / track_height;
-    let scale = scale_x.min(scale_y);
+    // Fit within the image minus a fixed margin on each side
+    let available = minimap_image_size(preview_size) - Vec2::splat(2.0 * MINIMAP_MARGIN * MINIMAP_RESOLUTION_SCALE);
+    let scale = (available / track_size).min_element();
 
     (scale, track_center)
 }
 
 /// Spawns a minimap camera that renders to a texture.
+/// Also used for the larger track preview, which keeps its camera rendering.
 pub fn spawn_minimap_camera(
     commands: &mut Commands,
     image_handle: Handle<Image>,
-    level: usize,
+    camera: MinimapCamera,
+    theme: &Theme,
     track_center: Vec2,
     scale: f32,
 ) -> Entity {
@@ -183,15 +298,14 @@ pub fn spawn_minimap_camera(
     // OrthographicProjection scale is inverse of our scale
     let projection_scale = 1.0 / scale;
 
-    // Get the render layer for this level
-    let render_layer = get_minimap_render_layer(level);
+    let render_layer = RenderLayers::layer(camera.layer);
 
     let entity = commands
         .spawn((
             Camera2d,
             Tonemapping::TonyMcMapface,
             DebandDither::Enabled,
-            MinimapCamera { level },
+            camera,
             render_layer,  // Only render entities on this layer
         ))
         .id();
@@ -200,7 +314,8 @@ pub fn spawn_minimap_camera(
         Camera {
             target: RenderTarget::from(image_handle.clone()),
             order: -1, // Render before main camera
-            clear_color: ClearColorConfig::Custom(GAME_BACKGROUND_COLOR),
+            // Same theme as the level in gameplay, so the preview matches it
+            clear_color: ClearColorConfig::Custom(theme.background),
             ..default()
         },
         // Wrap OrthographicProjection in the Projection component
@@ -211,22 +326,7 @@ pub fn spawn_minimap_camera(
         Transform::from_xyz(track_center.x, track_center.y, 1000.0),
     ));
 
-    commands.entity(entity).insert((
-        Bloom {
-            intensity: BLOOM_INTENSITY,
-            prefilter: BloomPrefilter {
-                threshold: 1.1,
-                threshold_softness: 0.2,
-            },
-            composite_mode: BloomCompositeMode::Additive,
-            high_pass_frequency: 0.45,
-            ..default()
-        },
-        MinimapRendered {
-            level,
-            frames_remaining: FRAMES_BEFORE_CAPTURE,
-        },
-    ));
+    commands.entity(entity).insert(camera_bloom(theme.bloom_intensity));
 
     entity
 }
@@ -237,22 +337,22 @@ pub fn spawn_minimap_track(
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<ColorMaterial>,
     track: &Track,
-    level: usize,
+    layer: usize,
+    theme: &Theme,
 ) {
-    let mut current_endpoint = Vec2::new(
-        track.starting_point.x,
-        track.starting_point.y - ROAD_SEGMENT_LENGTH / 2.0,
-    );
-    let mut current_direction = Direction::Up;
+    let mut current_direction = track.start_direction;
+    let mut current_endpoint = track.starting_point - get_position_offset(current_direction) / 2.0;
 
     for &segment_type in track.layout.iter() {
         match segment_type {
-            RoadSegmentType::Straight => {
+            RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => {
                 current_endpoint = spawn_minimap_straight_road(
                     commands,
                     current_endpoint,
                     current_direction,
-                    level,
+                    segment_type.length_in_cells(),
+                    layer,
+                    theme,
                 );
             }
             RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight => {
@@ -260,10 +360,10 @@ pub fn spawn_minimap_track(
                     commands,
                     meshes,
                     materials,
-                    current_endpoint,
-                    current_direction,
+                    (current_endpoint, current_direction),
                     segment_type,
-                    level,
+                    layer,
+                    theme,
                 );
                 current_endpoint = new_endpoint;
                 current_direction = new_direction;
@@ -272,61 +372,64 @@ pub fn spawn_minimap_track(
     }
 
     // Spawn start line marker
-    spawn_minimap_start_line(commands, track.starting_point, level);
+    spawn_minimap_start_line(commands, track.starting_point, track.start_direction, layer);
 }
 
 fn spawn_minimap_straight_road(
     commands: &mut Commands,
     current_endpoint: Vec2,
     current_direction: Direction,
-    level: usize,
+    cells: usize,
+    layer: usize,
+    theme: &Theme,
 ) -> Vec2 {
-    let offset = get_position_offset(current_direction);
+    let offset = get_position_offset(current_direction) * cells as f32;
+    let length = cells as f32 * ROAD_SEGMENT_LENGTH;
     let center = current_endpoint + offset / 2.0;
 
     let rotation = get_rotation(current_direction);
     let rotation_quat = Quat::from_rotation_z(rotation);
-    let render_layer = get_minimap_render_layer(level);
+    let render_layer = RenderLayers::layer(layer);
 
     // Road surface
     commands.spawn((
         Sprite {
-            color: ROAD_SEGMENT_COLOR,
-            custom_size: Some(Vec2::new(ROAD_WIDTH, ROAD_SEGMENT_LENGTH)),
+            color: theme.road,
+            custom_size: Some(Vec2::new(ROAD_WIDTH, length)),
             ..default()
         },
         Transform::from_xyz(center.x, center.y, 1.0).with_rotation(rotation_quat),
-        MinimapSceneEntity { level },
+        MinimapSceneEntity { layer },
         render_layer.clone(),
     ));
 
-    // Glowing edges (always use VISITED_EDGE_COLOR for full glow)
+    // Glowing edges (always use the visited edge color for full glow)
     let perpendicular = rotation_quat.mul_vec3(Vec3::X).xy();
     let edge_offset = perpendicular * (ROAD_WIDTH / 2.0 + ROAD_EDGE_WIDTH / 2.0);
 
     // Left edge
     commands.spawn((
         Sprite {
-            color: VISITED_EDGE_COLOR,
-            custom_size: Some(Vec2::new(ROAD_EDGE_WIDTH, ROAD_SEGMENT_LENGTH)),
+            color: theme.visited_edge,
+            custom_size: Some(Vec2::new(ROAD_EDGE_WIDTH, length)),
             ..default()
         },
         Transform::from_xyz(center.x - edge_offset.x, center.y - edge_offset.y, 1.2)
             .with_rotation(rotation_quat),
-        MinimapSceneEntity { level },
+        MinimapSceneEntity { layer },
         render_layer.clone(),
     ));
 
     // Right edge
     commands.spawn((
         Sprite {
-            color: VISITED_EDGE_COLOR,
-            custom_size: Some(Vec2::new(ROAD_EDGE_WIDTH, ROAD_SEGMENT_LENGTH)),
+            color: theme.visited_edge,
+            custom_size: Some(Vec2::new(ROAD_EDGE_WIDTH, length)),
             ..default()
         },
         Transform::from_xyz(center.x + edge_offset.x, center.y + edge_offset.y, 1.2)
             .with_rotation(rotation_quat),
-        MinimapSceneEntity { level },
+        MinimapSceneEntity { layer },
         render_layer,
     ));
 
@@ -337,10 +440,10 @@ fn spawn_minimap_corner_road(
     commands: &mut Commands,
     meshes: &mut Assets<Mesh>,
     materials: &mut Assets<ColorMaterial>,
-    current_endpoint: Vec2,
-    current_direction: Direction,
+    (current_endpoint, current_direction): (Vec2, Direction),
     segment_type: RoadSegmentType,
-    level: usize,
+    layer: usize,
+    theme: &Theme,
 ) -> (Vec2, Direction) {
     let exit_direction = get_exit_direction(current_direction, segment_type);
     let exit_vec = get_direction_vector(exit_direction);
@@ -349,7 +452,7 @@ fn spawn_minimap_corner_road(
     let pivot = current_endpoint + exit_vec * (ROAD_WIDTH / 2.0);
 
     let sector = CircularSector::from_degrees(ROAD_WIDTH, 90.0);
-    let render_layer = get_minimap_render_layer(level);
+    let render_layer = RenderLayers::layer(layer);
 
     let rotation_offset = match segment_type {
         RoadSegmentType::CornerRight => std::f32::consts::FRAC_PI_4,
@@ -361,10 +464,10 @@ fn spawn_minimap_corner_road(
     // Road surface
     commands.spawn((
         Mesh2d(meshes.add(sector)),
-        MeshMaterial2d(materials.add(ColorMaterial::from(ROAD_SEGMENT_COLOR))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(theme.road))),
         Transform::from_xyz(pivot.x, pivot.y, 0.0)
             .with_rotation(Quat::from_rotation_z(rotation_angle)),
-        MinimapSceneEntity { level },
+        MinimapSceneEntity { layer },
         render_layer.clone(),
     ));
 
@@ -375,20 +478,20 @@ fn spawn_minimap_corner_road(
 
     commands.spawn((
         Mesh2d(meshes.add(outer_sector)),
-        MeshMaterial2d(materials.add(ColorMaterial::from(VISITED_EDGE_COLOR))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(theme.visited_edge))),
         Transform::from_xyz(pivot.x, pivot.y, 1.2)
             .with_rotation(Quat::from_rotation_z(rotation_angle)),
-        MinimapSceneEntity { level },
+        MinimapSceneEntity { layer },
         render_layer.clone(),
     ));
 
     // Cutout to create ring effect
     commands.spawn((
         Mesh2d(meshes.add(cutout_sector)),
-        MeshMaterial2d(materials.add(ColorMaterial::from(ROAD_SEGMENT_COLOR))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(theme.road))),
         Transform::from_xyz(pivot.x, pivot.y, 1.21)
             .with_rotation(Quat::from_rotation_z(rotation_angle)),
-        MinimapSceneEntity { level },
+        MinimapSceneEntity { layer },
         render_layer,
     ));
 
@@ -396,6 +499,47 @@ fn spawn_minimap_corner_road(
     (new_endpoint, exit_direction)
 }
 
+/// Spawns a frame along the edges of the minimap image, in the theme's unvisited edge color.
+/// `track_center` and `scale` are the camera's, so the frame lines up with the image borders.
+pub fn spawn_minimap_frame(
+    commands: &mut Commands,
+    layer: usize,
+    theme: &Theme,
+    track_center: Vec2,
+    scale: f32,
+    preview_size: Vec2,
+) {
+    if MINIMAP_FRAME_WIDTH <= 0.0 {
+        return;
+    }
+    let view_size = minimap_image_size(preview_size) / scale;
+    let thickness = MINIMAP_FRAME_WIDTH * MINIMAP_RESOLUTION_SCALE / scale;
+    let render_layer = RenderLayers::layer(layer);
+
+    // (offset from the center, size) of the top, bottom, left and right bars
+    let half_x = (view_size.x - thickness) / 2.0;
+    let half_y = (view_size.y - thickness) / 2.0;
+    let bars = [
+        (Vec2::new(0.0, half_y), Vec2::new(view_size.x, thickness)),
+        (Vec2::new(0.0, -half_y), Vec2::new(view_size.x, thickness)),
+        (Vec2::new(-half_x, 0.0), Vec2::new(thickness, view_size.y)),
+        (Vec2::new(half_x, 0.0), Vec2::new(thickness, view_size.y)),
+    ];
+    for (offset, size) in bars {
+        let position = track_center + offset;
+        commands.spawn((
+            Sprite {
+                color: theme.unvisited_edge,
+                custom_size: Some(size),
+                ..default()
+            },
+            Transform::from_xyz(position.x, position.y, 2.0),
+            MinimapSceneEntity { layer },
+            render_layer.clone(),
+        ));
+    }
+}
+
 fn get_direction_vector(direction: Direction) -> Vec2 {
     match direction {
         Direction::Up => Vec2::Y,
@@ -405,16 +549,17 @@ fn get_direction_vector(direction: Direction) -> Vec2 {
     }
 }
 
-fn spawn_minimap_start_line(commands: &mut Commands, position: Vec2, level: usize) {
-    let render_layer = get_minimap_render_layer(level);
+fn spawn_minimap_start_line(commands: &mut Commands, position: Vec2, direction: Direction, layer: usize) {
+    let render_layer = RenderLayers::layer(layer);
     commands.spawn((
         Sprite {
             color: Color::srgb(0.2, 0.8, 0.2), // Green
             custom_size: Some(Vec2::new(ROAD_WIDTH, 2.0)),
             ..default()
         },
-        Transform::from_xyz(position.x, position.y, 1.5),
-        MinimapSceneEntity { level },
+        Transform::from_xyz(position.x, position.y, 1.5)
+            .with_rotation(Quat::from_rotation_z(get_rotation(direction))),
+        MinimapSceneEntity { layer },
         render_layer,
     ));
 }
@@ -423,56 +568,98 @@ fn spawn_minimap_start_line(commands: &mut Commands, position: Vec2, level: usiz
 // Systems
 // ============================================================================
 
-/// System to initiate minimap rendering for levels that aren't cached.
-pub fn setup_minimap_rendering(
-    mut commands: Commands,
-    mut images: ResMut<Assets<Image>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<ColorMaterial>>,
-    minimap_cache: Res<MinimapCache>,
-    current_save: Res<crate::save::CurrentSave>,
-    existing_cameras: Query<&MinimapCamera>,
+/// Starts rendering the minimaps of the given levels, skipping those already cached,
+/// being rendered (`rendering_levels`), or known to fail generation.
+/// Each level is rendered once even if it's listed more than once.
+/// Stops early once every render layer is in use, leaving the rest for a later call.
+pub fn render_level_minimaps(
+    commands: &mut Commands,
+    (images, meshes, materials): (&mut Assets<Image>, &mut Assets<Mesh>, &mut Assets<ColorMaterial>),
+    minimap_cache: &mut MinimapCache,
+    layers: &mut MinimapLayers,
+    glow: &GlowSettings,
+    levels: impl IntoIterator<Item = usize>,
+    mut rendering_levels: Vec<usize>,
 ) {
-    let highest_level = current_save
-        .0
-        .as_ref()
-        .map(|s| s.highest_level_unlocked)
-        .unwrap_or(1);
-
-    // Check which levels are already being rendered
-    let rendering_levels: Vec<usize> = existing_cameras.iter().map(|c| c.level).collect();
-
-    for level in 1..=highest_level {
-        // Skip if already cached or being rendered
-        if minimap_cache.images.contains_key(&level) || rendering_levels.contains(&level) {
+    for level in levels {
+        // Skip if already cached, being rendered, or known to fail generation
+        if minimap_cache.contains(level)
+            || rendering_levels.contains(&level)
+            || minimap_cache.failed_levels.contains(&level)
+        {
             continue;
         }
 
-        // Create render target
-        let image_handle = create_minimap_image(&mut images);
-
         // Get track for this level
-        let track = get_level_track(level);
+        let Some(track) = get_level_track(level) else {
+            error!("Could not generate a track for level {}", level);
+            minimap_cache.failed_levels.insert(level);
+            continue;
+        };
+        let Some(layer) = layers.take() else {
+            warn!("No free render layer for the minimap of level {}", level);
+            break;
+        };
+
+        // Create render target
+        let image_handle = create_minimap_image(images, LEVEL_CARD_PREVIEW_SIZE);
 
         // Calculate transform
-        let (scale, center) = calculate_minimap_transform(&track);
+        let (scale, center) = calculate_minimap_transform(&track, LEVEL_CARD_PREVIEW_SIZE);
+
+        // Glow is applied the same way as in gameplay
+        let theme = Theme::for_level(level).with_glow(glow);
 
         // Spawn camera
-        spawn_minimap_camera(&mut commands, image_handle, level, center, scale);
+        let camera = spawn_minimap_camera(commands, image_handle, MinimapCamera { level, layer }, &theme, center, scale);
+        commands.entity(camera).insert(MinimapRendered {
+            level,
+            frames_remaining: FRAMES_BEFORE_CAPTURE,
+        });
 
         // Spawn track scene
-        spawn_minimap_track(&mut commands, &mut meshes, &mut materials, &track, level);
+        spawn_minimap_track(commands, meshes, materials, &track, layer, &theme);
+        spawn_minimap_frame(commands, layer, &theme, center, scale, LEVEL_CARD_PREVIEW_SIZE);
+        rendering_levels.push(level);
+    }
+}
+
+/// System to render the minimaps of the previews still waiting for their image (the level
+/// cards and the save thumbnails). Renders as many at a time as there are free render
+/// layers, and the rest on later frames as layers are given back.
+/// Empties the cache first if the glow setting changed, so previews use the new one.
+pub fn render_pending_minimaps(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    (mut meshes, mut materials): (ResMut<Assets<Mesh>>, ResMut<Assets<ColorMaterial>>),
+    (mut minimap_cache, mut layers): (ResMut<MinimapCache>, ResMut<MinimapLayers>),
+    glow: Res<GlowSettings>,
+    pending: Query<&LevelMiniMapPreview, Without<MinimapImageAdded>>,
+    existing_cameras: Query<&MinimapCamera>,
+) {
+    if glow.is_changed() {
+        minimap_cache.clear();
     }
+
+    render_level_minimaps(
+        &mut commands,
+        (&mut images, &mut meshes, &mut materials),
+        &mut minimap_cache,
+        &mut layers,
+        &glow,
+        pending.iter().map(|preview| preview.0),
+        existing_cameras.iter().map(|c| c.level).collect(),
+    );
 }
 
-/// System to capture rendered minimaps and clean up.
+/// System to capture rendered minimaps and clean up, giving their render layers back
 pub fn capture_minimaps(
     mut commands: Commands,
-    mut minimap_cache: ResMut<MinimapCache>,
-    mut cameras: Query<(Entity, &Camera, &mut MinimapRendered)>,
+    (mut minimap_cache, mut layers): (ResMut<MinimapCache>, ResMut<MinimapLayers>),
+    mut cameras: Query<(Entity, &Camera, &MinimapCamera, &mut MinimapRendered)>,
     scene_entities: Query<(Entity, &MinimapSceneEntity)>,
 ) {
-    for (camera_entity, camera, mut rendered) in cameras.iter_mut() {
+    for (camera_entity, camera, minimap_camera, mut rendered) in cameras.iter_mut() {
         if rendered.frames_remaining > 0 {
             rendered.frames_remaining -= 1;
             continue;
@@ -480,33 +667,125 @@ pub fn capture_minimaps(
 
         // Extract image handle from camera target
         if let RenderTarget::Image(image_render_target) = &camera.target {
-            minimap_cache
-                .images
-                .insert(rendered.level, image_render_target.handle.clone());
+            minimap_cache.insert(rendered.level, image_render_target.handle.clone());
         }
 
         // Despawn camera
         commands.entity(camera_entity).despawn();
 
-        // Despawn scene entities for this level
+        // Despawn the scene rendered on the camera's layer, freeing the layer
         for (entity, scene_entity) in scene_entities.iter() {
-            if scene_entity.level == rendered.level {
+            if scene_entity.layer == minimap_camera.layer {
                 commands.entity(entity).despawn();
             }
         }
+        layers.give_back(minimap_camera.layer);
     }
 }
 
-/// System to clean up all minimap rendering resources when leaving the level menu.
+/// System to clean up all minimap rendering resources when leaving the level menu
+/// (or the other screens rendering minimaps).
 pub fn cleanup_minimap_rendering(
     mut commands: Commands,
-    cameras: Query<Entity, With<MinimapCamera>>,
+    mut layers: ResMut<MinimapLayers>,
+    cameras: Query<(Entity, &MinimapCamera)>,
     scene_entities: Query<Entity, With<MinimapSceneEntity>>,
 ) {
-    for entity in cameras.iter() {
+    for (entity, camera) in cameras.iter() {
         commands.entity(entity).despawn();
+        layers.give_back(camera.layer);
     }
     for entity in scene_entities.iter() {
         commands.entity(entity).despawn();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::road::helpers::is_closed_loop;
+
+    /// Rectangular loop `cells_up` cells tall and `cells_across` cells wide
+    fn rectangle_track(cells_up: usize, cells_across: usize) -> Track {
+        use RoadSegmentType::*;
+        let up = StraightRun(cells_up);
+        let across = StraightRun(cells_across);
+        let layout = vec![up, CornerRight, across, CornerRight, up, CornerRight, across, CornerRight];
+        assert!(is_closed_loop(&layout));
+        Track {
+            layout,
+            starting_point: Vec2::ZERO,
+            prop_indices: Vec::new(),
+            start_direction: Direction::Up,
+        }
+    }
+
+    #[test]
+    fn test_wide_and_tall_tracks_fill_preview() {
+        let available = minimap_image_size(LEVEL_CARD_PREVIEW_SIZE) - Vec2::splat(2.0 * MINIMAP_MARGIN * MINIMAP_RESOLUTION_SCALE);
+        for track in [rectangle_track(1, 12), rectangle_track(12, 1)] {
+            let (scale, _) = calculate_minimap_transform(&track, LEVEL_CARD_PREVIEW_SIZE);
+            let (min, max) = compute_track_bounds(track.starting_point, track.start_direction, &track.layout);
+            let padding = ROAD_WIDTH / 2.0 + ROAD_EDGE_WIDTH;
+            let fitted = (max - min + Vec2::splat(2.0 * padding)) * scale;
+
+            // Fits within the margin, and reaches it along one axis
+            assert!(fitted.x <= available.x + 1e-3 && fitted.y <= available.y + 1e-3);
+            assert!((fitted - available).abs().min_element() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_cache_respects_capacity() {
+        let mut cache = MinimapCache::default();
+        for level in 1..=MINIMAP_CACHE_CAPACITY + 3 {
+            cache.insert(level, Handle::default());
+        }
+
+        assert_eq!(cache.images.len(), MINIMAP_CACHE_CAPACITY);
+        // The first levels inserted are the first evicted
+        assert!(!cache.contains(1) && !cache.contains(3));
+        assert!(cache.contains(4));
+    }
+
+    #[test]
+    fn test_shown_level_is_evicted_last() {
+        let mut cache = MinimapCache::default();
+        for level in 1..=MINIMAP_CACHE_CAPACITY {
+            cache.insert(level, Handle::default());
+        }
+        cache.mark_shown(1);
+        cache.insert(MINIMAP_CACHE_CAPACITY + 1, Handle::default());
+
+        assert!(cache.contains(1));
+        assert!(!cache.contains(2));
+    }
+
+    #[test]
+    fn test_summaries_are_retaken_when_track_files_change() {
+        let stale = TrackSummary { difficulty: -1.0, bronze_target: -1.0 };
+        let mut cache = MinimapCache {
+            summaries: HashMap::from([(1, stale)]),
+            summaries_stamp: vec![(usize::MAX, None)],
+            ..default()
+        };
+
+        assert_ne!(cache.summary(1), Some(stale));
+    }
+
+    #[test]
+    fn test_layers_are_never_shared() {
+        let mut layers = MinimapLayers::default();
+        let taken: Vec<usize> = std::iter::from_fn(|| layers.take()).collect();
+
+        assert_eq!(taken.len(), MINIMAP_RENDER_LAYERS.count());
+        assert!(taken.iter().all(|layer| MINIMAP_RENDER_LAYERS.contains(layer)));
+        assert_eq!(taken.iter().collect::<HashSet<_>>().len(), taken.len());
+
+        // A layer given back (even twice) is handed out once more
+        layers.give_back(taken[3]);
+        layers.give_back(taken[3]);
+        assert_eq!(layers.take(), Some(taken[3]));
+        assert_eq!(layers.take(), None);
+    }
+}
@@ -6,10 +6,14 @@ pub mod systems;
 use bevy::prelude::*;
 use crate::constants::GameState;
 use crate::utils::despawn_all;
-use components::OnLevelMenuScreen;
-use minimap::{capture_minimaps, cleanup_minimap_rendering, setup_minimap_rendering, MinimapCache};
-use systems::{level_menu_action, spawn_level_menu, update_minimap_previews};
-use crate::styles::menu::standard_button_system;
+use components::{LevelSortOrder, OnLevelMenuScreen};
+use minimap::{capture_minimaps, cleanup_minimap_rendering, render_pending_minimaps, MinimapCache, MinimapLayers};
+use systems::{
+    cycle_finish_condition, handle_reset_confirm_action, level_menu_action, sort_level_cards, spawn_level_menu,
+    start_time_trial, toggle_level_sort, toggle_practice_mode, toggle_two_player_mode, update_level_sort_label,
+    update_minimap_previews, update_practice_label, update_finish_condition_labels, update_two_player_label,
+};
+use crate::styles::menu::{dismiss_notice_system, standard_button_system, NoticeOverlay};
 
 pub struct LevelMenuPlugin;
 
@@ -17,13 +21,23 @@ impl Plugin for LevelMenuPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<MinimapCache>()
-            .add_systems(OnEnter(GameState::LevelMenu), (spawn_level_menu, setup_minimap_rendering).chain())
-            .add_systems(OnExit(GameState::LevelMenu), (despawn_all::<OnLevelMenuScreen>, cleanup_minimap_rendering))
+            .init_resource::<MinimapLayers>()
+            .init_resource::<LevelSortOrder>()
+            .add_systems(OnEnter(GameState::LevelMenu), spawn_level_menu)
+            .add_systems(OnExit(GameState::LevelMenu), (despawn_all::<OnLevelMenuScreen>, despawn_all::<NoticeOverlay>, cleanup_minimap_rendering))
             .add_systems(
                 Update,
                 (
                     standard_button_system,
                     level_menu_action,
+                    handle_reset_confirm_action,
+                    (toggle_practice_mode, update_practice_label).chain(),
+                    (toggle_two_player_mode, update_two_player_label).chain(),
+                    (toggle_level_sort, update_level_sort_label, sort_level_cards).chain(),
+                    (cycle_finish_condition, update_finish_condition_labels).chain(),
+                    start_time_trial,
+                    dismiss_notice_system,
+                    render_pending_minimaps,
                     capture_minimaps,
                     update_minimap_previews,
                 )
@@ -1,61 +1,132 @@
 use bevy::prelude::*;
 use bevy_scrollbar::{Scrollbar, ScrollSpeed};
+use std::collections::HashMap;
 
-use crate::constants::{CurrentLevel, GameState, ResumeFromPause};
+use crate::constants::{CurrentLevel, GameState, PracticeMode, ResumeFromPause, TwoPlayerMode};
+use crate::level_complete::components::Medal;
 use crate::level_menu::components::{
-    LevelCard, LevelListContainer, LevelMenuButtonAction, LevelMiniMapPreview,
-    LevelTimeDisplay, OnLevelMenuScreen,
+    FinishConditionButton, LevelCard, LevelListContainer, LevelMenuButtonAction, LevelMiniMapPreview, LevelProgress,
+    LevelSortButton, LevelSortOrder, LevelTimeDisplay, OnLevelMenuScreen, PracticeToggleButton, ResetConfirmButtonAction,
+    ResetConfirmationOverlay, TimeTrialButton, TwoPlayerToggleButton,
 };
 use crate::level_menu::constants::*;
-use crate::level_menu::minimap::MinimapCache;
-use crate::save::CurrentSave;
+use crate::level_menu::minimap::{MinimapCache, TrackSummary};
+use crate::road::tracks::{get_level_track, level_seed};
+use crate::save::{save_or_report, CurrentSave, RunSetup, SaveData, SaveResult};
+use crate::sequence::components::SequenceState;
+use crate::sequence::constants::TIME_TRIAL_LEVELS;
+use crate::settings::components::GameSettings;
 use crate::styles::colors::{
     BUTTON_NORMAL_COLOR, MENU_BACKGROUND_COLOR, MENU_TEXT_COLOR, SECONDARY_TEXT_COLOR,
-    SUCCESS_TEXT_COLOR,
 };
 use crate::styles::menu::{
-    column_centered, spawn_button_with_width, spawn_menu_container, title_style,
-    LARGE_BUTTON_WIDTH,
+    button_row, column_centered, spawn_confirmation_overlay, spawn_menu_container,
+    spawn_notice_overlay, spawn_standard_button, title_style, SMALL_TEXT_FONT_SIZE,
 };
+use crate::styles::palette::Palette;
+use crate::track_preview::helpers::rating_stars;
+use crate::utils::format_time;
 
 // ============================================================================
 // Level Menu Spawning
 // ============================================================================
 
 /// Spawns the level menu screen UI
-pub fn spawn_level_menu(mut commands: Commands, current_save: Res<CurrentSave>) {
-    let save_data = current_save.0.as_ref();
+pub fn spawn_level_menu(
+    mut commands: Commands,
+    current_save: Res<CurrentSave>,
+    mut minimap_cache: ResMut<MinimapCache>,
+    palette: Res<Palette>,
+) {
+    spawn_level_menu_screen(&mut commands, current_save.get(), &mut minimap_cache, &palette);
+}
+
+/// Builds the level menu for the given save (also used to rebuild it after a reset)
+fn spawn_level_menu_screen(
+    commands: &mut Commands,
+    save_data: Option<&SaveData>,
+    minimap_cache: &mut MinimapCache,
+    palette: &Palette,
+) {
     let player_name = save_data.map(|s| s.player_name.as_str()).unwrap_or("Player");
     let highest_level = save_data.map(|s| s.highest_level_unlocked).unwrap_or(1);
 
-    spawn_menu_container(&mut commands, OnLevelMenuScreen, MENU_BACKGROUND_COLOR)
+    spawn_menu_container(commands, OnLevelMenuScreen, MENU_BACKGROUND_COLOR)
         .with_children(|parent| {
             parent.spawn(column_centered()).with_children(|parent| {
-                // Title with player name
-                parent.spawn((
-                    Text::new(format!("{}'s Levels", player_name)),
-                    title_style(),
-                ));
+                // Header: title with player name, stats alongside
+                spawn_level_menu_header(parent, player_name, save_data);
 
                 // Scrollable level list
-                spawn_level_list(parent, save_data, highest_level);
-
-                // Main Menu button
-                spawn_button_with_width(
-                    parent,
-                    "Main Menu",
-                    LevelMenuButtonAction::MainMenu,
-                    LARGE_BUTTON_WIDTH,
-                );
+                spawn_level_list(parent, save_data, highest_level, minimap_cache, palette);
+
+                // Options for the next race; labels are filled in by their update_*_label systems
+                parent.spawn(button_row()).with_children(|row| {
+                    spawn_standard_button(row, "", PracticeToggleButton);
+                    spawn_standard_button(row, "", TwoPlayerToggleButton);
+                    spawn_standard_button(row, "", LevelSortButton);
+                    spawn_standard_button(row, "Garage", LevelMenuButtonAction::Garage);
+                });
+
+                parent.spawn(button_row()).with_children(|row| {
+                    spawn_standard_button(row, "Time Trial", TimeTrialButton);
+                    spawn_standard_button(row, "Reset Progress", LevelMenuButtonAction::ResetProgress);
+                    spawn_standard_button(row, "Main Menu", LevelMenuButtonAction::MainMenu);
+                });
             });
         });
 }
 
+/// Spawns the title with the player's name and their playtime/race stats next to it
+fn spawn_level_menu_header(
+    parent: &mut ChildSpawnerCommands,
+    player_name: &str,
+    save_data: Option<&SaveData>,
+) {
+    // Reuse the title font/color; the bottom margin moves to the header row
+    let (title_font, title_color, title_node) = title_style();
+    let playtime = save_data.map(|s| s.total_playtime_secs).unwrap_or(0.0);
+    let races = save_data.map(|s| s.races_completed).unwrap_or(0);
+
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            margin: title_node.margin,
+            ..default()
+        })
+        .with_children(|header| {
+            header.spawn((
+                Text::new(format!("{}'s Levels", player_name)),
+                title_font,
+                title_color,
+            ));
+            header.spawn((
+                Text::new(format!(
+                    "Playtime: {}\nRaces: {}",
+                    format_playtime(playtime),
+                    races
+                )),
+                TextFont {
+                    font_size: SMALL_TEXT_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(SECONDARY_TEXT_COLOR),
+                Node {
+                    margin: UiRect::left(Val::Px(STATS_MARGIN_LEFT)),
+                    ..default()
+                },
+            ));
+        });
+}
+
 /// Spawns the scrollable list of level cards with a scrollbar
 fn spawn_level_list(
     parent: &mut ChildSpawnerCommands,
-    save_data: Option<&crate::save::SaveData>,
+    save_data: Option<&SaveData>,
     highest_level: usize,
+    minimap_cache: &mut MinimapCache,
+    palette: &Palette,
 ) {
     // Calculate if scrolling is needed based on content height vs container height
     // Each card is LEVEL_CARD_HEIGHT + LEVEL_CARD_SPACING (except the last one)
@@ -87,10 +158,12 @@ fn spawn_level_list(
                     ScrollSpeed(SCROLL_SPEED),
                 ))
                 .with_children(|scroll_parent| {
-                    // Show all unlocked levels (1 through highest_level)
+                    // Show all unlocked levels (1 through highest_level), `sort_level_cards` orders them
                     for level in 1..=highest_level {
-                        let best_time = save_data.and_then(|s| s.level_times.get(&level).copied());
-                        spawn_level_card(scroll_parent, level, best_time);
+                        let best_run = save_data.and_then(|s| s.get_best_run(level));
+                        let attempts = save_data.map(|s| s.get_attempts(level)).unwrap_or(0);
+                        let summary = minimap_cache.summary(level);
+                        spawn_level_card(scroll_parent, level, best_run, attempts, summary, palette);
                     }
                 })
                 .id();
@@ -113,9 +186,22 @@ fn spawn_level_list(
         });
 }
 
-/// Spawns a single level card with number, status, time, and mini-map placeholder
-fn spawn_level_card(parent: &mut ChildSpawnerCommands, level: usize, best_time: Option<f32>) {
+/// Spawns a single level card with number, difficulty stars, status, time, attempts,
+/// and mini-map placeholder ringed with the level's progress.
+/// `best_run` is the best time with the setup it was set with.
+fn spawn_level_card(
+    parent: &mut ChildSpawnerCommands,
+    level: usize,
+    best_run: Option<(f32, RunSetup)>,
+    attempts: u32,
+    summary: Option<TrackSummary>,
+    palette: &Palette,
+) {
+    let best_time = best_run.map(|(time, _)| time);
     let is_completed = best_time.is_some();
+    let stars = summary.map(|summary| rating_stars(summary.difficulty));
+    let bronze_target = summary.map(|summary| summary.bronze_target);
+    let progress = LevelProgress::new(best_time, attempts, bronze_target);
 
     parent
         .spawn((
@@ -134,10 +220,11 @@ fn spawn_level_card(parent: &mut ChildSpawnerCommands, level: usize, best_time:
             LevelMenuButtonAction::PlayLevel(level),
         ))
         .with_children(|card| {
-            // Level number column
+            // Level number column, with the track's difficulty rating below
             card.spawn((
                 Node {
                     width: Val::Px(LEVEL_NUMBER_WIDTH),
+                    flex_direction: FlexDirection::Column,
                     ..default()
                 },
             ))
@@ -150,6 +237,44 @@ fn spawn_level_card(parent: &mut ChildSpawnerCommands, level: usize, best_time:
                     },
                     TextColor(MENU_TEXT_COLOR),
                 ));
+                // The stars share a row with the level's finish condition
+                col.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|row| {
+                    if let Some(stars) = stars {
+                        row.spawn((
+                            Text::new(stars),
+                            TextFont {
+                                font_size: LEVEL_STATUS_FONT_SIZE,
+                                ..default()
+                            },
+                            TextColor(SECONDARY_TEXT_COLOR),
+                        ));
+                    }
+                    row.spawn((
+                        Button,
+                        Node {
+                            margin: UiRect::left(Val::Px(FINISH_BUTTON_MARGIN_LEFT)),
+                            padding: UiRect::horizontal(Val::Px(FINISH_BUTTON_PADDING)),
+                            border: UiRect::all(Val::Px(MINI_MAP_BORDER)),
+                            ..default()
+                        },
+                        BorderColor::all(SECONDARY_TEXT_COLOR),
+                        BackgroundColor(BUTTON_NORMAL_COLOR),
+                        FinishConditionButton(level),
+                    ))
+                    .with_child((
+                        Text::default(),
+                        TextFont {
+                            font_size: FINISH_BUTTON_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(SECONDARY_TEXT_COLOR),
+                    ));
+                });
             });
 
             // Status and time column (flexible width)
@@ -164,7 +289,7 @@ fn spawn_level_card(parent: &mut ChildSpawnerCommands, level: usize, best_time:
             .with_children(|col| {
                 // Status text
                 let (status_text, status_color) = if is_completed {
-                    ("Completed", SUCCESS_TEXT_COLOR)
+                    ("Completed", palette.success_text)
                 } else {
                     ("Not completed", SECONDARY_TEXT_COLOR)
                 };
@@ -179,8 +304,8 @@ fn spawn_level_card(parent: &mut ChildSpawnerCommands, level: usize, best_time:
                 ));
 
                 // Time display
-                let time_text = if let Some(time) = best_time {
-                    format_time(time)
+                let time_text = if let Some((time, setup)) = best_run {
+                    format!("{}{}", format_time(time), setup.time_suffix())
                 } else {
                     "-".to_string()
                 };
@@ -198,30 +323,83 @@ fn spawn_level_card(parent: &mut ChildSpawnerCommands, level: usize, best_time:
                     }),
                     LevelTimeDisplay(level),
                 ));
+
+                col.spawn((
+                    Text::new(format!("Attempts: {}", attempts)),
+                    TextFont {
+                        font_size: LEVEL_STATUS_FONT_SIZE,
+                        ..default()
+                    },
+                    TextColor(SECONDARY_TEXT_COLOR),
+                ));
             });
 
             // Mini-map preview container (populated by minimap rendering system)
+            let (ring_color, ring_width, badge) = progress_ring(progress, palette);
             card.spawn((
                 Node {
                     width: Val::Px(MINI_MAP_WIDTH),
                     height: Val::Px(MINI_MAP_HEIGHT),
-                    border: UiRect::all(Val::Px(1.0)),
+                    border: UiRect::all(Val::Px(ring_width)),
                     justify_content: JustifyContent::Center,
                     align_items: AlignItems::Center,
                     ..default()
                 },
-                BorderColor::all(SECONDARY_TEXT_COLOR),
+                BorderColor::all(ring_color),
                 BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.5)),
                 LevelMiniMapPreview(level),
-            ));
+            ))
+            .with_children(|preview| {
+                let Some(badge) = badge else {
+                    return;
+                };
+                // Laid over the corner of the mini-map image added later
+                preview
+                    .spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            top: Val::Px(0.0),
+                            right: Val::Px(0.0),
+                            padding: UiRect::horizontal(Val::Px(PROGRESS_BADGE_PADDING)),
+                            ..default()
+                        },
+                        BackgroundColor(PROGRESS_BADGE_BACKGROUND),
+                        ZIndex(1),
+                    ))
+                    .with_child((
+                        Text::new(badge),
+                        TextFont {
+                            font_size: PROGRESS_BADGE_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(ring_color),
+                    ));
+            });
         });
 }
 
-/// Formats time in seconds to MM:SS.ss format
-fn format_time(seconds: f32) -> String {
-    let mins = (seconds / 60.0).floor() as u32;
-    let secs = seconds % 60.0;
-    format!("{:02}:{:05.2}", mins, secs)
+/// Border color and width ringing a level's mini-map, and the badge shown over it:
+/// untried levels keep the plain border, attempted ones are ringed in the warning color,
+/// and finished ones are ringed and badged as done, or with the medal earned
+fn progress_ring(progress: LevelProgress, palette: &Palette) -> (Color, f32, Option<&'static str>) {
+    match progress {
+        LevelProgress::Untried => (SECONDARY_TEXT_COLOR, MINI_MAP_BORDER, None),
+        LevelProgress::Attempted => (palette.warning_text, PROGRESS_RING_WIDTH, None),
+        LevelProgress::Completed => (palette.success_text, PROGRESS_RING_WIDTH, Some("Done")),
+        LevelProgress::Bronze => (BRONZE_MEDAL_COLOR, PROGRESS_RING_WIDTH, Some(Medal::Bronze.label())),
+    }
+}
+
+/// Formats total playtime as hours and minutes (e.g., "1h 05m", or "12m" under an hour)
+fn format_playtime(seconds: f32) -> String {
+    let total_mins = (seconds / 60.0).floor() as u32;
+    let hours = total_mins / 60;
+    let mins = total_mins % 60;
+    if hours > 0 {
+        format!("{}h {:02}m", hours, mins)
+    } else {
+        format!("{}m", mins)
+    }
 }
 
 // ============================================================================
@@ -234,18 +412,49 @@ pub fn level_menu_action(
         (&Interaction, &LevelMenuButtonAction),
         (Changed<Interaction>, With<Button>),
     >,
+    mut commands: Commands,
     mut game_state: ResMut<NextState<GameState>>,
     mut current_level: ResMut<CurrentLevel>,
     mut resume_flag: ResMut<ResumeFromPause>,
+    minimap_cache: Res<MinimapCache>,
+    existing_overlay: Query<(), With<ResetConfirmationOverlay>>,
 ) {
     for (interaction, button_action) in &interaction_query {
         if *interaction == Interaction::Pressed {
             match button_action {
                 LevelMenuButtonAction::PlayLevel(level) => {
+                    if minimap_cache.failed_levels.contains(level) {
+                        spawn_notice_overlay(
+                            &mut commands,
+                            &format!("Level {} couldn't be generated.\nTry another level.", level),
+                        );
+                        continue;
+                    }
                     current_level.0 = *level;
                     // Ensure we start fresh (not resuming)
                     resume_flag.0 = false;
-                    game_state.set(GameState::Playing);
+                    // Generated tracks are previewed first, where another seed can be picked
+                    if level_seed(*level).is_some() {
+                        game_state.set(GameState::TrackPreview);
+                    } else {
+                        game_state.set(GameState::Playing);
+                    }
+                }
+                LevelMenuButtonAction::Garage => {
+                    game_state.set(GameState::Garage);
+                }
+                LevelMenuButtonAction::ResetProgress => {
+                    if existing_overlay.is_empty() {
+                        // Also marked as part of the screen, so leaving the menu closes it
+                        spawn_confirmation_overlay(
+                            &mut commands,
+                            (ResetConfirmationOverlay, OnLevelMenuScreen),
+                            "Reset all level progress?\nBest times will be lost.",
+                            "Reset",
+                            ResetConfirmButtonAction::ConfirmReset,
+                            ResetConfirmButtonAction::CancelReset,
+                        );
+                    }
                 }
                 LevelMenuButtonAction::MainMenu => {
                     game_state.set(GameState::StartMenu);
@@ -255,6 +464,261 @@ pub fn level_menu_action(
     }
 }
 
+/// Toggles practice mode for the next level started
+pub fn toggle_practice_mode(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<PracticeToggleButton>)>,
+    mut practice: ResMut<PracticeMode>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            practice.0 = !practice.0;
+        }
+    }
+}
+
+/// Shows the practice mode state on its toggle button (when spawned or toggled)
+pub fn update_practice_label(
+    practice: Res<PracticeMode>,
+    button_query: Query<(Ref<PracticeToggleButton>, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (button, children) in &button_query {
+        if !button.is_added() && !practice.is_changed() {
+            continue;
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) {
+                **text = format!("Practice: {}", if practice.0 { "On" } else { "Off" });
+            }
+        }
+    }
+}
+
+/// Switches a level's finish condition from the button on its card. Kept in the settings,
+/// so the choice holds for every profile on this machine.
+pub fn cycle_finish_condition(
+    interaction_query: Query<(&Interaction, &FinishConditionButton), Changed<Interaction>>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            settings.cycle_finish_condition(button.0);
+        }
+    }
+}
+
+/// Shows each level's finish condition on its card's button (when spawned or switched)
+pub fn update_finish_condition_labels(
+    settings: Res<GameSettings>,
+    button_query: Query<(Ref<FinishConditionButton>, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (button, children) in &button_query {
+        if !button.is_added() && !settings.is_changed() {
+            continue;
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) {
+                **text = format!("Finish: {}", settings.finish_condition(button.0).label());
+            }
+        }
+    }
+}
+
+/// Toggles two-player mode for the next level started
+pub fn toggle_two_player_mode(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<TwoPlayerToggleButton>)>,
+    mut two_player: ResMut<TwoPlayerMode>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            two_player.0 = !two_player.0;
+        }
+    }
+}
+
+/// Shows the number of players on the two-player toggle button (when spawned or toggled)
+pub fn update_two_player_label(
+    two_player: Res<TwoPlayerMode>,
+    button_query: Query<(Ref<TwoPlayerToggleButton>, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (button, children) in &button_query {
+        if !button.is_added() && !two_player.is_changed() {
+            continue;
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) {
+                **text = format!("Players: {}", if two_player.0 { 2 } else { 1 });
+            }
+        }
+    }
+}
+
+/// Switches the level list between level and difficulty order
+pub fn toggle_level_sort(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<LevelSortButton>)>,
+    mut sort_order: ResMut<LevelSortOrder>,
+) {
+    for interaction in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            *sort_order = match *sort_order {
+                LevelSortOrder::Level => LevelSortOrder::Difficulty,
+                LevelSortOrder::Difficulty => LevelSortOrder::Level,
+            };
+        }
+    }
+}
+
+/// Shows the level list's order on its sort button (when spawned or toggled)
+pub fn update_level_sort_label(
+    sort_order: Res<LevelSortOrder>,
+    button_query: Query<(Ref<LevelSortButton>, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (button, children) in &button_query {
+        if !button.is_added() && !sort_order.is_changed() {
+            continue;
+        }
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) {
+                **text = match *sort_order {
+                    LevelSortOrder::Level => "Sort: Level",
+                    LevelSortOrder::Difficulty => "Sort: Difficulty",
+                }
+                .to_string();
+            }
+        }
+    }
+}
+
+/// Reorders the level cards (when the list is spawned or the sort order changes).
+/// By difficulty, the easiest come first with ties by level, and levels without a track last.
+pub fn sort_level_cards(
+    mut commands: Commands,
+    sort_order: Res<LevelSortOrder>,
+    mut minimap_cache: ResMut<MinimapCache>,
+    list_query: Query<(Entity, Ref<LevelListContainer>, &Children)>,
+    card_query: Query<&LevelCard>,
+) {
+    for (list, container, children) in &list_query {
+        if !container.is_added() && !sort_order.is_changed() {
+            continue;
+        }
+        let cards: HashMap<usize, Entity> = children
+            .iter()
+            .filter_map(|card| card_query.get(card).ok().map(|level| (level.0, card)))
+            .collect();
+        let mut levels: Vec<(usize, Option<f32>)> = cards
+            .keys()
+            .map(|&level| (level, minimap_cache.summary(level).map(|summary| summary.difficulty)))
+            .collect();
+        sort_levels(&mut levels, *sort_order);
+        let cards: Vec<Entity> = levels.iter().map(|(level, _)| cards[level]).collect();
+        commands.entity(list).replace_children(&cards);
+    }
+}
+
+/// Sorts (level, difficulty rating) pairs into the given order.
+/// A rating is None when the level's track couldn't be generated.
+pub fn sort_levels(levels: &mut [(usize, Option<f32>)], sort_order: LevelSortOrder) {
+    match sort_order {
+        LevelSortOrder::Level => levels.sort_by_key(|(level, _)| *level),
+        LevelSortOrder::Difficulty => levels.sort_by(|(level_a, rating_a), (level_b, rating_b)| {
+            let rating_a = rating_a.unwrap_or(f32::INFINITY);
+            let rating_b = rating_b.unwrap_or(f32::INFINITY);
+            rating_a.total_cmp(&rating_b).then(level_a.cmp(level_b))
+        }),
+    }
+}
+
+/// Starts a time trial over TIME_TRIAL_LEVELS once they're all unlocked.
+/// Time trials are single-player races that count, so practice and two-player mode are turned off.
+pub fn start_time_trial(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<TimeTrialButton>)>,
+    mut commands: Commands,
+    current_save: Res<CurrentSave>,
+    settings: Res<GameSettings>,
+    (mut practice, mut two_player): (ResMut<PracticeMode>, ResMut<TwoPlayerMode>),
+    mut sequence: ResMut<SequenceState>,
+    (mut current_level, mut resume_flag, mut game_state): (
+        ResMut<CurrentLevel>,
+        ResMut<ResumeFromPause>,
+        ResMut<NextState<GameState>>,
+    ),
+) {
+    for interaction in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let last_level = TIME_TRIAL_LEVELS[TIME_TRIAL_LEVELS.len() - 1];
+        let highest_level = current_save.get().map(|s| s.highest_level_unlocked).unwrap_or(1);
+        if highest_level < last_level {
+            spawn_notice_overlay(
+                &mut commands,
+                &format!("Unlock level {} to play the time trial.", last_level),
+            );
+            continue;
+        }
+        // Check every level up front, so the sequence never stops at one that can't be built
+        let (min_props, max_props) = settings.difficulty.powerup_count_range();
+        let failed_level = TIME_TRIAL_LEVELS
+            .into_iter()
+            .find(|&level| get_level_track(level, min_props, max_props).is_none());
+        if let Some(level) = failed_level {
+            spawn_notice_overlay(&mut commands, &format!("Level {} couldn't be generated.", level));
+            continue;
+        }
+
+        practice.0 = false;
+        two_player.0 = false;
+        *sequence = SequenceState::new(TIME_TRIAL_LEVELS.to_vec());
+        current_level.0 = TIME_TRIAL_LEVELS[0];
+        resume_flag.0 = false;
+        game_state.set(GameState::Playing);
+    }
+}
+
+/// Handles the reset progress confirmation dialog.
+/// On confirm, wipes the save's level progress, writes it to disk and rebuilds the menu.
+pub fn handle_reset_confirm_action(
+    interaction_query: Query<
+        (&Interaction, &ResetConfirmButtonAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut commands: Commands,
+    overlay_query: Query<Entity, With<ResetConfirmationOverlay>>,
+    screen_query: Query<Entity, (With<OnLevelMenuScreen>, Without<ResetConfirmationOverlay>)>,
+    (mut current_save, mut save_results): (ResMut<CurrentSave>, MessageWriter<SaveResult>),
+    (mut current_level, mut minimap_cache): (ResMut<CurrentLevel>, ResMut<MinimapCache>),
+    palette: Res<Palette>,
+) {
+    for (interaction, action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if let ResetConfirmButtonAction::ConfirmReset = action {
+            if let Some(save_data) = current_save.get_mut() {
+                save_data.reset_progress();
+                save_or_report(save_data, &mut save_results);
+            }
+            current_level.0 = 1;
+
+            // Rebuild the screen so the level list only shows level 1 again
+            for entity in &screen_query {
+                commands.entity(entity).despawn();
+            }
+            spawn_level_menu_screen(&mut commands, current_save.get(), &mut minimap_cache, &palette);
+        }
+
+        for entity in &overlay_query {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
 // ============================================================================
 // Minimap Preview Updates
 // ============================================================================
@@ -266,16 +730,16 @@ pub struct MinimapImageAdded;
 /// System to update minimap preview nodes with rendered images from the cache.
 pub fn update_minimap_previews(
     mut commands: Commands,
-    minimap_cache: Res<MinimapCache>,
+    mut minimap_cache: ResMut<MinimapCache>,
     preview_query: Query<(Entity, &LevelMiniMapPreview), Without<MinimapImageAdded>>,
 ) {
     for (entity, preview) in preview_query.iter() {
-        if let Some(image_handle) = minimap_cache.images.get(&preview.0) {
+        if let Some(image_handle) = minimap_cache.get(preview.0).cloned() {
             // Add the image as a child of the preview container
             commands.entity(entity).with_children(|parent| {
                 parent.spawn((
                     ImageNode {
-                        image: image_handle.clone(),
+                        image: image_handle,
                         ..default()
                     },
                     Node {
@@ -287,6 +751,24 @@ pub fn update_minimap_previews(
             });
             // Mark as populated to avoid re-adding
             commands.entity(entity).insert(MinimapImageAdded);
+            minimap_cache.mark_shown(preview.0);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difficulty_order_puts_untracked_levels_last() {
+        let sorted_by = |sort_order| {
+            let mut levels = [(4, Some(0.6)), (2, None), (3, Some(0.2)), (1, Some(0.6))];
+            sort_levels(&mut levels, sort_order);
+            levels.map(|(level, _)| level)
+        };
+
+        assert_eq!(sorted_by(LevelSortOrder::Level), [1, 2, 3, 4]);
+        assert_eq!(sorted_by(LevelSortOrder::Difficulty), [3, 1, 4, 2]);
+    }
+}
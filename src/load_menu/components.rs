@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use std::time::Duration;
 
 /// Marker component for entities that belong to the load menu screen
 #[derive(Component)]
@@ -28,6 +29,42 @@ pub struct NoSavesMessage;
 #[derive(Component)]
 pub struct DeleteConfirmationOverlay;
 
+/// Delete button that deletes its save once held for a while, instead of opening
+/// the confirmation overlay. Releasing early resets it.
+#[derive(Component)]
+pub struct HoldToConfirm {
+    /// Timer counting up the hold while the button is pressed
+    pub timer: Timer,
+}
+
+impl HoldToConfirm {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+        }
+    }
+
+    /// Advances the hold while pressed, or resets it fully once released.
+    /// Returns true on the frame the hold completes.
+    pub fn step(&mut self, pressed: bool, delta: Duration) -> bool {
+        if pressed {
+            self.timer.tick(delta);
+        } else {
+            self.timer.reset();
+        }
+        self.timer.just_finished()
+    }
+
+    /// Progress of the hold (0.0 = released, 1.0 = complete)
+    pub fn progress(&self) -> f32 {
+        self.timer.fraction()
+    }
+}
+
+/// Marker component for the fill showing the progress of a held delete button
+#[derive(Component)]
+pub struct HoldProgressFill;
+
 /// All actions that can be triggered from load menu buttons
 #[derive(Component)]
 pub enum LoadMenuButtonAction {
@@ -47,3 +84,23 @@ pub struct DeleteConfirmation {
     pub filename: Option<String>,
     pub player_name: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_releasing_mid_hold_resets() {
+        let mut hold = HoldToConfirm::new(1.5);
+
+        assert!(!hold.step(true, Duration::from_secs_f32(1.0)));
+        assert!(!hold.step(false, Duration::from_secs_f32(0.1)));
+        assert_eq!(hold.progress(), 0.0);
+
+        // The earlier second of holding doesn't count towards the next hold
+        assert!(!hold.step(true, Duration::from_secs_f32(1.0)));
+        assert!(hold.step(true, Duration::from_secs_f32(0.5)));
+        // Keeping it held afterwards doesn't confirm again
+        assert!(!hold.step(true, Duration::from_secs_f32(0.5)));
+    }
+}
@@ -1,7 +1,6 @@
-use bevy::prelude::*;
-
+use crate::level_menu::constants::{MINI_MAP_HEIGHT, MINI_MAP_WIDTH};
 use crate::styles::menu::{
-    LARGE_MARGIN, SMALL_MARGIN, STANDARD_MARGIN, XSMALL_TEXT_FONT_SIZE,
+    SMALL_MARGIN, STANDARD_MARGIN, XSMALL_TEXT_FONT_SIZE,
 };
 
 // ============================================================================
@@ -13,25 +12,24 @@ pub const SAVE_SLOT_HEIGHT: f32 = 80.0;
 pub const SAVE_SLOT_PADDING: f32 = 15.0;
 pub const SAVE_SLOT_SPACING: f32 = SMALL_MARGIN;
 pub const DELETE_BUTTON_SIZE: f32 = 40.0;
+/// Save thumbnails fit the slot's height and keep the level card previews' aspect ratio,
+/// as they share the same rendered images
+pub const SAVE_THUMBNAIL_HEIGHT: f32 = SAVE_SLOT_HEIGHT - 2.0 * SAVE_SLOT_PADDING;
+pub const SAVE_THUMBNAIL_WIDTH: f32 = SAVE_THUMBNAIL_HEIGHT * MINI_MAP_WIDTH / MINI_MAP_HEIGHT;
 pub const SCROLL_CONTAINER_HEIGHT: f32 = 400.0;
 pub const SAVES_LIST_MARGIN: f32 = STANDARD_MARGIN;
-pub const NO_SAVES_MESSAGE_MARGIN: f32 = LARGE_MARGIN;
-pub const CONFIRM_BUTTON_WIDTH: f32 = 150.0;
-pub const CONFIRM_BUTTON_SPACING: f32 = STANDARD_MARGIN;
 
 // ============================================================================
-// Font Sizes
+// Hold To Delete
 // ============================================================================
 
-pub const PLAYER_NAME_FONT_SIZE: f32 = 28.0;
-pub const STATS_LINE_FONT_SIZE: f32 = XSMALL_TEXT_FONT_SIZE;
-pub const CONFIRM_MESSAGE_FONT_SIZE: f32 = 32.0;
-pub const DELETE_BUTTON_FONT_SIZE: f32 = 20.0;
+/// Seconds the delete button must be held to delete a save (with hold to delete on)
+pub const HOLD_TO_DELETE_DURATION: f32 = 1.5;
 
 // ============================================================================
-// Colors
+// Font Sizes
 // ============================================================================
 
-pub const DELETE_BUTTON_COLOR: Color = Color::srgb(0.6, 0.2, 0.2);
-pub const DELETE_BUTTON_HOVER: Color = Color::srgb(0.8, 0.3, 0.3);
-pub const DELETE_BUTTON_PRESSED: Color = Color::srgb(0.9, 0.2, 0.2);
+pub const PLAYER_NAME_FONT_SIZE: f32 = 28.0;
+pub const STATS_LINE_FONT_SIZE: f32 = XSMALL_TEXT_FONT_SIZE;
+pub const DELETE_BUTTON_FONT_SIZE: f32 = 20.0;
@@ -8,8 +8,10 @@ use crate::utils::despawn_all;
 use components::OnLoadMenuScreen;
 use systems::{
     cleanup_load_menu, handle_delete_click, handle_delete_confirm_action, handle_save_slot_click,
-    load_menu_action, spawn_load_menu,
+    load_menu_action, spawn_load_menu, update_hold_to_delete,
 };
+use crate::level_menu::minimap::{capture_minimaps, cleanup_minimap_rendering, render_pending_minimaps};
+use crate::level_menu::systems::update_minimap_previews;
 use crate::styles::menu::standard_button_system;
 
 pub struct LoadMenuPlugin;
@@ -18,15 +20,22 @@ impl Plugin for LoadMenuPlugin {
     fn build(&self, app: &mut App) {
         app
             .add_systems(OnEnter(GameState::LoadGameMenu), spawn_load_menu)
-            .add_systems(OnExit(GameState::LoadGameMenu), (despawn_all::<OnLoadMenuScreen>, cleanup_load_menu))
+            .add_systems(
+                OnExit(GameState::LoadGameMenu),
+                (despawn_all::<OnLoadMenuScreen>, cleanup_load_menu, cleanup_minimap_rendering),
+            )
             .add_systems(
                 Update,
                 (
                     standard_button_system,
                     handle_save_slot_click,
                     handle_delete_click,
+                    update_hold_to_delete,
                     handle_delete_confirm_action,
                     load_menu_action,
+                    render_pending_minimaps,
+                    capture_minimaps,
+                    update_minimap_previews,
                 )
                     .run_if(in_state(GameState::LoadGameMenu)),
             );
@@ -1,17 +1,18 @@
 use bevy::prelude::*;
-use bevy::ui::FocusPolicy;
 
 use crate::constants::{CurrentLevel, GameState};
-use crate::load_menu::components::{DeleteConfirmation, DeleteConfirmButtonAction, DeleteConfirmationOverlay, LoadMenuButtonAction, MenuPanel, NoSavesMessage, OnLoadMenuScreen, SaveSlot, SaveSlotRow, SavesListContainer};
+use crate::load_menu::components::{DeleteConfirmation, DeleteConfirmButtonAction, DeleteConfirmationOverlay, HoldProgressFill, HoldToConfirm, LoadMenuButtonAction, MenuPanel, NoSavesMessage, OnLoadMenuScreen, SaveSlot, SaveSlotRow, SavesListContainer};
+use crate::level_menu::components::LevelMiniMapPreview;
 use crate::load_menu::constants::*;
-use crate::save::{delete_save_file, list_saves, load_from_file, CurrentSave, SaveData};
+use crate::save::{delete_save_file, load_from_file, CurrentSave, SaveCache, SaveData};
+use crate::settings::components::GameSettings;
 use crate::styles::colors::{
-    BUTTON_NORMAL_COLOR, MENU_BACKGROUND_COLOR, MENU_TEXT_COLOR, OVERLAY_BACKGROUND_COLOR,
-    SECONDARY_TEXT_COLOR,
+    BUTTON_NORMAL_COLOR, DANGER_BUTTON_COLOR, DANGER_BUTTON_HOVERED_COLOR,
+    DANGER_BUTTON_PRESSED_COLOR, DANGER_HOLD_FILL_COLOR, MENU_BACKGROUND_COLOR, MENU_TEXT_COLOR, SECONDARY_TEXT_COLOR,
 };
 use crate::styles::menu::{
-    column_centered, spawn_menu_container, spawn_standard_button, title_style,
-    button_text_style, no_saves_message_bundle, ButtonColors, BUTTON_HEIGHT,
+    column_centered, spawn_confirmation_overlay, spawn_menu_container, spawn_standard_button,
+    title_style, no_saves_message_bundle, ButtonColors,
 };
 
 // ============================================================================
@@ -19,11 +20,15 @@ use crate::styles::menu::{
 // ============================================================================
 
 /// Spawns the load menu screen UI
-pub fn spawn_load_menu(mut commands: Commands) {
+pub fn spawn_load_menu(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    mut save_cache: ResMut<SaveCache>,
+) {
     // Initialize delete confirmation resource
     commands.insert_resource(DeleteConfirmation::default());
 
-    let saves = list_saves().unwrap_or_default();
+    let saves = save_cache.saves();
 
     spawn_menu_container(&mut commands, OnLoadMenuScreen, MENU_BACKGROUND_COLOR)
         .with_children(|parent| {
@@ -33,7 +38,7 @@ pub fn spawn_load_menu(mut commands: Commands) {
                 if saves.is_empty() {
                     parent.spawn((no_saves_message_bundle(), NoSavesMessage));
                 } else {
-                    spawn_saves_list(parent, &saves);
+                    spawn_saves_list(parent, saves, settings.hold_to_delete);
                 }
 
                 spawn_standard_button(parent, "Back", LoadMenuButtonAction::Back);
@@ -41,7 +46,7 @@ pub fn spawn_load_menu(mut commands: Commands) {
         });
 }
 
-fn spawn_saves_list(parent: &mut ChildSpawnerCommands, saves: &[SaveData]) {
+fn spawn_saves_list(parent: &mut ChildSpawnerCommands, saves: &[SaveData], hold_to_delete: bool) {
     // Scrollable container for save slots
     parent
         .spawn((
@@ -57,12 +62,12 @@ fn spawn_saves_list(parent: &mut ChildSpawnerCommands, saves: &[SaveData]) {
         ))
         .with_children(|scroll_parent| {
             for save in saves {
-                spawn_save_slot(scroll_parent, save);
+                spawn_save_slot(scroll_parent, save, hold_to_delete);
             }
         });
 }
 
-fn spawn_save_slot(parent: &mut ChildSpawnerCommands, save: &SaveData) {
+fn spawn_save_slot(parent: &mut ChildSpawnerCommands, save: &SaveData, hold_to_delete: bool) {
     let filename = save.filename();
     let last_played = save.last_played.format("%Y-%m-%d %H:%M").to_string();
     let levels_completed = save.level_times.len();
@@ -86,41 +91,62 @@ fn spawn_save_slot(parent: &mut ChildSpawnerCommands, save: &SaveData) {
                     width: Val::Px(SAVE_SLOT_WIDTH),
                     height: Val::Px(SAVE_SLOT_HEIGHT),
                     padding: UiRect::all(Val::Px(SAVE_SLOT_PADDING)),
-                    flex_direction: FlexDirection::Column,
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::FlexStart,
+                    flex_direction: FlexDirection::Row,
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::Center,
                     ..default()
                 },
                 BackgroundColor(BUTTON_NORMAL_COLOR),
                 SaveSlot(filename.clone()),
             ))
             .with_children(|slot| {
-                // Player name
-                slot.spawn((
-                    Text::new(&save.player_name),
-                    TextFont {
-                        font_size: PLAYER_NAME_FONT_SIZE,
-                        ..default()
-                    },
-                    TextColor(MENU_TEXT_COLOR),
-                ));
+                slot.spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::FlexStart,
+                    ..default()
+                })
+                .with_children(|details| {
+                    // Player name
+                    details.spawn((
+                        Text::new(&save.player_name),
+                        TextFont {
+                            font_size: PLAYER_NAME_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(MENU_TEXT_COLOR),
+                    ));
+
+                    // Stats line
+                    details.spawn((
+                        Text::new(format!(
+                            "Level {} | {} completed | Last: {}",
+                            highest_level, levels_completed, last_played
+                        )),
+                        TextFont {
+                            font_size: STATS_LINE_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(SECONDARY_TEXT_COLOR),
+                    ));
+                });
 
-                // Stats line
+                // Thumbnail of the highest unlocked level's track (placeholder until rendered)
                 slot.spawn((
-                    Text::new(format!(
-                        "Level {} | {} completed | Last: {}",
-                        highest_level, levels_completed, last_played
-                    )),
-                    TextFont {
-                        font_size: STATS_LINE_FONT_SIZE,
+                    Node {
+                        width: Val::Px(SAVE_THUMBNAIL_WIDTH),
+                        height: Val::Px(SAVE_THUMBNAIL_HEIGHT),
+                        border: UiRect::all(Val::Px(1.0)),
                         ..default()
                     },
-                    TextColor(SECONDARY_TEXT_COLOR),
+                    BorderColor::all(SECONDARY_TEXT_COLOR),
+                    BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.5)),
+                    LevelMiniMapPreview(highest_level),
                 ));
             });
 
             // Delete button
-            row.spawn((
+            let mut delete_button = row.spawn((
                 Button,
                 Node {
                     width: Val::Px(DELETE_BUTTON_SIZE),
@@ -130,11 +156,29 @@ fn spawn_save_slot(parent: &mut ChildSpawnerCommands, save: &SaveData) {
                     align_items: AlignItems::Center,
                     ..default()
                 },
-                BackgroundColor(DELETE_BUTTON_COLOR),
-                ButtonColors::new(DELETE_BUTTON_COLOR, DELETE_BUTTON_HOVER, DELETE_BUTTON_PRESSED),
+                BackgroundColor(DANGER_BUTTON_COLOR),
+                ButtonColors::new(DANGER_BUTTON_COLOR, DANGER_BUTTON_HOVERED_COLOR, DANGER_BUTTON_PRESSED_COLOR),
                 DeleteButton(filename),
-            ))
-            .with_children(|btn| {
+            ));
+            if hold_to_delete {
+                delete_button.insert(HoldToConfirm::new(HOLD_TO_DELETE_DURATION));
+            }
+            delete_button.with_children(|btn| {
+                if hold_to_delete {
+                    // Rises from the bottom while held (spawned first so the label draws on top)
+                    btn.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Px(0.0),
+                            bottom: Val::Px(0.0),
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(0.0),
+                            ..default()
+                        },
+                        BackgroundColor(DANGER_HOLD_FILL_COLOR),
+                        HoldProgressFill,
+                    ));
+                }
                 btn.spawn((
                     Text::new("X"),
                     TextFont {
@@ -179,13 +223,15 @@ pub fn handle_save_slot_click(
 }
 
 /// Handles clicking the delete button - shows confirmation overlay
+/// (unless hold to delete is on, see `update_hold_to_delete`)
 pub fn handle_delete_click(
     interaction_query: Query<
         (&Interaction, &DeleteButton),
-        (Changed<Interaction>, With<Button>),
+        (Changed<Interaction>, With<Button>, Without<HoldToConfirm>),
     >,
     mut commands: Commands,
     mut delete_confirmation: ResMut<DeleteConfirmation>,
+    mut save_cache: ResMut<SaveCache>,
     _saves: Query<&SaveSlot>,
     existing_overlay: Query<Entity, With<DeleteConfirmationOverlay>>,
 ) {
@@ -197,9 +243,9 @@ pub fn handle_delete_click(
             }
 
             // Find the player name for this save
-            let player_name = list_saves()
-                .unwrap_or_default()
-                .into_iter()
+            let player_name = save_cache
+                .saves()
+                .iter()
                 .find(|s| s.filename() == delete_button.0)
                 .map(|s| s.player_name.clone())
                 .unwrap_or_else(|| "Unknown".to_string());
@@ -216,93 +262,14 @@ pub fn handle_delete_click(
 
 /// Spawns the delete confirmation overlay
 fn spawn_delete_confirmation_overlay(commands: &mut Commands, player_name: &str) {
-    commands
-        .spawn((
-            Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                align_items: AlignItems::Center,
-                justify_content: JustifyContent::Center,
-                flex_direction: FlexDirection::Column,
-                position_type: PositionType::Absolute,
-                left: Val::Px(0.0),
-                top: Val::Px(0.0),
-                ..default()
-            },
-            BackgroundColor(OVERLAY_BACKGROUND_COLOR),
-            DeleteConfirmationOverlay,
-            GlobalZIndex(100),
-            FocusPolicy::Block,
-        ))
-        .with_children(|parent| {
-            // Confirmation message
-            parent.spawn((
-                Text::new(format!("Are you sure you want to delete \"{}\"?", player_name)),
-                TextFont {
-                    font_size: CONFIRM_MESSAGE_FONT_SIZE,
-                    ..default()
-                },
-                TextColor(MENU_TEXT_COLOR),
-                TextLayout::new_with_justify(Justify::Center),
-                Node {
-                    margin: UiRect::bottom(Val::Px(NO_SAVES_MESSAGE_MARGIN)),
-                    ..default()
-                },
-            ));
-
-            // Button row
-            parent
-                .spawn(Node {
-                    flex_direction: FlexDirection::Row,
-                    justify_content: JustifyContent::Center,
-                    ..default()
-                })
-                .with_children(|buttons| {
-                    // Delete button (red)
-                    buttons
-                        .spawn((
-                            Button,
-                            Node {
-                                width: Val::Px(CONFIRM_BUTTON_WIDTH),
-                                height: Val::Px(BUTTON_HEIGHT),
-                                margin: UiRect::right(Val::Px(CONFIRM_BUTTON_SPACING)),
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::Center,
-                                ..default()
-                            },
-                            BackgroundColor(DELETE_BUTTON_COLOR),
-                            ButtonColors::new(DELETE_BUTTON_COLOR, DELETE_BUTTON_HOVER, DELETE_BUTTON_PRESSED),
-                            DeleteConfirmButtonAction::ConfirmDelete,
-                        ))
-                        .with_children(|btn| {
-                            btn.spawn((
-                                Text::new("Delete"),
-                                button_text_style(),
-                            ));
-                        });
-
-                    // Cancel button
-                    buttons
-                        .spawn((
-                            Button,
-                            Node {
-                                width: Val::Px(CONFIRM_BUTTON_WIDTH),
-                                height: Val::Px(BUTTON_HEIGHT),
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::Center,
-                                ..default()
-                            },
-                            BackgroundColor(BUTTON_NORMAL_COLOR),
-                            DeleteConfirmButtonAction::CancelDelete,
-                        ))
-                        .with_children(|btn| {
-                            btn.spawn((
-                                Text::new("Cancel"),
-                                button_text_style(),
-                            ));
-                        });
-                });
-        });
+    spawn_confirmation_overlay(
+        commands,
+        DeleteConfirmationOverlay,
+        &format!("Are you sure you want to delete \"{}\"?", player_name),
+        "Delete",
+        DeleteConfirmButtonAction::ConfirmDelete,
+        DeleteConfirmButtonAction::CancelDelete,
+    );
 }
 
 /// Handles confirmation dialog button actions
@@ -322,38 +289,8 @@ pub fn handle_delete_confirm_action(
         if *interaction == Interaction::Pressed {
             match action {
                 DeleteConfirmButtonAction::ConfirmDelete => {
-                    // Delete the save file and despawn the row
                     if let Some(filename) = &delete_confirmation.filename {
-                        let _ = delete_save_file(filename);
-
-                        // Find and despawn the save slot row
-                        for (entity, row) in &save_slot_rows {
-                            if &row.0 == filename {
-                                commands.entity(entity).despawn();
-                                break;
-                            }
-                        }
-
-                        // Check if this was the last save (only 1 row existed before deletion)
-                        if save_slot_rows.iter().count() == 1 {
-                            // Despawn the saves list container
-                            for entity in &saves_list_container {
-                                commands.entity(entity).despawn();
-                            }
-
-                            // Spawn the "no saves" message as a child of the menu panel
-                            // Insert at index 1 (after title, before Back button) to match spawn_load_menu order
-                            if let Ok(panel_entity) = menu_panel.single() {
-                                let message_entity = commands.spawn((
-                                    no_saves_message_bundle(),
-                                    NoSavesMessage,
-                                )).id();
-
-                                // Insert at index 1 (after title at index 0, before Back button)
-                                // This matches the order in spawn_load_menu: title, no_saves_message, back_button
-                                commands.entity(panel_entity).insert_children(1, &[message_entity]);
-                            }
-                        }
+                        delete_save(&mut commands, filename, &save_slot_rows, &saves_list_container, &menu_panel);
                     }
 
                     // Clear confirmation state
@@ -380,6 +317,72 @@ pub fn handle_delete_confirm_action(
     }
 }
 
+/// Deletes the save file and despawns its row, showing the "no saves" message
+/// once the last save is gone
+fn delete_save(
+    commands: &mut Commands,
+    filename: &str,
+    save_slot_rows: &Query<(Entity, &SaveSlotRow)>,
+    saves_list_container: &Query<Entity, With<SavesListContainer>>,
+    menu_panel: &Query<Entity, With<MenuPanel>>,
+) {
+    let _ = delete_save_file(filename);
+
+    // Find and despawn the save slot row
+    for (entity, row) in save_slot_rows {
+        if row.0 == filename {
+            commands.entity(entity).despawn();
+            break;
+        }
+    }
+
+    // Check if this was the last save (only 1 row existed before deletion)
+    if save_slot_rows.iter().count() == 1 {
+        // Despawn the saves list container
+        for entity in saves_list_container {
+            commands.entity(entity).despawn();
+        }
+
+        // Spawn the "no saves" message as a child of the menu panel
+        // Insert at index 1 (after title, before Back button) to match spawn_load_menu order
+        if let Ok(panel_entity) = menu_panel.single() {
+            let message_entity = commands.spawn((
+                no_saves_message_bundle(),
+                NoSavesMessage,
+            )).id();
+
+            // Insert at index 1 (after title at index 0, before Back button)
+            // This matches the order in spawn_load_menu: title, no_saves_message, back_button
+            commands.entity(panel_entity).insert_children(1, &[message_entity]);
+        }
+    }
+}
+
+/// Fills a held delete button (with hold to delete on) and deletes its save once
+/// the hold completes. Releasing the button, or moving off it, resets the hold.
+pub fn update_hold_to_delete(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut button_query: Query<(&Interaction, &mut HoldToConfirm, &DeleteButton, &Children)>,
+    mut fill_query: Query<&mut Node, With<HoldProgressFill>>,
+    save_slot_rows: Query<(Entity, &SaveSlotRow)>,
+    saves_list_container: Query<Entity, With<SavesListContainer>>,
+    menu_panel: Query<Entity, With<MenuPanel>>,
+) {
+    for (interaction, mut hold, delete_button, children) in &mut button_query {
+        let confirmed = hold.step(*interaction == Interaction::Pressed, time.delta());
+
+        let mut fills = fill_query.iter_many_mut(children);
+        while let Some(mut fill) = fills.fetch_next() {
+            fill.height = Val::Percent(hold.progress() * 100.0);
+        }
+
+        if confirmed {
+            delete_save(&mut commands, &delete_button.0, &save_slot_rows, &saves_list_container, &menu_panel);
+        }
+    }
+}
+
 /// Handles load menu button actions (Back)
 pub fn load_menu_action(
     interaction_query: Query<
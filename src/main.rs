@@ -1,45 +1,80 @@
+use bevy::camera::{CameraOutputMode, ScalingMode};
 use bevy::core_pipeline::tonemapping::{DebandDither, Tonemapping};
-use bevy::post_process::bloom::{Bloom, BloomCompositeMode, BloomPrefilter};
+use bevy::post_process::bloom::Bloom;
 use bevy::prelude::*;
+use bevy::window::{PresentMode, PrimaryWindow, WindowResized};
 use bevy_scrollbar::ScrollbarPlugin;
 
+mod analysis;
 mod car;
+mod checkpoint;
 mod collision;
 mod constants;
+#[cfg(debug_assertions)]
+mod debug;
 mod game_plugin;
+mod garage;
 mod hud;
 mod level_complete;
 mod level_menu;
 mod load_menu;
+mod menu_navigation;
+mod music;
 mod name_entry;
 mod pause_menu;
 mod props;
+mod racing_line;
+mod replay;
 mod save;
+mod sequence;
+mod settings;
+mod split_screen;
 mod start_menu;
 mod road;
+#[cfg(test)]
+mod state_tests;
 mod styles;
+mod track_preview;
 mod utils;
 
+use analysis::AnalysisPlugin;
 use car::CarPlugin;
-use constants::{CurrentLevel, GameState, ResumeFromPause, WINDOW_HEIGHT, WINDOW_WIDTH, BLOOM_INTENSITY, GAME_BACKGROUND_COLOR};
+use checkpoint::CheckpointPlugin;
+use constants::{CurrentLevel, GameRng, GameState, MainCamera, PracticeMode, PreviousState, ResumeFromPause, SeedOverride, TwoPlayerMode, WINDOW_HEIGHT, WINDOW_WIDTH, GAME_BACKGROUND_COLOR, LETTERBOX_COLOR};
 use game_plugin::GamePlugin;
+use garage::GaragePlugin;
 use hud::HudPlugin;
 use level_complete::LevelCompletePlugin;
 use level_menu::LevelMenuPlugin;
 use load_menu::LoadMenuPlugin;
+use menu_navigation::MenuNavigationPlugin;
+use music::MusicPlugin;
 use name_entry::NameEntryPlugin;
 use pause_menu::PauseMenuPlugin;
+use racing_line::RacingLinePlugin;
+use replay::ReplayPlugin;
 use road::RoadPlugin;
-use save::CurrentSave;
+use save::{CurrentSave, SavePlugin};
+use sequence::SequencePlugin;
+use settings::components::GameSettings;
+use settings::SettingsPlugin;
+use split_screen::components::SplitScreenCamera;
+use split_screen::SplitScreenPlugin;
 use start_menu::StartMenuPlugin;
+use styles::theme::{camera_bloom, CurrentTheme};
+use track_preview::TrackPreviewPlugin;
+use utils::letterbox_viewport;
 
 fn main() {
-    App::new()
+    let mut app = App::new();
+    app
         .add_plugins((
             DefaultPlugins.set(WindowPlugin {
                 primary_window: Some(Window {
                     title: "Bevy Driver".to_string(),
                     resolution: (WINDOW_WIDTH, WINDOW_HEIGHT).into(),
+                    // Switched by the latency setting once it's loaded (see `sync_window_latency`)
+                    present_mode: PresentMode::AutoVsync,
                     ..default()
                 }),
                 ..default()
@@ -54,10 +89,35 @@ fn main() {
         .init_resource::<CurrentSave>()
         // Initialize resume from pause flag
         .init_resource::<ResumeFromPause>()
-        // Set the clear color (background color)
+        // Initialize the screen the settings menu returns to
+        .init_resource::<PreviousState>()
+        // Initialize practice mode flag (toggled in the level menu)
+        .init_resource::<PracticeMode>()
+        // Initialize two-player flag (toggled in the level menu)
+        .init_resource::<TwoPlayerMode>()
+        // Initialize the seed picked on the track preview (none until one is picked)
+        .init_resource::<SeedOverride>()
+        // Seed the gameplay RNG once from the global seed
+        .insert_resource(GameRng::from_env())
+        // Set the clear color (background color); races switch to their theme's background
         .insert_resource(ClearColor(GAME_BACKGROUND_COLOR))
+        // Initialize the track theme (replaced when a level is set up)
+        .init_resource::<CurrentTheme>()
         // Spawn camera once on startup (persists across states)
         .add_systems(Startup, spawn_camera)
+        .add_systems(Update, sync_camera_bloom.run_if(resource_changed::<CurrentTheme>))
+        .add_systems(
+            Update,
+            sync_clear_color.run_if(state_changed::<GameState>.or(resource_changed::<CurrentTheme>)),
+        )
+        .add_systems(
+            Update,
+            (sync_camera_msaa, sync_window_latency).run_if(resource_changed::<GameSettings>),
+        )
+        .add_systems(
+            Update,
+            sync_camera_letterbox.run_if(resource_changed::<GameSettings>.or(on_message::<WindowResized>)),
+        )
         // Add all our plugins
         .add_plugins((
             StartMenuPlugin,
@@ -70,27 +130,102 @@ fn main() {
             RoadPlugin,
             PauseMenuPlugin,
             LevelCompletePlugin,
+            MenuNavigationPlugin,
+            ReplayPlugin,
+            SettingsPlugin,
+            CheckpointPlugin,
+            SequencePlugin,
         ))
-        .run();
+        // Plugin tuples hold at most 15 plugins
+        .add_plugins((TrackPreviewPlugin, RacingLinePlugin, GaragePlugin, AnalysisPlugin, SavePlugin, MusicPlugin, SplitScreenPlugin));
+
+    // Hitbox overlay and other debugging aids never ship in release builds
+    #[cfg(debug_assertions)]
+    app.add_plugins(debug::DebugPlugin);
+
+    app.run();
 }
 
-fn spawn_camera(mut commands: Commands) {
+fn spawn_camera(mut commands: Commands, theme: Res<CurrentTheme>, settings: Res<GameSettings>) {
     commands.spawn((
         Camera2d,
-        Tonemapping::TonyMcMapface,
-        Bloom {
-            intensity: BLOOM_INTENSITY,
-            // Use prefilter to only bloom pixels above threshold (more localized)
-            prefilter: BloomPrefilter {
-                threshold: 1.1,           // Only bloom pixels brighter than 1.0 (HDR)
-                threshold_softness: 0.2,  // Soft transition
+        Camera {
+            // Fills the window around a letterboxed viewport (see `sync_camera_letterbox`)
+            output_mode: CameraOutputMode::Write {
+                blend_state: None,
+                clear_color: ClearColorConfig::Custom(LETTERBOX_COLOR),
             },
-            // Additive mode works better with prefilter for localized glow
-            composite_mode: BloomCompositeMode::Additive,
-            // Tighten the scatter for more localized glow
-            high_pass_frequency: 0.45,
             ..default()
         },
+        MainCamera,
+        // The UI is drawn by this camera even while the split-screen views draw the world
+        IsDefaultUiCamera,
+        settings.msaa(),
+        Tonemapping::TonyMcMapface,
+        camera_bloom(theme.0.bloom_intensity),
         DebandDither::Enabled,
     ));
 }
+
+/// Filter for the cameras drawing the race: the main camera, and the split-screen views
+/// of a two-player race
+type RaceCameraFilter = Or<(With<MainCamera>, With<SplitScreenCamera>)>;
+
+/// Keeps the cameras' bloom in line with the current theme and glow setting
+fn sync_camera_bloom(theme: Res<CurrentTheme>, mut cameras: Query<&mut Bloom, RaceCameraFilter>) {
+    for mut bloom in cameras.iter_mut() {
+        bloom.intensity = theme.0.bloom_intensity;
+    }
+}
+
+/// Clears behind the race with its theme's background, and behind every other screen with
+/// the default one, so a themed level's background never carries over into the menus
+fn sync_clear_color(state: Res<State<GameState>>, theme: Res<CurrentTheme>, mut clear_color: ResMut<ClearColor>) {
+    clear_color.0 = match state.get() {
+        GameState::Playing | GameState::Paused | GameState::Replay => theme.0.background,
+        _ => GAME_BACKGROUND_COLOR,
+    };
+}
+
+/// Applies the latency setting to the window: its present mode and how many frames may be
+/// queued. Runs when the settings are loaded too, so the saved choice holds from the start.
+fn sync_window_latency(settings: Res<GameSettings>, mut window: Single<&mut Window, With<PrimaryWindow>>) {
+    let present_mode = settings.present_mode();
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+    let frame_latency = settings.max_frame_latency();
+    if window.desired_maximum_frame_latency != frame_latency {
+        window.desired_maximum_frame_latency = frame_latency;
+    }
+}
+
+/// Fits the main camera's viewport to the window at the playfield's aspect (see
+/// `letterbox_viewport`), showing the same `WINDOW_WIDTH` x `WINDOW_HEIGHT` of the world
+/// however large it is, so the boundaries and HUD line up in any window.
+/// Without letterboxing the camera fills the window, showing as much of the world as it has pixels.
+fn sync_camera_letterbox(
+    settings: Res<GameSettings>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut camera: Single<(&mut Camera, &mut Projection), With<MainCamera>>,
+) {
+    let (camera, projection) = &mut *camera;
+    camera.viewport = settings.letterbox.then(|| letterbox_viewport(window.physical_size()));
+    if let Projection::Orthographic(orthographic) = projection.as_mut() {
+        orthographic.scaling_mode = if settings.letterbox {
+            ScalingMode::Fixed {
+                width: WINDOW_WIDTH as f32,
+                height: WINDOW_HEIGHT as f32,
+            }
+        } else {
+            ScalingMode::WindowSize
+        };
+    }
+}
+
+/// Applies the anti-aliasing setting to the cameras when it's toggled
+fn sync_camera_msaa(settings: Res<GameSettings>, mut cameras: Query<&mut Msaa, RaceCameraFilter>) {
+    for mut msaa in cameras.iter_mut() {
+        msaa.set_if_neq(settings.msaa());
+    }
+}
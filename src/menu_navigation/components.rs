@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+/// Resource tracking which menu button has keyboard focus.
+/// The index refers to the focusable buttons in spawn order (see `collect_focusable_buttons`).
+#[derive(Resource, Default)]
+pub struct MenuFocus {
+    /// Index of the focused button, None until the player presses Up/Down
+    pub index: Option<usize>,
+    /// The focused button entity (resolved from the index each frame)
+    pub focused: Option<Entity>,
+    /// Button that received a synthesized press (released again on the next frame)
+    pub pressed: Option<Entity>,
+}
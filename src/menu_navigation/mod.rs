@@ -0,0 +1,25 @@
+pub mod components;
+pub mod systems;
+
+use bevy::prelude::*;
+use bevy::ui::UiSystems;
+use crate::constants::GameState;
+use components::MenuFocus;
+use systems::{highlight_focused_button, menu_keyboard_navigation, reset_menu_focus};
+
+/// Keyboard navigation (Up/Down + Enter) for every button-based menu.
+/// Runs globally so individual screens don't need to register anything.
+pub struct MenuNavigationPlugin;
+
+impl Plugin for MenuNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<MenuFocus>()
+            // Runs right after Bevy updates mouse interactions so a synthesized press
+            // is seen by the per-screen action systems in the same frame
+            .add_systems(PreUpdate, menu_keyboard_navigation.after(UiSystems::Focus))
+            .add_systems(Update, reset_menu_focus.run_if(state_changed::<GameState>))
+            // Painted after standard_button_system so the highlight isn't overwritten
+            .add_systems(PostUpdate, highlight_focused_button);
+    }
+}
@@ -0,0 +1,148 @@
+use bevy::prelude::*;
+
+use crate::menu_navigation::components::MenuFocus;
+use crate::styles::menu::{button_color, button_colors, ButtonColors};
+
+/// Top-level UI nodes, with their optional global z-index used to find the front-most overlay.
+type UiRootQuery<'w, 's> =
+    Query<'w, 's, (Entity, Option<&'static GlobalZIndex>), (With<Node>, Without<ChildOf>)>;
+
+// ============================================================================
+// Focus Helpers
+// ============================================================================
+
+/// Collects the focusable buttons in spawn order (depth-first through the UI hierarchy).
+/// Only the top-most UI root that contains buttons is used, so overlays such as the
+/// delete confirmation capture keyboard focus the same way they block the mouse.
+fn collect_focusable_buttons(
+    roots: &UiRootQuery,
+    children_query: &Query<&Children>,
+    buttons: &Query<&mut Interaction, With<Button>>,
+) -> Vec<Entity> {
+    let mut sorted_roots: Vec<(Entity, i32)> = roots
+        .iter()
+        .map(|(entity, z_index)| (entity, z_index.map(|z| z.0).unwrap_or(0)))
+        .collect();
+    // Highest z-index first, ties broken by entity for a stable order
+    sorted_roots.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+    for (root, _) in sorted_roots {
+        let mut focusable = Vec::new();
+        collect_buttons_recursive(root, children_query, buttons, &mut focusable);
+        if !focusable.is_empty() {
+            return focusable;
+        }
+    }
+
+    Vec::new()
+}
+
+fn collect_buttons_recursive(
+    entity: Entity,
+    children_query: &Query<&Children>,
+    buttons: &Query<&mut Interaction, With<Button>>,
+    focusable: &mut Vec<Entity>,
+) {
+    if buttons.contains(entity) {
+        focusable.push(entity);
+    }
+    if let Ok(children) = children_query.get(entity) {
+        for child in children.iter() {
+            collect_buttons_recursive(child, children_query, buttons, focusable);
+        }
+    }
+}
+
+/// Moves the focus index one step forward or backward, wrapping around.
+/// With no current focus, Down focuses the first button and Up the last.
+fn step_focus(current: Option<usize>, count: usize, forward: bool) -> usize {
+    match (current, forward) {
+        (None, true) => 0,
+        (None, false) => count - 1,
+        (Some(index), true) => (index + 1) % count,
+        (Some(index), false) => (index + count - 1) % count,
+    }
+}
+
+// ============================================================================
+// Navigation Systems
+// ============================================================================
+
+/// Handles Up/Down to move menu focus and Enter to activate the focused button.
+/// Activation synthesizes an `Interaction::Pressed`, so every menu's existing
+/// `Changed<Interaction>` action system handles it exactly like a mouse click.
+pub fn menu_keyboard_navigation(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<MenuFocus>,
+    roots: UiRootQuery,
+    children_query: Query<&Children>,
+    mut buttons: Query<&mut Interaction, With<Button>>,
+) {
+    // Release last frame's synthesized press (Bevy only resets presses on mouse release)
+    if let Some(entity) = focus.pressed.take() {
+        if let Ok(mut interaction) = buttons.get_mut(entity) {
+            if *interaction == Interaction::Pressed {
+                *interaction = Interaction::None;
+            }
+        }
+    }
+
+    let focusable = collect_focusable_buttons(&roots, &children_query, &buttons);
+    if focusable.is_empty() {
+        focus.index = None;
+        focus.focused = None;
+        return;
+    }
+
+    if keyboard.just_pressed(KeyCode::ArrowDown) {
+        focus.index = Some(step_focus(focus.index, focusable.len(), true));
+    }
+    if keyboard.just_pressed(KeyCode::ArrowUp) {
+        focus.index = Some(step_focus(focus.index, focusable.len(), false));
+    }
+
+    // Keep the index valid if buttons were despawned (e.g. a deleted save slot)
+    focus.index = focus.index.map(|index| index.min(focusable.len() - 1));
+    focus.focused = focus.index.map(|index| focusable[index]);
+
+    if keyboard.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter]) {
+        if let Some(entity) = focus.focused {
+            if let Ok(mut interaction) = buttons.get_mut(entity) {
+                *interaction = Interaction::Pressed;
+                focus.pressed = Some(entity);
+            }
+        }
+    }
+}
+
+/// Paints the focused button with its hover color, restoring the previous one when focus moves.
+pub fn highlight_focused_button(
+    focus: Res<MenuFocus>,
+    mut highlighted: Local<Option<Entity>>,
+    mut buttons: Query<(&Interaction, &mut BackgroundColor, Option<&ButtonColors>), With<Button>>,
+) {
+    if *highlighted != focus.focused {
+        if let Some(previous) = highlighted.take() {
+            if let Ok((interaction, mut background_color, custom_colors)) = buttons.get_mut(previous) {
+                background_color.set_if_neq(button_color(*interaction, custom_colors).into());
+            }
+        }
+    }
+
+    if let Some(entity) = focus.focused {
+        if let Ok((interaction, mut background_color, custom_colors)) = buttons.get_mut(entity) {
+            // Mouse hover/press colors take precedence over the keyboard highlight
+            if *interaction == Interaction::None {
+                let (_, hovered, _) = button_colors(custom_colors);
+                background_color.set_if_neq(hovered.into());
+            }
+        }
+    }
+
+    *highlighted = focus.focused;
+}
+
+/// Clears menu focus whenever the game state changes (a new screen was spawned).
+pub fn reset_menu_focus(mut focus: ResMut<MenuFocus>) {
+    *focus = MenuFocus::default();
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use crate::constants::GameState;
+use crate::music::constants::{MENU_MUSIC_PATH, RACE_MUSIC_PATH};
+
+/// Which of the music tracks fits a screen
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MusicKind {
+    Menu,
+    Race,
+}
+
+impl MusicKind {
+    /// The race music carries on through the pause menu and the replay,
+    /// every other screen gets the calmer menu track
+    pub fn for_state(state: GameState) -> Self {
+        match state {
+            GameState::Playing | GameState::Paused | GameState::Replay => MusicKind::Race,
+            _ => MusicKind::Menu,
+        }
+    }
+
+    /// Path of the track's file in the assets folder
+    pub fn path(&self) -> &'static str {
+        match self {
+            MusicKind::Menu => MENU_MUSIC_PATH,
+            MusicKind::Race => RACE_MUSIC_PATH,
+        }
+    }
+}
+
+/// Component for the entity playing the background music (kept across screens)
+#[derive(Component)]
+pub struct Music(pub MusicKind);
+
+/// Handles to the music tracks, loaded at startup
+#[derive(Resource)]
+pub struct MusicTracks {
+    pub menu: Handle<AudioSource>,
+    pub race: Handle<AudioSource>,
+}
+
+impl MusicTracks {
+    pub fn get(&self, kind: MusicKind) -> &Handle<AudioSource> {
+        match kind {
+            MusicKind::Menu => &self.menu,
+            MusicKind::Race => &self.race,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+// ============================================================================
+// Music Constants
+// ============================================================================
+
+/// Music looped through a race (relative to the assets folder)
+pub const RACE_MUSIC_PATH: &str = "music/race.ogg";
+/// Calmer music looped on the menus
+pub const MENU_MUSIC_PATH: &str = "music/menu.ogg";
+/// Playback speed of the race music while a NOS boost fires, for a more intense beat
+pub const BOOST_MUSIC_SPEED: f32 = 1.15;
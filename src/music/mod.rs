@@ -0,0 +1,23 @@
+pub mod components;
+pub mod constants;
+pub mod systems;
+
+use bevy::prelude::*;
+use crate::constants::GameState;
+use crate::settings::components::GameSettings;
+use systems::{load_music, play_music_for_state, sync_music_volume, update_music_intensity};
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_music).add_systems(
+            Update,
+            (
+                play_music_for_state.run_if(state_changed::<GameState>),
+                update_music_intensity,
+                sync_music_volume.run_if(resource_changed::<GameSettings>),
+            ),
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use crate::car::components::NosBoostAvailable;
+use crate::constants::GameState;
+use crate::music::components::{Music, MusicKind, MusicTracks};
+use crate::music::constants::BOOST_MUSIC_SPEED;
+use crate::settings::components::GameSettings;
+
+// ============================================================================
+// Loading
+// ============================================================================
+
+/// Starts loading the music tracks
+pub fn load_music(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(MusicTracks {
+        menu: asset_server.load(MusicKind::Menu.path()),
+        race: asset_server.load(MusicKind::Race.path()),
+    });
+}
+
+// ============================================================================
+// Playback
+// ============================================================================
+
+/// Starts the track fitting the current screen, stopping the other one.
+/// A track that couldn't be loaded (e.g. a missing file) leaves the game silent;
+/// one still loading starts playing once it's ready.
+pub fn play_music_for_state(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    tracks: Res<MusicTracks>,
+    asset_server: Res<AssetServer>,
+    settings: Res<GameSettings>,
+    music_query: Query<(Entity, &Music)>,
+) {
+    let kind = MusicKind::for_state(*state.get());
+    if music_query.iter().any(|(_, music)| music.0 == kind) {
+        return;
+    }
+    for (entity, _) in &music_query {
+        commands.entity(entity).despawn();
+    }
+
+    let track = tracks.get(kind);
+    if asset_server.load_state(track).is_failed() {
+        return;
+    }
+    // The master volume (the global volume) is applied on top as the track starts
+    commands.spawn((
+        AudioPlayer(track.clone()),
+        PlaybackSettings::LOOP.with_volume(Volume::Linear(settings.music_volume())),
+        Music(kind),
+    ));
+}
+
+/// Speeds the race music up while a NOS boost fires, and back down once it ends
+pub fn update_music_intensity(
+    state: Res<State<GameState>>,
+    boost_query: Query<&NosBoostAvailable>,
+    music_query: Query<(&Music, &AudioSink)>,
+) {
+    let boosting = *state.get() == GameState::Playing && boost_query.iter().any(|boost| boost.active);
+    for (music, sink) in &music_query {
+        let speed = if boosting && music.0 == MusicKind::Race { BOOST_MUSIC_SPEED } else { 1.0 };
+        if sink.speed() != speed {
+            sink.set_speed(speed);
+        }
+    }
+}
+
+/// Applies the master and music volume to the track playing
+/// (changes to the global volume only reach sounds started afterwards)
+pub fn sync_music_volume(settings: Res<GameSettings>, mut music_query: Query<&mut AudioSink, With<Music>>) {
+    for mut sink in music_query.iter_mut() {
+        sink.set_volume(Volume::Linear(settings.effective_volume() * settings.music_volume()));
+    }
+}
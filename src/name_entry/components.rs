@@ -8,6 +8,24 @@ pub struct OnNameEntryScreen;
 #[derive(Component)]
 pub struct NameInputText;
 
+/// Component for the blinking caret after the typed name (a text span of the input text)
+#[derive(Component)]
+pub struct NameCaret {
+    pub timer: Timer,
+    pub shown: bool,
+}
+
+/// Component for the name input field, whose border flashes when typing past the length limit
+#[derive(Component, Default)]
+pub struct NameInputField {
+    /// Seconds left of the border flash
+    pub flash_remaining: f32,
+}
+
+/// Marker component for the "12/20" character counter below the input field
+#[derive(Component)]
+pub struct NameLengthCounter;
+
 /// Resource to store the currently typed player name
 #[derive(Resource, Default)]
 pub struct PlayerNameInput(pub String);
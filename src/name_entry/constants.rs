@@ -7,6 +7,12 @@ use crate::styles::menu::{MEDIUM_TEXT_FONT_SIZE, SMALL_MARGIN, SMALL_TEXT_FONT_S
 // ============================================================================
 
 pub const MAX_NAME_LENGTH: usize = 20;
+/// Whether held keys keep typing characters through OS auto-repeat (Backspace always repeats)
+pub const ACCEPT_KEY_REPEAT: bool = false;
+/// Time the caret stays shown or hidden in each blink (seconds)
+pub const CARET_BLINK_INTERVAL: f32 = 0.5;
+/// Duration of the border flash when a character is rejected at the length limit (seconds)
+pub const LIMIT_FLASH_DURATION: f32 = 0.3;
 
 // ============================================================================
 // Layout Constants
@@ -20,6 +26,7 @@ pub const INPUT_FIELD_MARGIN: f32 = SMALL_MARGIN;
 pub const SUBTITLE_MARGIN: f32 = STANDARD_MARGIN;
 pub const ERROR_TEXT_HEIGHT: f32 = 25.0;
 pub const ERROR_TEXT_MARGIN: f32 = SMALL_MARGIN;
+pub const COUNTER_MARGIN: f32 = SMALL_MARGIN;
 
 // ============================================================================
 // Font Sizes
@@ -27,6 +34,7 @@ pub const ERROR_TEXT_MARGIN: f32 = SMALL_MARGIN;
 
 pub const SUBTITLE_FONT_SIZE: f32 = MEDIUM_TEXT_FONT_SIZE;
 pub const ERROR_TEXT_FONT_SIZE: f32 = SMALL_TEXT_FONT_SIZE;
+pub const COUNTER_FONT_SIZE: f32 = SMALL_TEXT_FONT_SIZE;
 
 // ============================================================================
 // Colors
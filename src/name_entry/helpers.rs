@@ -0,0 +1,141 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+
+use crate::name_entry::constants::MAX_NAME_LENGTH;
+
+/// Only alphanumeric characters (in any script, accented ones included), spaces, underscores,
+/// and hyphens are allowed in names. The save file gets an ASCII name (see `sanitize_filename`).
+fn is_valid_name_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == ' ' || ch == '_' || ch == '-'
+}
+
+/// Applies one keyboard event to the name being typed.
+/// OS auto-repeat events are ignored for typed characters unless `accept_repeat` is set;
+/// Backspace always repeats so a held key clears the name quickly.
+/// Returns true if the typed characters were rejected because the name is at `MAX_NAME_LENGTH`
+/// (counted in characters, so accented and non-Latin letters count once).
+pub fn apply_name_key(name: &mut String, event: &KeyboardInput, accept_repeat: bool) -> bool {
+    if event.state != ButtonState::Pressed {
+        return false;
+    }
+
+    // Space arrives as a named key, so it's mapped to its character and takes the same path
+    let typed = match &event.logical_key {
+        Key::Backspace => {
+            name.pop();
+            return false;
+        }
+        Key::Character(c) => c.as_str(),
+        Key::Space => " ",
+        _ => return false,
+    };
+    if event.repeat && !accept_repeat {
+        return false;
+    }
+
+    let mut valid_chars: String = typed.chars().filter(|ch| is_valid_name_char(*ch)).collect();
+    // Names can't start with a space
+    if name.is_empty() {
+        valid_chars = valid_chars.trim_start().to_string();
+    }
+
+    if name.chars().count() + valid_chars.chars().count() > MAX_NAME_LENGTH {
+        return true;
+    }
+    name.push_str(&valid_chars);
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::input::keyboard::KeyCode;
+    use bevy::prelude::Entity;
+
+    fn key_event(key_code: KeyCode, logical_key: Key, repeat: bool) -> KeyboardInput {
+        KeyboardInput {
+            key_code,
+            logical_key,
+            state: ButtonState::Pressed,
+            text: None,
+            repeat,
+            window: Entity::PLACEHOLDER,
+        }
+    }
+
+    fn type_keys(events: &[KeyboardInput]) -> String {
+        let mut name = String::new();
+        for event in events {
+            apply_name_key(&mut name, event, false);
+        }
+        name
+    }
+
+    #[test]
+    fn test_space_is_inserted_once() {
+        let name = type_keys(&[
+            key_event(KeyCode::KeyA, Key::Character("a".into()), false),
+            key_event(KeyCode::Space, Key::Space, false),
+            key_event(KeyCode::KeyB, Key::Character("b".into()), false),
+        ]);
+        assert_eq!(name, "a b");
+    }
+
+    #[test]
+    fn test_leading_space_is_ignored() {
+        let name = type_keys(&[
+            key_event(KeyCode::Space, Key::Space, false),
+            key_event(KeyCode::Space, Key::Character(" ".into()), false),
+            key_event(KeyCode::KeyA, Key::Character("a".into()), false),
+        ]);
+        assert_eq!(name, "a");
+    }
+
+    #[test]
+    fn test_repeated_character_is_ignored() {
+        let events = [
+            key_event(KeyCode::KeyA, Key::Character("a".into()), false),
+            key_event(KeyCode::KeyA, Key::Character("a".into()), true),
+            key_event(KeyCode::KeyA, Key::Character("a".into()), true),
+        ];
+        assert_eq!(type_keys(&events), "a");
+
+        let mut name = String::new();
+        for event in &events {
+            apply_name_key(&mut name, event, true);
+        }
+        assert_eq!(name, "aaa");
+    }
+
+    #[test]
+    fn test_accented_and_non_latin_letters_are_kept() {
+        let typed = ["J", "o", "s", "é", " ", "Ж", "太", "🚗"]
+            .map(|c| key_event(KeyCode::KeyA, Key::Character(c.into()), false));
+        assert_eq!(type_keys(&typed), "José Ж太");
+
+        // The limit counts characters, not bytes
+        let mut name = "é".repeat(MAX_NAME_LENGTH - 1);
+        let event = key_event(KeyCode::KeyA, Key::Character("ñ".into()), false);
+        assert!(!apply_name_key(&mut name, &event, false));
+        assert_eq!(name.chars().count(), MAX_NAME_LENGTH);
+    }
+
+    #[test]
+    fn test_typing_past_limit_is_rejected() {
+        let mut name = "a".repeat(MAX_NAME_LENGTH);
+        let event = key_event(KeyCode::KeyB, Key::Character("b".into()), false);
+        assert!(apply_name_key(&mut name, &event, false));
+        assert_eq!(name.len(), MAX_NAME_LENGTH);
+    }
+
+    #[test]
+    fn test_repeated_backspace_deletes() {
+        let name = type_keys(&[
+            key_event(KeyCode::KeyA, Key::Character("a".into()), false),
+            key_event(KeyCode::KeyB, Key::Character("b".into()), false),
+            key_event(KeyCode::Backspace, Key::Backspace, false),
+            key_event(KeyCode::Backspace, Key::Backspace, true),
+        ]);
+        assert_eq!(name, "");
+    }
+}
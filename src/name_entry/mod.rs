@@ -1,12 +1,16 @@
 pub mod components;
 pub mod constants;
+pub mod helpers;
 pub mod systems;
 
 use bevy::prelude::*;
 use crate::constants::GameState;
 use crate::utils::despawn_all;
 use components::OnNameEntryScreen;
-use systems::{cleanup_name_entry, handle_name_input, name_entry_action, spawn_name_entry};
+use systems::{
+    blink_caret, cleanup_name_entry, flash_input_border, handle_name_input, name_entry_action,
+    spawn_name_entry,
+};
 use crate::styles::menu::standard_button_system;
 
 pub struct NameEntryPlugin;
@@ -18,7 +22,12 @@ impl Plugin for NameEntryPlugin {
             .add_systems(OnExit(GameState::NewGameNameEntry), (despawn_all::<OnNameEntryScreen>, cleanup_name_entry))
             .add_systems(
                 Update,
-                (handle_name_input, standard_button_system, name_entry_action)
+                (
+                    (handle_name_input, blink_caret).chain(),
+                    flash_input_border,
+                    standard_button_system,
+                    name_entry_action,
+                )
                     .run_if(in_state(GameState::NewGameNameEntry)),
             );
     }
@@ -1,23 +1,28 @@
 use bevy::prelude::*;
-use bevy::input::keyboard::{Key, KeyboardInput};
-use bevy::input::ButtonState;
+use bevy::input::keyboard::KeyboardInput;
 
 use crate::constants::{CurrentLevel, GameState};
-use crate::name_entry::components::{NameEntryButtonAction, NameInputText, OnNameEntryScreen, PlayerNameInput};
+use crate::name_entry::components::{
+    NameCaret, NameEntryButtonAction, NameInputField, NameInputText, NameLengthCounter, OnNameEntryScreen,
+    PlayerNameInput,
+};
 use crate::name_entry::constants::*;
-use crate::save::{save_exists, save_to_file, CurrentSave, SaveData};
-use crate::styles::colors::{ERROR_TEXT_COLOR, MENU_BACKGROUND_COLOR, MENU_TEXT_COLOR};
+use crate::name_entry::helpers::apply_name_key;
+use crate::save::{create_save, save_exists, save_or_report, CurrentSave, SaveResult};
+use crate::settings::components::GameSettings;
+use crate::styles::colors::{MENU_BACKGROUND_COLOR, MENU_TEXT_COLOR};
 use crate::styles::menu::{
     column_centered, spawn_menu_container, spawn_standard_button, title_style,
     BUTTON_FONT_SIZE,
 };
+use crate::styles::palette::Palette;
 
 // ============================================================================
 // Name Entry Screen Spawning
 // ============================================================================
 
 /// Spawns the name entry screen UI
-pub fn spawn_name_entry(mut commands: Commands) {
+pub fn spawn_name_entry(mut commands: Commands, palette: Res<Palette>) {
     // Initialize the player name input resource
     commands.insert_resource(PlayerNameInput::default());
 
@@ -27,7 +32,8 @@ pub fn spawn_name_entry(mut commands: Commands) {
                 parent.spawn((Text::new("New Game"), title_style()));
                 spawn_subtitle(parent);
                 spawn_input_field(parent);
-                spawn_error_text(parent);
+                spawn_length_counter(parent);
+                spawn_error_text(parent, &palette);
                 spawn_standard_button(parent, "Start Game", NameEntryButtonAction::StartGame);
                 spawn_standard_button(parent, "Back", NameEntryButtonAction::Back);
             });
@@ -64,28 +70,62 @@ fn spawn_input_field(parent: &mut ChildSpawnerCommands) {
             },
             BackgroundColor(INPUT_BACKGROUND_COLOR),
             BorderColor::all(INPUT_BORDER_COLOR),
+            NameInputField::default(),
         ))
         .with_children(|parent| {
-            parent.spawn((
-                Text::new("Type your name..."),
-                TextFont {
-                    font_size: BUTTON_FONT_SIZE,
-                    ..default()
-                },
-                TextColor(PLACEHOLDER_COLOR),
-                NameInputText,
-            ));
+            parent
+                .spawn((
+                    Text::new("Type your name..."),
+                    TextFont {
+                        font_size: BUTTON_FONT_SIZE,
+                        ..default()
+                    },
+                    TextColor(PLACEHOLDER_COLOR),
+                    NameInputText,
+                ))
+                .with_child((
+                    TextSpan::new("_"),
+                    TextFont {
+                        font_size: BUTTON_FONT_SIZE,
+                        ..default()
+                    },
+                    // Hidden behind the placeholder; blink_caret shows it once a name is typed
+                    TextColor(Color::NONE),
+                    NameCaret {
+                        timer: Timer::from_seconds(CARET_BLINK_INTERVAL, TimerMode::Repeating),
+                        shown: true,
+                    },
+                ));
         });
 }
 
-fn spawn_error_text(parent: &mut ChildSpawnerCommands) {
+/// Spawns the "0/20" character counter, right-aligned under the input field
+fn spawn_length_counter(parent: &mut ChildSpawnerCommands) {
+    parent.spawn((
+        Text::new(format!("0/{}", MAX_NAME_LENGTH)),
+        TextFont {
+            font_size: COUNTER_FONT_SIZE,
+            ..default()
+        },
+        TextColor(PLACEHOLDER_COLOR),
+        TextLayout::new_with_justify(Justify::Right),
+        Node {
+            width: Val::Px(INPUT_FIELD_WIDTH),
+            margin: UiRect::bottom(Val::Px(COUNTER_MARGIN)),
+            ..default()
+        },
+        NameLengthCounter,
+    ));
+}
+
+fn spawn_error_text(parent: &mut ChildSpawnerCommands, palette: &Palette) {
     parent.spawn((
         Text::new(""),
         TextFont {
             font_size: ERROR_TEXT_FONT_SIZE,
             ..default()
         },
-        TextColor(ERROR_TEXT_COLOR),
+        TextColor(palette.error_text),
         Node {
             margin: UiRect::bottom(Val::Px(ERROR_TEXT_MARGIN)),
             height: Val::Px(ERROR_TEXT_HEIGHT),
@@ -103,52 +143,85 @@ pub struct ErrorText;
 // Keyboard Input Handling
 // ============================================================================
 
-/// Handles keyboard input for the player name
+/// Handles keyboard input for the player name.
+/// Updates the displayed name and character counter, and flashes the field border
+/// when a character is rejected at the length limit.
 pub fn handle_name_input(
     mut keyboard_events: MessageReader<KeyboardInput>,
     mut player_name: ResMut<PlayerNameInput>,
     mut text_query: Query<(&mut Text, &mut TextColor), With<NameInputText>>,
+    mut counter_query: Query<&mut Text, (With<NameLengthCounter>, Without<NameInputText>)>,
+    mut field_query: Query<&mut NameInputField>,
 ) {
     for event in keyboard_events.read() {
-        if event.state != ButtonState::Pressed {
-            continue;
-        }
-
-        match &event.logical_key {
-            Key::Backspace => {
-                player_name.0.pop();
+        if apply_name_key(&mut player_name.0, event, ACCEPT_KEY_REPEAT) {
+            if let Ok(mut field) = field_query.single_mut() {
+                field.flash_remaining = LIMIT_FLASH_DURATION;
             }
-            Key::Character(c) => {
-                // Only allow alphanumeric characters, spaces, underscores, and hyphens
-                let valid_chars: String = c.chars()
-                    .filter(|ch| ch.is_alphanumeric() || *ch == ' ' || *ch == '_' || *ch == '-')
-                    .collect();
-
-                if player_name.0.len() + valid_chars.len() <= MAX_NAME_LENGTH {
-                    player_name.0.push_str(&valid_chars);
-                }
-            }
-            Key::Space => {
-                if player_name.0.len() < MAX_NAME_LENGTH && !player_name.0.is_empty() {
-                    player_name.0.push(' ');
-                }
-            }
-            _ => {}
         }
     }
 
-    // Update the displayed text
+    if let Ok(mut counter) = counter_query.single_mut() {
+        **counter = format!("{}/{}", player_name.0.chars().count(), MAX_NAME_LENGTH);
+    }
+
+    // Update the displayed text (the caret is a separate span, see blink_caret)
     if let Ok((mut text, mut color)) = text_query.single_mut() {
         if player_name.0.is_empty() {
             *text = Text::new("Type your name...");
             *color = TextColor(PLACEHOLDER_COLOR);
         } else {
-            *text = Text::new(format!("{}_", &player_name.0));
+            *text = Text::new(player_name.0.clone());
             *color = TextColor(MENU_TEXT_COLOR);
         }
     }
 }
 
+/// Blinks the caret after the typed name. It stays shown while typing (or with reduced motion)
+/// and is hidden over the placeholder.
+pub fn blink_caret(
+    time: Res<Time>,
+    player_name: Res<PlayerNameInput>,
+    settings: Res<GameSettings>,
+    mut caret_query: Query<(&mut NameCaret, &mut TextColor)>,
+) {
+    for (mut caret, mut color) in caret_query.iter_mut() {
+        if settings.reduced_motion {
+            caret.shown = true;
+        } else if player_name.is_changed() {
+            caret.timer.reset();
+            caret.shown = true;
+        } else if caret.timer.tick(time.delta()).just_finished() {
+            caret.shown = !caret.shown;
+        }
+
+        let visible = caret.shown && !player_name.0.is_empty();
+        color.0 = if visible { MENU_TEXT_COLOR } else { Color::NONE };
+    }
+}
+
+/// Fades the input field border from the error color back to normal after a rejected character.
+/// With reduced motion the error color is held for the flash duration instead.
+pub fn flash_input_border(
+    time: Res<Time>,
+    palette: Res<Palette>,
+    settings: Res<GameSettings>,
+    mut field_query: Query<(&mut NameInputField, &mut BorderColor)>,
+) {
+    for (mut field, mut border) in field_query.iter_mut() {
+        if field.flash_remaining <= 0.0 {
+            continue;
+        }
+        field.flash_remaining = (field.flash_remaining - time.delta_secs()).max(0.0);
+        let flash = if settings.reduced_motion && field.flash_remaining > 0.0 {
+            1.0
+        } else {
+            field.flash_remaining / LIMIT_FLASH_DURATION
+        };
+        *border = BorderColor::all(INPUT_BORDER_COLOR.mix(&palette.error_text, flash));
+    }
+}
+
 // ============================================================================
 // Button Actions
 // ============================================================================
@@ -164,6 +237,7 @@ pub fn name_entry_action(
     mut current_level: ResMut<CurrentLevel>,
     player_name: Res<PlayerNameInput>,
     mut error_text_query: Query<&mut Text, With<ErrorText>>,
+    mut save_results: MessageWriter<SaveResult>,
 ) {
     for (interaction, button_action) in &interaction_query {
         if *interaction == Interaction::Pressed {
@@ -187,21 +261,17 @@ pub fn name_entry_action(
                         continue;
                     }
 
-                    // Create new save
-                    let save_data = SaveData::new(name);
+                    // Create new save, in a file of its own
+                    let save_data = create_save(name);
 
-                    // Save to file
-                    if let Err(e) = save_to_file(&save_data) {
-                        if let Ok(mut text) = error_text_query.single_mut() {
-                            *text = Text::new(format!("Failed to save: {}", e));
-                        }
-                        continue;
-                    }
+                    // Save to file (if that fails the game is played with the save in memory)
+                    save_or_report(&save_data, &mut save_results);
 
-                    // Set current save and level
+                    // Set current save and level, then let the player pick a level
+                    // (matches the load flow; only level 1 is unlocked)
                     current_level.0 = 1;
                     current_save.set(save_data);
-                    game_state.set(GameState::Playing);
+                    game_state.set(GameState::LevelMenu);
                 }
                 NameEntryButtonAction::Back => {
                     game_state.set(GameState::StartMenu);
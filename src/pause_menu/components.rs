@@ -8,6 +8,8 @@ pub struct OnPauseMenuScreen;
 #[derive(Component)]
 pub enum PauseMenuButtonAction {
     Resume,
+    /// Open the settings screen, returning to this menu afterwards
+    Options,
     LevelMenu,
     MainMenu,
     Quit,
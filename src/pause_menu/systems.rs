@@ -1,9 +1,8 @@
 use bevy::app::AppExit;
 use bevy::prelude::*;
 
-use crate::constants::{GameState, ResumeFromPause};
+use crate::constants::{GameState, PreviousState, ResumeFromPause};
 use crate::pause_menu::components::{OnPauseMenuScreen, PauseMenuButtonAction};
-use crate::start_menu::components::GameEntity;
 use crate::styles::colors::OVERLAY_BACKGROUND_COLOR;
 use crate::styles::menu::{
     column_centered, spawn_menu_container, spawn_button_with_width, title_style, LARGE_BUTTON_WIDTH,
@@ -20,6 +19,7 @@ pub fn spawn_pause_menu(mut commands: Commands) {
             parent.spawn(column_centered()).with_children(|parent| {
                 parent.spawn((Text::new("Paused"), title_style()));
                 spawn_button_with_width(parent, "Resume", PauseMenuButtonAction::Resume, LARGE_BUTTON_WIDTH);
+                spawn_button_with_width(parent, "Options", PauseMenuButtonAction::Options, LARGE_BUTTON_WIDTH);
                 spawn_button_with_width(parent, "Level Menu", PauseMenuButtonAction::LevelMenu, LARGE_BUTTON_WIDTH);
                 spawn_button_with_width(parent, "Main Menu", PauseMenuButtonAction::MainMenu, LARGE_BUTTON_WIDTH);
                 spawn_button_with_width(parent, "Quit", PauseMenuButtonAction::Quit, LARGE_BUTTON_WIDTH);
@@ -63,11 +63,10 @@ pub fn pause_menu_action(
         (&Interaction, &PauseMenuButtonAction),
         (Changed<Interaction>, With<Button>),
     >,
-    mut commands: Commands,
-    game_entities: Query<Entity, With<GameEntity>>,
     mut app_exit_writer: MessageWriter<AppExit>,
     mut game_state: ResMut<NextState<GameState>>,
     mut resume_flag: ResMut<ResumeFromPause>,
+    mut previous_state: ResMut<PreviousState>,
 ) {
     for (interaction, button_action) in &interaction_query {
         if *interaction == Interaction::Pressed {
@@ -76,18 +75,16 @@ pub fn pause_menu_action(
                     resume_flag.0 = true;
                     game_state.set(GameState::Playing);
                 }
+                PauseMenuButtonAction::Options => {
+                    // The race entities are kept, so Back resumes the paused race
+                    previous_state.0 = GameState::Paused;
+                    game_state.set(GameState::Settings);
+                }
+                // The race is cleaned up on entering the menu (see `GamePlugin`)
                 PauseMenuButtonAction::LevelMenu => {
-                    // Clean up game entities when going to level menu
-                    for entity in &game_entities {
-                        commands.entity(entity).despawn();
-                    }
                     game_state.set(GameState::LevelMenu);
                 }
                 PauseMenuButtonAction::MainMenu => {
-                    // Clean up game entities when returning to main menu
-                    for entity in &game_entities {
-                        commands.entity(entity).despawn();
-                    }
                     game_state.set(GameState::StartMenu);
                 }
                 PauseMenuButtonAction::Quit => {
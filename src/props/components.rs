@@ -2,3 +2,27 @@ use bevy::prelude::*;
 
 #[derive(Component)]
 pub struct NosPowerUp;
+
+/// Powerup that temporarily slows the car's physics down when collected
+#[derive(Component)]
+pub struct SlowMoPowerUp;
+
+/// Coin placed along the track when collecting coins is turned on. Purely for the score,
+/// it has no effect on the car.
+#[derive(Component)]
+pub struct Coin;
+
+/// Resource counting the coins collected in the current run
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Score {
+    pub collected: u32,
+    /// Coins placed on the track (0 when coins are turned off)
+    pub total: u32,
+}
+
+/// The kind of prop placed at a track's prop index
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PropKind {
+    Nos,
+    SlowMo,
+}
@@ -9,3 +9,17 @@ pub const NOS_HALF_SIZE: f32 = NOS_SIZE / 2.0;
 pub const NOS_THICKNESS: f32 = 2.0;
 pub const NOS_COLOR: Color = Color::srgb(0.0, 2.0, 2.0); // Cyan glow
 pub const NOS_ROTATION_SPEED: f32 = 3.0;
+
+// -- Coin -- //
+/// Side length of a coin (a square turned into a diamond)
+pub const COIN_SIZE: f32 = 7.0;
+/// Half the size of a coin (for collision detection)
+pub const COIN_HALF_SIZE: f32 = COIN_SIZE / 2.0;
+pub const COIN_COLOR: Color = Color::srgb(2.0, 1.6, 0.0); // Gold glow
+/// A coin is placed on every Nth segment of a track (skipping those with a powerup)
+pub const COIN_SEGMENT_INTERVAL: usize = 3;
+
+// -- Slow-Mo Powerup -- //
+pub const SLOW_MO_COLOR: Color = Color::srgb(2.0, 0.0, 2.0); // Magenta glow
+/// Every Nth prop on a track is a slow-mo powerup instead of NOS
+pub const SLOW_MO_PROP_INTERVAL: usize = 3;
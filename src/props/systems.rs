@@ -1,67 +1,180 @@
 use bevy::prelude::*;
-use crate::car::components::{Car, NosBoostAvailable};
-use crate::car::constants::{CAR_WIDTH, CAR_HEIGHT, NOS_AVAILABILITY_DURATION};
+use crate::car::components::{Car, NosBoostAvailable, SlowMotion};
+use crate::car::constants::{CAR_WIDTH, CAR_HEIGHT, NOS_AVAILABILITY_DURATION, SLOW_MO_DURATION};
 use crate::collision::check_obb_collision;
-use crate::props::components::NosPowerUp;
+use crate::props::components::{Coin, NosPowerUp, PropKind, Score, SlowMoPowerUp};
 use crate::props::constants::*;
+use crate::settings::components::GameSettings;
+use crate::styles::theme::Glowing;
 use crate::start_menu::components::GameEntity;
 
+/// Filter matching every collectible powerup kind
+type AnyPowerUp = Or<(With<NosPowerUp>, With<SlowMoPowerUp>)>;
+
 /// Half dimensions for car collision box
 const CAR_HALF_WIDTH: f32 = CAR_WIDTH / 2.0;
 const CAR_HALF_HEIGHT: f32 = CAR_HEIGHT / 2.0;
 
-/// System to check collision between car and NOS powerups using OBB collision detection.
+/// Returns true if the car's rotated hitbox overlaps a powerup's hitbox
+fn car_touches_powerup(car_transform: &Transform, powerup_transform: &Transform) -> bool {
+    check_obb_collision(
+        car_transform,
+        CAR_HALF_WIDTH,
+        CAR_HALF_HEIGHT,
+        powerup_transform,
+        NOS_HALF_SIZE,
+        NOS_HALF_SIZE,
+    )
+}
+
+/// Returns the car touching a powerup, if any. When both cars of a two-player race reach
+/// it on the same frame, the first car found takes it.
+fn car_touching_powerup(
+    car_query: &Query<(Entity, &Transform), With<Car>>,
+    powerup_transform: &Transform,
+) -> Option<Entity> {
+    car_query
+        .iter()
+        .find(|(_, car_transform)| car_touches_powerup(car_transform, powerup_transform))
+        .map(|(car_entity, _)| car_entity)
+}
+
+/// System to check collision between the cars and NOS powerups using OBB collision detection.
 /// Properly handles rotated rectangles for accurate collision.
-/// On collision, adds NosBoostAvailable component to the car (resets timer if already present).
+/// On collision, adds NosBoostAvailable component to the car that touched it (resets timer
+/// if already present).
 pub fn check_powerup_collision(
     mut commands: Commands,
     car_query: Query<(Entity, &Transform), With<Car>>,
     powerup_query: Query<(Entity, &Transform), With<NosPowerUp>>,
 ) {
-    let Some((car_entity, car_transform)) = car_query.iter().next() else {
-        return;
-    };
-
     for (powerup_entity, powerup_transform) in &powerup_query {
         // Use OBB collision for accurate rotated rectangle detection
-        if check_obb_collision(
-            car_transform,
-            CAR_HALF_WIDTH,
-            CAR_HALF_HEIGHT,
-            powerup_transform,
-            NOS_HALF_SIZE,
-            NOS_HALF_SIZE,
-        ) {
+        if let Some(car_entity) = car_touching_powerup(&car_query, powerup_transform) {
             // Despawn the powerup (and its children)
             commands.entity(powerup_entity).despawn();
 
             // Add or reset NosBoostAvailable on the car
-            // This gives the player a window to activate the boost with SPACE
+            // This gives the player a window to activate the boost with their boost key
             commands.entity(car_entity).insert(NosBoostAvailable::new(NOS_AVAILABILITY_DURATION));
         }
     }
 }
 
+/// System to check collision between the cars and slow-mo powerups.
+/// On collision, adds SlowMotion to the car that touched it. Collecting another while active
+/// resets the timer rather than stacking the slowdown.
+pub fn check_slow_mo_powerup_collision(
+    mut commands: Commands,
+    car_query: Query<(Entity, &Transform), With<Car>>,
+    powerup_query: Query<(Entity, &Transform), With<SlowMoPowerUp>>,
+) {
+    for (powerup_entity, powerup_transform) in &powerup_query {
+        if let Some(car_entity) = car_touching_powerup(&car_query, powerup_transform) {
+            commands.entity(powerup_entity).despawn();
+            commands.entity(car_entity).insert(SlowMotion::new(SLOW_MO_DURATION));
+        }
+    }
+}
+
+/// System to collect the coins any car touches, adding them to the score
+pub fn check_collectible_collision(
+    mut commands: Commands,
+    car_query: Query<&Transform, With<Car>>,
+    coin_query: Query<(Entity, &Transform), With<Coin>>,
+    mut score: ResMut<Score>,
+) {
+    for (coin_entity, coin_transform) in &coin_query {
+        let collected = car_query.iter().any(|car_transform| {
+            check_obb_collision(
+                car_transform,
+                CAR_HALF_WIDTH,
+                CAR_HALF_HEIGHT,
+                coin_transform,
+                COIN_HALF_SIZE,
+                COIN_HALF_SIZE,
+            )
+        });
+        if collected {
+            commands.entity(coin_entity).despawn();
+            score.collected += 1;
+        }
+    }
+}
+
+/// Resets the score for a run with `total` coins on the track
+pub fn init_score(commands: &mut Commands, total: u32) {
+    commands.insert_resource(Score { collected: 0, total });
+}
+
+/// Spins the powerups in place (they hold still with reduced motion)
 pub fn rotate_powerups(
     time: Res<Time>,
-    mut query: Query<&mut Transform, With<NosPowerUp>>,
+    settings: Res<GameSettings>,
+    mut query: Query<&mut Transform, AnyPowerUp>,
 ) {
+    if settings.reduced_motion {
+        return;
+    }
     for mut transform in &mut query {
         transform.rotate_z(NOS_ROTATION_SPEED * time.delta_secs());
     }
 }
 
+/// Returns which prop to place for the n-th prop index of a track
+pub fn prop_kind_for(prop_number: usize) -> PropKind {
+    if prop_number % SLOW_MO_PROP_INTERVAL == SLOW_MO_PROP_INTERVAL - 1 {
+        PropKind::SlowMo
+    } else {
+        PropKind::Nos
+    }
+}
+
+/// Spawns the given kind of prop at a world position
+pub fn spawn_prop(commands: &mut Commands, kind: PropKind, position: Vec2) {
+    match kind {
+        PropKind::Nos => spawn_nos_powerup(commands, position),
+        PropKind::SlowMo => spawn_slow_mo_powerup(commands, position),
+    }
+}
+
 pub fn spawn_nos_powerup(commands: &mut Commands, position: Vec2) {
+    spawn_square_powerup(commands, NosPowerUp, position, NOS_COLOR);
+}
+
+pub fn spawn_slow_mo_powerup(commands: &mut Commands, position: Vec2) {
+    spawn_square_powerup(commands, SlowMoPowerUp, position, SLOW_MO_COLOR);
+}
+
+/// Spawns a coin: a small glowing diamond
+pub fn spawn_coin(commands: &mut Commands, position: Vec2) {
+    commands.spawn((
+        Coin,
+        Glowing(COIN_COLOR),
+        Sprite {
+            color: COIN_COLOR,
+            custom_size: Some(Vec2::splat(COIN_SIZE)),
+            ..default()
+        },
+        Transform::from_xyz(position.x, position.y, PROP_Z)
+            .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+        GameEntity,
+    ));
+}
+
+/// Spawns a glowing square outline powerup with the given marker component
+fn spawn_square_powerup<M: Component>(commands: &mut Commands, marker: M, position: Vec2, color: Color) {
     commands.spawn((
-        NosPowerUp,
+        marker,
         Transform::from_xyz(position.x, position.y, PROP_Z),
         Visibility::default(),
         GameEntity,
     )).with_children(|parent| {
         // Top
         parent.spawn((
+            Glowing(color),
             Sprite {
-                color: NOS_COLOR,
+                color,
                 custom_size: Some(Vec2::new(NOS_SIZE, NOS_THICKNESS)),
                 ..default()
             },
@@ -69,8 +182,9 @@ pub fn spawn_nos_powerup(commands: &mut Commands, position: Vec2) {
         ));
         // Bottom
         parent.spawn((
+            Glowing(color),
             Sprite {
-                color: NOS_COLOR,
+                color,
                 custom_size: Some(Vec2::new(NOS_SIZE, NOS_THICKNESS)),
                 ..default()
             },
@@ -78,8 +192,9 @@ pub fn spawn_nos_powerup(commands: &mut Commands, position: Vec2) {
         ));
         // Left
         parent.spawn((
+            Glowing(color),
             Sprite {
-                color: NOS_COLOR,
+                color,
                 custom_size: Some(Vec2::new(NOS_THICKNESS, NOS_SIZE)),
                 ..default()
             },
@@ -87,8 +202,9 @@ pub fn spawn_nos_powerup(commands: &mut Commands, position: Vec2) {
         ));
         // Right
         parent.spawn((
+            Glowing(color),
             Sprite {
-                color: NOS_COLOR,
+                color,
                 custom_size: Some(Vec2::new(NOS_THICKNESS, NOS_SIZE)),
                 ..default()
             },
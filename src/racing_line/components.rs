@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+/// Component on a car recording where its last breadcrumb was dropped.
+/// Added with the first breadcrumb, so it goes with the car on a restart.
+#[derive(Component)]
+pub struct BreadcrumbDropper {
+    /// Position of the last breadcrumb (world coordinates)
+    pub last_drop: Vec2,
+}
+
+/// A breadcrumb of the racing line trail
+#[derive(Component)]
+pub struct Breadcrumb {
+    /// Order the breadcrumbs were dropped in (higher = newer)
+    pub index: u64,
+    /// Speed-based color, faded in alpha as the breadcrumb gets old
+    pub color: Color,
+}
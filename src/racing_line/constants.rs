@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+/// Distance (pixels) the car travels between breadcrumbs
+pub const BREADCRUMB_SPACING: f32 = 8.0;
+/// Side length of a breadcrumb sprite
+pub const BREADCRUMB_SIZE: f32 = 3.0;
+/// Opacity of a breadcrumb before it starts fading
+pub const BREADCRUMB_ALPHA: f32 = 0.4;
+/// Z-index for breadcrumbs (above the road edges, below the start line and car)
+pub const BREADCRUMB_Z: f32 = 1.3;
+
+/// Most breadcrumbs kept at once (the oldest are despawned past this)
+pub const MAX_BREADCRUMBS: u64 = 1500;
+/// Number of the oldest kept breadcrumbs that fade out towards the end of the trail
+pub const BREADCRUMB_FADE_COUNT: u64 = 300;
+
+/// Trail color at a standstill
+pub const SLOW_TRAIL_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+/// Trail color at the car's top speed
+pub const FAST_TRAIL_COLOR: Color = Color::srgb(0.2, 1.0, 0.2);
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use crate::racing_line::constants::{
+    BREADCRUMB_ALPHA, BREADCRUMB_FADE_COUNT, FAST_TRAIL_COLOR, MAX_BREADCRUMBS, SLOW_TRAIL_COLOR,
+};
+
+/// Trail color for a speed, from red at a standstill to green at `max_speed`
+pub fn speed_color(speed: f32, max_speed: f32) -> Color {
+    let fraction = (speed / max_speed).clamp(0.0, 1.0);
+    SLOW_TRAIL_COLOR.mix(&FAST_TRAIL_COLOR, fraction)
+}
+
+/// Opacity of a breadcrumb `age` breadcrumbs older than the newest one.
+/// Full until the last `BREADCRUMB_FADE_COUNT` kept breadcrumbs, which fade out linearly;
+/// None once it's past `MAX_BREADCRUMBS` and should be despawned.
+pub fn breadcrumb_alpha(age: u64) -> Option<f32> {
+    if age >= MAX_BREADCRUMBS {
+        return None;
+    }
+    let remaining = MAX_BREADCRUMBS - age;
+    if remaining >= BREADCRUMB_FADE_COUNT {
+        Some(BREADCRUMB_ALPHA)
+    } else {
+        Some(BREADCRUMB_ALPHA * remaining as f32 / BREADCRUMB_FADE_COUNT as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oldest_breadcrumbs_fade_then_despawn() {
+        assert_eq!(breadcrumb_alpha(0), Some(BREADCRUMB_ALPHA));
+        assert_eq!(breadcrumb_alpha(MAX_BREADCRUMBS - BREADCRUMB_FADE_COUNT), Some(BREADCRUMB_ALPHA));
+
+        let fading = breadcrumb_alpha(MAX_BREADCRUMBS - BREADCRUMB_FADE_COUNT / 2).unwrap();
+        assert!((fading - BREADCRUMB_ALPHA / 2.0).abs() < 1e-6);
+
+        assert_eq!(breadcrumb_alpha(MAX_BREADCRUMBS), None);
+    }
+
+    #[test]
+    fn test_speed_color_runs_from_slow_to_fast() {
+        assert_eq!(speed_color(0.0, 400.0), SLOW_TRAIL_COLOR);
+        assert_eq!(speed_color(400.0, 400.0), FAST_TRAIL_COLOR);
+        // Boosting past top speed stays at the fast color
+        assert_eq!(speed_color(600.0, 400.0), FAST_TRAIL_COLOR);
+    }
+}
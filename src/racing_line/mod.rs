@@ -0,0 +1,21 @@
+pub mod components;
+pub mod constants;
+pub mod helpers;
+pub mod systems;
+
+use bevy::prelude::*;
+use crate::constants::GameState;
+use systems::{drop_breadcrumbs, fade_old_breadcrumbs, racing_line_enabled};
+
+pub struct RacingLinePlugin;
+
+impl Plugin for RacingLinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (drop_breadcrumbs, fade_old_breadcrumbs)
+                .chain()
+                .run_if(in_state(GameState::Playing).and(racing_line_enabled)),
+        );
+    }
+}
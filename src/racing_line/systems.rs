@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+
+use crate::car::components::{Car, CarStats, Velocity};
+use crate::hud::components::{RaceState, RaceStatus};
+use crate::racing_line::components::{Breadcrumb, BreadcrumbDropper};
+use crate::racing_line::constants::{BREADCRUMB_SIZE, BREADCRUMB_SPACING, BREADCRUMB_Z};
+use crate::racing_line::helpers::{breadcrumb_alpha, speed_color};
+use crate::settings::components::GameSettings;
+use crate::start_menu::components::GameEntity;
+
+/// Run condition: returns true if the racing line trail is turned on in settings
+pub fn racing_line_enabled(settings: Res<GameSettings>) -> bool {
+    settings.show_racing_line
+}
+
+/// Car state the trail is sampled from
+type DropperQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static Transform,
+        &'static Velocity,
+        &'static CarStats,
+        Option<&'static mut BreadcrumbDropper>,
+    ),
+    With<Car>,
+>;
+
+/// System to drop a breadcrumb behind each car every `BREADCRUMB_SPACING` pixels of travel
+/// while the race is running, colored by the car's speed at that point
+pub fn drop_breadcrumbs(
+    mut commands: Commands,
+    race_state: Res<RaceState>,
+    mut next_index: Local<u64>,
+    mut car_query: DropperQuery,
+) {
+    if race_state.status != RaceStatus::Racing {
+        return;
+    }
+
+    for (entity, transform, velocity, stats, dropper) in car_query.iter_mut() {
+        let position = transform.translation.truncate();
+        match dropper {
+            Some(mut dropper) if dropper.last_drop.distance(position) >= BREADCRUMB_SPACING => {
+                dropper.last_drop = position;
+            }
+            Some(_) => continue,
+            None => {
+                commands.entity(entity).insert(BreadcrumbDropper { last_drop: position });
+            }
+        }
+
+        let color = speed_color(velocity.0.length(), stats.max_speed);
+        commands.spawn((
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(BREADCRUMB_SIZE)),
+                ..default()
+            },
+            Transform::from_xyz(position.x, position.y, BREADCRUMB_Z),
+            Breadcrumb { index: *next_index, color },
+            GameEntity,
+        ));
+        *next_index += 1;
+    }
+}
+
+/// System to fade the oldest breadcrumbs out and despawn those past the cap,
+/// so a long run keeps a bounded trail. Only runs on frames a breadcrumb was dropped.
+pub fn fade_old_breadcrumbs(
+    mut commands: Commands,
+    added_query: Query<(), Added<Breadcrumb>>,
+    mut breadcrumb_query: Query<(Entity, &Breadcrumb, &mut Sprite)>,
+) {
+    if added_query.is_empty() {
+        return;
+    }
+    let Some(newest) = breadcrumb_query.iter().map(|(_, breadcrumb, _)| breadcrumb.index).max() else {
+        return;
+    };
+
+    for (entity, breadcrumb, mut sprite) in breadcrumb_query.iter_mut() {
+        match breadcrumb_alpha(newest - breadcrumb.index) {
+            Some(alpha) => sprite.color = breadcrumb.color.with_alpha(alpha),
+            None => commands.entity(entity).despawn(),
+        }
+    }
+}
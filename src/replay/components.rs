@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+/// Marker component for the replay label text
+#[derive(Component)]
+pub struct ReplayText;
+
+/// A single recorded snapshot of the car's pose during a run
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GhostSample {
+    /// Real seconds since the race started (not the penalized race timer)
+    pub time: f32,
+    /// Car position in world coordinates
+    pub position: Vec2,
+    /// Car rotation
+    pub rotation: Quat,
+}
+
+/// Resource that records the car's pose at a fixed interval while racing.
+/// Reset at the start of every level, so it always holds the most recent run
+/// (whether or not it set a best time).
+#[derive(Resource, Default)]
+pub struct GhostRecorder {
+    /// Recorded samples, ordered by time
+    pub samples: Vec<GhostSample>,
+    /// Real time elapsed since recording started
+    pub elapsed: f32,
+    /// Time accumulated since the last sample was taken
+    pub since_last_sample: f32,
+}
+
+impl GhostRecorder {
+    /// Returns true if any samples were recorded
+    pub fn has_samples(&self) -> bool {
+        !self.samples.is_empty()
+    }
+
+    /// Returns the duration of the recording in seconds
+    pub fn duration(&self) -> f32 {
+        self.samples.last().map(|sample| sample.time).unwrap_or(0.0)
+    }
+
+    /// Appends a sample of the given pose at the current elapsed time
+    pub fn push_sample(&mut self, transform: &Transform) {
+        self.samples.push(GhostSample {
+            time: self.elapsed,
+            position: transform.translation.truncate(),
+            rotation: transform.rotation,
+        });
+        self.since_last_sample = 0.0;
+    }
+}
+
+/// How the main camera shows the replay
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum ReplayCamera {
+    /// The whole track, as it's shown while racing
+    #[default]
+    Overview,
+    /// Zoomed in and centered on the car
+    Follow,
+}
+
+impl ReplayCamera {
+    /// The other camera mode
+    pub fn toggled(self) -> Self {
+        match self {
+            ReplayCamera::Overview => ReplayCamera::Follow,
+            ReplayCamera::Follow => ReplayCamera::Overview,
+        }
+    }
+}
+
+/// Resource tracking replay playback progress (only exists in the Replay state)
+#[derive(Resource, Default)]
+pub struct ReplayPlayback {
+    /// Seconds of the recording played back so far
+    pub elapsed: f32,
+    pub camera: ReplayCamera,
+}
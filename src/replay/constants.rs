@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+// ============================================================================
+// Recording Constants
+// ============================================================================
+
+/// Interval between recorded ghost samples (seconds)
+pub const GHOST_SAMPLE_INTERVAL: f32 = 1.0 / 30.0;
+
+// ============================================================================
+// Playback Constants
+// ============================================================================
+
+/// How long the car rests on the finish line before the replay ends (seconds)
+pub const REPLAY_END_HOLD: f32 = 1.0;
+/// Key switching the replay between the overview and follow cameras
+pub const REPLAY_CAMERA_TOGGLE_KEY: KeyCode = KeyCode::KeyC;
+/// Projection scale of the follow camera (below 1 zooms in)
+pub const REPLAY_FOLLOW_ZOOM: f32 = 0.5;
@@ -0,0 +1,75 @@
+//! Interpolation helpers for playing back recorded ghost samples.
+
+use bevy::prelude::*;
+
+use crate::replay::components::GhostSample;
+
+/// Returns the car pose (position, rotation) at the given time, interpolated
+/// between the two surrounding samples. Times outside the recording are clamped
+/// to the first/last sample. Returns None if there are no samples.
+///
+/// # Arguments
+/// * `samples` - Recorded samples, ordered by time
+/// * `time` - Playback time in seconds since the race started
+pub fn sample_pose_at(samples: &[GhostSample], time: f32) -> Option<(Vec2, Quat)> {
+    let first = samples.first()?;
+    let last = samples.last()?;
+
+    if time <= first.time {
+        return Some((first.position, first.rotation));
+    }
+    if time >= last.time {
+        return Some((last.position, last.rotation));
+    }
+
+    // Index of the first sample strictly after `time` (always >= 1 here)
+    let next_index = samples.partition_point(|sample| sample.time <= time);
+    let previous = &samples[next_index - 1];
+    let next = &samples[next_index];
+
+    let span = next.time - previous.time;
+    let t = if span > 0.0 { (time - previous.time) / span } else { 0.0 };
+
+    Some((
+        previous.position.lerp(next.position, t),
+        previous.rotation.slerp(next.rotation, t),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn sample(time: f32, x: f32, angle: f32) -> GhostSample {
+        GhostSample {
+            time,
+            position: Vec2::new(x, 0.0),
+            rotation: Quat::from_rotation_z(angle),
+        }
+    }
+
+    #[test]
+    fn test_empty_recording() {
+        assert!(sample_pose_at(&[], 1.0).is_none());
+    }
+
+    #[test]
+    fn test_interpolates_between_samples() {
+        let samples = [sample(0.0, 0.0, 0.0), sample(1.0, 10.0, FRAC_PI_2)];
+
+        let (position, rotation) = sample_pose_at(&samples, 0.5).unwrap();
+
+        assert!((position.x - 5.0).abs() < 1e-4);
+        let (_, _, angle) = rotation.to_euler(EulerRot::XYZ);
+        assert!((angle - FRAC_PI_2 / 2.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_clamps_outside_recording() {
+        let samples = [sample(0.0, 0.0, 0.0), sample(1.0, 10.0, 0.0)];
+
+        assert_eq!(sample_pose_at(&samples, -1.0).unwrap().0, Vec2::ZERO);
+        assert_eq!(sample_pose_at(&samples, 5.0).unwrap().0, Vec2::new(10.0, 0.0));
+    }
+}
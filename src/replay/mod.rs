@@ -0,0 +1,44 @@
+pub mod components;
+pub mod constants;
+pub mod helpers;
+pub mod systems;
+
+use bevy::prelude::*;
+use crate::car::systems::move_car;
+use crate::constants::GameState;
+use crate::hud::systems::check_finish_line_crossing;
+use crate::road::systems::update_segment_visited_status;
+use crate::start_menu::components::GameEntity;
+use crate::utils::{despawn_all, is_two_player};
+use components::GhostRecorder;
+use systems::{
+    advance_replay, cleanup_replay, handle_replay_skip_input, record_ghost_sample, setup_replay,
+    toggle_replay_camera, update_replay_camera,
+};
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GhostRecorder>()
+            .add_systems(
+                Update,
+                record_ghost_sample
+                    .after(move_car)
+                    .after(check_finish_line_crossing)
+                    .run_if(in_state(GameState::Playing).and(not(is_two_player))),
+            )
+            .add_systems(OnEnter(GameState::Replay), setup_replay)
+            .add_systems(OnExit(GameState::Replay), (despawn_all::<GameEntity>, cleanup_replay))
+            .add_systems(
+                Update,
+                (
+                    (advance_replay, update_segment_visited_status).chain(),
+                    (toggle_replay_camera, update_replay_camera).chain().after(advance_replay),
+                    handle_replay_skip_input,
+                )
+                    .run_if(in_state(GameState::Replay)),
+            );
+    }
+}
@@ -0,0 +1,187 @@
+use bevy::prelude::*;
+
+use crate::car::components::{Car, CarClass, PlayerId};
+use crate::car::systems::spawn_car;
+use crate::constants::{CurrentLevel, GameState, MainCamera, SeedOverride};
+use crate::hud::components::{RaceState, RaceStatus};
+use crate::replay::components::{GhostRecorder, ReplayCamera, ReplayPlayback, ReplayText};
+use crate::replay::constants::{
+    GHOST_SAMPLE_INTERVAL, REPLAY_CAMERA_TOGGLE_KEY, REPLAY_END_HOLD, REPLAY_FOLLOW_ZOOM,
+};
+use crate::replay::helpers::sample_pose_at;
+use crate::road::components::VisitOrder;
+use crate::road::helpers::get_rotation;
+use crate::road::systems::spawn_race_course;
+use crate::road::tracks::get_played_track;
+use crate::save::CurrentSave;
+use crate::styles::hud::level_text_style;
+use crate::styles::palette::Palette;
+use crate::styles::theme::{apply_level_theme, GlowSettings};
+use crate::utils::spawn_hud_element;
+
+// ============================================================================
+// Recording
+// ============================================================================
+
+/// Resets the ghost recorder for a fresh run
+pub fn init_ghost_recorder(commands: &mut Commands) {
+    commands.insert_resource(GhostRecorder::default());
+}
+
+/// System to record the car's pose at a fixed interval once the race has started.
+/// Always captures the frame the race finishes so playback ends on the line.
+pub fn record_ghost_sample(
+    time: Res<Time>,
+    race_state: Res<RaceState>,
+    car_query: Single<&Transform, With<Car>>,
+    mut recorder: ResMut<GhostRecorder>,
+) {
+    if race_state.status == RaceStatus::WaitingToStart {
+        return;
+    }
+
+    // First sample is taken the moment the race starts
+    if !recorder.has_samples() {
+        recorder.push_sample(&car_query);
+        return;
+    }
+
+    let delta = time.delta_secs();
+    recorder.elapsed += delta;
+    recorder.since_last_sample += delta;
+
+    let finished = race_state.status == RaceStatus::Finished;
+    if recorder.since_last_sample >= GHOST_SAMPLE_INTERVAL
+        || (finished && recorder.since_last_sample > 0.0)
+    {
+        recorder.push_sample(&car_query);
+    }
+}
+
+// ============================================================================
+// Playback
+// ============================================================================
+
+/// Rebuilds the just-completed level and spawns the car at the start of the recording
+pub fn setup_replay(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    (current_level, seed_override, current_save): (
+        Res<CurrentLevel>,
+        Res<SeedOverride>,
+        Res<CurrentSave>,
+    ),
+    (palette, glow): (Res<Palette>, Res<GlowSettings>),
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    commands.insert_resource(ReplayPlayback::default());
+
+    // Powerups can't be collected during playback, so only the course is rebuilt
+    let Some(track) = get_played_track(current_level.0, &seed_override, 0, 0) else {
+        error!("Could not generate a track for level {} replay", current_level.0);
+        game_state.set(GameState::LevelMenu);
+        return;
+    };
+    let theme = apply_level_theme(&mut commands, current_level.0, &glow);
+    spawn_race_course(&mut commands, &mut meshes, &mut materials, &track, &theme, &palette);
+    // The visit order lets the replayed car light up the segments it drives over
+    // Playback sets the car's transform directly, so its handling doesn't matter
+    let car_stats = CarClass::default().stats();
+    let car_color = current_save.get().map(|s| s.car_color).unwrap_or_default();
+    let car = spawn_car(
+        &mut commands,
+        track.starting_point,
+        get_rotation(track.start_direction),
+        PlayerId::One,
+        car_stats,
+        PlayerId::One.car_color(car_color),
+    );
+    commands.entity(car).insert(VisitOrder::for_track(&track));
+
+    spawn_hud_element(
+        &mut commands,
+        format!("Level {} Replay (C to change camera, ESC to skip)", current_level.0),
+        level_text_style(),
+        ReplayText,
+        Visibility::Inherited,
+    );
+}
+
+/// System to advance playback and move the car along the recorded path.
+/// Returns to the level menu shortly after the recording ends.
+pub fn advance_replay(
+    time: Res<Time>,
+    recorder: Res<GhostRecorder>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut car_query: Single<&mut Transform, With<Car>>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    playback.elapsed += time.delta_secs();
+
+    if let Some((position, rotation)) = sample_pose_at(&recorder.samples, playback.elapsed) {
+        car_query.translation.x = position.x;
+        car_query.translation.y = position.y;
+        car_query.rotation = rotation;
+    }
+
+    if playback.elapsed >= recorder.duration() + REPLAY_END_HOLD {
+        game_state.set(GameState::LevelMenu);
+    }
+}
+
+/// Handles the Escape key to skip the rest of the replay
+pub fn handle_replay_skip_input(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        game_state.set(GameState::LevelMenu);
+    }
+}
+
+/// Switches between the overview and follow cameras
+pub fn toggle_replay_camera(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut playback: ResMut<ReplayPlayback>,
+) {
+    if keyboard_input.just_pressed(REPLAY_CAMERA_TOGGLE_KEY) {
+        playback.camera = playback.camera.toggled();
+    }
+}
+
+/// Points the main camera at the whole track, or zoomed in on the car when following it
+pub fn update_replay_camera(
+    playback: Res<ReplayPlayback>,
+    car_query: Single<&Transform, (With<Car>, Without<MainCamera>)>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<MainCamera>>,
+) {
+    let (center, zoom) = match playback.camera {
+        ReplayCamera::Overview => (Vec2::ZERO, 1.0),
+        ReplayCamera::Follow => (car_query.translation.truncate(), REPLAY_FOLLOW_ZOOM),
+    };
+    for (mut transform, mut projection) in camera_query.iter_mut() {
+        set_camera_view(&mut transform, &mut projection, center, zoom);
+    }
+}
+
+/// Removes the playback resource when leaving the replay, and returns the main camera
+/// to the whole track
+pub fn cleanup_replay(
+    mut commands: Commands,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<MainCamera>>,
+) {
+    commands.remove_resource::<ReplayPlayback>();
+    for (mut transform, mut projection) in camera_query.iter_mut() {
+        set_camera_view(&mut transform, &mut projection, Vec2::ZERO, 1.0);
+    }
+}
+
+/// Centers the camera on a point at a projection scale
+fn set_camera_view(transform: &mut Transform, projection: &mut Projection, center: Vec2, zoom: f32) {
+    transform.translation.x = center.x;
+    transform.translation.y = center.y;
+    if let Projection::Orthographic(orthographic) = projection {
+        orthographic.scale = zoom;
+    }
+}
@@ -1,13 +1,65 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, PartialEq, Debug)]
+use crate::road::track_generator::TrackGeneratorConfig;
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum RoadSegmentType {
     Straight,
     CornerLeft,
     CornerRight,
+    /// A straight spanning several grid cells, spawned as one long segment
+    StraightRun(usize),
+}
+
+impl RoadSegmentType {
+    /// Number of grid cells the segment covers (1 for everything but straight runs)
+    pub fn length_in_cells(self) -> usize {
+        match self {
+            RoadSegmentType::StraightRun(cells) => cells,
+            _ => 1,
+        }
+    }
+}
+
+/// Where the track being played came from, recorded by the gameplay setup for the level
+/// complete screen (see `load_track`)
+/// Resource with the played track's starting point and direction, as laid out (cars may be
+/// spawned nudged away from it, see `get_car_spawns`). Inserted when the course is built.
+#[derive(Resource, Clone, Copy)]
+pub struct TrackStart {
+    /// `Track::starting_point`, the center of generation grid cell (0, 0)
+    pub position: Vec2,
+    pub direction: Direction,
+}
+
+#[derive(Resource, Default)]
+pub enum PlayedTrackInfo {
+    /// One of the fixed tracks (see `BUILTIN_LEVEL_COUNT`)
+    #[default]
+    BuiltIn,
+    /// A user track file in place of a fixed track (see `track_loader`)
+    UserFile,
+    /// Generated with this config; entering its seed on the track preview replays the track
+    Generated(TrackGeneratorConfig),
+}
+
+impl PlayedTrackInfo {
+    /// Describes the track for the level complete screen
+    pub fn label(&self) -> String {
+        match self {
+            PlayedTrackInfo::BuiltIn => "Built-in track".to_string(),
+            PlayedTrackInfo::UserFile => "Custom track file".to_string(),
+            PlayedTrackInfo::Generated(config) => format!(
+                "Seed {} ({}-{} segments, difficulty {:.1})",
+                config.seed, config.min_segments, config.max_segments, config.target_difficulty
+            ),
+        }
+    }
 }
 
 /// A track definition containing the layout and starting position
+#[derive(Clone)]
 pub struct Track {
     /// The sequence of road segments that make up the track
     pub layout: Vec<RoadSegmentType>,
@@ -15,20 +67,25 @@ pub struct Track {
     pub starting_point: Vec2,
     /// Indices of segments where props should be placed
     pub prop_indices: Vec<usize>,
+    /// Direction the track heads from its starting point (the first segment's entry direction)
+    pub start_direction: Direction,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum Direction {
+    #[default]
     Up,
     Down,
     Left,
     Right,
 }
 
-#[derive(Component)]
+#[derive(Component, Clone, Copy)]
 pub struct RoadSegment {
     pub segment_type: RoadSegmentType,
     pub direction: Direction,
+    /// Position of this segment in the track layout (0 = the segment under the start line)
+    pub index: usize,
 }
 
 /// Component for road edge entities (the glowing borders)
@@ -38,10 +95,58 @@ pub struct RoadEdge {
     pub parent_segment: Entity,
 }
 
-/// Marker component indicating a road segment has been visited by the car
+/// Component for the road-colored cutout that hollows a corner's outer edge into a ring.
+/// Links the cutout to its parent road segment so it fades with it under the fog of war.
+#[derive(Component)]
+pub struct RoadEdgeCutout {
+    pub parent_segment: Entity,
+}
+
+/// Marker component indicating a road segment has been visited by any car
 #[derive(Component)]
 pub struct Visited;
 
+/// Component for a road edge fading to the visited glow after its segment is first touched.
+/// Removed once the fade finishes.
+#[derive(Component)]
+pub struct VisitingAnimation {
+    pub timer: Timer,
+    /// Edge color when the segment was visited (unvisited or missed tint)
+    pub start_color: Color,
+}
+
+/// Component on each racing car recording the layout indices of road segments in the order
+/// that car first visited them. Used on finish to check the car drove the whole loop,
+/// as a genuine traversal.
+///
+/// Each `RoadSegment` carries its position in the track layout (`index`), so consecutive
+/// segments of the loop have consecutive indices (wrapping from the last to the first).
+/// `update_segment_visited_status` appends a segment's index the first time a car corner
+/// touches it. With the lap check on, a touch more than `MAX_VISIT_GAP` indices from
+/// every recorded segment is a jump across the track and isn't recorded (see
+/// `continues_traversal`), so a shortcut leaves the segments it skipped still to visit.
+#[derive(Component, Default)]
+pub struct VisitOrder {
+    pub indices: Vec<usize>,
+    /// Total number of segments in the current track
+    pub segment_count: usize,
+}
+
+impl VisitOrder {
+    /// Creates an empty visit order for a fresh run of the given track
+    pub fn for_track(track: &Track) -> Self {
+        Self {
+            indices: Vec::new(),
+            segment_count: track.layout.len(),
+        }
+    }
+
+    /// Whether the car has visited every segment of the track
+    pub fn is_complete(&self) -> bool {
+        self.indices.len() == self.segment_count
+    }
+}
+
 /// Component for the start line entity
 /// The direction indicates which way the car must cross to trigger the start
 #[derive(Component)]
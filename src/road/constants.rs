@@ -5,6 +5,9 @@ pub const ROAD_SEGMENT_LENGTH: f32 = ROAD_WIDTH; // Square segments makes ALOT o
 pub const STARTING_LINE_WIDTH: f32 = ROAD_WIDTH;
 pub const STARTING_LINE_HEIGHT: f32 = 2.0;
 
+/// Levels played on the fixed tracks; every later level has a generated track
+pub const BUILTIN_LEVEL_COUNT: usize = 3;
+
 // -- Road Edge Settings -- //
 /// Width of the glowing road edges
 pub const ROAD_EDGE_WIDTH: f32 = 3.0;
@@ -18,8 +21,42 @@ pub const ROAD_SEGMENT_COLOR: Color = Color::srgb(0.3, 0.3, 0.3);
 pub const VISITED_EDGE_COLOR: Color = Color::srgb(1.0, 1.0, 2.8);
 /// Color for unvisited road edges (no glow)
 pub const UNVISITED_EDGE_COLOR: Color = Color::srgb(0.3, 0.3, 0.3);
+/// Color for unvisited road edges after a too-early finish attempt (points out what's left)
+pub const MISSED_EDGE_COLOR: Color = Color::srgb(2.0, 0.6, 0.1);
+/// Duration of the fade from the current edge color to the visited glow (seconds)
+pub const VISIT_ANIMATION_DURATION: f32 = 0.2;
 pub const START_LINE_COLOR: Color = Color::srgb(0.2, 0.8, 0.2);
 pub const FINISH_LINE_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+/// Dark squares of the checkered finish line (darker than the road, so both colors stand out on it)
+pub const FINISH_LINE_DARK_COLOR: Color = Color::srgb(0.05, 0.05, 0.05);
+/// Checker squares across the finish line (their size scales with the road width)
+pub const FINISH_LINE_CHECKER_COLUMNS: usize = 10;
+/// Rows of checker squares along the direction of travel
+pub const FINISH_LINE_CHECKER_ROWS: usize = 2;
+
+/// Car corners that may hang off the road before the car counts as off it (per difficulty).
+/// Normal keeps the original rule of any corner off the road, which its best times were set
+/// under; Hard can't be stricter than that.
+pub const CASUAL_OFF_ROAD_CORNER_ALLOWANCE: usize = 2;
+pub const OFF_ROAD_CORNER_ALLOWANCE: usize = 0;
+pub const HARD_OFF_ROAD_CORNER_ALLOWANCE: usize = 0;
+
+/// Distance from the car within which unvisited segments are fully shown under the fog of war
+/// (measured to the segment's nearest end)
+pub const FOG_REVEAL_RADIUS: f32 = 250.0;
+/// Distance beyond the reveal radius over which segments fade out completely
+pub const FOG_FADE_DISTANCE: f32 = 100.0;
+
+/// Bronze medal target time per grid cell of track (seconds).
+/// About 2.5x the time it takes to cross a cell at top speed.
+pub const BRONZE_SECONDS_PER_CELL: f32 = 0.5;
+
+/// Maximum layout distance between a newly visited segment and one already visited
+/// for the lap to count as a contiguous traversal (the car can reach two new segments in one frame)
+pub const MAX_VISIT_GAP: usize = 2;
+
+/// Step (world units) a car is nudged sideways at spawn until it sits fully on the first segment
+pub const SPAWN_NUDGE_STEP: f32 = 1.0;
 
 /// Z-index for the starting line (above road segments)
 pub const STARTING_LINE_Z: f32 = 1.5;
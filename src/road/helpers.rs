@@ -1,13 +1,23 @@
 use bevy::prelude::*;
-use crate::road::components::{Direction, RoadSegmentType};
-use crate::road::constants::{ROAD_SEGMENT_LENGTH, ROAD_WIDTH};
+use rand::Rng;
+use crate::car::constants::CAR_HEIGHT;
+use crate::car::helpers::get_car_corners;
+use crate::collision::world_to_local_2d;
+use crate::props::constants::COIN_SEGMENT_INTERVAL;
+use crate::road::components::{Direction, RoadSegment, RoadSegmentType, Track};
+use crate::road::constants::{
+    BRONZE_SECONDS_PER_CELL, FOG_FADE_DISTANCE, FOG_REVEAL_RADIUS, ROAD_SEGMENT_LENGTH, ROAD_WIDTH,
+    SPAWN_NUDGE_STEP,
+};
+use crate::road::track_generator::get_next_grid_position;
+use std::collections::HashSet;
 
 
-/// Check if a point (in local space) is inside a straight road segment.
-/// The straight road is a rectangle centered at (0,0) with dimensions ROAD_WIDTH x ROAD_SEGMENT_LENGTH.
-pub fn is_point_in_straight(local_pos: Vec2) -> bool {
+/// Check if a point (in local space) is inside a straight road segment spanning `cells` grid cells.
+/// The straight road is a rectangle centered at (0,0) with dimensions ROAD_WIDTH x (cells * ROAD_SEGMENT_LENGTH).
+pub fn is_point_in_straight(local_pos: Vec2, cells: usize) -> bool {
     let half_w = ROAD_WIDTH / 2.0;
-    let half_h = ROAD_SEGMENT_LENGTH / 2.0;
+    let half_h = cells as f32 * ROAD_SEGMENT_LENGTH / 2.0;
 
     local_pos.x >= -half_w
         && local_pos.x <= half_w
@@ -49,7 +59,7 @@ pub fn is_point_in_corner_left(local_pos: Vec2) -> bool {
 /// Given an entry direction and segment type, return the exit direction
 pub fn get_exit_direction(entry_direction: Direction, segment_type: RoadSegmentType) -> Direction {
     match segment_type {
-        RoadSegmentType::Straight => entry_direction,
+        RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => entry_direction,
         RoadSegmentType::CornerLeft => match entry_direction {
             Direction::Up => Direction::Left,
             Direction::Left => Direction::Down,
@@ -94,34 +104,150 @@ pub fn get_direction_vector(direction: Direction) -> Vec2 {
     }
 }
 
+/// Get the world-space center of a road segment's driving line.
+/// Straight segments are centered on their transform; corner transforms sit on the
+/// inner pivot, so the center is half a road width out along the sector's middle (+Y).
+pub fn get_segment_center(transform: &Transform, segment_type: RoadSegmentType) -> Vec2 {
+    match segment_type {
+        RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => {
+            transform.translation.truncate()
+        }
+        RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight => transform
+            .transform_point(Vec3::new(0.0, ROAD_WIDTH / 2.0, 0.0))
+            .truncate(),
+    }
+}
+
+/// Transform of the road piece for a segment entered at `entry_point` heading `entry_direction`,
+/// as `spawn_track` places it: straights on their center, corners on their inner pivot
+pub fn get_segment_transform(
+    entry_point: Vec2,
+    entry_direction: Direction,
+    segment_type: RoadSegmentType,
+) -> Transform {
+    let (position, rotation) = match segment_type {
+        RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => {
+            let cells = segment_type.length_in_cells() as f32;
+            let center = entry_point + get_position_offset(entry_direction) * cells / 2.0;
+            (center, get_rotation(entry_direction))
+        }
+        RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight => {
+            let exit_direction = get_exit_direction(entry_direction, segment_type);
+            let pivot = entry_point + get_direction_vector(exit_direction) * (ROAD_WIDTH / 2.0);
+            let rotation_offset = match segment_type {
+                RoadSegmentType::CornerRight => std::f32::consts::FRAC_PI_4,
+                _ => -std::f32::consts::FRAC_PI_4,
+            };
+            (pivot, get_rotation(entry_direction) + rotation_offset)
+        }
+    };
+    Transform::from_translation(position.extend(0.0)).with_rotation(Quat::from_rotation_z(rotation))
+}
+
+/// Positions and rotation of the cars on the starting grid, each one its `lateral_offsets` entry
+/// to the right of the track's starting point, facing the start direction. If part of a car
+/// would be off the first segment (a track starting on a corner, say), the whole grid is nudged
+/// sideways by the least that fits and turned to follow the road there, so the cars keep their
+/// spacing.
+pub fn get_car_spawns(track: &Track, lateral_offsets: &[f32]) -> Vec<(Vec2, f32)> {
+    let start_rotation = get_rotation(track.start_direction);
+    let grid = |center: Vec2, rotation: f32| -> Vec<(Vec2, f32)> {
+        lateral_offsets
+            .iter()
+            .map(|&offset| (center + Vec2::from_angle(rotation) * offset, rotation))
+            .collect()
+    };
+    let Some(&first_segment) = track.layout.first() else {
+        return grid(track.starting_point, start_rotation);
+    };
+    let entry_point = track.starting_point - get_position_offset(track.start_direction) / 2.0;
+    let segment = get_segment_transform(entry_point, track.start_direction, first_segment);
+    let fits = |&(position, rotation): &(Vec2, f32)| {
+        let car = Transform::from_translation(position.extend(0.0))
+            .with_rotation(Quat::from_rotation_z(rotation));
+        get_car_corners(&car)
+            .iter()
+            .all(|&corner| is_point_in_segment(world_to_local_2d(&segment, corner), first_segment))
+    };
+
+    let max_steps = (ROAD_WIDTH / SPAWN_NUDGE_STEP) as usize;
+    for step in 0..=max_steps {
+        let nudge = step as f32 * SPAWN_NUDGE_STEP;
+        for shift in [nudge, -nudge] {
+            let center = track.starting_point + Vec2::from_angle(start_rotation) * shift;
+            let rotation = road_heading(&segment, first_segment, center, start_rotation);
+            let cars = grid(center, rotation);
+            if cars.iter().all(fits) {
+                return cars;
+            }
+        }
+    }
+    grid(track.starting_point, start_rotation)
+}
+
+/// Rotation of a car driving along the road at `position` on a segment placed at `segment`
+/// (see `get_segment_transform`): across the radius from a corner's pivot, turned the way
+/// `rotation` faces, or `rotation` itself on a straight
+fn road_heading(segment: &Transform, segment_type: RoadSegmentType, position: Vec2, rotation: f32) -> f32 {
+    match segment_type {
+        RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => rotation,
+        RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight => {
+            let forward = Vec2::from_angle(rotation).perp();
+            let tangent = (position - segment.translation.truncate()).perp();
+            let tangent = if tangent.dot(forward) < 0.0 { -tangent } else { tangent };
+            Vec2::Y.angle_to(tangent)
+        }
+    }
+}
+
 /// Check if a point (in local space) is inside a road segment of the given type.
 /// Dispatches to the appropriate geometry check based on segment type.
 pub fn is_point_in_segment(local_pos: Vec2, segment_type: RoadSegmentType) -> bool {
     match segment_type {
-        RoadSegmentType::Straight => is_point_in_straight(local_pos),
+        RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => {
+            is_point_in_straight(local_pos, segment_type.length_in_cells())
+        }
         RoadSegmentType::CornerRight => is_point_in_corner_right(local_pos),
         RoadSegmentType::CornerLeft => is_point_in_corner_left(local_pos),
     }
 }
 
+/// Number of the given points (the car's corners) that aren't on any of the road segments
+pub fn corners_off_road(corners: &[Vec3], segments: &[(&Transform, &RoadSegment)]) -> usize {
+    corners
+        .iter()
+        .filter(|corner| {
+            !segments.iter().any(|(transform, segment)| {
+                is_point_in_segment(world_to_local_2d(transform, **corner), segment.segment_type)
+            })
+        })
+        .count()
+}
+
 /// Compute the bounding box of a track's world-space positions.
 /// Returns (min_corner, max_corner) representing the AABB of all segment centers.
 /// The actual visual bounds should add ROAD_WIDTH/2 padding on all sides.
-pub fn compute_track_bounds(starting_point: Vec2, layout: &[RoadSegmentType]) -> (Vec2, Vec2) {
+pub fn compute_track_bounds(
+    starting_point: Vec2,
+    start_direction: Direction,
+    layout: &[RoadSegmentType],
+) -> (Vec2, Vec2) {
     let mut min = starting_point;
     let mut max = starting_point;
 
     let mut current_pos = starting_point;
-    let mut current_dir = Direction::Up;
+    let mut current_dir = start_direction;
 
     for &segment in layout {
-        // Move to next position
+        // Move to next position (once per grid cell the segment covers)
         let offset = get_position_offset(current_dir);
-        current_pos += offset;
+        for _ in 0..segment.length_in_cells() {
+            current_pos += offset;
 
-        // Update bounds
-        min = min.min(current_pos);
-        max = max.max(current_pos);
+            // Update bounds
+            min = min.min(current_pos);
+            max = max.max(current_pos);
+        }
 
         // Update direction for next iteration
         current_dir = get_exit_direction(current_dir, segment);
@@ -129,3 +255,355 @@ pub fn compute_track_bounds(starting_point: Vec2, layout: &[RoadSegmentType]) ->
 
     (min, max)
 }
+
+/// Returns true if the layout forms a single closed loop on the segment grid:
+/// walking it from the start (heading up) returns to the starting cell heading up,
+/// without any segment overlapping another.
+pub fn is_closed_loop(layout: &[RoadSegmentType]) -> bool {
+    let mut visited = HashSet::new();
+    let mut current_cell = IVec2::ZERO;
+    let mut current_dir = Direction::Up;
+
+    for &segment in layout {
+        current_dir = get_exit_direction(current_dir, segment);
+        for _ in 0..segment.length_in_cells() {
+            if !visited.insert(current_cell) {
+                return false;
+            }
+            current_cell = get_next_grid_position(current_cell, current_dir);
+        }
+    }
+
+    !layout.is_empty() && current_cell == IVec2::ZERO && current_dir == Direction::Up
+}
+
+/// Bronze medal target time for a track layout, scaled by the number of grid cells it covers
+pub fn bronze_target_time(layout: &[RoadSegmentType]) -> f32 {
+    let cells: usize = layout.iter().map(|segment| segment.length_in_cells()).sum();
+    cells as f32 * BRONZE_SECONDS_PER_CELL
+}
+
+/// Opacity of an unvisited segment under the fog of war, given the car's distance to its center.
+/// Half the segment's length is taken off so long straight runs are revealed from either end.
+pub fn fog_alpha(distance_to_center: f32, segment_type: RoadSegmentType) -> f32 {
+    let half_length = segment_type.length_in_cells() as f32 * ROAD_SEGMENT_LENGTH / 2.0;
+    let beyond_radius = distance_to_center - half_length - FOG_REVEAL_RADIUS;
+    1.0 - (beyond_radius / FOG_FADE_DISTANCE).clamp(0.0, 1.0)
+}
+
+/// Walk the track layout and return each segment's entry point and entry direction.
+/// Mirrors the walk in `spawn_track`: the first segment is centered on the starting point.
+pub fn get_segment_entries(track: &Track) -> Vec<(Vec2, Direction)> {
+    let mut current_direction = track.start_direction;
+    let mut current_endpoint = track.starting_point - get_position_offset(current_direction) / 2.0;
+    let mut entries = Vec::with_capacity(track.layout.len());
+
+    for &segment_type in &track.layout {
+        entries.push((current_endpoint, current_direction));
+
+        let exit_direction = get_exit_direction(current_direction, segment_type);
+        current_endpoint = match segment_type {
+            RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => {
+                current_endpoint
+                    + get_position_offset(current_direction) * segment_type.length_in_cells() as f32
+            }
+            // Corners pivot around the inner corner and exit half a road width further on
+            RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight => {
+                let pivot = current_endpoint + get_direction_vector(exit_direction) * (ROAD_WIDTH / 2.0);
+                pivot + get_direction_vector(current_direction) * (ROAD_WIDTH / 2.0)
+            }
+        };
+        current_direction = exit_direction;
+    }
+
+    entries
+}
+
+/// World positions of the start line (CAR_HEIGHT ahead of the starting point) and the
+/// finish line (CAR_HEIGHT behind it), along the direction the track starts in
+pub fn get_start_and_finish_positions(track: &Track) -> (Vec2, Vec2) {
+    let offset = get_direction_vector(track.start_direction) * CAR_HEIGHT;
+    (track.starting_point + offset, track.starting_point - offset)
+}
+
+/// Get the world position of a prop on a segment, placed in the left or right lane
+/// (a quarter road width off the driving line). Corner props sit halfway around the arc.
+pub fn get_prop_position(
+    entry_point: Vec2,
+    entry_direction: Direction,
+    segment_type: RoadSegmentType,
+    left_lane: bool,
+) -> Vec2 {
+    let lane_offset = if left_lane { ROAD_WIDTH / 4.0 } else { -ROAD_WIDTH / 4.0 };
+
+    match segment_type {
+        RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => {
+            let length = segment_type.length_in_cells() as f32;
+            let center = entry_point + get_position_offset(entry_direction) * length / 2.0;
+            // perp() gives vector to the Left. So + is Left, - is Right.
+            center + get_direction_vector(entry_direction).perp() * lane_offset
+        }
+        RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight => {
+            let exit_direction = get_exit_direction(entry_direction, segment_type);
+            let pivot = entry_point + get_direction_vector(exit_direction) * (ROAD_WIDTH / 2.0);
+
+            // Rotate the pivot->entry direction 45 degrees towards the exit
+            let angle = match segment_type {
+                RoadSegmentType::CornerRight => -std::f32::consts::FRAC_PI_4, // Clockwise
+                _ => std::f32::consts::FRAC_PI_4,                             // Counter-clockwise
+            };
+            let pivot_dir = (entry_point - pivot).normalize();
+            let midpoint_dir = Vec2::from_angle(angle).rotate(pivot_dir);
+
+            // The road's driving line is at radius ROAD_WIDTH/2 from the inner pivot
+            pivot + midpoint_dir * (ROAD_WIDTH / 2.0 + lane_offset)
+        }
+    }
+}
+
+/// Get the world positions of all props on a track, in `prop_indices` order.
+/// The lane for each prop is picked at random. Indices outside the layout are skipped.
+pub fn get_prop_positions<R: Rng + ?Sized>(track: &Track, rng: &mut R) -> Vec<Vec2> {
+    let entries = get_segment_entries(track);
+
+    track
+        .prop_indices
+        .iter()
+        .filter_map(|&index| {
+            let &(entry_point, entry_direction) = entries.get(index)?;
+            let left_lane = rng.random_bool(0.5);
+            Some(get_prop_position(entry_point, entry_direction, track.layout[index], left_lane))
+        })
+        .collect()
+}
+
+/// Get the world positions of a track's coins: one on every `COIN_SEGMENT_INTERVAL`th segment
+/// after the start, skipping segments with a powerup, alternating between the lanes
+pub fn get_coin_positions(track: &Track) -> Vec<Vec2> {
+    let entries = get_segment_entries(track);
+
+    (1..track.layout.len())
+        .step_by(COIN_SEGMENT_INTERVAL)
+        .filter(|index| !track.prop_indices.contains(index))
+        .enumerate()
+        .filter_map(|(coin_number, index)| {
+            let &(entry_point, entry_direction) = entries.get(index)?;
+            Some(get_prop_position(entry_point, entry_direction, track.layout[index], coin_number % 2 == 0))
+        })
+        .collect()
+}
+
+/// Whether a segment a car has just touched continues its traversal of the loop.
+/// The first segment anchors the traversal; every later one must lie within `max_gap`
+/// layout positions (wrapping around) of a segment already visited. One that doesn't
+/// is a jump across the track: a shortcut, or a corner clipping a nearby part of the loop.
+///
+/// # Arguments
+/// * `visited` - Layout indices of the segments the car has visited so far
+/// * `index` - Layout index of the touched segment
+/// * `segment_count` - Total number of segments in the track
+/// * `max_gap` - Largest allowed layout distance to an already visited segment
+pub fn continues_traversal(visited: &[usize], index: usize, segment_count: usize, max_gap: usize) -> bool {
+    visited.is_empty()
+        || visited.iter().any(|&other| {
+            let distance = index.abs_diff(other);
+            distance.min(segment_count - distance) <= max_gap
+        })
+}
+
+/// Check that segments were visited as one contiguous traversal of the loop.
+/// Every segment must be visited exactly once, and each newly visited segment must lie
+/// within `max_gap` layout positions (wrapping around) of a segment visited before it.
+///
+/// # Arguments
+/// * `order` - Layout indices of segments in the order they were first visited
+/// * `segment_count` - Total number of segments in the track
+/// * `max_gap` - Largest allowed layout distance to an already visited segment
+pub fn is_contiguous_traversal(order: &[usize], segment_count: usize, max_gap: usize) -> bool {
+    if segment_count == 0 || order.len() != segment_count {
+        return false;
+    }
+
+    let mut visited = vec![false; segment_count];
+    for (n, &index) in order.iter().enumerate() {
+        if index >= segment_count || visited[index] {
+            return false;
+        }
+
+        // The first visited segment anchors the traversal
+        if n > 0 {
+            let near_visited = (1..=max_gap.min(segment_count - 1)).any(|offset| {
+                visited[(index + offset) % segment_count]
+                    || visited[(index + segment_count - offset) % segment_count]
+            });
+            if !near_visited {
+                return false;
+            }
+        }
+        visited[index] = true;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::props::constants::NOS_SIZE;
+    use crate::road::tracks::get_builtin_track;
+    use crate::settings::components::Difficulty;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_one_prop_position_per_prop_index() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for level in 1..=3 {
+            let track = get_builtin_track(level);
+            assert!(track.prop_indices.iter().all(|&index| index < track.layout.len()));
+            assert_eq!(get_prop_positions(&track, &mut rng).len(), track.prop_indices.len());
+        }
+    }
+
+    #[test]
+    fn test_coins_stay_clear_of_powerups() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for level in 1..=3 {
+            let track = get_builtin_track(level);
+            let coins = get_coin_positions(&track);
+            assert!(!coins.is_empty());
+            for powerup in get_prop_positions(&track, &mut rng) {
+                assert!(coins.iter().all(|coin| coin.distance(powerup) > NOS_SIZE));
+            }
+        }
+    }
+
+    #[test]
+    fn test_builtin_tracks_are_closed_loops() {
+        for level in 1..=3 {
+            assert!(is_closed_loop(&get_builtin_track(level).layout));
+        }
+    }
+
+    #[test]
+    fn test_open_or_overlapping_layouts_are_rejected() {
+        use RoadSegmentType::*;
+        assert!(is_closed_loop(&[CornerRight, CornerRight, CornerRight, CornerRight]));
+        assert!(!is_closed_loop(&[Straight, CornerRight, CornerRight]));
+        // Two full right-hand squares: returns to the start but overlaps itself
+        assert!(!is_closed_loop(&[CornerRight; 8]));
+        assert!(!is_closed_loop(&[]));
+    }
+
+    #[test]
+    fn test_straight_prop_is_in_lane() {
+        // First segment is straight, heading up, centered on the starting point
+        let entry = Vec2::new(0.0, -ROAD_SEGMENT_LENGTH / 2.0);
+        let position = get_prop_position(entry, Direction::Up, RoadSegmentType::Straight, true);
+        assert!(position.abs_diff_eq(Vec2::new(-ROAD_WIDTH / 4.0, 0.0), 1e-4));
+    }
+
+    #[test]
+    fn test_straight_run_matches_single_straights() {
+        use RoadSegmentType::*;
+        let singles = [Straight, Straight, CornerRight, Straight, Straight, Straight, CornerRight,
+            Straight, Straight, CornerRight, Straight, Straight, Straight, CornerRight];
+        let runs = [Straight, Straight, CornerRight, StraightRun(3), CornerRight,
+            StraightRun(2), CornerRight, StraightRun(3), CornerRight];
+        assert!(is_closed_loop(&singles));
+        assert!(is_closed_loop(&runs));
+        assert_eq!(
+            compute_track_bounds(Vec2::ZERO, Direction::Up, &singles),
+            compute_track_bounds(Vec2::ZERO, Direction::Up, &runs)
+        );
+    }
+
+    #[test]
+    fn test_point_in_straight_run() {
+        let point = Vec2::new(0.0, ROAD_SEGMENT_LENGTH);
+        assert!(!is_point_in_segment(point, RoadSegmentType::Straight));
+        assert!(is_point_in_segment(point, RoadSegmentType::StraightRun(3)));
+    }
+
+    #[test]
+    fn test_corner_allowance_forgives_clipped_corners() {
+        let transform = Transform::default();
+        let segment = RoadSegment {
+            segment_type: RoadSegmentType::Straight,
+            direction: Direction::Up,
+            index: 0,
+        };
+        let segments = [(&transform, &segment)];
+        let (inside, outside) = (ROAD_WIDTH / 4.0, ROAD_WIDTH);
+        let car = |off: usize| -> Vec<Vec3> {
+            (0..4)
+                .map(|i| Vec3::new(if i < off { outside } else { inside }, inside, 0.0))
+                .collect()
+        };
+        let on_road = |off: usize, difficulty: Difficulty| {
+            corners_off_road(&car(off), &segments) <= difficulty.off_road_corner_allowance()
+        };
+
+        assert_eq!(corners_off_road(&car(3), &segments), 3);
+        // One clipped corner puts the car off the road, as it always has, except on Casual
+        assert!(!on_road(1, Difficulty::Normal) && !on_road(1, Difficulty::Hard));
+        // Half the car over the edge is still forgiven on Casual
+        assert!(on_road(2, Difficulty::Casual) && !on_road(2, Difficulty::Normal));
+        assert!(!on_road(3, Difficulty::Casual));
+        assert!(on_road(0, Difficulty::Hard));
+    }
+
+    #[test]
+    fn test_fog_alpha_fades_beyond_reveal_radius() {
+        let near = ROAD_SEGMENT_LENGTH / 2.0 + FOG_REVEAL_RADIUS;
+        assert_eq!(fog_alpha(near, RoadSegmentType::Straight), 1.0);
+        assert_eq!(fog_alpha(near + FOG_FADE_DISTANCE, RoadSegmentType::Straight), 0.0);
+        // A long run is still revealed while its center is out of range
+        assert!(fog_alpha(near + FOG_FADE_DISTANCE, RoadSegmentType::StraightRun(4)) > 0.0);
+    }
+
+    #[test]
+    fn test_corner_prop_is_on_arc() {
+        let entry = Vec2::ZERO;
+        let position = get_prop_position(entry, Direction::Up, RoadSegmentType::CornerRight, false);
+        // Right turn heading up pivots around the inner corner to the right
+        let pivot = entry + Vec2::X * (ROAD_WIDTH / 2.0);
+        let radius = position.distance(pivot);
+        assert!((radius - ROAD_WIDTH / 4.0).abs() < 1e-4);
+        assert!(position.y > entry.y);
+    }
+
+    #[test]
+    fn test_in_order_traversal_is_contiguous() {
+        assert!(is_contiguous_traversal(&[0, 1, 2, 3, 4, 5], 6, 2));
+    }
+
+    #[test]
+    fn test_traversal_wraps_around_start() {
+        // Car starts on segment 0 and touches the last segment behind it
+        assert!(is_contiguous_traversal(&[0, 5, 1, 2, 4, 3], 6, 2));
+    }
+
+    #[test]
+    fn test_skipped_section_is_rejected() {
+        // Jumps from segment 2 straight to segment 6 across the track
+        assert!(!is_contiguous_traversal(&[1, 2, 6, 7, 3, 4, 5, 0], 8, 1));
+    }
+
+    #[test]
+    fn test_shortcut_touch_does_not_continue_traversal() {
+        use crate::road::constants::MAX_VISIT_GAP;
+
+        // Visited 0-2 of a 12 segment loop, then clipped segment 8 across the track
+        assert!(!continues_traversal(&[0, 1, 2], 8, 12, MAX_VISIT_GAP));
+        assert!(continues_traversal(&[0, 1, 2], 3, 12, MAX_VISIT_GAP));
+        // Backing up over the start wraps around the loop
+        assert!(continues_traversal(&[0, 1, 2], 11, 12, MAX_VISIT_GAP));
+        assert!(continues_traversal(&[], 8, 12, MAX_VISIT_GAP));
+    }
+
+    #[test]
+    fn test_incomplete_traversal_is_rejected() {
+        assert!(!is_contiguous_traversal(&[0, 1, 2], 6, 2));
+    }
+}
@@ -3,6 +3,7 @@ pub mod constants;
 pub mod helpers;
 pub mod systems;
 pub mod track_generator;
+pub mod track_loader;
 pub mod tracks;
 
 use bevy::prelude::*;
@@ -1,59 +1,96 @@
 use bevy::math::primitives::CircularSector;
 use bevy::prelude::*;
-use rand::prelude::*;
+use bevy::sprite_render::AlphaMode2d;
 
-use crate::car::components::Car;
-use crate::car::constants::CAR_HEIGHT;
-use crate::car::helpers::get_car_corners;
+use crate::car::components::{Car, SubstepPath};
+use crate::car::helpers::{get_car_corners, substep_positions};
+use crate::checkpoint::components::StartReset;
 use crate::collision::world_to_local_2d;
+use crate::constants::GameRng;
+use crate::hud::components::{FinishBlocked, RaceState};
+use crate::settings::components::GameSettings;
 use crate::start_menu::components::GameEntity;
+use crate::styles::palette::Palette;
+use crate::styles::theme::{CurrentTheme, Theme};
 use crate::road::components::{
-    Direction, FinishLine, RoadEdge, RoadSegment, RoadSegmentType, StartLine, Track, Visited,
+    Direction, FinishLine, RoadEdge, RoadEdgeCutout, RoadSegment, RoadSegmentType, StartLine, Track,
+    VisitOrder, Visited, VisitingAnimation,
 };
-use crate::props::systems::spawn_nos_powerup;
+use crate::props::systems::{prop_kind_for, spawn_coin, spawn_prop};
 use crate::road::constants::*;
 use crate::road::helpers::{
-    get_direction_vector, get_exit_direction, get_position_offset, get_rotation,
-    is_point_in_segment,
+    continues_traversal, corners_off_road, fog_alpha, get_coin_positions, get_start_and_finish_positions, get_direction_vector, get_exit_direction, get_position_offset, get_prop_positions,
+    get_rotation, get_segment_center, is_point_in_segment,
 };
+use std::collections::HashMap;
 
-/// Helper to spawn a line marker (start or finish line)
-fn spawn_line_marker<T: Component>(
-    commands: &mut Commands,
-    position: Vec3,
-    color: Color,
-    marker: T,
-) {
+/// Spawns the start line at the given position, across the road, crossed heading in `direction`
+pub fn spawn_start_line(commands: &mut Commands, position: Vec2, direction: Direction, color: Color) {
     let sprite = Sprite {
         color,
         custom_size: Some(Vec2::new(STARTING_LINE_WIDTH, STARTING_LINE_HEIGHT)),
         ..default()
     };
-    let transform = Transform::from_xyz(position.x, position.y, position.z);
+    let transform = Transform::from_xyz(position.x, position.y, STARTING_LINE_Z)
+        .with_rotation(Quat::from_rotation_z(get_rotation(direction)));
 
-    commands.spawn((sprite, transform, marker, GameEntity));
+    commands.spawn((sprite, transform, StartLine { direction }, GameEntity));
 }
 
-/// Spawns the start line at the given position
-pub fn spawn_start_line(commands: &mut Commands, position: Vec2, direction: Direction) {
-    // Place the starting line CAR_HEIGHT ahead of the starting point
-    // This mirrors the finish line which is CAR_HEIGHT behind the starting point
-    spawn_line_marker(
-        commands,
-        Vec3::new(position.x, position.y + CAR_HEIGHT, STARTING_LINE_Z),
-        START_LINE_COLOR,
-        StartLine { direction },
-    );
+/// Spawns the finish line at the given position as a checkered strip across the road.
+/// `FinishLine` is on the parent, centered on the line, so the crossing check works off
+/// its single transform; the squares are its children.
+pub fn spawn_finish_line(commands: &mut Commands, position: Vec2, direction: Direction) {
+    let square_size = STARTING_LINE_WIDTH / FINISH_LINE_CHECKER_COLUMNS as f32;
+    let rows = FINISH_LINE_CHECKER_ROWS;
+
+    commands
+        .spawn((
+            Transform::from_xyz(position.x, position.y, STARTING_LINE_Z)
+                .with_rotation(Quat::from_rotation_z(get_rotation(direction))),
+            Visibility::default(),
+            FinishLine { direction },
+            GameEntity,
+        ))
+        .with_children(|parent| {
+            for row in 0..rows {
+                for column in 0..FINISH_LINE_CHECKER_COLUMNS {
+                    let color = if (row + column) % 2 == 0 {
+                        FINISH_LINE_COLOR
+                    } else {
+                        FINISH_LINE_DARK_COLOR
+                    };
+                    let x = (column as f32 + 0.5) * square_size - STARTING_LINE_WIDTH / 2.0;
+                    let y = (row as f32 + 0.5 - rows as f32 / 2.0) * square_size;
+                    parent.spawn((
+                        Sprite {
+                            color,
+                            custom_size: Some(Vec2::splat(square_size)),
+                            ..default()
+                        },
+                        Transform::from_xyz(x, y, 0.0),
+                    ));
+                }
+            }
+        });
 }
 
-/// Spawns the finish line at the given position
-pub fn spawn_finish_line(commands: &mut Commands, position: Vec2, direction: Direction) {
-    spawn_line_marker(
-        commands,
-        Vec3::new(position.x, position.y, STARTING_LINE_Z),
-        FINISH_LINE_COLOR,
-        FinishLine { direction },
-    );
+/// Spawns the full race course: road segments, start line and finish line
+pub fn spawn_race_course(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<ColorMaterial>>,
+    track: &Track,
+    theme: &Theme,
+    palette: &Palette,
+) {
+    spawn_track(commands, meshes, materials, track, theme);
+
+    // The car crosses both lines heading the way the track starts: the start line just
+    // ahead of it, and the finish line just behind it after completing the lap
+    let (start_position, finish_position) = get_start_and_finish_positions(track);
+    spawn_start_line(commands, start_position, track.start_direction, palette.start_line);
+    spawn_finish_line(commands, finish_position, track.start_direction);
 }
 
 pub fn spawn_track(
@@ -61,27 +98,23 @@ pub fn spawn_track(
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
     track: &Track,
+    theme: &Theme,
 ) {
     //validate_track_layout(track.layout);
 
-    let mut current_endpoint = Vec2::new(
-        track.starting_point.x,
-        track.starting_point.y - ROAD_SEGMENT_LENGTH / 2.0,
-    );
-    let mut current_direction = Direction::Up;
+    let mut current_direction = track.start_direction;
+    let mut current_endpoint = track.starting_point - get_position_offset(current_direction) / 2.0;
 
     for (i, &segment_type) in track.layout.iter().enumerate() {
-        let spawn_prop_here = track.prop_indices.contains(&i);
+        let segment = RoadSegment {
+            segment_type,
+            direction: current_direction,
+            index: i,
+        };
 
         match segment_type {
-            RoadSegmentType::Straight => {
-                current_endpoint = spawn_straight_road(
-                    commands,
-                    current_endpoint,
-                    current_direction,
-                    segment_type,
-                    spawn_prop_here,
-                );
+            RoadSegmentType::Straight | RoadSegmentType::StraightRun(_) => {
+                current_endpoint = spawn_straight_road(commands, current_endpoint, segment, theme);
             }
             RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight => {
                 let (new_endpoint, new_direction) = spawn_corner_road(
@@ -89,9 +122,8 @@ pub fn spawn_track(
                     meshes,
                     materials,
                     current_endpoint,
-                    current_direction,
-                    segment_type,
-                    spawn_prop_here,
+                    segment,
+                    theme,
                 );
                 current_endpoint = new_endpoint;
                 current_direction = new_direction;
@@ -100,30 +132,40 @@ pub fn spawn_track(
     }
 }
 
+/// Spawns the track's powerups at the world positions of its prop indices
+pub fn spawn_track_props(commands: &mut Commands, track: &Track, rng: &mut GameRng) {
+    for (prop_number, position) in get_prop_positions(track, &mut rng.0).into_iter().enumerate() {
+        spawn_prop(commands, prop_kind_for(prop_number), position);
+    }
+}
+
+/// Spawns the track's coins (see `get_coin_positions`) and returns how many there are
+pub fn spawn_track_coins(commands: &mut Commands, track: &Track) -> u32 {
+    let positions = get_coin_positions(track);
+    for &position in &positions {
+        spawn_coin(commands, position);
+    }
+    positions.len() as u32
+}
+
 fn spawn_straight_road(
     commands: &mut Commands,
     current_endpoint: Vec2,
-    current_direction: Direction,
-    segment_type: RoadSegmentType,
-    spawn_prop: bool,
+    segment: RoadSegment,
+    theme: &Theme,
 ) -> Vec2 {
+    let current_direction = segment.direction;
+    // Straight runs are spawned as one long segment covering all their cells
+    let cells = segment.segment_type.length_in_cells();
+    let length = cells as f32 * ROAD_SEGMENT_LENGTH;
     // Calculate the offset for the straight segment
-    let offset = get_position_offset(current_direction);
+    let offset = get_position_offset(current_direction) * cells as f32;
     // The center of the segment is halfway along the offset from the current endpoint
     let center = current_endpoint + offset / 2.0;
 
-    if spawn_prop {
-        let mut rng = rand::rng();
-        // Randomly place on left or right side (offset by 1/4 road width)
-        let side_multiplier = if rng.random_bool(0.5) { 1.0 } else { -1.0 };
-        // perp() gives vector to the Left. So + is Left, - is Right.
-        let offset_vec = get_direction_vector(current_direction).perp() * side_multiplier * (ROAD_WIDTH / 4.0);
-        spawn_nos_powerup(commands, center + offset_vec);
-    }
-
     let road_sprite = Sprite {
-        color: ROAD_SEGMENT_COLOR,
-        custom_size: Some(Vec2::new(ROAD_WIDTH, ROAD_SEGMENT_LENGTH)),
+        color: theme.road,
+        custom_size: Some(Vec2::new(ROAD_WIDTH, length)),
         ..default()
     };
 
@@ -131,30 +173,27 @@ fn spawn_straight_road(
     let road_transform = Transform::from_xyz(center.x, center.y, STRAIGHT_ROAD_Z)
         .with_rotation(Quat::from_rotation_z(rotation));
 
-    let road_component = RoadSegment {
-        segment_type,
-        direction: current_direction,
-    };
-
-    let segment_entity = commands.spawn((road_sprite, road_transform, road_component, GameEntity)).id();
+    let segment_entity = commands.spawn((road_sprite, road_transform, segment, GameEntity)).id();
 
     // Spawn glowing edges on both sides of the road
-    spawn_straight_road_edges(commands, center, rotation, segment_entity);
+    spawn_straight_road_edges(commands, center, rotation, length, theme.unvisited_edge, segment_entity);
 
     // Return the new endpoint (end of this segment)
     current_endpoint + offset
 }
 
-/// Helper to spawn a single road edge
+/// Helper to spawn a single road edge of the given length
 fn spawn_edge(
     commands: &mut Commands,
     position: Vec2,
     rotation_quat: Quat,
+    length: f32,
+    color: Color,
     parent_segment: Entity,
 ) {
     let edge_sprite = Sprite {
-        color: UNVISITED_EDGE_COLOR,
-        custom_size: Some(Vec2::new(ROAD_EDGE_WIDTH, ROAD_SEGMENT_LENGTH)),
+        color,
+        custom_size: Some(Vec2::new(ROAD_EDGE_WIDTH, length)),
         ..default()
     };
     commands.spawn((
@@ -170,6 +209,8 @@ fn spawn_straight_road_edges(
     commands: &mut Commands,
     center: Vec2,
     rotation: f32,
+    length: f32,
+    color: Color,
     parent_segment: Entity,
 ) {
     let rotation_quat = Quat::from_rotation_z(rotation);
@@ -180,10 +221,10 @@ fn spawn_straight_road_edges(
     let edge_offset = perpendicular * (ROAD_WIDTH / 2.0 + ROAD_EDGE_WIDTH / 2.0);
 
     // Left edge
-    spawn_edge(commands, center - edge_offset, rotation_quat, parent_segment);
+    spawn_edge(commands, center - edge_offset, rotation_quat, length, color, parent_segment);
 
     // Right edge
-    spawn_edge(commands, center + edge_offset, rotation_quat, parent_segment);
+    spawn_edge(commands, center + edge_offset, rotation_quat, length, color, parent_segment);
 }
 
 fn spawn_corner_road(
@@ -191,10 +232,11 @@ fn spawn_corner_road(
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
     current_endpoint: Vec2,
-    current_direction: Direction,
-    segment_type: RoadSegmentType,
-    spawn_prop: bool,
+    segment: RoadSegment,
+    theme: &Theme,
 ) -> (Vec2, Direction) {
+    let current_direction = segment.direction;
+    let segment_type = segment.segment_type;
     let exit_direction = get_exit_direction(current_direction, segment_type);
     let exit_vec = get_direction_vector(exit_direction);
     let entry_vec = get_direction_vector(current_direction);
@@ -204,38 +246,6 @@ fn spawn_corner_road(
     // by half the road width to find the inner corner pivot.
     let pivot = current_endpoint + exit_vec * (ROAD_WIDTH / 2.0);
 
-    if spawn_prop {
-        let mut rng = rand::rng();
-        // Randomly place on inner or outer lane
-        // Inner lane is closer to pivot (radius - offset), Outer is further (radius + offset)
-        // Since pivot is at the inner corner, the road center is at radius ROAD_WIDTH/2.
-        // So we want radius = ROAD_WIDTH/2 +/- ROAD_WIDTH/4.
-        let radius_offset = if rng.random_bool(0.5) { ROAD_WIDTH / 4.0 } else { -ROAD_WIDTH / 4.0 };
-
-        // Place prop at the midpoint of the turn (45 degrees)
-        // Vector from pivot to entry point (length is ROAD_WIDTH/2)
-        let pivot_to_entry = current_endpoint - pivot;
-
-        // Normalize to get direction from pivot
-        let pivot_dir = pivot_to_entry.normalize();
-
-        // Rotate 45 degrees towards exit
-        let angle = match segment_type {
-            RoadSegmentType::CornerRight => -std::f32::consts::FRAC_PI_4, // Clockwise
-            RoadSegmentType::CornerLeft => std::f32::consts::FRAC_PI_4,   // Counter-clockwise
-            _ => 0.0,
-        };
-
-        let rotation = Quat::from_rotation_z(angle);
-        let rotated_dir = rotation.mul_vec3(pivot_dir.extend(0.0)).xy();
-
-        // Calculate position at the correct radius
-        let target_radius = (ROAD_WIDTH / 2.0) + radius_offset;
-        let midpoint = pivot + rotated_dir * target_radius;
-
-        spawn_nos_powerup(commands, midpoint);
-    }
-
     // Create a 90-degree circular sector
     let sector = CircularSector::from_degrees(ROAD_WIDTH, 90.0);
 
@@ -252,18 +262,15 @@ fn spawn_corner_road(
 
     let segment_entity = commands.spawn((
         Mesh2d(meshes.add(sector)),
-        MeshMaterial2d(materials.add(ColorMaterial::from(ROAD_SEGMENT_COLOR))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(theme.road))),
         Transform::from_xyz(pivot.x, pivot.y, CORNER_ROAD_Z)
             .with_rotation(Quat::from_rotation_z(rotation_angle)),
-        RoadSegment {
-            segment_type,
-            direction: current_direction,
-        },
+        segment,
         GameEntity,
     )).id();
 
     // Spawn glowing arc edges for the corner
-    spawn_corner_road_edges(commands, meshes, materials, pivot, rotation_angle, segment_type, segment_entity);
+    spawn_corner_road_edges(commands, meshes, materials, pivot, rotation_angle, theme, segment_entity);
 
     // The new endpoint is calculated from the pivot.
     // We move from the pivot in the direction of the entry vector by half the road width.
@@ -279,7 +286,7 @@ fn spawn_corner_road_edges(
     materials: &mut ResMut<Assets<ColorMaterial>>,
     pivot: Vec2,
     rotation_angle: f32,
-    _segment_type: RoadSegmentType,
+    theme: &Theme,
     parent_segment: Entity,
 ) {
     // Inner arc edge - placed OUTSIDE the inner edge of the road
@@ -304,7 +311,7 @@ fn spawn_corner_road_edges(
     // Spawn outer edge (full outer sector)
     commands.spawn((
         Mesh2d(meshes.add(outer_sector)),
-        MeshMaterial2d(materials.add(ColorMaterial::from(UNVISITED_EDGE_COLOR))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(theme.unvisited_edge))),
         Transform::from_xyz(pivot.x, pivot.y, ROAD_EDGE_Z)
             .with_rotation(Quat::from_rotation_z(rotation_angle)),
         RoadEdge { parent_segment },
@@ -314,100 +321,508 @@ fn spawn_corner_road_edges(
     // Spawn cutout to create ring effect (same color as background/transparent)
     commands.spawn((
         Mesh2d(meshes.add(cutout_sector)),
-        MeshMaterial2d(materials.add(ColorMaterial::from(ROAD_SEGMENT_COLOR))),
+        MeshMaterial2d(materials.add(ColorMaterial::from(theme.road))),
         Transform::from_xyz(pivot.x, pivot.y, ROAD_EDGE_Z + 0.01)
             .with_rotation(Quat::from_rotation_z(rotation_angle)),
+        RoadEdgeCutout { parent_segment },
         GameEntity,
     ));
 }
 
+/// Returns true while every car is on the road (used for the timer multiplier).
+/// A car counts as on it with no more corners off the road than the difficulty allows
+/// (see `Difficulty::off_road_corner_allowance`).
 pub fn check_car_on_road(
-    car_query: Query<&Transform, With<Car>>,
+    race_state: Res<RaceState>,
+    car_query: Query<(&Transform, Option<&SubstepPath>), With<Car>>,
     road_query: Query<(&Transform, &RoadSegment)>,
 ) -> bool {
-    let car_transform = car_query.single().unwrap();
-    let car_corners = get_car_corners(car_transform);
-
-    // For each corner of the car, check if it's on ANY road segment
-    for corner in car_corners.iter() {
-        let mut corner_on_road = false;
+    let segments: Vec<_> = road_query.iter().collect();
+    let allowance = race_state.difficulty.off_road_corner_allowance();
 
-        for (road_transform, road_segment) in road_query.iter() {
-            let local_corner = world_to_local_2d(road_transform, *corner);
+    for (car_transform, path) in &car_query {
+        // Every physics substep is checked, so a fast car can't cut across a corner unnoticed
+        for position in substep_positions(path, car_transform.translation.truncate()) {
+            let substep = car_transform.with_translation(position.extend(car_transform.translation.z));
 
-            if is_point_in_segment(local_corner, road_segment.segment_type) {
-                corner_on_road = true;
-                break;
+            // More corners off the road than the difficulty forgives puts the car off the road
+            if corners_off_road(&get_car_corners(&substep), &segments) > allowance {
+                return false;
             }
         }
+    }
+
+    // Enough corners are on the road
+    return true;
+}
+
+/// Edges of road segments, with either a sprite (straight) or a mesh material (corner)
+type RoadEdgeQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static RoadEdge,
+        Option<&'static mut Sprite>,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+    ),
+>;
+
+/// Returns the current color of a road edge.
+/// Straight segment edges use Sprite, corner segment edges use MeshMaterial2d<ColorMaterial>.
+fn edge_color(
+    sprite: Option<&Sprite>,
+    material: Option<&MeshMaterial2d<ColorMaterial>>,
+    materials: &Assets<ColorMaterial>,
+) -> Option<Color> {
+    match (sprite, material) {
+        (Some(sprite), _) => Some(sprite.color),
+        (None, Some(handle)) => materials.get(&handle.0).map(|material| material.color),
+        (None, None) => None,
+    }
+}
 
-        // If any corner is off the road, the car is off the road
-        if !corner_on_road {
-            return false;
+/// Sets the color of a single road edge (see `edge_color` for the two edge types)
+fn set_edge_color(
+    sprite: Option<Mut<Sprite>>,
+    material: Option<&MeshMaterial2d<ColorMaterial>>,
+    materials: &mut Assets<ColorMaterial>,
+    color: Color,
+) {
+    if let Some(mut sprite) = sprite {
+        sprite.color = color;
+    } else if let Some(material) = material.and_then(|handle| materials.get_mut(&handle.0)) {
+        material.color = color;
+    }
+}
+
+/// Sets the color of all edges belonging to the given road segment.
+fn set_segment_edge_color(
+    segment: Entity,
+    color: Color,
+    edge_query: &mut RoadEdgeQuery,
+    materials: &mut Assets<ColorMaterial>,
+) {
+    for (_, road_edge, sprite_opt, material_opt) in edge_query.iter_mut() {
+        if road_edge.parent_segment == segment {
+            set_edge_color(sprite_opt, material_opt, materials, color);
         }
     }
+}
 
-    // All corners are on the road
-    return true;
+/// Starts the fade to the visited glow on all edges of the given road segment
+fn start_visit_animation(
+    commands: &mut Commands,
+    segment: Entity,
+    edge_query: &RoadEdgeQuery,
+    materials: &Assets<ColorMaterial>,
+) {
+    for (edge, road_edge, sprite_opt, material_opt) in edge_query.iter() {
+        if road_edge.parent_segment != segment {
+            continue;
+        }
+        if let Some(start_color) = edge_color(sprite_opt, material_opt, materials) {
+            commands.entity(edge).insert(VisitingAnimation {
+                timer: Timer::from_seconds(VISIT_ANIMATION_DURATION, TimerMode::Once),
+                start_color,
+            });
+        }
+    }
 }
 
-/// System to mark road segments as visited when the car touches them and change their color.
-/// Uses Without<Visited> filter to only query unvisited segments.
-/// A segment is marked visited as soon as any part of the car (any corner) touches it.
+/// System to record the road segments each car touches in its visit order, and to mark
+/// segments visited (starting their glow animation) the first time any car touches them.
+/// A segment counts as visited as soon as any part of the car (any corner) touches it.
+/// With the lap check on, touches that jump across the track are ignored (see `VisitOrder`).
 pub fn update_segment_visited_status(
     mut commands: Commands,
-    car_query: Query<&Transform, With<Car>>,
-    mut road_query: Query<
-        (
-            Entity,
-            &Transform,
-            &RoadSegment,
-        ),
-        Without<Visited>,
-    >,
-    mut edge_query: Query<
-        (
-            &RoadEdge,
-            Option<&mut Sprite>,
-            Option<&MeshMaterial2d<ColorMaterial>>,
-        ),
-    >,
+    settings: Res<GameSettings>,
+    mut car_query: Query<(&Transform, &mut VisitOrder), With<Car>>,
+    road_query: Query<(Entity, &Transform, &RoadSegment, Has<Visited>)>,
+    edge_query: RoadEdgeQuery,
+    materials: Res<Assets<ColorMaterial>>,
+) {
+    // Segments marked this frame (the Visited insert is deferred, so two cars could both see them unvisited)
+    let mut newly_visited = Vec::new();
+
+    for (car_transform, mut visit_order) in car_query.iter_mut() {
+        let car_corners = get_car_corners(car_transform);
+
+        for (entity, road_transform, road_segment, visited) in road_query.iter() {
+            if visit_order.indices.contains(&road_segment.index) {
+                continue;
+            }
+
+            // Check if ANY corner of the car is on this segment
+            let car_touches_segment = car_corners.iter().any(|corner| {
+                let local_corner = world_to_local_2d(road_transform, *corner);
+                is_point_in_segment(local_corner, road_segment.segment_type)
+            });
+            if !car_touches_segment {
+                continue;
+            }
+
+            // A shortcut gains nothing: the segment (and those skipped) stay to be visited
+            // until the car reaches it along the loop
+            if settings.validate_visit_order
+                && !continues_traversal(
+                    &visit_order.indices,
+                    road_segment.index,
+                    visit_order.segment_count,
+                    MAX_VISIT_GAP,
+                )
+            {
+                continue;
+            }
+
+            // Remember when this car visited it
+            visit_order.indices.push(road_segment.index);
+
+            if !visited && !newly_visited.contains(&entity) {
+                commands.entity(entity).insert(Visited);
+                start_visit_animation(&mut commands, entity, &edge_query, &materials);
+                newly_visited.push(entity);
+            }
+        }
+    }
+}
+
+/// Query for edges with a running visit animation
+type VisitingEdgeQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        &'static mut VisitingAnimation,
+        Option<&'static mut Sprite>,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+    ),
+>;
+
+/// System to fade edges of newly visited segments to the visited glow.
+/// Removes the animation once the edge reaches the glow color.
+/// With reduced motion the edges switch to the glow on the first frame.
+pub fn animate_visited_edges(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut edge_query: VisitingEdgeQuery,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    theme: Res<CurrentTheme>,
+    settings: Res<GameSettings>,
+) {
+    for (entity, mut animation, sprite_opt, material_opt) in edge_query.iter_mut() {
+        animation.timer.tick(time.delta());
+
+        let fraction = if settings.reduced_motion { 1.0 } else { animation.timer.fraction() };
+        let color = animation.start_color.mix(&theme.0.visited_edge, fraction);
+        set_edge_color(sprite_opt, material_opt, &mut materials, color);
+
+        if settings.reduced_motion || animation.timer.is_finished() {
+            commands.entity(entity).remove::<VisitingAnimation>();
+        }
+    }
+}
+
+/// System to tint the edges of segments still left to visit after a too-early finish attempt.
+/// The tint stays until the segment is visited and switches to the visited glow.
+pub fn highlight_missed_segments(
+    mut finish_blocked: MessageReader<FinishBlocked>,
+    unvisited_query: Query<Entity, (With<RoadSegment>, Without<Visited>)>,
+    mut edge_query: RoadEdgeQuery,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    palette: Res<Palette>,
+) {
+    if finish_blocked.read().count() == 0 {
+        return;
+    }
+
+    for segment in &unvisited_query {
+        set_segment_edge_color(segment, palette.missed_edge, &mut edge_query, &mut materials);
+    }
+}
+
+/// Forgets every visit on the track: removes the `Visited` markers and running visit
+/// animations, and returns every edge to `unvisited_color` (missed-segment tints included).
+/// For resets that keep the track standing, such as a reset to the start or a lap reset;
+/// a restart through `setup_game` rebuilds the track instead.
+pub fn reset_track_visited(
+    commands: &mut Commands,
+    visited_query: &Query<Entity, With<Visited>>,
+    edge_query: &mut RoadEdgeQuery,
+    materials: &mut Assets<ColorMaterial>,
+    unvisited_color: Color,
+) {
+    for segment in visited_query {
+        commands.entity(segment).remove::<Visited>();
+    }
+    for (edge, _, sprite_opt, material_opt) in edge_query.iter_mut() {
+        commands.entity(edge).remove::<VisitingAnimation>();
+        set_edge_color(sprite_opt, material_opt, materials, unvisited_color);
+    }
+}
+
+/// System to forget the segments visited before a reset to the start (see `StartReset`)
+pub fn clear_visits_on_start_reset(
+    mut commands: Commands,
+    mut start_reset: MessageReader<StartReset>,
+    visited_query: Query<Entity, With<Visited>>,
+    mut edge_query: RoadEdgeQuery,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    theme: Res<CurrentTheme>,
 ) {
-    let car_transform = car_query.single().unwrap();
-    let car_corners = get_car_corners(car_transform);
+    if start_reset.read().count() == 0 {
+        return;
+    }
 
-    for (entity, road_transform, road_segment) in road_query.iter_mut() {
-        // Check if ANY corner of the car is on this segment
-        let mut car_touches_segment = false;
+    reset_track_visited(
+        &mut commands,
+        &visited_query,
+        &mut edge_query,
+        &mut materials,
+        theme.0.unvisited_edge,
+    );
+}
 
-        for corner in car_corners.iter() {
-            let local_corner = world_to_local_2d(road_transform, *corner);
+// ============================================================================
+// Fog of War
+// ============================================================================
+
+/// Road pieces that fade under the fog of war: segments, their edges and corner edge cutouts.
+/// Like edges, each piece has either a sprite (straight) or a mesh material (corner).
+type FogPieceQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<&'static RoadEdge>,
+        Option<&'static RoadEdgeCutout>,
+        Option<&'static mut Sprite>,
+        Option<&'static MeshMaterial2d<ColorMaterial>>,
+    ),
+    Or<(With<RoadSegment>, With<RoadEdge>, With<RoadEdgeCutout>)>,
+>;
+
+/// Sets the opacity of a road piece, keeping its color.
+/// Only writes when the opacity changes, so idle materials aren't re-uploaded every frame.
+fn set_piece_alpha(
+    sprite: Option<Mut<Sprite>>,
+    material: Option<&MeshMaterial2d<ColorMaterial>>,
+    materials: &mut Assets<ColorMaterial>,
+    alpha: f32,
+) {
+    if let Some(mut sprite) = sprite {
+        if sprite.color.alpha() != alpha {
+            sprite.color.set_alpha(alpha);
+        }
+    } else if let Some(handle) = material {
+        let changed = materials
+            .get(&handle.0)
+            .is_some_and(|material| material.color.alpha() != alpha);
+        if let Some(material) = materials.get_mut(&handle.0).filter(|_| changed) {
+            material.color.set_alpha(alpha);
+            // Mesh materials ignore alpha unless blended
+            material.alpha_mode = if alpha < 1.0 { AlphaMode2d::Blend } else { AlphaMode2d::Opaque };
+        }
+    }
+}
+
+/// System to hide unvisited road segments far from every car, revealing them as a car approaches.
+/// Visited segments stay fully shown. Only the rendering fades - the road geometry
+/// (and so the off-road check) is unaffected.
+pub fn reveal_track(
+    car_query: Query<&Transform, With<Car>>,
+    segment_query: Query<(Entity, &Transform, &RoadSegment, Has<Visited>)>,
+    mut piece_query: FogPieceQuery,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let car_positions: Vec<Vec2> = car_query.iter().map(|t| t.translation.truncate()).collect();
 
-            if is_point_in_segment(local_corner, road_segment.segment_type) {
-                car_touches_segment = true;
-                break;
+    let segment_alphas: HashMap<Entity, f32> = segment_query
+        .iter()
+        .map(|(entity, transform, segment, visited)| {
+            if visited {
+                return (entity, 1.0);
             }
+            let center = get_segment_center(transform, segment.segment_type);
+            let distance = car_positions
+                .iter()
+                .map(|car| car.distance(center))
+                .fold(f32::MAX, f32::min);
+            (entity, fog_alpha(distance, segment.segment_type))
+        })
+        .collect();
+
+    for (entity, edge, cutout, sprite, material) in piece_query.iter_mut() {
+        let segment = edge
+            .map(|edge| edge.parent_segment)
+            .or(cutout.map(|cutout| cutout.parent_segment))
+            .unwrap_or(entity);
+        if let Some(&alpha) = segment_alphas.get(&segment) {
+            set_piece_alpha(sprite, material, &mut materials, alpha);
         }
+    }
+}
 
-        if car_touches_segment {
-            // Insert Visited marker component
-            commands.entity(entity).insert(Visited);
-
-            // Update colors of all edges belonging to this segment
-            for (road_edge, sprite_opt, material_opt) in edge_query.iter_mut() {
-                if road_edge.parent_segment == entity {
-                    // Update color based on edge type:
-                    // - Straight segment edges use Sprite component
-                    // - Corner segment edges use MeshMaterial2d<ColorMaterial>
-                    if let Some(mut sprite) = sprite_opt {
-                        sprite.color = VISITED_EDGE_COLOR;
-                    } else if let Some(material_handle) = material_opt {
-                        if let Some(material) = materials.get_mut(&material_handle.0) {
-                            material.color = VISITED_EDGE_COLOR;
-                        }
+/// System to show the whole track again when the fog of war is turned off mid-race
+/// (from the pause menu's options), as `reveal_track` stops running and would leave it hidden
+pub fn clear_fog(mut piece_query: FogPieceQuery, mut materials: ResMut<Assets<ColorMaterial>>) {
+    for (_, _, _, sprite, material) in piece_query.iter_mut() {
+        set_piece_alpha(sprite, material, &mut materials, 1.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use crate::car::components::{CarClass, PlayerId};
+    use crate::car::constants::TWO_PLAYER_START_OFFSET;
+    use crate::car::systems::spawn_car;
+    use crate::car::constants::CAR_WIDTH;
+    use crate::road::helpers::{get_car_spawns, get_segment_entries};
+    use crate::road::tracks::get_builtin_track;
+    use crate::hud::systems::init_race_state;
+    use crate::settings::components::{Difficulty, FinishCondition};
+
+    /// Spawns the track and the cars of a one- and a two-player race where `setup_game` puts
+    /// them, and checks the road before any moves, with no corner allowed off it
+    fn cars_start_on_road(track: Track) -> bool {
+        let mut world = World::new();
+        init_race_state(&mut world.commands(), None, Difficulty::Hard, FinishCondition::default());
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<ColorMaterial>>();
+        world
+            .run_system_once(
+                move |mut commands: Commands,
+                      mut meshes: ResMut<Assets<Mesh>>,
+                      mut materials: ResMut<Assets<ColorMaterial>>| {
+                    spawn_track(&mut commands, &mut meshes, &mut materials, &track, &Theme::default());
+                    let stats = CarClass::default().stats();
+                    let one_player = get_car_spawns(&track, &[0.0]);
+                    let two_player = get_car_spawns(&track, &[-TWO_PLAYER_START_OFFSET, TWO_PLAYER_START_OFFSET]);
+                    for (position, rotation) in one_player.into_iter().chain(two_player) {
+                        spawn_car(&mut commands, position, rotation, PlayerId::One, stats, Color::WHITE);
                     }
-                }
+                },
+            )
+            .unwrap();
+        world.run_system_once(check_car_on_road).unwrap()
+    }
+
+    /// The loop of level 1 started on its first corner, as a user track could be
+    fn corner_start_track() -> Track {
+        let track = get_builtin_track(1);
+        let corner = track
+            .layout
+            .iter()
+            .position(|segment| matches!(segment, RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight))
+            .unwrap();
+        let (entry_point, entry_direction) = get_segment_entries(&track)[corner];
+        let mut layout = track.layout;
+        layout.rotate_left(corner);
+        Track {
+            layout,
+            starting_point: entry_point + get_position_offset(entry_direction) / 2.0,
+            prop_indices: Vec::new(),
+            start_direction: entry_direction,
+        }
+    }
+
+    #[test]
+    fn test_cars_spawn_on_the_road() {
+        for level in 1..=3 {
+            assert!(cars_start_on_road(get_builtin_track(level)), "level {}", level);
+        }
+        assert!(cars_start_on_road(corner_start_track()));
+    }
+
+    #[test]
+    fn test_two_player_spawns_stay_apart_on_a_corner_start() {
+        let spawns = get_car_spawns(&corner_start_track(), &[-TWO_PLAYER_START_OFFSET, TWO_PLAYER_START_OFFSET]);
+        let [(left, left_rotation), (right, right_rotation)] = spawns[..] else {
+            panic!("expected two spawns, got {:?}", spawns);
+        };
+        // Nudged together, so they keep their spacing side by side and face the same way
+        assert!((left.distance(right) - 2.0 * TWO_PLAYER_START_OFFSET).abs() < 1e-3);
+        assert!(left.distance(right) > CAR_WIDTH);
+        assert_eq!(left_rotation, right_rotation);
+    }
+
+    /// A frame where the car is gone (e.g. during a state transition) but the road is not
+    #[test]
+    fn test_road_systems_run_without_a_car() {
+        let mut world = World::new();
+        world.init_resource::<GameSettings>();
+        world.init_resource::<Assets<ColorMaterial>>();
+        init_race_state(&mut world.commands(), None, Difficulty::default(), FinishCondition::default());
+        let segment = world
+            .spawn((
+                Transform::default(),
+                RoadSegment {
+                    segment_type: RoadSegmentType::Straight,
+                    direction: Direction::Up,
+                    index: 0,
+                },
+            ))
+            .id();
+
+        // With no car, nothing counts as off the road
+        assert!(world.run_system_once(check_car_on_road).unwrap());
+        world.run_system_once(update_segment_visited_status).unwrap();
+        assert!(world.get::<Visited>(segment).is_none());
+    }
+
+    #[test]
+    fn test_reset_track_visited_reverts_every_edge() {
+        let mut world = World::new();
+        world.init_resource::<Assets<ColorMaterial>>();
+        let glow = world
+            .resource_mut::<Assets<ColorMaterial>>()
+            .add(ColorMaterial::from_color(VISITED_EDGE_COLOR));
+        let visited = world.spawn(Visited).id();
+        let unvisited = world.spawn_empty().id();
+        let edge_sprite = |color: Color| Sprite::from_color(color, Vec2::ONE);
+        // A fully glowing straight edge and corner edge, one mid-fade, and a missed tint
+        let edges = [
+            world.spawn((RoadEdge { parent_segment: visited }, edge_sprite(VISITED_EDGE_COLOR))).id(),
+            world.spawn((RoadEdge { parent_segment: visited }, MeshMaterial2d(glow.clone()))).id(),
+            world
+                .spawn((
+                    RoadEdge { parent_segment: visited },
+                    edge_sprite(UNVISITED_EDGE_COLOR),
+                    VisitingAnimation {
+                        timer: Timer::from_seconds(VISIT_ANIMATION_DURATION, TimerMode::Once),
+                        start_color: UNVISITED_EDGE_COLOR,
+                    },
+                ))
+                .id(),
+            world.spawn((RoadEdge { parent_segment: unvisited }, edge_sprite(MISSED_EDGE_COLOR))).id(),
+        ];
+
+        world
+            .run_system_once(
+                |mut commands: Commands,
+                 visited_query: Query<Entity, With<Visited>>,
+                 mut edge_query: RoadEdgeQuery,
+                 mut materials: ResMut<Assets<ColorMaterial>>| {
+                    reset_track_visited(
+                        &mut commands,
+                        &visited_query,
+                        &mut edge_query,
+                        &mut materials,
+                        UNVISITED_EDGE_COLOR,
+                    );
+                },
+            )
+            .unwrap();
+
+        assert!(world.get::<Visited>(visited).is_none());
+        let corner_material = world.resource::<Assets<ColorMaterial>>().get(&glow).unwrap();
+        assert_eq!(corner_material.color, UNVISITED_EDGE_COLOR);
+        for edge in edges {
+            assert!(world.get::<VisitingAnimation>(edge).is_none());
+            if let Some(sprite) = world.get::<Sprite>(edge) {
+                assert_eq!(sprite.color, UNVISITED_EDGE_COLOR);
             }
         }
     }
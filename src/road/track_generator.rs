@@ -32,25 +32,78 @@ pub struct TrackGeneratorConfig {
     pub max_segments: usize,
     /// Target difficulty (0.0 = easy/few turns, 1.0 = hard/many turns)
     pub target_difficulty: f32,
-    /// Seed for reproducible track generation
+    /// Seed for reproducible track generation. A level's own, or drawn from `GameRng` for
+    /// the preview's new tracks (see `generate_random_track`).
     pub seed: u64,
+    /// Minimum number of powerups placed on the track
+    pub min_props: usize,
+    /// Maximum number of powerups placed on the track
+    pub max_props: usize,
+    /// Segments kept clear between the track and the window edges (larger = more central)
+    pub grid_margin: usize,
 }
 
 /// Minimum segments required for a valid closed loop (a square)
 pub const MIN_VALID_SEGMENTS: usize = 4;
 
-/// Calculate the maximum number of segments possible on the grid
+/// Default grid margin, leaving room for the road width at the window edges
+pub const DEFAULT_GRID_MARGIN: usize = 2;
+
+/// Weights of the measures making up a track's difficulty rating (summing to 1.0):
+/// the share of cells that are corners, the share of segments turning straight into
+/// another corner (chicanes), and the track's length
+const CORNER_DENSITY_WEIGHT: f32 = 0.5;
+const CHICANE_WEIGHT: f32 = 0.3;
+const LENGTH_WEIGHT: f32 = 0.2;
+
+/// Track length (in grid cells) from which length adds nothing more to the difficulty rating
+const LONG_TRACK_CELLS: usize = 150;
+
+/// Half the usable grid size (in segments) horizontally and vertically, keeping
+/// `margin` segments clear of the window edges
+fn grid_half_extents(margin: usize) -> (usize, usize) {
+    let half_width = (WINDOW_WIDTH as f32 / 2.0 / ROAD_SEGMENT_LENGTH) as usize;
+    let half_height = (WINDOW_HEIGHT as f32 / 2.0 / ROAD_SEGMENT_LENGTH) as usize;
+    (half_width.saturating_sub(margin), half_height.saturating_sub(margin))
+}
+
+/// Calculate the maximum number of segments possible on the grid for a margin
 /// This is the number of cells in the usable grid area (Hamiltonian cycle upper bound)
-pub fn max_grid_segments() -> usize {
-    let margin = 2; // Same margin used in generation
-    let half_width = (WINDOW_WIDTH as f32 / 2.0 / ROAD_SEGMENT_LENGTH) as usize - margin;
-    let half_height = (WINDOW_HEIGHT as f32 / 2.0 / ROAD_SEGMENT_LENGTH) as usize - margin;
+pub fn max_grid_segments(margin: usize) -> usize {
+    let (half_width, half_height) = grid_half_extents(margin);
     // Grid dimensions: from -half to +half inclusive = 2*half + 1
     let grid_width = 2 * half_width + 1;
     let grid_height = 2 * half_height + 1;
     grid_width * grid_height
 }
 
+/// Measured difficulty of a track layout, from 0.0 (easy) to 1.0 (hard).
+/// Unlike `TrackGeneratorConfig::target_difficulty`, which only sets the odds of a corner
+/// as the track is laid, this rates the result: how much of it is corners, how often
+/// corners follow one another with no straight between (the track loops round, so the
+/// last segment leads into the first), and how long it is.
+pub fn rate_track_difficulty(layout: &[RoadSegmentType]) -> f32 {
+    let is_corner =
+        |segment: &RoadSegmentType| matches!(segment, RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight);
+    let cells: usize = layout.iter().map(|segment| segment.length_in_cells()).sum();
+    if cells == 0 {
+        return 0.0;
+    }
+
+    let corners = layout.iter().filter(|segment| is_corner(segment)).count();
+    let chicanes = layout
+        .iter()
+        .zip(layout.iter().cycle().skip(1))
+        .filter(|(segment, next)| is_corner(segment) && is_corner(next))
+        .count();
+    let corner_density = corners as f32 / cells as f32;
+    let chicane_share = chicanes as f32 / layout.len() as f32;
+    let length = (cells as f32 / LONG_TRACK_CELLS as f32).min(1.0);
+
+    (CORNER_DENSITY_WEIGHT * corner_density + CHICANE_WEIGHT * chicane_share + LENGTH_WEIGHT * length)
+        .clamp(0.0, 1.0)
+}
+
 impl Default for TrackGeneratorConfig {
     fn default() -> Self {
         Self {
@@ -58,6 +111,9 @@ impl Default for TrackGeneratorConfig {
             max_segments: 150,
             target_difficulty: 0.5,
             seed: 42,
+            min_props: 1,
+            max_props: 3,
+            grid_margin: DEFAULT_GRID_MARGIN,
         }
     }
 }
@@ -65,7 +121,7 @@ impl Default for TrackGeneratorConfig {
 impl TrackGeneratorConfig {
     /// Validate the configuration, panicking with descriptive errors if invalid
     pub fn validate(&self) {
-        let max_possible = max_grid_segments();
+        let max_possible = max_grid_segments(self.grid_margin);
 
         if self.min_segments < MIN_VALID_SEGMENTS {
             panic!(
@@ -83,8 +139,8 @@ impl TrackGeneratorConfig {
 
         if self.max_segments > max_possible {
             panic!(
-                "Invalid TrackGeneratorConfig: max_segments ({}) exceeds maximum possible segments ({}) for the grid size (Hamiltonian cycle upper bound)",
-                self.max_segments, max_possible
+                "Invalid TrackGeneratorConfig: max_segments ({}) exceeds maximum possible segments ({}) for the grid size with a margin of {} (Hamiltonian cycle upper bound)",
+                self.max_segments, max_possible, self.grid_margin
             );
         }
 
@@ -101,6 +157,13 @@ impl TrackGeneratorConfig {
                 self.target_difficulty
             );
         }
+
+        if self.min_props > self.max_props {
+            panic!(
+                "Invalid TrackGeneratorConfig: min_props ({}) cannot be greater than max_props ({})",
+                self.min_props, self.max_props
+            );
+        }
     }
 }
 
@@ -116,23 +179,33 @@ impl TrackGeneratorConfig {
 /// 5. Ensure no parallel road segments (prevents shortcut cheating)
 /// 6. If we can close the loop back to origin, do so
 /// 7. Backtrack if stuck
+/// 8. Merge consecutive straights into long `StraightRun` segments
 ///
 /// # Panics
 /// Panics if the config is invalid:
 /// - min_segments < 4 (minimum for a closed loop)
-/// - max_segments > grid cell count (Hamiltonian cycle upper bound)
+/// - max_segments > grid cell count inside the margin (Hamiltonian cycle upper bound)
 /// - min_segments > max_segments
 /// - target_difficulty not in [0.0, 1.0]
+/// - min_props > max_props
 ///
 /// Returns None if generation fails after max attempts.
+///
+/// # Seeding
+/// The layout comes from `config.seed` alone. A level's seed is its own rather than drawn from
+/// `GameRng`, as its track has to be the same on every launch and machine whatever the global
+/// seed: its best times, its minimap preview, the `seed=` in shared results and the resumed race
+/// marker all stand for the track by its level or seed. The new seeds the track preview tries
+/// are drawn from `GameRng`, so a replayed run regenerates the same tracks. Each attempt gets
+/// its own generator, derived from the seed, so the parallel attempts give the same result in
+/// any order.
 pub fn generate_random_track(config: &TrackGeneratorConfig) -> Option<GeneratedTrack> {
     // Validate config - will panic with descriptive message if invalid
     config.validate();
 
-    // Grid bounds based on window size (with margin for road width)
-    let margin = 2; // Keep 2 segments away from edges
-    let half_width = (WINDOW_WIDTH as f32 / 2.0 / ROAD_SEGMENT_LENGTH) as i32 - margin;
-    let half_height = (WINDOW_HEIGHT as f32 / 2.0 / ROAD_SEGMENT_LENGTH) as i32 - margin;
+    // Grid bounds based on window size (with the configured margin)
+    let (half_width, half_height) = grid_half_extents(config.grid_margin);
+    let (half_width, half_height) = (half_width as i32, half_height as i32);
 
     // Helper to derive a unique seed from the base seed and attempt index
     // Using a hash ensures no overlap between different base seeds
@@ -212,7 +285,7 @@ fn try_generate_track<R: Rng>(
         if layout.len() >= config.min_segments {
             if let Some(closing_segment) = can_close_loop(current_pos, current_dir, &visited) {
                 layout.push(closing_segment);
-                return Some(finalize_track(layout, rng));
+                return Some(finalize_track(layout, config, rng));
             }
         }
 
@@ -306,7 +379,7 @@ fn can_close_loop(
 }
 
 /// Get the next grid position when moving in a direction
-fn get_next_grid_position(pos: IVec2, dir: Direction) -> IVec2 {
+pub fn get_next_grid_position(pos: IVec2, dir: Direction) -> IVec2 {
     match dir {
         Direction::Up => IVec2::new(pos.x, pos.y + 1),
         Direction::Down => IVec2::new(pos.x, pos.y - 1),
@@ -420,7 +493,11 @@ fn choose_segment<R: Rng>(
 }
 
 /// Finalize the track by computing starting point and generating prop indices
-fn finalize_track<R: Rng + ?Sized>(layout: Vec<RoadSegmentType>, rng: &mut R) -> GeneratedTrack {
+fn finalize_track<R: Rng + ?Sized>(
+    layout: Vec<RoadSegmentType>,
+    config: &TrackGeneratorConfig,
+    rng: &mut R,
+) -> GeneratedTrack {
     // Calculate the bounding box of the track path
     let mut current_pos = IVec2::ZERO;
     let mut current_dir = Direction::Up;
@@ -438,6 +515,9 @@ fn finalize_track<R: Rng + ?Sized>(layout: Vec<RoadSegmentType>, rng: &mut R) ->
         current_dir = get_exit_direction(current_dir, segment);
     }
 
+    // Merge straights only after walking the grid, which steps one cell per segment
+    let layout = merge_straight_runs(layout);
+
     // Center the track: calculate offset to center the bounding box
     let center_x = (min_x + max_x) as f32 / 2.0;
     let center_y = (min_y + max_y) as f32 / 2.0;
@@ -450,30 +530,8 @@ fn finalize_track<R: Rng + ?Sized>(layout: Vec<RoadSegmentType>, rng: &mut R) ->
     );
 
     // Generate prop indices
-    let track_length = layout.len();
-    let min_separation = track_length / 5;
-    let num_props = rng.random_range(1..=3);
-
-    let mut prop_indices = Vec::new();
-    let mut attempts = 0;
-
-    while prop_indices.len() < num_props && attempts < 100 {
-        let idx = rng.random_range(0..track_length);
-
-        let mut valid = true;
-        for &existing_idx in &prop_indices {
-            let dist = (idx as isize - existing_idx as isize).abs();
-            if dist < min_separation as isize {
-                valid = false;
-                break;
-            }
-        }
-
-        if valid {
-            prop_indices.push(idx);
-        }
-        attempts += 1;
-    }
+    let num_props = rng.random_range(config.min_props..=config.max_props);
+    let prop_indices = place_props(layout.len(), num_props, rng);
 
     GeneratedTrack {
         layout,
@@ -481,3 +539,133 @@ fn finalize_track<R: Rng + ?Sized>(layout: Vec<RoadSegmentType>, rng: &mut R) ->
         prop_indices,
     }
 }
+
+/// Minimum distance (in layout indices) between two props: a fifth of the track,
+/// and at least 1 so no two props share a segment
+fn prop_min_separation(track_length: usize) -> usize {
+    (track_length / 5).max(1)
+}
+
+/// Picks `num_props` prop indices on a track of `track_length` segments, at least
+/// `prop_min_separation` apart. Short tracks get as many props as fit.
+/// The indices are spread by handing the slack left after the minimum spacing out at
+/// random, so placement always succeeds without retries.
+fn place_props<R: Rng + ?Sized>(track_length: usize, num_props: usize, rng: &mut R) -> Vec<usize> {
+    if track_length == 0 || num_props == 0 {
+        return Vec::new();
+    }
+
+    let min_separation = prop_min_separation(track_length);
+    let max_props = (track_length - 1) / min_separation + 1;
+    let num_props = num_props.min(max_props);
+
+    // Indices left over once the props are packed at the minimum spacing
+    let slack = (track_length - 1) - (num_props - 1) * min_separation;
+    let mut offsets: Vec<usize> = (0..num_props).map(|_| rng.random_range(0..=slack)).collect();
+    offsets.sort_unstable();
+
+    offsets
+        .into_iter()
+        .enumerate()
+        .map(|(i, offset)| i * min_separation + offset)
+        .collect()
+}
+
+/// Merge consecutive straight segments into `StraightRun`s so long straights spawn
+/// as a single road piece. The two forced straights under the start line are kept as is.
+fn merge_straight_runs(layout: Vec<RoadSegmentType>) -> Vec<RoadSegmentType> {
+    let mut merged: Vec<RoadSegmentType> = Vec::with_capacity(layout.len());
+
+    for (i, segment) in layout.into_iter().enumerate() {
+        if i < 2 || segment != RoadSegmentType::Straight {
+            merged.push(segment);
+            continue;
+        }
+
+        let last = merged.len() - 1;
+        match merged[last] {
+            RoadSegmentType::Straight if last >= 2 => merged[last] = RoadSegmentType::StraightRun(2),
+            RoadSegmentType::StraightRun(cells) => merged[last] = RoadSegmentType::StraightRun(cells + 1),
+            _ => merged.push(segment),
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::road::helpers::is_closed_loop;
+
+    #[test]
+    fn test_corner_heavy_tracks_rate_harder() {
+        use RoadSegmentType::*;
+        // A rectangle of long straights, and a winding loop of nothing but corners
+        let straight_heavy = [StraightRun(4), CornerRight, StraightRun(4), CornerRight,
+            StraightRun(4), CornerRight, StraightRun(4), CornerRight];
+        let corner_heavy = [CornerLeft, CornerRight, CornerRight, CornerLeft, CornerRight,
+            CornerRight, CornerLeft, CornerRight, CornerRight, CornerLeft, CornerRight, CornerRight];
+        assert!(rate_track_difficulty(&straight_heavy) < rate_track_difficulty(&corner_heavy));
+
+        // Corners spread out rate easier than the same corners back to back
+        let spread = [Straight, CornerRight, Straight, CornerRight, Straight, CornerRight, Straight, CornerRight];
+        let bunched = [Straight, Straight, CornerRight, CornerRight, Straight, Straight, CornerRight, CornerRight];
+        assert!(rate_track_difficulty(&spread) < rate_track_difficulty(&bunched));
+
+        assert_eq!(rate_track_difficulty(&[]), 0.0);
+        assert!(rate_track_difficulty(&[CornerRight; 4]) <= 1.0);
+    }
+
+    #[test]
+    fn test_larger_margin_shrinks_grid() {
+        assert!(max_grid_segments(DEFAULT_GRID_MARGIN + 2) < max_grid_segments(DEFAULT_GRID_MARGIN));
+        assert!(max_grid_segments(0) > max_grid_segments(DEFAULT_GRID_MARGIN));
+    }
+
+    #[test]
+    fn test_central_track_still_closes_loop() {
+        let config = TrackGeneratorConfig {
+            min_segments: 30,
+            max_segments: 80,
+            grid_margin: DEFAULT_GRID_MARGIN + 2,
+            ..default()
+        };
+        let track = generate_random_track(&config).expect("central track should generate");
+
+        assert!(is_closed_loop(&track.layout));
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds maximum possible segments")]
+    fn test_max_segments_beyond_margin_grid_is_rejected() {
+        let config = TrackGeneratorConfig {
+            max_segments: max_grid_segments(DEFAULT_GRID_MARGIN + 2) + 1,
+            grid_margin: DEFAULT_GRID_MARGIN + 2,
+            ..default()
+        };
+        config.validate();
+    }
+
+    #[test]
+    fn test_props_fit_tracks_of_any_length() {
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for track_length in 0..60 {
+            let min_separation = prop_min_separation(track_length);
+            for num_props in 0..=5 {
+                let props = place_props(track_length, num_props, &mut rng);
+
+                assert!(props.iter().all(|&index| index < track_length));
+                assert!(props.windows(2).all(|pair| pair[1] - pair[0] >= min_separation));
+                // Every prop is placed whenever they fit at the minimum spacing
+                let fits = num_props == 0
+                    || (track_length > 0 && (num_props - 1) * min_separation < track_length);
+                if fits {
+                    assert_eq!(props.len(), num_props);
+                } else {
+                    assert!(props.len() < num_props);
+                }
+            }
+        }
+    }
+}
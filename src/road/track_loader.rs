@@ -0,0 +1,242 @@
+//! Loading hand-made tracks from JSON files in the user data directory.
+//!
+//! A track file for level N is stored as `tracks/level_N.json` and, when present and
+//! valid, replaces the built-in track for that level.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::SystemTime;
+
+use crate::road::components::{Direction, RoadSegmentType, Track};
+use crate::road::helpers::is_closed_loop;
+use crate::road::track_generator::max_grid_segments;
+use crate::save::data_dir;
+
+/// Each track file's level and modified time, in level order. Changes whenever a track file
+/// is added, removed or edited in place, so it tells when anything read from them is stale.
+pub type TracksStamp = Vec<(usize, Option<SystemTime>)>;
+
+/// The valid user tracks by level, with the track files' stamp when they were read.
+/// Only re-read when the stamp changes.
+type UserTracks = (TracksStamp, HashMap<usize, Track>);
+static USER_TRACKS: Mutex<Option<UserTracks>> = Mutex::new(None);
+
+/// Serializable track format (layout + starting point + prop indices)
+#[derive(Serialize, Deserialize)]
+pub struct TrackFile {
+    /// The sequence of road segments, starting under the start line heading up
+    pub layout: Vec<RoadSegmentType>,
+    /// The starting position of the track (world coordinates, [x, y])
+    pub starting_point: [f32; 2],
+    /// Indices of segments where props should be placed
+    #[serde(default)]
+    pub prop_indices: Vec<usize>,
+    /// Direction the track heads from its starting point (Up if absent)
+    #[serde(default)]
+    pub start_direction: Direction,
+}
+
+impl From<&Track> for TrackFile {
+    fn from(track: &Track) -> Self {
+        Self {
+            layout: track.layout.clone(),
+            starting_point: track.starting_point.to_array(),
+            prop_indices: track.prop_indices.clone(),
+            start_direction: track.start_direction,
+        }
+    }
+}
+
+impl From<TrackFile> for Track {
+    fn from(file: TrackFile) -> Self {
+        Self {
+            layout: file.layout,
+            starting_point: Vec2::from_array(file.starting_point),
+            prop_indices: file.prop_indices,
+            start_direction: file.start_direction,
+        }
+    }
+}
+
+/// Gets the user track directory, creating it if necessary
+fn get_tracks_dir() -> io::Result<PathBuf> {
+    let tracks_dir = data_dir()?.join("tracks");
+    fs::create_dir_all(&tracks_dir)?;
+    Ok(tracks_dir)
+}
+
+/// The current stamp of the user track files (empty if there's no tracks directory)
+pub fn user_tracks_stamp() -> TracksStamp {
+    get_tracks_dir().map(|tracks_dir| tracks_stamp(&tracks_dir)).unwrap_or_default()
+}
+
+/// Lists the level files in the directory with their modified times
+fn tracks_stamp(tracks_dir: &Path) -> TracksStamp {
+    let Ok(entries) = fs::read_dir(tracks_dir) else {
+        return Vec::new();
+    };
+    let mut stamp: TracksStamp = entries
+        .flatten()
+        .filter_map(|entry| {
+            let level = track_file_level(&entry.file_name().to_string_lossy())?;
+            Some((level, entry.metadata().and_then(|metadata| metadata.modified()).ok()))
+        })
+        .collect();
+    stamp.sort_unstable();
+    stamp
+}
+
+/// Level a user track file is for, from its name (`level_N.json`)
+fn track_file_level(file_name: &str) -> Option<usize> {
+    file_name.strip_prefix("level_")?.strip_suffix(".json")?.parse().ok()
+}
+
+/// Checks that a track is a single closed loop that fits the grid, and its prop indices
+/// are in range
+pub fn validate_track(track: &Track) -> Result<(), String> {
+    if track.layout.contains(&RoadSegmentType::StraightRun(0)) {
+        return Err("a straight run covers no cells".to_string());
+    }
+    // Checked before walking the loop, so a huge run can't take forever or overflow
+    let max_cells = max_grid_segments(0);
+    let cells = track
+        .layout
+        .iter()
+        .try_fold(0usize, |cells, segment| cells.checked_add(segment.length_in_cells()));
+    if cells.is_none_or(|cells| cells > max_cells) {
+        return Err(format!("layout covers more than the grid's {} cells", max_cells));
+    }
+    if !is_closed_loop(&track.layout) {
+        return Err("layout does not form a closed loop".to_string());
+    }
+    if let Some(&index) = track.prop_indices.iter().find(|&&index| index >= track.layout.len()) {
+        return Err(format!(
+            "prop index {} is out of range for {} segments",
+            index,
+            track.layout.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Parses and validates a track from JSON
+pub fn parse_track(json: &str) -> io::Result<Track> {
+    let file: TrackFile = serde_json::from_str(json)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let track = Track::from(file);
+
+    validate_track(&track).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(track)
+}
+
+/// Loads the user track for a level, if a file exists for it.
+/// The files are read once and kept until one of them changes (see `USER_TRACKS`).
+pub fn load_user_track(level: usize) -> Option<Track> {
+    let tracks_dir = get_tracks_dir().ok()?;
+    let stamp = tracks_stamp(&tracks_dir);
+
+    let mut cache = USER_TRACKS.lock().unwrap_or_else(PoisonError::into_inner);
+    if cache.as_ref().is_none_or(|(read_at, _)| *read_at != stamp) {
+        *cache = Some((stamp, read_user_tracks(&tracks_dir)));
+    }
+    cache.as_ref()?.1.get(&level).cloned()
+}
+
+/// Reads every user track file in the directory.
+/// Invalid files are reported and ignored so the built-in track is used instead.
+fn read_user_tracks(tracks_dir: &Path) -> HashMap<usize, Track> {
+    let Ok(entries) = fs::read_dir(tracks_dir) else {
+        return HashMap::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_path = entry.path();
+            let level = track_file_level(&entry.file_name().to_string_lossy())?;
+            match fs::read_to_string(&file_path).and_then(|json| parse_track(&json)) {
+                Ok(track) => Some((level, track)),
+                Err(e) => {
+                    warn!("Ignoring invalid track file {}: {}", file_path.display(), e);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::road::tracks::get_builtin_track;
+    use crate::save::ScratchDataDir;
+
+    #[test]
+    fn test_track_round_trips_through_json() {
+        let track = get_builtin_track(2);
+        let json = serde_json::to_string(&TrackFile::from(&track)).unwrap();
+
+        let loaded = parse_track(&json).unwrap();
+
+        assert_eq!(loaded.layout, track.layout);
+        assert_eq!(loaded.starting_point, track.starting_point);
+        assert_eq!(loaded.prop_indices, track.prop_indices);
+    }
+
+    #[test]
+    fn test_only_level_files_are_read() {
+        assert_eq!(track_file_level("level_7.json"), Some(7));
+        assert_eq!(track_file_level("level_7.json.tmp"), None);
+        assert_eq!(track_file_level("notes.json"), None);
+    }
+
+    #[test]
+    fn test_editing_a_track_file_changes_the_stamp() {
+        let _data_dir = ScratchDataDir::new();
+        let tracks_dir = get_tracks_dir().unwrap();
+        let file_path = tracks_dir.join("level_2.json");
+        fs::write(&file_path, "{}").unwrap();
+        fs::write(tracks_dir.join("notes.json"), "{}").unwrap();
+        let set_modified = |seconds: u64| {
+            let file = fs::File::options().write(true).open(&file_path).unwrap();
+            file.set_modified(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(seconds)).unwrap();
+        };
+
+        set_modified(1_000);
+        let before = tracks_stamp(&tracks_dir);
+        set_modified(2_000);
+        let after = tracks_stamp(&tracks_dir);
+
+        // Only the level file is listed, and rewriting it in place is noticed
+        assert_eq!(before.len(), 1);
+        assert_eq!(before[0].0, 2);
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    fn test_invalid_tracks_are_rejected() {
+        let open_loop = r#"{"layout": ["Straight", "CornerRight"], "starting_point": [0.0, 0.0]}"#;
+        assert!(parse_track(open_loop).is_err());
+
+        let bad_prop = r#"{
+            "layout": ["CornerRight", "CornerRight", "CornerRight", "CornerRight"],
+            "starting_point": [0.0, 0.0],
+            "prop_indices": [4]
+        }"#;
+        assert!(parse_track(bad_prop).is_err());
+
+        // A run must cover at least one cell, and no more than the grid holds
+        for run in ["0", "18446744073709551615"] {
+            let bad_run = format!(
+                r#"{{"layout": [{{"StraightRun": {}}}, "CornerRight", "CornerRight",
+                    {{"StraightRun": {}}}, "CornerRight", "CornerRight"], "starting_point": [0.0, 0.0]}}"#,
+                run, run
+            );
+            assert!(parse_track(&bad_run).is_err(), "run of {}", run);
+        }
+    }
+}
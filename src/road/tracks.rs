@@ -1,5 +1,8 @@
-use crate::road::components::{RoadSegmentType, Track};
+use crate::constants::SeedOverride;
+use crate::road::components::{Direction, PlayedTrackInfo, RoadSegmentType, Track};
 use crate::road::constants::*;
+use crate::road::track_generator::{generate_random_track, TrackGeneratorConfig, DEFAULT_GRID_MARGIN};
+use crate::road::track_loader::load_user_track;
 use bevy::prelude::*;
 
 const TRACK_1_LAYOUT: [RoadSegmentType; 41-5] = [
@@ -149,24 +152,160 @@ const TRACK_3_LAYOUT: [RoadSegmentType; 147 - 83] = [
 
 // Shift starting points in units of ROAD_SEGMENT_LENGTH to enforce square fixed-size segments of screen
 
-/// Returns the track for the given level number (1, 2, or 3)
-pub fn get_track(level: usize) -> Track {
+/// Where a track comes from. Every screen showing or playing a level's track builds it
+/// from one of these (see `resolve` and `load_track`), so they all show the same track.
+pub enum TrackSource {
+    /// One of the fixed tracks (levels 1 to `BUILTIN_LEVEL_COUNT`)
+    Builtin(usize),
+    /// A random track, the same every time for the same config (which holds its seed)
+    Generated(TrackGeneratorConfig),
+}
+
+impl TrackSource {
+    /// The random track for a seed. Props are placed after the layout, so the layout
+    /// for a seed is the same for any prop range.
+    pub fn seeded(seed: u64, min_props: usize, max_props: usize) -> Self {
+        TrackSource::Generated(seeded_track_config(seed, min_props, max_props))
+    }
+}
+
+/// Returns where a level's track comes from: the fixed tracks for the first levels and a
+/// random track seeded by the level number after them, or by the seed picked on the
+/// track preview if there is one (see `SeedOverride`)
+pub fn resolve(
+    level: usize,
+    seed_override: &SeedOverride,
+    min_props: usize,
+    max_props: usize,
+) -> TrackSource {
+    match seed_override.seed_for(level) {
+        Some(seed) => TrackSource::seeded(seed, min_props, max_props),
+        None => TrackSource::Builtin(level),
+    }
+}
+
+/// Builds the track from its source, with where it came from.
+/// A valid user track file takes precedence over a fixed track.
+/// Returns None if no closed loop could be generated for the seed.
+pub fn load_track(source: TrackSource) -> Option<(Track, PlayedTrackInfo)> {
+    match source {
+        TrackSource::Builtin(level) => Some(match load_user_track(level) {
+            Some(track) => (track, PlayedTrackInfo::UserFile),
+            None => (get_builtin_track(level), PlayedTrackInfo::BuiltIn),
+        }),
+        TrackSource::Generated(config) => {
+            let track = generate_track(&config)?;
+            Some((track, PlayedTrackInfo::Generated(config)))
+        }
+    }
+}
+
+/// Returns the track for any level on its own seed (see `resolve`).
+/// Returns None if no closed loop could be generated for the level's seed.
+pub fn get_level_track(level: usize, min_props: usize, max_props: usize) -> Option<Track> {
+    get_played_track(level, &SeedOverride::default(), min_props, max_props)
+}
+
+/// Returns the track a level is played on (see `resolve`)
+pub fn get_played_track(
+    level: usize,
+    seed_override: &SeedOverride,
+    min_props: usize,
+    max_props: usize,
+) -> Option<Track> {
+    load_track(resolve(level, seed_override, min_props, max_props)).map(|(track, _)| track)
+}
+
+/// Generator settings every seeded track is made with
+fn seeded_track_config(seed: u64, min_props: usize, max_props: usize) -> TrackGeneratorConfig {
+    TrackGeneratorConfig {
+        min_segments: 50,
+        max_segments: 120,
+        target_difficulty: 0.5,
+        seed,
+        min_props,
+        max_props,
+        grid_margin: DEFAULT_GRID_MARGIN,
+    }
+}
+
+/// Generates a track from the config, heading up from its starting point
+fn generate_track(config: &TrackGeneratorConfig) -> Option<Track> {
+    let generated = generate_random_track(config)?;
+
+    Some(Track {
+        layout: generated.layout,
+        starting_point: generated.starting_point,
+        prop_indices: generated.prop_indices,
+        start_direction: Direction::Up,
+    })
+}
+
+/// Seed the level's track is generated from, or None for the fixed tracks
+pub fn level_seed(level: usize) -> Option<u64> {
+    (level > BUILTIN_LEVEL_COUNT).then_some(level as u64)
+}
+
+/// Returns the hardcoded track for the given level number (1 to `BUILTIN_LEVEL_COUNT`)
+pub fn get_builtin_track(level: usize) -> Track {
     match level {
         1 => Track {
             layout: TRACK_1_LAYOUT.to_vec(),
             starting_point: Vec2::new(-5.0 * ROAD_SEGMENT_LENGTH, -3.0 * ROAD_SEGMENT_LENGTH),
             prop_indices: vec![10, 25],
+            start_direction: Direction::Up,
         },
         2 => Track {
             layout: TRACK_2_LAYOUT.to_vec(),
             starting_point: Vec2::new(-1.0 * ROAD_SEGMENT_LENGTH, -2.0 * ROAD_SEGMENT_LENGTH),
-            prop_indices: vec![20, 50],
+            prop_indices: vec![20, 30],
+            start_direction: Direction::Up,
         },
         3 => Track {
             layout: TRACK_3_LAYOUT.to_vec(),
             starting_point: Vec2::new(-2.0 * ROAD_SEGMENT_LENGTH, 0.0),
-            prop_indices: vec![15, 40, 70],
+            prop_indices: vec![15, 40, 55],
+            start_direction: Direction::Up,
         },
-        _ => panic!("Invalid level: {}. Only levels 1-3 are available.", level),
+        _ => panic!(
+            "Invalid level: {}. Only levels 1-{} are available.",
+            level, BUILTIN_LEVEL_COUNT
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_levels_come_first_then_seeds() {
+        let seed_override = SeedOverride(Some((5, 1234)));
+        for level in 1..=BUILTIN_LEVEL_COUNT {
+            let source = resolve(level, &seed_override, 1, 3);
+            assert!(matches!(source, TrackSource::Builtin(l) if l == level));
+        }
+        for (level, seed) in [(BUILTIN_LEVEL_COUNT + 1, BUILTIN_LEVEL_COUNT as u64 + 1), (5, 1234)] {
+            let TrackSource::Generated(config) = resolve(level, &seed_override, 1, 3) else {
+                panic!("level {} should be generated", level);
+            };
+            assert_eq!(config.seed, seed);
+        }
+    }
+
+    #[test]
+    fn test_played_seed_replays_the_same_track() {
+        let seed_override = SeedOverride(Some((5, 1234)));
+        for (level, seed) in [(4, 4), (5, 1234)] {
+            let (track, info) = load_track(resolve(level, &seed_override, 1, 3)).unwrap();
+            assert!(info.label().starts_with(&format!("Seed {} ", seed)));
+            let PlayedTrackInfo::Generated(config) = info else {
+                panic!("level {} should be generated", level);
+            };
+            // The seed shown is all the track preview needs to rebuild the layout
+            let source = TrackSource::seeded(config.seed, config.min_props, config.max_props);
+            let (replayed, _) = load_track(source).unwrap();
+            assert_eq!(replayed.layout, track.layout);
+        }
     }
 }
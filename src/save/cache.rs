@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+use std::time::SystemTime;
+
+use super::{list_saves, saves_stamp, SaveData};
+
+/// The saves listed in the save directory, parsed once and re-read only after the game
+/// writes or deletes a save, or the directory changes on disk (see `saves_stamp`)
+#[derive(Resource, Default)]
+pub struct SaveCache {
+    saves: Vec<SaveData>,
+    /// Stamp of the directory when the saves were read (None until they first are)
+    read_at: Option<(u64, Option<SystemTime>)>,
+}
+
+impl SaveCache {
+    /// All saves, most recently played first
+    pub fn saves(&mut self) -> &[SaveData] {
+        // Stamped before listing, so a change made while listing is picked up next time
+        let stamp = saves_stamp();
+        if self.read_at != Some(stamp) {
+            self.saves = list_saves().unwrap_or_default();
+            self.read_at = Some(stamp);
+        }
+        &self.saves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::{delete_save_file, save_to_file, ScratchDataDir};
+
+    fn has_player(cache: &mut SaveCache, name: &str) -> bool {
+        cache.saves().iter().any(|save| save.player_name == name)
+    }
+
+    #[test]
+    fn test_cache_is_refreshed_after_save_and_delete() {
+        let _data_dir = ScratchDataDir::new();
+        let mut cache = SaveCache::default();
+        assert!(!has_player(&mut cache, "Cache Test"));
+
+        let save = SaveData::new("Cache Test".to_string());
+        save_to_file(&save).unwrap();
+        assert!(has_player(&mut cache, "Cache Test"));
+
+        delete_save_file(&save.filename()).unwrap();
+        assert!(!has_player(&mut cache, "Cache Test"));
+    }
+}
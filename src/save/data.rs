@@ -3,6 +3,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::car::components::{CarClass, CarColor};
+use crate::settings::components::Difficulty;
+
 /// Represents a saved game with player progress
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SaveData {
@@ -16,6 +19,81 @@ pub struct SaveData {
     pub created_at: DateTime<Utc>,
     /// When this save was last played
     pub last_played: DateTime<Utc>,
+    /// Total real time spent in gameplay (seconds)
+    #[serde(default)]
+    pub total_playtime_secs: f32,
+    /// Number of races finished (any level, including repeats)
+    #[serde(default)]
+    pub races_completed: u32,
+    /// Car class picked for the next race
+    #[serde(default)]
+    pub car_class: CarClass,
+    /// Paint of player one's car, picked in the garage
+    #[serde(default)]
+    pub car_color: CarColor,
+    /// Difficulty each best time was set on (level number -> difficulty), shown next to the time.
+    /// Levels missing from it were set on Normal.
+    #[serde(default)]
+    pub best_time_difficulty: HashMap<usize, Difficulty>,
+    /// Car class each best time was set in (level number -> class), shown next to the time.
+    /// Levels missing from it were set in the Balanced car.
+    #[serde(default)]
+    pub best_time_class: HashMap<usize, CarClass>,
+    /// Best combined time for the time trial sequence
+    #[serde(default)]
+    pub campaign_best: Option<f32>,
+    /// Difficulty the campaign best was set on (see `SequenceState::difficulty`)
+    #[serde(default)]
+    pub campaign_difficulty: Difficulty,
+    /// Car class the campaign best was set in
+    #[serde(default)]
+    pub campaign_car_class: CarClass,
+    /// Number of times each level was started (level number -> attempts)
+    #[serde(default)]
+    pub level_attempts: HashMap<usize, u32>,
+    /// Most coins collected in a run of each level (level number -> coins)
+    #[serde(default)]
+    pub best_coins: HashMap<usize, u32>,
+    /// Name of the file the save was read from, kept out of the file itself.
+    /// None for a save not yet written, which goes to the sanitized player name.
+    #[serde(skip)]
+    file_name: Option<String>,
+}
+
+/// What a time was set with, shown next to it. Times are only comparable on the same setup:
+/// each difficulty runs the off-road timer at its own rate, and each car class handles differently.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunSetup {
+    pub difficulty: Difficulty,
+    pub car_class: CarClass,
+}
+
+impl RunSetup {
+    /// Suffix marking a time set off the defaults, e.g. " (Hard, Speedster)".
+    /// Empty for Normal in the Balanced car.
+    pub fn time_suffix(&self) -> String {
+        let mut labels = Vec::new();
+        if self.difficulty != Difficulty::default() {
+            labels.push(self.difficulty.label());
+        }
+        if self.car_class != CarClass::default() {
+            labels.push(self.car_class.label());
+        }
+        if labels.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", labels.join(", "))
+        }
+    }
+}
+
+/// Outcome of recording a level completion
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompletionResult {
+    /// Best time for the level before this run (None on the first completion)
+    pub previous_best: Option<f32>,
+    /// Whether this run set a new best time
+    pub is_new_best: bool,
 }
 
 impl SaveData {
@@ -28,30 +106,151 @@ impl SaveData {
             level_times: HashMap::new(),
             created_at: now,
             last_played: now,
+            total_playtime_secs: 0.0,
+            races_completed: 0,
+            car_class: CarClass::default(),
+            car_color: CarColor::default(),
+            best_time_difficulty: HashMap::new(),
+            best_time_class: HashMap::new(),
+            campaign_best: None,
+            campaign_difficulty: Difficulty::default(),
+            campaign_car_class: CarClass::default(),
+            level_attempts: HashMap::new(),
+            best_coins: HashMap::new(),
+            file_name: None,
         }
     }
 
-    /// Records a level completion, updating best time if this is faster
-    /// Returns true if this was a new best time
-    pub fn record_level_completion(&mut self, level: usize, time: f32) -> bool {
+    /// Counts a fresh start of a level (resuming from pause isn't a new attempt)
+    pub fn record_level_attempt(&mut self, level: usize) {
+        *self.level_attempts.entry(level).or_insert(0) += 1;
+    }
+
+    /// Number of times a level was started
+    pub fn get_attempts(&self, level: usize) -> u32 {
+        self.level_attempts.get(&level).copied().unwrap_or(0)
+    }
+
+    /// Records a level completion in the save's car class, updating best time (and the setup it
+    /// was set with) if this is faster.
+    /// The next level unlocks on any finish, or only when `time` beats `unlock_target` if set.
+    /// Returns the previous best time alongside whether this was a new best.
+    pub fn record_level_completion(
+        &mut self,
+        level: usize,
+        time: f32,
+        difficulty: Difficulty,
+        unlock_target: Option<f32>,
+    ) -> CompletionResult {
         self.last_played = Utc::now();
+        self.races_completed += 1;
 
-        // Unlock next level if this is the highest completed
-        if level >= self.highest_level_unlocked {
+        // Unlock next level if this is the highest completed (and the target time was met)
+        let target_met = unlock_target.is_none_or(|target| time <= target);
+        if target_met && level >= self.highest_level_unlocked {
             self.highest_level_unlocked = level + 1;
         }
 
         // Update best time if this is faster (or first completion)
-        let is_new_best = match self.level_times.get(&level) {
-            Some(&best_time) => time < best_time,
+        let previous_best = self.level_times.get(&level).copied();
+        let is_new_best = match previous_best {
+            Some(best_time) => time < best_time,
             None => true,
         };
 
         if is_new_best {
             self.level_times.insert(level, time);
+            self.best_time_difficulty.insert(level, difficulty);
+            self.best_time_class.insert(level, self.car_class);
+        }
+
+        CompletionResult {
+            previous_best,
+            is_new_best,
         }
+    }
+
+    /// Records the coins collected in a run of a level, keeping the most.
+    /// Returns the previous best (None if no run collected any before).
+    pub fn record_coins(&mut self, level: usize, coins: u32) -> Option<u32> {
+        let previous_best = self.best_coins.get(&level).copied();
+        if previous_best.is_none_or(|best| coins > best) {
+            self.best_coins.insert(level, coins);
+        }
+        previous_best
+    }
 
-        is_new_best
+    /// Records a finished time trial sequence in the save's car class, keeping the best combined time.
+    /// Returns the previous campaign best alongside whether this was a new best.
+    pub fn record_campaign_time(&mut self, total_time: f32, difficulty: Difficulty) -> CompletionResult {
+        self.last_played = Utc::now();
+
+        let previous_best = self.campaign_best;
+        let is_new_best = previous_best.is_none_or(|best| total_time < best);
+        if is_new_best {
+            self.campaign_best = Some(total_time);
+            self.campaign_difficulty = difficulty;
+            self.campaign_car_class = self.car_class;
+        }
+
+        CompletionResult {
+            previous_best,
+            is_new_best,
+        }
+    }
+
+    /// Repairs fields a hand-edited or corrupted save could get out of range:
+    /// levels start at 1, and times must be positive (playtime can be zero).
+    /// Returns true if anything was changed.
+    pub fn validate(&mut self) -> bool {
+        let original_len = self.level_times.len();
+        let mut repaired = false;
+
+        if self.highest_level_unlocked < 1 {
+            self.highest_level_unlocked = 1;
+            repaired = true;
+        }
+
+        self.level_times
+            .retain(|&level, &mut time| level >= 1 && time.is_finite() && time > 0.0);
+        repaired |= self.level_times.len() != original_len;
+        self.best_time_difficulty.retain(|level, _| self.level_times.contains_key(level));
+        self.best_time_class.retain(|level, _| self.level_times.contains_key(level));
+
+        let original_attempts = self.level_attempts.len();
+        self.level_attempts.retain(|&level, _| level >= 1);
+        repaired |= self.level_attempts.len() != original_attempts;
+
+        let original_coins = self.best_coins.len();
+        self.best_coins.retain(|&level, _| level >= 1);
+        repaired |= self.best_coins.len() != original_coins;
+
+        if self.campaign_best.is_some_and(|time| !time.is_finite() || time <= 0.0) {
+            self.campaign_best = None;
+            repaired = true;
+        }
+
+        if !self.total_playtime_secs.is_finite() || self.total_playtime_secs < 0.0 {
+            self.total_playtime_secs = 0.0;
+            repaired = true;
+        }
+
+        repaired
+    }
+
+    /// Adds real gameplay time to the total playtime
+    pub fn add_playtime(&mut self, seconds: f32) {
+        self.total_playtime_secs += seconds;
+    }
+
+    /// Wipes level progress (best times and unlocked levels), keeping the profile itself
+    pub fn reset_progress(&mut self) {
+        self.level_times.clear();
+        self.best_time_difficulty.clear();
+        self.best_time_class.clear();
+        self.highest_level_unlocked = 1;
+        self.campaign_best = None;
+        self.best_coins.clear();
     }
 
     /// Gets the best time for a level, if any
@@ -60,18 +259,116 @@ impl SaveData {
         self.level_times.get(&level).copied()
     }
 
-    /// Generates a safe filename from the player name
+    /// Gets the best time for a level with the setup it was set with, if any
+    pub fn get_best_run(&self, level: usize) -> Option<(f32, RunSetup)> {
+        let setup = RunSetup {
+            difficulty: self.best_time_difficulty.get(&level).copied().unwrap_or_default(),
+            car_class: self.best_time_class.get(&level).copied().unwrap_or_default(),
+        };
+        self.get_best_time(level).map(|time| (time, setup))
+    }
+
+    /// The setup the campaign best was set with
+    pub fn campaign_setup(&self) -> RunSetup {
+        RunSetup {
+            difficulty: self.campaign_difficulty,
+            car_class: self.campaign_car_class,
+        }
+    }
+
+    /// File the save is kept in: the one it was read from, or else a safe filename
+    /// generated from the player name
     pub fn filename(&self) -> String {
-        format!("{}.json", sanitize_filename(&self.player_name))
+        self.file_name
+            .clone()
+            .unwrap_or_else(|| format!("{}.json", sanitize_filename(&self.player_name)))
+    }
+
+    /// `filename` without its extension, naming the save's other files and records
+    /// (see `get_analysis_dir`)
+    pub fn file_stem(&self) -> String {
+        let file_name = self.filename();
+        file_name.strip_suffix(".json").unwrap_or(&file_name).to_string()
+    }
+
+    /// Remembers the file the save was read from, so it's written back to the same file
+    pub fn set_filename(&mut self, file_name: String) {
+        self.file_name = Some(file_name);
     }
 }
 
-/// Sanitizes a string for use as a filename by replacing invalid characters with underscores.
-/// Keeps alphanumeric characters, underscores, and hyphens.
+/// Sanitizes a string for use as an ASCII filename. Keeps ASCII letters, digits, underscores
+/// and hyphens, spells accented Latin letters without the accent ("José" becomes "Jose"),
+/// writes other letters as their code point ("Ж" becomes "u0416") so non-Latin names stay
+/// distinct, and replaces anything else with an underscore.
+/// The name shown in the game is kept as typed; only the file it's saved to is ASCII.
 pub fn sanitize_filename(name: &str) -> String {
-    name.chars()
-        .map(|c| if c.is_alphanumeric() || c == '_' || c == '-' { c } else { '_' })
-        .collect()
+    let mut filename = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' || c == '-' {
+            filename.push(c);
+        } else if let Some(ascii) = transliterate(c) {
+            filename.push_str(ascii);
+        } else if c.is_alphanumeric() {
+            filename.push_str(&format!("u{:04x}", c as u32));
+        } else {
+            filename.push('_');
+        }
+    }
+    filename
+}
+
+/// ASCII spelling of an accented or joined Latin letter (Latin-1 and Latin Extended-A)
+fn transliterate(c: char) -> Option<&'static str> {
+    let ascii = match c {
+        'À'..='Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+        'à'..='å' | 'ā' | 'ă' | 'ą' => "a",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => "C",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'Ð' | 'Ď' | 'Đ' => "D",
+        'ð' | 'ď' | 'đ' => "d",
+        'È'..='Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => "E",
+        'è'..='ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => "G",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'Ĥ' | 'Ħ' => "H",
+        'ĥ' | 'ħ' => "h",
+        'Ì'..='Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => "I",
+        'ì'..='ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'Ĵ' => "J",
+        'ĵ' => "j",
+        'Ķ' => "K",
+        'ķ' => "k",
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => "L",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => "N",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'Ò'..='Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => "O",
+        'ò'..='ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Ŕ' | 'Ŗ' | 'Ř' => "R",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' => "S",
+        'ś' | 'ŝ' | 'ş' | 'š' => "s",
+        'ß' => "ss",
+        'Ţ' | 'Ť' | 'Ŧ' => "T",
+        'ţ' | 'ť' | 'ŧ' => "t",
+        'Þ' => "TH",
+        'þ' => "th",
+        'Ù'..='Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => "U",
+        'ù'..='ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'Ŵ' => "W",
+        'ŵ' => "w",
+        'Ý' | 'Ŷ' | 'Ÿ' => "Y",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'Ź' | 'Ż' | 'Ž' => "Z",
+        'ź' | 'ż' | 'ž' => "z",
+        _ => return None,
+    };
+    Some(ascii)
 }
 
 /// Resource to track the currently active save game
@@ -101,3 +398,178 @@ impl CurrentSave {
         self.0 = None;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filenames_are_ascii_for_any_name() {
+        assert_eq!(sanitize_filename("José Ñúñez"), "Jose_Nunez");
+        assert_eq!(sanitize_filename("Straße-Œuvre"), "Strasse-OEuvre");
+        // Non-Latin names keep distinct filenames instead of collapsing to underscores
+        assert_eq!(sanitize_filename("Иван"), "u0418u0432u0430u043d");
+        assert_ne!(sanitize_filename("太郎"), sanitize_filename("花子"));
+        assert_eq!(sanitize_filename("a/b🚗"), "a_b_");
+
+        // The player name itself is kept as typed
+        let save = SaveData::new("José".to_string());
+        assert_eq!(save.player_name, "José");
+        assert_eq!(save.filename(), "Jose.json");
+    }
+
+    #[test]
+    fn test_completion_counts_every_race() {
+        let mut save = SaveData::new("Tester".to_string());
+
+        save.record_level_completion(1, 30.0, Difficulty::Normal, None);
+        save.record_level_completion(1, 40.0, Difficulty::Normal, None); // Slower repeat still counts
+        save.record_level_completion(2, 25.0, Difficulty::Normal, None);
+
+        assert_eq!(save.races_completed, 3);
+    }
+
+    #[test]
+    fn test_completion_reports_previous_best() {
+        let mut save = SaveData::new("Tester".to_string());
+
+        let first = save.record_level_completion(1, 45.0, Difficulty::Normal, None);
+        assert_eq!(first, CompletionResult { previous_best: None, is_new_best: true });
+
+        let faster = save.record_level_completion(1, 43.5, Difficulty::Normal, None);
+        assert_eq!(faster, CompletionResult { previous_best: Some(45.0), is_new_best: true });
+
+        let slower = save.record_level_completion(1, 50.0, Difficulty::Normal, None);
+        assert_eq!(slower, CompletionResult { previous_best: Some(43.5), is_new_best: false });
+        assert_eq!(save.get_best_time(1), Some(43.5));
+    }
+
+    #[test]
+    fn test_unlock_target_gates_next_level() {
+        let mut save = SaveData::new("Tester".to_string());
+
+        save.record_level_completion(1, 30.0, Difficulty::Normal, Some(25.0));
+        assert_eq!(save.highest_level_unlocked, 1);
+
+        save.record_level_completion(1, 24.0, Difficulty::Normal, Some(25.0));
+        assert_eq!(save.highest_level_unlocked, 2);
+
+    }
+
+    #[test]
+    fn test_best_time_keeps_its_setup() {
+        let mut save = SaveData::new("Tester".to_string());
+        let hard = RunSetup { difficulty: Difficulty::Hard, car_class: CarClass::Balanced };
+        let casual_speedster = RunSetup { difficulty: Difficulty::Casual, car_class: CarClass::Speedster };
+
+        save.record_level_completion(1, 30.0, Difficulty::Hard, None);
+        assert_eq!(save.get_best_run(1), Some((30.0, hard)));
+
+        // A slower run doesn't change it, a faster one on another setup does
+        save.car_class = CarClass::Speedster;
+        save.record_level_completion(1, 35.0, Difficulty::Casual, None);
+        assert_eq!(save.get_best_run(1), Some((30.0, hard)));
+        save.record_level_completion(1, 25.0, Difficulty::Casual, None);
+        assert_eq!(save.get_best_run(1), Some((25.0, casual_speedster)));
+        assert_eq!(casual_speedster.time_suffix(), " (Casual, Speedster)");
+        assert_eq!(RunSetup::default().time_suffix(), "");
+    }
+
+    #[test]
+    fn test_campaign_best_keeps_fastest_sequence() {
+        let mut save = SaveData::new("Tester".to_string());
+
+        save.record_campaign_time(120.0, Difficulty::Normal);
+        let slower = save.record_campaign_time(130.0, Difficulty::Normal);
+        assert_eq!(slower, CompletionResult { previous_best: Some(120.0), is_new_best: false });
+
+        let faster = save.record_campaign_time(110.0, Difficulty::Normal);
+        assert!(faster.is_new_best);
+        assert_eq!(save.campaign_best, Some(110.0));
+    }
+
+    #[test]
+    fn test_validate_repairs_out_of_range_fields() {
+        let json = r#"{
+            "player_name": "Broken",
+            "highest_level_unlocked": 0,
+            "level_times": {"0": 20.0, "1": 30.0, "2": 0.0, "3": -5.0},
+            "created_at": "2024-01-01T00:00:00Z",
+            "last_played": "2024-01-01T00:00:00Z",
+            "total_playtime_secs": -10.0,
+            "campaign_best": -1.0
+        }"#;
+        let mut save: SaveData = serde_json::from_str(json).unwrap();
+
+        assert!(save.validate());
+
+        assert_eq!(save.highest_level_unlocked, 1);
+        assert_eq!(save.level_times, HashMap::from([(1, 30.0)]));
+        assert_eq!(save.total_playtime_secs, 0.0);
+        assert_eq!(save.campaign_best, None);
+    }
+
+    #[test]
+    fn test_validate_keeps_valid_save() {
+        let mut save = SaveData::new("Tester".to_string());
+        save.record_level_completion(1, 30.0, Difficulty::Normal, None);
+        save.record_campaign_time(150.0, Difficulty::Normal);
+
+        assert!(!save.validate());
+        assert_eq!(save.highest_level_unlocked, 2);
+        assert_eq!(save.get_best_time(1), Some(30.0));
+    }
+
+    #[test]
+    fn test_playtime_accumulates() {
+        let mut save = SaveData::new("Tester".to_string());
+
+        save.add_playtime(1.5);
+        save.add_playtime(2.25);
+
+        assert!((save.total_playtime_secs - 3.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_best_coins_keeps_the_most() {
+        let mut save = SaveData::new("Tester".to_string());
+        assert_eq!(save.record_coins(1, 3), None);
+        assert_eq!(save.record_coins(1, 2), Some(3));
+        assert_eq!(save.record_coins(1, 5), Some(3));
+        assert_eq!(save.best_coins.get(&1), Some(&5));
+    }
+
+    #[test]
+    fn test_reset_progress_keeps_profile() {
+        let mut save = SaveData::new("Tester".to_string());
+        let created_at = save.created_at;
+        save.record_level_completion(1, 30.0, Difficulty::Normal, None);
+        save.record_level_completion(2, 25.0, Difficulty::Normal, None);
+
+        save.reset_progress();
+
+        assert!(save.level_times.is_empty());
+        assert_eq!(save.highest_level_unlocked, 1);
+        assert_eq!(save.player_name, "Tester");
+        assert_eq!(save.created_at, created_at);
+    }
+
+    #[test]
+    fn test_old_saves_default_stats() {
+        // Save written before the stats fields existed
+        let json = r#"{
+            "player_name": "Old",
+            "highest_level_unlocked": 2,
+            "level_times": {"1": 30.0},
+            "created_at": "2024-01-01T00:00:00Z",
+            "last_played": "2024-01-01T00:00:00Z"
+        }"#;
+
+        let save: SaveData = serde_json::from_str(json).unwrap();
+
+        assert_eq!(save.races_completed, 0);
+        assert_eq!(save.total_playtime_secs, 0.0);
+        assert_eq!(save.car_class, CarClass::Balanced);
+        assert_eq!(save.get_attempts(1), 0);
+    }
+}
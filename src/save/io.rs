@@ -1,21 +1,153 @@
-use directories::ProjectDirs;
+use bevy::prelude::*;
 use std::fs;
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
 
-use super::{SaveData, sanitize_filename};
+use super::{RaceInProgress, SaveData, sanitize_filename};
 
-/// Gets the save directory for the game, creating it if necessary
-fn get_save_dir() -> io::Result<PathBuf> {
-    let project_dirs = ProjectDirs::from("com", "BevyDriver", "BevyDriver")
-        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not determine save directory"))?;
+/// File in the data directory marking a race in progress (see `RaceInProgress`)
+const RACE_MARKER_FILENAME: &str = "race_in_progress.json";
+
+/// Counts the writes and deletes in the save directory, so `SaveCache` knows to re-read it
+static SAVES_GENERATION: AtomicU64 = AtomicU64::new(0);
 
-    let save_dir = project_dirs.data_dir().join("saves");
+/// Gets the save directory for the game, creating it if necessary
+pub(super) fn get_save_dir() -> io::Result<PathBuf> {
+    let save_dir = data_dir()?.join("saves");
     fs::create_dir_all(&save_dir)?;
     Ok(save_dir)
 }
 
-/// Saves game data to a JSON file
+/// Gets the directory exported result cards are written to, creating it if necessary
+pub fn get_cards_dir() -> io::Result<PathBuf> {
+    let cards_dir = data_dir()?.join("cards");
+    fs::create_dir_all(&cards_dir)?;
+    Ok(cards_dir)
+}
+
+/// Gets the directory a save's per-level run analysis is kept in (see `analysis::io`),
+/// creating it if necessary. It's named after the save's file, so players whose names
+/// share a file name each keep their own.
+pub fn get_analysis_dir(save_data: &SaveData) -> io::Result<PathBuf> {
+    let analysis_dir = player_analysis_dir(&save_data.file_stem())?;
+    fs::create_dir_all(&analysis_dir)?;
+    Ok(analysis_dir)
+}
+
+/// Analysis directory for the save file named `file_stem`
+fn player_analysis_dir(file_stem: &str) -> io::Result<PathBuf> {
+    Ok(data_dir()?.join("analysis").join(file_stem))
+}
+
+/// The game's data directory for this user
+#[cfg(not(test))]
+pub fn data_dir() -> io::Result<PathBuf> {
+    let project_dirs = directories::ProjectDirs::from("com", "BevyDriver", "BevyDriver")
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not determine save directory"))?;
+    Ok(project_dirs.data_dir().to_path_buf())
+}
+
+/// Tests use the directory of the `ScratchDataDir` they hold, never the player's data.
+/// Without one there's no data directory, so a test can't write anywhere by mistake.
+#[cfg(test)]
+pub fn data_dir() -> io::Result<PathBuf> {
+    scratch::current()
+}
+
+#[cfg(test)]
+pub use scratch::ScratchDataDir;
+
+#[cfg(test)]
+mod scratch {
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Mutex, MutexGuard, PoisonError};
+
+    /// Held by the test using the data directory, as the whole process shares it
+    static DATA_DIR_LOCK: Mutex<()> = Mutex::new(());
+    /// The directory of the `ScratchDataDir` being held. Not thread-local, as the systems
+    /// a test runs may read it from other threads.
+    static DATA_DIR: Mutex<Option<PathBuf>> = Mutex::new(None);
+    /// Numbers the directories, so each test starts from an empty one
+    static NEXT_DIR: AtomicU64 = AtomicU64::new(0);
+
+    /// An empty data directory for one test, removed with everything written to it when
+    /// dropped. Tests holding one run one at a time.
+    pub struct ScratchDataDir {
+        path: PathBuf,
+        _lock: MutexGuard<'static, ()>,
+    }
+
+    impl ScratchDataDir {
+        pub fn new() -> Self {
+            // A test failing while holding it doesn't affect the next one
+            let lock = DATA_DIR_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+            let path = std::env::temp_dir().join(format!(
+                "bevy_driver_test_{}_{}",
+                std::process::id(),
+                NEXT_DIR.fetch_add(1, Ordering::Relaxed)
+            ));
+            let _ = fs::remove_dir_all(&path);
+            fs::create_dir_all(&path).unwrap();
+            *DATA_DIR.lock().unwrap_or_else(PoisonError::into_inner) = Some(path.clone());
+            Self { path, _lock: lock }
+        }
+
+        pub fn path(&self) -> &Path {
+            &self.path
+        }
+    }
+
+    impl Drop for ScratchDataDir {
+        fn drop(&mut self) {
+            *DATA_DIR.lock().unwrap_or_else(PoisonError::into_inner) = None;
+            let _ = fs::remove_dir_all(&self.path);
+        }
+    }
+
+    /// The directory of the `ScratchDataDir` being held
+    pub fn current() -> io::Result<PathBuf> {
+        DATA_DIR
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No scratch data directory is held"))
+    }
+}
+
+/// Path of the temporary file a save is written to before being renamed into place.
+/// The `.tmp` extension keeps it out of `list_saves`.
+fn temp_path(file_path: &Path) -> PathBuf {
+    file_path.with_extension("json.tmp")
+}
+
+/// Writes the contents to the temporary file next to `file_path`
+fn write_temp(file_path: &Path, contents: &str) -> io::Result<PathBuf> {
+    let temp_file = temp_path(file_path);
+    fs::write(&temp_file, contents)?;
+    Ok(temp_file)
+}
+
+/// Writes a file by writing a temporary file and renaming it over the target,
+/// so a crash mid-write never leaves a half-written file behind.
+/// If the rename fails the old file is left intact and the temporary file is removed.
+pub fn write_atomically(file_path: &Path, contents: &str) -> io::Result<()> {
+    let temp_file = write_temp(file_path, contents)?;
+
+    if let Err(e) = fs::rename(&temp_file, file_path) {
+        let _ = fs::remove_file(&temp_file);
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Saves game data to a JSON file.
+/// Each save's file is its own, picked when the save was created (see `create_save`)
+/// or read, so the file is written without checking whose it is.
 pub fn save_to_file(save_data: &SaveData) -> io::Result<()> {
     let save_dir = get_save_dir()?;
     let file_path = save_dir.join(save_data.filename());
@@ -23,8 +155,23 @@ pub fn save_to_file(save_data: &SaveData) -> io::Result<()> {
     let json = serde_json::to_string_pretty(save_data)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
-    fs::write(file_path, json)?;
-    Ok(())
+    let result = write_atomically(&file_path, &json);
+    SAVES_GENERATION.fetch_add(1, Ordering::Relaxed);
+    result
+}
+
+/// Parses a save file's contents, repairing any out-of-range fields
+fn parse_save(json: &str, file_path: &Path) -> io::Result<SaveData> {
+    let mut save_data: SaveData = serde_json::from_str(json)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    if save_data.validate() {
+        warn!("Repaired invalid fields in save file {}", file_path.display());
+    }
+    if let Some(file_name) = file_path.file_name().and_then(|name| name.to_str()) {
+        save_data.set_filename(file_name.to_string());
+    }
+    Ok(save_data)
 }
 
 /// Loads game data from a JSON file by player name
@@ -32,18 +179,20 @@ pub fn load_from_file(filename: &str) -> io::Result<SaveData> {
     let save_dir = get_save_dir()?;
     let file_path = save_dir.join(filename);
 
-    let json = fs::read_to_string(file_path)?;
-    let save_data: SaveData = serde_json::from_str(&json)
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-
-    Ok(save_data)
+    let json = fs::read_to_string(&file_path)?;
+    parse_save(&json, &file_path)
 }
 
-/// Deletes a save file
+/// Deletes a save file, along with the player's run analysis
 pub fn delete_save_file(filename: &str) -> io::Result<()> {
     let save_dir = get_save_dir()?;
     let file_path = save_dir.join(filename);
-    fs::remove_file(file_path)?;
+    fs::remove_file(&file_path)?;
+    SAVES_GENERATION.fetch_add(1, Ordering::Relaxed);
+    if let Some(file_stem) = file_path.file_stem().and_then(|stem| stem.to_str()) {
+        // Players without any analysis have no directory
+        let _ = fs::remove_dir_all(player_analysis_dir(file_stem)?);
+    }
     Ok(())
 }
 
@@ -62,7 +211,7 @@ pub fn list_saves() -> io::Result<Vec<SaveData>> {
 
         if path.extension().is_some_and(|ext| ext == "json") {
             if let Ok(json) = fs::read_to_string(&path) {
-                if let Ok(save_data) = serde_json::from_str::<SaveData>(&json) {
+                if let Ok(save_data) = parse_save(&json, &path) {
                     saves.push(save_data);
                 }
             }
@@ -75,12 +224,191 @@ pub fn list_saves() -> io::Result<Vec<SaveData>> {
     Ok(saves)
 }
 
-/// Checks if a save file exists for the given player name
-pub fn save_exists(player_name: &str) -> bool {
+/// Path of the race marker file, creating the data directory if necessary
+fn race_marker_path() -> io::Result<PathBuf> {
+    let dir = data_dir()?;
+    fs::create_dir_all(&dir)?;
+    Ok(dir.join(RACE_MARKER_FILENAME))
+}
+
+/// Writes the marker of the race in progress, replacing the last one
+pub fn save_race_marker(race: &RaceInProgress) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(race)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    write_atomically(&race_marker_path()?, &json)
+}
+
+/// Reads the marker left by a race still in progress when the game last closed
+/// (None if there isn't one, or it can't be read)
+pub fn load_race_marker() -> Option<RaceInProgress> {
+    let json = fs::read_to_string(race_marker_path().ok()?).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+/// Removes the race marker. There being none already is fine.
+pub fn delete_race_marker() -> io::Result<()> {
+    match fs::remove_file(race_marker_path()?) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+/// Identifies the save directory's contents: the writes and deletes made by the game, and the
+/// directory's modified time for changes made outside it. Equal stamps mean nothing changed.
+pub fn saves_stamp() -> (u64, Option<SystemTime>) {
+    let modified = get_save_dir()
+        .and_then(fs::metadata)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+    (SAVES_GENERATION.load(Ordering::Relaxed), modified)
+}
+
+/// Renames the saves kept under an older file name (such as an accented name saved before
+/// file names were made ASCII) to an ASCII one. Run once as the game starts, before any save
+/// is listed. When another save already has the new name, a numbered one is used instead
+/// (see `free_file_name`), so both saves keep their own file.
+pub fn migrate_save_file_names() {
+    let Ok(entries) = get_save_dir().and_then(fs::read_dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        if path.extension().is_none_or(|ext| ext != "json") || sanitize_filename(stem) == stem {
+            continue;
+        }
+        let Some(player_name) = stored_player_name(&path) else {
+            continue;
+        };
+        let Some(save_dir) = path.parent() else {
+            continue;
+        };
+        let new_path = save_dir.join(free_file_name(save_dir, &player_name));
+        match fs::rename(&path, &new_path) {
+            Ok(()) => info!("Renamed save file {} to {}", path.display(), new_path.display()),
+            Err(e) => warn!("Failed to rename save file {}: {}", path.display(), e),
+        }
+    }
+}
+
+/// First file name for the player's save not already used in the save directory:
+/// the sanitized name, then the same with `_2`, `_3`... appended
+fn free_file_name(save_dir: &Path, player_name: &str) -> String {
+    let stem = sanitize_filename(player_name);
+    (1..)
+        .map(|n| match n {
+            1 => format!("{}.json", stem),
+            n => format!("{}_{}.json", stem, n),
+        })
+        .find(|file_name| !save_dir.join(file_name).exists())
+        .expect("some numbered file name is free")
+}
+
+/// Player name stored in a save file (None if it's missing or unreadable)
+fn stored_player_name(file_path: &Path) -> Option<String> {
+    let json = fs::read_to_string(file_path).ok()?;
+    parse_save(&json, file_path).ok().map(|save_data| save_data.player_name)
+}
+
+/// Creates the save for a new player, in a file no other save uses. Names that sanitize
+/// to the same file get a numbered one (e.g. "John Doe" keeps `John_Doe.json` and a later
+/// "John_Doe" gets `John_Doe_2.json`, see `free_file_name`).
+pub fn create_save(player_name: String) -> SaveData {
+    let mut save_data = SaveData::new(player_name);
     if let Ok(save_dir) = get_save_dir() {
-        let file_path = save_dir.join(format!("{}.json", sanitize_filename(player_name)));
-        file_path.exists()
-    } else {
-        false
+        save_data.set_filename(free_file_name(&save_dir, &save_data.player_name));
+    }
+    save_data
+}
+
+/// Checks if a save exists for exactly the given player name
+pub fn save_exists(player_name: &str) -> bool {
+    list_saves().is_ok_and(|saves| saves.iter().any(|save| save.player_name == player_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::components::LevelAnalysis;
+    use crate::analysis::io::{load_level_analysis, save_level_analysis};
+
+    #[test]
+    fn test_interrupted_write_leaves_original_intact() {
+        let data_dir = ScratchDataDir::new();
+        let file_path = data_dir.path().join("player.json");
+        fs::write(&file_path, "original").unwrap();
+
+        // Simulate a crash after writing the temp file but before the rename
+        write_temp(&file_path, "partial").unwrap();
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), "original");
+
+        write_atomically(&file_path, "updated").unwrap();
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), "updated");
+        assert!(!temp_path(&file_path).exists());
+    }
+
+    #[test]
+    fn test_colliding_names_do_not_overwrite_each_other() {
+        let _data_dir = ScratchDataDir::new();
+        let first = create_save("Collision Test".to_string());
+        save_to_file(&first).unwrap();
+        assert!(save_exists("Collision Test"));
+        assert!(!save_exists("Collision_Test"));
+
+        // Created after the first was written, so it gets a file of its own
+        let second = create_save("Collision_Test".to_string());
+        assert_eq!(first.filename(), "Collision_Test.json");
+        assert_eq!(second.filename(), "Collision_Test_2.json");
+        save_to_file(&second).unwrap();
+
+        assert_eq!(load_from_file(&first.filename()).unwrap().player_name, "Collision Test");
+        assert_eq!(load_from_file(&second.filename()).unwrap().player_name, "Collision_Test");
+    }
+
+    #[test]
+    fn test_migration_keeps_both_colliding_saves() {
+        let _data_dir = ScratchDataDir::new();
+        let save_dir = get_save_dir().unwrap();
+        let existing = SaveData::new("Migrate Test".to_string());
+        save_to_file(&existing).unwrap();
+        // Saved under its accented name, before file names were made ASCII
+        let legacy = SaveData::new("Migrate Tést".to_string());
+        let json = serde_json::to_string(&legacy).unwrap();
+        fs::write(save_dir.join("Migrate Tést.json"), json).unwrap();
+
+        migrate_save_file_names();
+        assert!(!save_dir.join("Migrate Tést.json").exists());
+        let saves = list_saves().unwrap();
+        let file_of = |name: &str| saves.iter().find(|save| save.player_name == name).unwrap().filename();
+        assert_eq!(file_of("Migrate Test"), "Migrate_Test.json");
+        assert_eq!(file_of("Migrate Tést"), "Migrate_Test_2.json");
+
+        // Each save is written back to its own file
+        let mut moved = load_from_file("Migrate_Test_2.json").unwrap();
+        moved.races_completed = 3;
+        save_to_file(&moved).unwrap();
+        assert_eq!(load_from_file("Migrate_Test_2.json").unwrap().races_completed, 3);
+        assert_eq!(load_from_file("Migrate_Test.json").unwrap().races_completed, 0);
+    }
+
+    #[test]
+    fn test_deleting_a_colliding_save_keeps_the_others_analysis() {
+        let _data_dir = ScratchDataDir::new();
+        let first = create_save("Analysis Test".to_string());
+        save_to_file(&first).unwrap();
+        let second = create_save("Analysis_Test".to_string());
+        save_to_file(&second).unwrap();
+        for (save, runs) in [(&first, 1), (&second, 2)] {
+            let analysis = LevelAnalysis { runs, ..default() };
+            save_level_analysis(save, 1, &analysis).unwrap();
+        }
+
+        delete_save_file(&first.filename()).unwrap();
+        assert!(!player_analysis_dir("Analysis_Test").unwrap().exists());
+        assert_eq!(load_level_analysis(&second, 1).runs, 2);
+
+        delete_save_file(&second.filename()).unwrap();
+        assert!(!player_analysis_dir("Analysis_Test_2").unwrap().exists());
     }
 }
@@ -1,5 +1,40 @@
+mod cache;
 mod data;
 mod io;
+mod recovery;
+mod status;
 
+pub use cache::*;
 pub use data::*;
 pub use io::*;
+pub use recovery::*;
+pub use status::*;
+
+use bevy::prelude::*;
+use crate::constants::GameState;
+
+/// Reports saves that couldn't be written with a toast on whatever screen is showing,
+/// and keeps the game going with the save in memory.
+/// Also marks races in progress, so one a crash cut short is offered on the next launch.
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        // Before any save is listed, so every save is read from the file it's kept in
+        migrate_save_file_names();
+        app.add_message::<SaveResult>()
+            .init_resource::<SaveStatus>()
+            .init_resource::<SaveCache>()
+            // Read before the start menu is first shown
+            .insert_resource(InterruptedRace(load_race_marker()))
+            .init_resource::<RaceMarkerTimer>()
+            .add_systems(Update, (show_save_toast, update_save_toast).chain())
+            .add_systems(Update, mark_race_in_progress.run_if(in_state(GameState::Playing)))
+            // A race ends on the level complete screen, or is abandoned to either menu.
+            // The interrupted race was read already, so the start menu still offers it.
+            .add_systems(OnEnter(GameState::LevelComplete), clear_race_marker)
+            .add_systems(OnEnter(GameState::LevelMenu), (clear_race_marker, forget_interrupted_race))
+            .add_systems(OnEnter(GameState::StartMenu), clear_race_marker)
+            .add_systems(Last, clear_race_marker.run_if(on_message::<AppExit>));
+    }
+}
@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{delete_race_marker, save_race_marker, CurrentSave, SaveStatus};
+use crate::constants::{CurrentLevel, PracticeMode, SeedOverride, TwoPlayerMode};
+use crate::hud::components::RaceState;
+use crate::sequence::components::SequenceState;
+use crate::settings::components::Difficulty;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Seconds of racing between rewrites of the race marker
+const RACE_MARKER_INTERVAL_SECS: f32 = 1.0;
+
+// ============================================================================
+// Components & Resources
+// ============================================================================
+
+/// Marker of a race in progress, kept apart from the save in `race_in_progress.json` in the
+/// data directory while a race runs. The game removes it whenever a race ends or it exits
+/// cleanly, so one found on launch means the last session crashed mid-race. For example:
+///
+/// ```json
+/// { "save_file": "Alice", "level": 7, "seed": null, "elapsed_secs": 12.5, "difficulty": "Hard" }
+/// ```
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RaceInProgress {
+    /// Save the race was played on, by its file stem (see `SaveData::file_stem`), which tells
+    /// apart profiles with colliding names
+    pub save_file: String,
+    pub level: usize,
+    /// Seed picked on the track preview, when it replaced the level's own (see `SeedOverride`)
+    pub seed: Option<u64>,
+    /// Race time when the marker was last written
+    pub elapsed_secs: f32,
+    /// Time trial the race was part of, with the levels finished before it
+    #[serde(default)]
+    pub sequence: Option<SequenceState>,
+    /// Whether both players were racing (see `TwoPlayerMode`)
+    #[serde(default)]
+    pub two_player: bool,
+    /// Difficulty the race was started on (see `RaceState::difficulty`)
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+/// The race the last session crashed during, offered to resume on the start menu.
+/// Read once as the game starts, and forgotten once another game is picked.
+#[derive(Resource, Default)]
+pub struct InterruptedRace(pub Option<RaceInProgress>);
+
+/// Resource timing the rewrites of the race marker during gameplay
+#[derive(Resource)]
+pub struct RaceMarkerTimer(pub Timer);
+
+impl Default for RaceMarkerTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(RACE_MARKER_INTERVAL_SECS, TimerMode::Repeating))
+    }
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Keeps the race marker up to date while racing, so a crash can be recovered from.
+/// Practice runs aren't marked (they never touch the save), and neither are races once
+/// saving has failed (see `SaveStatus`).
+pub fn mark_race_in_progress(
+    time: Res<Time>,
+    mut timer: ResMut<RaceMarkerTimer>,
+    (practice, two_player, status): (Res<PracticeMode>, Res<TwoPlayerMode>, Res<SaveStatus>),
+    (current_level, seed_override): (Res<CurrentLevel>, Res<SeedOverride>),
+    (current_save, sequence): (Res<CurrentSave>, Res<SequenceState>),
+    race_state: Option<Res<RaceState>>,
+) {
+    if practice.0 || status.in_memory_only || !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+    let Some(save_data) = current_save.get() else {
+        return;
+    };
+    let level = current_level.0;
+    let race = RaceInProgress {
+        save_file: save_data.file_stem(),
+        level,
+        seed: seed_override.replaces(level).then(|| seed_override.seed_for(level)).flatten(),
+        elapsed_secs: race_state.as_ref().map(|state| state.stopwatch.elapsed_secs()).unwrap_or_default(),
+        sequence: sequence.is_active().then(|| sequence.clone()),
+        two_player: two_player.0,
+        difficulty: race_state.map(|state| state.difficulty).unwrap_or_default(),
+    };
+    if let Err(e) = save_race_marker(&race) {
+        warn!("Failed to write the race marker: {}", e);
+    }
+}
+
+/// Removes the race marker once a race is over (finished or abandoned to a menu),
+/// or the game exits cleanly
+pub fn clear_race_marker() {
+    if let Err(e) = delete_race_marker() {
+        warn!("Failed to remove the race marker: {}", e);
+    }
+}
+
+/// Stops offering the interrupted race once another game has been picked
+pub fn forget_interrupted_race(mut interrupted: ResMut<InterruptedRace>) {
+    interrupted.0 = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::{load_race_marker, ScratchDataDir};
+
+    #[test]
+    fn test_race_marker_round_trips_until_cleared() {
+        let _data_dir = ScratchDataDir::new();
+        let race = RaceInProgress {
+            save_file: "Marker_Test".to_string(),
+            level: 7,
+            seed: Some(42),
+            elapsed_secs: 12.5,
+            sequence: Some(SequenceState {
+                levels: vec![7, 8],
+                level_times: vec![30.25],
+                is_valid: true,
+                is_ranked: false,
+                difficulty: Some(Difficulty::Hard),
+            }),
+            two_player: true,
+            difficulty: Difficulty::Hard,
+        };
+        save_race_marker(&race).unwrap();
+        assert_eq!(load_race_marker(), Some(race));
+
+        delete_race_marker().unwrap();
+        assert_eq!(load_race_marker(), None);
+        // Clearing again, with no marker left, is fine
+        delete_race_marker().unwrap();
+    }
+}
@@ -0,0 +1,179 @@
+use bevy::prelude::*;
+
+use super::{save_to_file, SaveData};
+use crate::styles::colors::{ERROR_TEXT_COLOR, OVERLAY_BACKGROUND_COLOR};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Seconds the "couldn't save" toast stays on screen
+const SAVE_TOAST_DURATION: f32 = 4.0;
+const SAVE_TOAST_FONT_SIZE: f32 = 18.0;
+const SAVE_TOAST_MARGIN: f32 = 20.0;
+const SAVE_TOAST_PADDING: f32 = 10.0;
+/// Above menus and overlays, so the toast shows on every screen
+const SAVE_TOAST_Z_INDEX: i32 = 20;
+
+// ============================================================================
+// Components & Resources
+// ============================================================================
+
+/// Message sent after each attempt to write the save, with the reason when it failed
+#[derive(Message)]
+pub struct SaveResult(pub Result<(), String>);
+
+/// Whether the save is still being written to disk.
+/// After a failed write the game carries on with the save kept in memory only,
+/// and the periodic auto-save stops trying until another save is written.
+#[derive(Resource, Default)]
+pub struct SaveStatus {
+    pub in_memory_only: bool,
+}
+
+/// Component for the toast reporting a failed save (of the progress or a result card).
+/// Counts down the seconds it stays on screen.
+#[derive(Component)]
+pub struct SaveToast {
+    pub remaining: f32,
+}
+
+// ============================================================================
+// Saving
+// ============================================================================
+
+/// Writes the save, reporting a failure to the player rather than stopping the game.
+/// Returns whether the save was written.
+pub fn save_or_report(save_data: &SaveData, save_results: &mut MessageWriter<SaveResult>) -> bool {
+    let result = save_to_file(save_data).map_err(|e| e.to_string());
+    let written = result.is_ok();
+    save_results.write(SaveResult(result));
+    written
+}
+
+// ============================================================================
+// Toast
+// ============================================================================
+
+/// Switches to in-memory saving and shows the toast (or puts the shown one back up) when a
+/// save fails, and back to saving to disk once a later save is written
+pub fn show_save_toast(
+    mut commands: Commands,
+    mut save_results: MessageReader<SaveResult>,
+    mut status: ResMut<SaveStatus>,
+    toast_query: Query<Entity, With<SaveToast>>,
+) {
+    let mut failed = false;
+    for SaveResult(result) in save_results.read() {
+        match result {
+            Ok(()) => status.in_memory_only = false,
+            Err(reason) => {
+                warn!("Failed to save progress: {}", reason);
+                status.in_memory_only = true;
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        show_toast(&mut commands, &toast_query, "Couldn't save progress - it's kept until you quit");
+    }
+}
+
+/// Shows the toast with the given text, in place of one already on screen
+pub fn show_toast(commands: &mut Commands, toast_query: &Query<Entity, With<SaveToast>>, text: &str) {
+    for toast in toast_query {
+        commands.entity(toast).despawn();
+    }
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(SAVE_TOAST_MARGIN),
+                right: Val::Px(SAVE_TOAST_MARGIN),
+                padding: UiRect::all(Val::Px(SAVE_TOAST_PADDING)),
+                ..default()
+            },
+            BackgroundColor(OVERLAY_BACKGROUND_COLOR),
+            GlobalZIndex(SAVE_TOAST_Z_INDEX),
+            SaveToast {
+                remaining: SAVE_TOAST_DURATION,
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: SAVE_TOAST_FONT_SIZE,
+                    ..default()
+                },
+                TextColor(ERROR_TEXT_COLOR),
+            ));
+        });
+}
+
+/// Removes the toast once its time is up
+pub fn update_save_toast(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut toast_query: Query<(Entity, &mut SaveToast)>,
+) {
+    for (entity, mut toast) in toast_query.iter_mut() {
+        toast.remaining -= time.delta_secs();
+        if toast.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use std::fs;
+
+    use super::*;
+    use crate::save::{get_save_dir, ScratchDataDir};
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<SaveResult>()
+            .init_resource::<SaveStatus>()
+            .add_systems(Update, (show_save_toast, update_save_toast).chain());
+        app
+    }
+
+    /// Writes the save through `save_or_report`, as the game's systems do, then runs the
+    /// toast systems. Returns whether the save was written.
+    fn save_in_app(app: &mut App, save_data: &SaveData) -> bool {
+        let save_data = save_data.clone();
+        let written = app
+            .world_mut()
+            .run_system_once(move |mut save_results: MessageWriter<SaveResult>| {
+                save_or_report(&save_data, &mut save_results)
+            })
+            .unwrap();
+        app.update();
+        written
+    }
+
+    #[test]
+    fn test_failed_write_shows_toast_until_a_save_is_written() {
+        let _data_dir = ScratchDataDir::new();
+        let mut app = test_app();
+        let save_data = SaveData::new("Toast Test".to_string());
+        // A directory in the way of the save file fails the write, as a full disk would
+        let blocking_dir = get_save_dir().unwrap().join(save_data.filename());
+        fs::create_dir_all(blocking_dir.join("blocked")).unwrap();
+
+        assert!(!save_in_app(&mut app, &save_data));
+        assert!(app.world().resource::<SaveStatus>().in_memory_only);
+        let mut toast_query = app.world_mut().query::<&SaveToast>();
+        assert_eq!(toast_query.iter(app.world()).count(), 1);
+
+        // Once the disk recovers the next save is written, and saving to disk resumes
+        fs::remove_dir_all(&blocking_dir).unwrap();
+        assert!(save_in_app(&mut app, &save_data));
+        assert!(!app.world().resource::<SaveStatus>().in_memory_only);
+    }
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::settings::components::Difficulty;
+
+/// Marker component for entities that belong to the time trial results screen
+#[derive(Component)]
+pub struct OnSequenceResultsScreen;
+
+/// All actions that can be triggered from the time trial results buttons
+#[derive(Component)]
+pub enum SequenceResultsButtonAction {
+    /// Run the same levels again from the first one
+    PlayAgain,
+    /// Return to the level menu
+    LevelMenu,
+}
+
+/// Resource tracking a time trial: levels played back to back, with their finish
+/// times adding up to one combined time. Empty when no time trial is running.
+/// Kept in the race marker too, so a crashed time trial resumes where it left off.
+#[derive(Resource, Default, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SequenceState {
+    /// Levels in play order
+    pub levels: Vec<usize>,
+    /// Finish time of each completed level, in play order
+    pub level_times: Vec<f32>,
+    /// False if any finish failed visit-order validation (the total is not saved)
+    pub is_valid: bool,
+    /// False if any level was finished lap-only (the total is not saved)
+    pub is_ranked: bool,
+    /// Gentlest difficulty any level was raced on, which the combined time is kept under
+    /// (None before the first finish)
+    #[serde(default)]
+    pub difficulty: Option<Difficulty>,
+}
+
+impl SequenceState {
+    /// Starts a time trial over the given levels
+    pub fn new(levels: Vec<usize>) -> Self {
+        Self {
+            levels,
+            level_times: Vec::new(),
+            is_valid: true,
+            is_ranked: true,
+            difficulty: None,
+        }
+    }
+
+    /// Whether a time trial is running (or just finished and showing its results)
+    pub fn is_active(&self) -> bool {
+        !self.levels.is_empty()
+    }
+
+    /// Records the current level's finish and returns the level to play next,
+    /// or None once every level has been completed
+    pub fn complete_level(
+        &mut self,
+        time: f32,
+        is_valid: bool,
+        is_ranked: bool,
+        difficulty: Difficulty,
+    ) -> Option<usize> {
+        self.level_times.push(time);
+        self.is_valid &= is_valid;
+        self.is_ranked &= is_ranked;
+        self.difficulty = Some(self.difficulty.map_or(difficulty, |gentlest| gentlest.min(difficulty)));
+        self.levels.get(self.level_times.len()).copied()
+    }
+
+    /// Combined finish time of the completed levels
+    pub fn total_time(&self) -> f32 {
+        self.level_times.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_advances_through_levels_and_sums_times() {
+        let mut sequence = SequenceState::new(vec![3, 4]);
+
+        assert_eq!(sequence.complete_level(20.0, true, false, Difficulty::Hard), Some(4));
+        assert_eq!(sequence.complete_level(25.5, false, true, Difficulty::Normal), None);
+        assert_eq!(sequence.total_time(), 45.5);
+        assert!(!sequence.is_valid);
+        assert!(!sequence.is_ranked);
+        assert_eq!(sequence.difficulty, Some(Difficulty::Normal));
+    }
+}
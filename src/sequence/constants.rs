@@ -0,0 +1,18 @@
+use crate::styles::menu::{LARGE_TEXT_FONT_SIZE, MEDIUM_TEXT_FONT_SIZE, SMALL_MARGIN, SMALL_TEXT_FONT_SIZE};
+
+// ============================================================================
+// Sequence Settings
+// ============================================================================
+
+/// Levels played in a time trial, in order. Runs from the hardcoded levels into the
+/// generated ones, so it unlocks once the last of them is unlocked.
+pub const TIME_TRIAL_LEVELS: [usize; 5] = [1, 2, 3, 4, 5];
+
+// ============================================================================
+// Results Screen Layout
+// ============================================================================
+
+pub const LEVEL_TIME_FONT_SIZE: f32 = SMALL_TEXT_FONT_SIZE;
+pub const TOTAL_TIME_FONT_SIZE: f32 = LARGE_TEXT_FONT_SIZE;
+pub const CAMPAIGN_BEST_FONT_SIZE: f32 = MEDIUM_TEXT_FONT_SIZE;
+pub const RESULTS_LINE_MARGIN: f32 = SMALL_MARGIN;
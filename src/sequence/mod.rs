@@ -0,0 +1,34 @@
+pub mod components;
+pub mod constants;
+pub mod systems;
+
+use bevy::prelude::*;
+use crate::constants::GameState;
+use crate::level_complete::systems::spawn_level_complete_menu;
+use crate::utils::{despawn_all, in_sequence};
+use components::{OnSequenceResultsScreen, SequenceState};
+use systems::{advance_sequence, end_sequence, sequence_results_action, spawn_sequence_results};
+use crate::styles::menu::standard_button_system;
+
+/// Time trial mode: a run of levels played back to back for a combined time
+pub struct SequencePlugin;
+
+impl Plugin for SequencePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SequenceState>()
+            .add_systems(
+                OnEnter(GameState::LevelComplete),
+                advance_sequence.after(spawn_level_complete_menu).run_if(in_sequence),
+            )
+            .add_systems(OnEnter(GameState::LevelMenu), end_sequence)
+            .add_systems(OnEnter(GameState::StartMenu), end_sequence)
+            .add_systems(OnEnter(GameState::SequenceResults), spawn_sequence_results)
+            .add_systems(OnExit(GameState::SequenceResults), despawn_all::<OnSequenceResultsScreen>)
+            .add_systems(
+                Update,
+                (standard_button_system, sequence_results_action)
+                    .run_if(in_state(GameState::SequenceResults)),
+            );
+    }
+}
@@ -0,0 +1,196 @@
+use bevy::prelude::*;
+
+use crate::constants::{CurrentLevel, GameState, ResumeFromPause};
+use crate::hud::components::RaceState;
+use crate::save::{save_or_report, CompletionResult, CurrentSave, RunSetup, SaveResult};
+use crate::sequence::components::{
+    OnSequenceResultsScreen, SequenceResultsButtonAction, SequenceState,
+};
+use crate::sequence::constants::*;
+use crate::settings::components::FinishCondition;
+use crate::styles::colors::{MENU_TEXT_COLOR, OVERLAY_BACKGROUND_COLOR, SECONDARY_TEXT_COLOR};
+use crate::styles::menu::{
+    button_row, column_centered, spawn_button_with_width, spawn_menu_container, title_style,
+    LARGE_BUTTON_WIDTH,
+};
+use crate::styles::palette::Palette;
+use crate::utils::format_time;
+
+// ============================================================================
+// Sequence Progress
+// ============================================================================
+
+/// Adds the finished level to the running time trial and moves straight on to the
+/// next level, or to the results screen once the last level is done
+pub fn advance_sequence(
+    race_state: Res<RaceState>,
+    mut sequence: ResMut<SequenceState>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut resume_flag: ResMut<ResumeFromPause>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    let time = race_state.final_time.unwrap_or_default();
+    let is_ranked = race_state.finish_condition == FinishCondition::VisitAll;
+    match sequence.complete_level(time, race_state.is_valid, is_ranked, race_state.difficulty) {
+        Some(next_level) => {
+            // Leaving LevelComplete clears the finished course, and the next one
+            // (hardcoded or generated) is built on entering Playing
+            current_level.0 = next_level;
+            resume_flag.0 = false;
+            game_state.set(GameState::Playing);
+        }
+        None => game_state.set(GameState::SequenceResults),
+    }
+}
+
+/// Stops any time trial (when returning to the level menu or main menu)
+pub fn end_sequence(mut sequence: ResMut<SequenceState>) {
+    *sequence = SequenceState::default();
+}
+
+// ============================================================================
+// Results Screen
+// ============================================================================
+
+/// Spawns the time trial results and saves the combined time as the campaign best
+/// (unless a level failed visit-order validation)
+pub fn spawn_sequence_results(
+    mut commands: Commands,
+    sequence: Res<SequenceState>,
+    mut current_save: ResMut<CurrentSave>,
+    mut save_results: MessageWriter<SaveResult>,
+    palette: Res<Palette>,
+) {
+    let total_time = sequence.total_time();
+    let difficulty = sequence.difficulty.unwrap_or_default();
+    let setup = RunSetup {
+        difficulty,
+        car_class: current_save.get().map(|s| s.car_class).unwrap_or_default(),
+    };
+    let mut completion = None;
+    if let Some(save_data) = current_save.get_mut() {
+        if sequence.is_valid && sequence.is_ranked {
+            let previous_setup = save_data.campaign_setup();
+            completion = Some((save_data.record_campaign_time(total_time, difficulty), previous_setup));
+            save_or_report(save_data, &mut save_results);
+        }
+    }
+
+    spawn_menu_container(&mut commands, OnSequenceResultsScreen, OVERLAY_BACKGROUND_COLOR)
+        .with_children(|parent| {
+            parent.spawn(column_centered()).with_children(|parent| {
+                parent.spawn((Text::new("Time Trial Complete!"), title_style()));
+
+                for (level, time) in sequence.levels.iter().zip(&sequence.level_times) {
+                    parent.spawn((
+                        Text::new(format!("Level {}: {}", level, format_time(*time))),
+                        results_line_style(LEVEL_TIME_FONT_SIZE, SECONDARY_TEXT_COLOR),
+                    ));
+                }
+                parent.spawn((
+                    Text::new(format!("Total: {}{}", format_time(total_time), setup.time_suffix())),
+                    results_line_style(TOTAL_TIME_FONT_SIZE, MENU_TEXT_COLOR),
+                ));
+
+                if let Some((text, color)) =
+                    campaign_status(&sequence, completion, &palette)
+                {
+                    parent.spawn((Text::new(text), results_line_style(CAMPAIGN_BEST_FONT_SIZE, color)));
+                }
+
+                parent.spawn(button_row()).with_children(|row| {
+                    spawn_button_with_width(row, "Play Again", SequenceResultsButtonAction::PlayAgain, LARGE_BUTTON_WIDTH);
+                    spawn_button_with_width(row, "Level Menu", SequenceResultsButtonAction::LevelMenu, LARGE_BUTTON_WIDTH);
+                });
+            });
+        });
+}
+
+/// Font, color and spacing for one line of the results
+fn results_line_style(font_size: f32, color: Color) -> (TextFont, TextColor, Node) {
+    (
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(color),
+        Node {
+            margin: UiRect::bottom(Val::Px(RESULTS_LINE_MARGIN)),
+            ..default()
+        },
+    )
+}
+
+/// Line comparing the combined time to the campaign best, with the setup that was set with
+/// (None without a save)
+fn campaign_status(
+    sequence: &SequenceState,
+    completion: Option<(CompletionResult, RunSetup)>,
+    palette: &Palette,
+) -> Option<(String, Color)> {
+    if !sequence.is_valid {
+        return Some((
+            "Invalid run - a level wasn't driven as one lap".to_string(),
+            palette.error_text,
+        ));
+    }
+    if !sequence.is_ranked {
+        return Some((
+            "Not saved - campaign bests take whole-track runs".to_string(),
+            SECONDARY_TEXT_COLOR,
+        ));
+    }
+
+    let total_time = sequence.total_time();
+
+    completion.map(|(result, previous_setup)| match result.previous_best {
+        None => ("First campaign best!".to_string(), palette.success_text),
+        Some(previous) if result.is_new_best => (
+            format!(
+                "New campaign best! (Previous: {}{})",
+                format_time(previous),
+                previous_setup.time_suffix()
+            ),
+            palette.success_text,
+        ),
+        Some(previous) => (
+            format!(
+                "Campaign best: {}{} ({:+.2})",
+                format_time(previous),
+                previous_setup.time_suffix(),
+                total_time - previous
+            ),
+            SECONDARY_TEXT_COLOR,
+        ),
+    })
+}
+
+// ============================================================================
+// Button Actions
+// ============================================================================
+
+/// Handles time trial results button actions
+pub fn sequence_results_action(
+    interaction_query: Query<
+        (&Interaction, &SequenceResultsButtonAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut sequence: ResMut<SequenceState>,
+    mut current_level: ResMut<CurrentLevel>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, button_action) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            match button_action {
+                SequenceResultsButtonAction::PlayAgain => {
+                    *sequence = SequenceState::new(sequence.levels.clone());
+                    current_level.0 = sequence.levels[0];
+                    game_state.set(GameState::Playing);
+                }
+                SequenceResultsButtonAction::LevelMenu => {
+                    game_state.set(GameState::LevelMenu);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,511 @@
+use bevy::prelude::*;
+use bevy::window::PresentMode;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+use std::time::Duration;
+
+use crate::hud::constants::{
+    CASUAL_OFF_ROAD_TIME_MULTIPLIER, HARD_OFF_ROAD_TIME_MULTIPLIER, OFF_ROAD_TIME_MULTIPLIER,
+};
+use crate::road::components::VisitOrder;
+use crate::road::constants::{
+    CASUAL_OFF_ROAD_CORNER_ALLOWANCE, HARD_OFF_ROAD_CORNER_ALLOWANCE, OFF_ROAD_CORNER_ALLOWANCE,
+};
+use crate::settings::constants::{
+    DEFAULT_MASTER_VOLUME, DEFAULT_MAX_FRAME_TIME, DEFAULT_MUSIC_VOLUME, DEFAULT_PHYSICS_SUBSTEPS,
+    MAX_MAX_FRAME_TIME, MAX_PHYSICS_SUBSTEPS, MIN_MAX_FRAME_TIME, MUSIC_VOLUME_STEP,
+};
+
+/// Marker component for entities that belong to the settings screen
+#[derive(Component)]
+pub struct OnSettingsScreen;
+
+/// All actions that can be triggered from settings menu buttons
+#[derive(Component)]
+pub enum SettingsButtonAction {
+    CycleDifficulty,
+    CycleNosMode,
+    ToggleVisitOrderCheck,
+    ToggleMedalUnlock,
+    CycleColorblindMode,
+    ToggleReducedGlow,
+    ToggleReducedMotion,
+    CycleSpeedUnit,
+    ToggleFogOfWar,
+    ToggleGuideArrow,
+    ToggleHoldToDelete,
+    ToggleRacingLine,
+    ToggleControlsHint,
+    ToggleMsaa,
+    ToggleLowLatency,
+    ToggleMinimapRotation,
+    ToggleCoins,
+    CycleMusicVolume,
+    ToggleLetterbox,
+    ToggleLocalRanking,
+    Back,
+}
+
+impl SettingsButtonAction {
+    /// Returns the button label for the current settings (None for plain buttons)
+    pub fn label(&self, settings: &GameSettings) -> Option<String> {
+        match self {
+            SettingsButtonAction::CycleDifficulty => {
+                Some(format!("Difficulty: {}", settings.difficulty.label()))
+            }
+            SettingsButtonAction::CycleNosMode => {
+                Some(format!("NOS: {}", settings.nos_mode.label()))
+            }
+            SettingsButtonAction::ToggleVisitOrderCheck => Some(format!(
+                "Lap Check: {}",
+                if settings.validate_visit_order { "On" } else { "Off" }
+            )),
+            SettingsButtonAction::ToggleMedalUnlock => Some(format!(
+                "Unlock: {}",
+                if settings.require_medal_to_unlock { "Bronze" } else { "Finish" }
+            )),
+            SettingsButtonAction::CycleColorblindMode => {
+                Some(format!("Colors: {}", settings.colorblind_mode.label()))
+            }
+            SettingsButtonAction::ToggleReducedGlow => Some(format!(
+                "Glow: {}",
+                if settings.reduced_glow { "Reduced" } else { "Full" }
+            )),
+            SettingsButtonAction::ToggleReducedMotion => Some(format!(
+                "Motion: {}",
+                if settings.reduced_motion { "Reduced" } else { "Full" }
+            )),
+            SettingsButtonAction::CycleSpeedUnit => {
+                Some(format!("Speed: {}", settings.speed_unit.label()))
+            }
+            SettingsButtonAction::ToggleFogOfWar => Some(format!(
+                "Fog: {}",
+                if settings.fog_of_war { "On" } else { "Off" }
+            )),
+            SettingsButtonAction::ToggleGuideArrow => Some(format!(
+                "Guide: {}",
+                if settings.show_guide_arrow { "On" } else { "Off" }
+            )),
+            SettingsButtonAction::ToggleHoldToDelete => Some(format!(
+                "Delete: {}",
+                if settings.hold_to_delete { "Hold" } else { "Confirm" }
+            )),
+            SettingsButtonAction::ToggleRacingLine => Some(format!(
+                "Trail: {}",
+                if settings.show_racing_line { "On" } else { "Off" }
+            )),
+            SettingsButtonAction::ToggleControlsHint => Some(format!(
+                "Hints: {}",
+                if settings.show_controls_hint { "On" } else { "Off" }
+            )),
+            SettingsButtonAction::ToggleMsaa => Some(format!(
+                "MSAA: {}",
+                if settings.msaa { "On" } else { "Off" }
+            )),
+            // The label names the cost of lower latency: torn frames
+            SettingsButtonAction::ToggleLowLatency => Some(format!(
+                "Lag: {}",
+                if settings.low_latency { "Low, may tear" } else { "VSync, smooth" }
+            )),
+            SettingsButtonAction::ToggleMinimapRotation => Some(format!(
+                "Minimap: {}",
+                if settings.rotate_minimap { "Heading" } else { "North" }
+            )),
+            SettingsButtonAction::ToggleCoins => Some(format!(
+                "Coins: {}",
+                if settings.collect_coins { "On" } else { "Off" }
+            )),
+            SettingsButtonAction::CycleMusicVolume => Some(match settings.music_volume_percent() {
+                0 => "Music: Off".to_string(),
+                percent => format!("Music: {}%", percent),
+            }),
+            // The label names what fills the rest of a resized window: bars, or more of the world
+            SettingsButtonAction::ToggleLetterbox => Some(format!(
+                "Fit: {}",
+                if settings.letterbox { "Bars" } else { "Fill" }
+            )),
+            SettingsButtonAction::ToggleLocalRanking => Some(format!(
+                "Ranking: {}",
+                if settings.local_ranking { "On" } else { "Off" }
+            )),
+            SettingsButtonAction::Back => None,
+        }
+    }
+}
+
+/// Game difficulty, controls how forgiving gameplay assists are
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
+pub enum Difficulty {
+    /// Forgiving mode - the car is returned to the last checkpoint after going off-road too long
+    Casual,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    /// Display name shown in menus
+    pub fn label(&self) -> &'static str {
+        match self {
+            Difficulty::Casual => "Casual",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// Returns the next difficulty, wrapping around
+    pub fn next(&self) -> Self {
+        match self {
+            Difficulty::Casual => Difficulty::Normal,
+            Difficulty::Normal => Difficulty::Hard,
+            Difficulty::Hard => Difficulty::Casual,
+        }
+    }
+
+    /// Whether the car is respawned at the last checkpoint after staying off-road too long
+    pub fn respawns_off_road(&self) -> bool {
+        *self == Difficulty::Casual
+    }
+
+    /// How much faster the race timer runs while the car is off the road
+    pub fn off_road_multiplier(&self) -> f32 {
+        match self {
+            Difficulty::Casual => CASUAL_OFF_ROAD_TIME_MULTIPLIER,
+            Difficulty::Normal => OFF_ROAD_TIME_MULTIPLIER,
+            Difficulty::Hard => HARD_OFF_ROAD_TIME_MULTIPLIER,
+        }
+    }
+
+    /// How many of the car's corners may be off the road while the car still counts as on it
+    pub fn off_road_corner_allowance(&self) -> usize {
+        match self {
+            Difficulty::Casual => CASUAL_OFF_ROAD_CORNER_ALLOWANCE,
+            Difficulty::Normal => OFF_ROAD_CORNER_ALLOWANCE,
+            Difficulty::Hard => HARD_OFF_ROAD_CORNER_ALLOWANCE,
+        }
+    }
+
+    /// Range (min, max) of powerups placed on generated tracks
+    pub fn powerup_count_range(&self) -> (usize, usize) {
+        match self {
+            Difficulty::Casual => (2, 4),
+            Difficulty::Normal => (1, 3),
+            Difficulty::Hard => (1, 2),
+        }
+    }
+}
+
+/// How the NOS boost responds to the boost key
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum NosMode {
+    /// Boost only while the key is held inside the availability window
+    #[default]
+    Hold,
+    /// A single press triggers a full-power burst of fixed length
+    Tap,
+}
+
+impl NosMode {
+    /// Display name shown in menus
+    pub fn label(&self) -> &'static str {
+        match self {
+            NosMode::Hold => "Hold",
+            NosMode::Tap => "Tap",
+        }
+    }
+
+    /// Returns the other mode
+    pub fn next(&self) -> Self {
+        match self {
+            NosMode::Hold => NosMode::Tap,
+            NosMode::Tap => NosMode::Hold,
+        }
+    }
+}
+
+/// What a car must have done for crossing the finish line to end the race
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum FinishCondition {
+    /// Every segment of the track must be visited
+    #[default]
+    VisitAll,
+    /// Driving round the loop is enough: the car only has to reach the segment halfway
+    /// along it, so backing over the finish line straight after the start doesn't count
+    LapOnly,
+}
+
+impl FinishCondition {
+    /// Display name shown in menus
+    pub fn label(&self) -> &'static str {
+        match self {
+            FinishCondition::VisitAll => "All",
+            FinishCondition::LapOnly => "Lap",
+        }
+    }
+
+    /// Returns the other condition
+    pub fn next(&self) -> Self {
+        match self {
+            FinishCondition::VisitAll => FinishCondition::LapOnly,
+            FinishCondition::LapOnly => FinishCondition::VisitAll,
+        }
+    }
+
+    /// Whether a car with these visits may finish the race
+    pub fn is_met(&self, visit_order: &VisitOrder) -> bool {
+        match self {
+            FinishCondition::VisitAll => visit_order.is_complete(),
+            FinishCondition::LapOnly => {
+                visit_order.indices.contains(&(visit_order.segment_count / 2))
+            }
+        }
+    }
+}
+
+/// Colorblind-friendly palette presets for status colors
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum ColorblindMode {
+    /// Standard red/green palette
+    #[default]
+    Off,
+    Deuteranopia,
+    Protanopia,
+}
+
+impl ColorblindMode {
+    /// Display name shown in menus (short forms so the label fits the button)
+    pub fn label(&self) -> &'static str {
+        match self {
+            ColorblindMode::Off => "Standard",
+            ColorblindMode::Deuteranopia => "Deutan",
+            ColorblindMode::Protanopia => "Protan",
+        }
+    }
+
+    /// Returns the next mode, wrapping around
+    pub fn next(&self) -> Self {
+        match self {
+            ColorblindMode::Off => ColorblindMode::Deuteranopia,
+            ColorblindMode::Deuteranopia => ColorblindMode::Protanopia,
+            ColorblindMode::Protanopia => ColorblindMode::Off,
+        }
+    }
+}
+
+/// Message sent when the volume is changed with a gameplay hotkey
+#[derive(Message)]
+pub struct VolumeChanged;
+
+/// Units shown on the speedometer
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum SpeedUnit {
+    /// Raw game units (pixels per second)
+    #[default]
+    GameUnits,
+    Kph,
+    Mph,
+}
+
+impl SpeedUnit {
+    /// Display name shown in menus and on the speedometer
+    pub fn label(&self) -> &'static str {
+        match self {
+            SpeedUnit::GameUnits => "u/s",
+            SpeedUnit::Kph => "km/h",
+            SpeedUnit::Mph => "mph",
+        }
+    }
+
+    /// Returns the next unit, wrapping around
+    pub fn next(&self) -> Self {
+        match self {
+            SpeedUnit::GameUnits => SpeedUnit::Kph,
+            SpeedUnit::Kph => SpeedUnit::Mph,
+            SpeedUnit::Mph => SpeedUnit::GameUnits,
+        }
+    }
+}
+
+/// Resource holding player-configurable game settings.
+/// Persisted to `settings.json`; fields missing from the file use their defaults.
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSettings {
+    pub difficulty: Difficulty,
+    /// Levels switched from visiting every segment to just driving a lap, picked on their
+    /// level card (see `GameSettings::finish_condition`)
+    pub finish_conditions: HashMap<usize, FinishCondition>,
+    /// Whether NOS boosts while held or fires a fixed burst on a tap
+    pub nos_mode: NosMode,
+    /// Flag finish times as invalid unless segments were visited as one continuous lap,
+    /// and ignore segments reached by jumping across the track (see `VisitOrder`)
+    pub validate_visit_order: bool,
+    /// Unlock the next level only with a bronze medal time instead of on any finish
+    pub require_medal_to_unlock: bool,
+    /// Palette used for status colors (see `Palette`)
+    pub colorblind_mode: ColorblindMode,
+    /// Tone down bloom, glowing edges and props, and the wall warning for comfort
+    /// (see `GlowSettings`)
+    pub reduced_glow: bool,
+    /// Replace animations with instant changes. The single switch for motion effects;
+    /// systems that animate or flash must check it. Currently honored by:
+    /// - `road::systems::animate_visited_edges` (edges switch straight to the visited glow)
+    /// - `hud::systems::update_finish_blocked_text` (the message disappears without fading)
+    /// - `name_entry::systems::blink_caret` (the caret stays shown)
+    /// - `name_entry::systems::flash_input_border` (the error border is held, not faded)
+    /// - `garage::systems::rotate_garage_car` (the preview car holds still)
+    /// - `props::systems::rotate_powerups` (the powerups hold still)
+    /// - `hud::systems::update_controls_hint` (the hint disappears without fading)
+    /// - `hud::systems::update_volume_overlay` (the overlay disappears without fading)
+    /// - `hud::helpers::ease_bar_fill` (the NOS bar jumps to its new fill)
+    pub reduced_motion: bool,
+    /// Units shown on the speedometer
+    pub speed_unit: SpeedUnit,
+    /// Hide the track beyond a radius around the car until it's approached
+    pub fog_of_war: bool,
+    /// Draw an arrow from the car toward the nearest unvisited segment
+    pub show_guide_arrow: bool,
+    /// Turn the live minimap with the car so its heading points up, instead of north up
+    pub rotate_minimap: bool,
+    /// Leave a trail along the line driven, colored by speed (see `racing_line`)
+    pub show_racing_line: bool,
+    /// Place coins along the track to collect for a score, shown on the HUD and the
+    /// level complete screen (see `props::components::Coin`)
+    pub collect_coins: bool,
+    /// Show the controls hint at the start of a race (off for experienced players)
+    pub show_controls_hint: bool,
+    /// Smooth the main camera's edges with multisample anti-aliasing (see `GameSettings::msaa`)
+    pub msaa: bool,
+    /// Show frames as soon as they're drawn instead of waiting for the display's refresh,
+    /// cutting input-to-display latency at the cost of screen tearing (see `GameSettings::present_mode`)
+    pub low_latency: bool,
+    /// Keep the playfield at the `WINDOW_WIDTH` x `WINDOW_HEIGHT` aspect in a window of any
+    /// size, with bars filling the rest, instead of showing more or less of the world
+    /// (see `utils::letterbox_viewport`)
+    pub letterbox: bool,
+    /// Rank a recorded time against the best times of every other profile on this machine
+    /// on the level complete screen
+    pub local_ranking: bool,
+    /// Delete saves by holding their delete button instead of through a confirmation dialog
+    pub hold_to_delete: bool,
+    /// Master volume in the linear scale (0.0 to 1.0)
+    pub master_volume: f32,
+    /// Silences all audio without losing the volume level
+    pub muted: bool,
+    /// Volume of the background music relative to the master volume, in the linear scale
+    /// (see `GameSettings::music_volume`)
+    pub music_volume: f32,
+    /// Steps each frame's car movement is split into, so a fast car at a low frame rate
+    /// can't jump past the lines and road edges it's checked against. Left out of the menu,
+    /// as only a very slow machine needs more (see `GameSettings::physics_substeps`).
+    pub physics_substeps: u32,
+    /// Longest frame (seconds) gameplay advances by, so a hitch or a dragged window moves
+    /// the car one capped step instead of teleporting it; the game runs slow for that frame.
+    /// A tuning value for testers rather than players (see `GameSettings::max_frame_time`).
+    pub max_frame_time: f32,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::default(),
+            finish_conditions: HashMap::new(),
+            nos_mode: NosMode::default(),
+            validate_visit_order: true,
+            require_medal_to_unlock: false,
+            colorblind_mode: ColorblindMode::default(),
+            reduced_glow: false,
+            reduced_motion: false,
+            speed_unit: SpeedUnit::default(),
+            fog_of_war: false,
+            show_guide_arrow: false,
+            rotate_minimap: false,
+            show_racing_line: false,
+            collect_coins: false,
+            show_controls_hint: true,
+            msaa: true,
+            low_latency: false,
+            letterbox: true,
+            local_ranking: true,
+            hold_to_delete: false,
+            master_volume: DEFAULT_MASTER_VOLUME,
+            muted: false,
+            music_volume: DEFAULT_MUSIC_VOLUME,
+            physics_substeps: DEFAULT_PHYSICS_SUBSTEPS,
+            max_frame_time: DEFAULT_MAX_FRAME_TIME,
+        }
+    }
+}
+
+impl GameSettings {
+    /// Volume actually applied to audio (zero while muted)
+    pub fn effective_volume(&self) -> f32 {
+        if self.muted { 0.0 } else { self.master_volume }
+    }
+
+    /// Music volume relative to the master volume, kept within 0.0-1.0 whatever the file says
+    pub fn music_volume(&self) -> f32 {
+        self.music_volume.clamp(0.0, 1.0)
+    }
+
+    /// Music volume as a whole percentage, for display
+    pub fn music_volume_percent(&self) -> u32 {
+        (self.music_volume() * 100.0).round() as u32
+    }
+
+    /// Raises the music volume by `MUSIC_VOLUME_STEP`, wrapping round to silence after full volume
+    pub fn cycle_music_volume(&mut self) {
+        let steps = (1.0 / MUSIC_VOLUME_STEP).round() as u32;
+        let step = (self.music_volume() / MUSIC_VOLUME_STEP).round() as u32;
+        self.music_volume = ((step + 1) % (steps + 1)) as f32 * MUSIC_VOLUME_STEP;
+    }
+
+    /// Anti-aliasing applied to the main camera
+    pub fn msaa(&self) -> Msaa {
+        if self.msaa { Msaa::Sample4 } else { Msaa::Off }
+    }
+
+    /// How frames are presented: without vsync when latency is reduced
+    pub fn present_mode(&self) -> PresentMode {
+        if self.low_latency { PresentMode::AutoNoVsync } else { PresentMode::AutoVsync }
+    }
+
+    /// Frames the GPU may queue ahead of the display: one when latency is reduced,
+    /// the renderer's default otherwise
+    pub fn max_frame_latency(&self) -> Option<NonZeroU32> {
+        self.low_latency.then_some(NonZeroU32::MIN)
+    }
+
+    /// Physics substeps per frame, kept within 1 to `MAX_PHYSICS_SUBSTEPS` whatever the file says
+    pub fn physics_substeps(&self) -> u32 {
+        self.physics_substeps.clamp(1, MAX_PHYSICS_SUBSTEPS)
+    }
+
+    /// Longest frame gameplay advances by, kept within `MIN_MAX_FRAME_TIME` to
+    /// `MAX_MAX_FRAME_TIME` whatever the file says
+    pub fn max_frame_time(&self) -> Duration {
+        Duration::from_secs_f32(self.max_frame_time.clamp(MIN_MAX_FRAME_TIME, MAX_MAX_FRAME_TIME))
+    }
+
+    /// What finishing the level takes: visiting every segment unless it was switched to lap-only
+    pub fn finish_condition(&self, level: usize) -> FinishCondition {
+        self.finish_conditions.get(&level).copied().unwrap_or_default()
+    }
+
+    /// Switches the level to the other finish condition. Only levels off the default are kept.
+    pub fn cycle_finish_condition(&mut self, level: usize) {
+        let next = self.finish_condition(level).next();
+        if next == FinishCondition::default() {
+            self.finish_conditions.remove(&level);
+        } else {
+            self.finish_conditions.insert(level, next);
+        }
+    }
+
+    /// Changes the master volume by `delta`, clamped to 0.0-1.0. Unmutes.
+    pub fn adjust_volume(&mut self, delta: f32) {
+        self.master_volume = (self.master_volume + delta).clamp(0.0, 1.0);
+        self.muted = false;
+    }
+}
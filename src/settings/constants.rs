@@ -0,0 +1,33 @@
+// ============================================================================
+// Audio Constants
+// ============================================================================
+
+/// Master volume for new installs (linear scale)
+pub const DEFAULT_MASTER_VOLUME: f32 = 0.8;
+/// Music volume relative to the master volume for new installs (linear scale)
+pub const DEFAULT_MUSIC_VOLUME: f32 = 0.5;
+/// Volume change per +/- key press
+pub const VOLUME_STEP: f32 = 0.1;
+/// Music volume change per press of its settings button
+pub const MUSIC_VOLUME_STEP: f32 = 0.25;
+
+// ============================================================================
+// Physics Constants
+// ============================================================================
+
+/// Physics substeps per frame for new installs (see `GameSettings::physics_substeps`)
+pub const DEFAULT_PHYSICS_SUBSTEPS: u32 = 4;
+/// Most physics substeps per frame a settings file may ask for
+pub const MAX_PHYSICS_SUBSTEPS: u32 = 16;
+/// Longest frame (seconds) gameplay advances by for new installs (see `GameSettings::max_frame_time`)
+pub const DEFAULT_MAX_FRAME_TIME: f32 = 0.1;
+/// Range a settings file may set the longest frame within
+pub const MIN_MAX_FRAME_TIME: f32 = 0.02;
+pub const MAX_MAX_FRAME_TIME: f32 = 0.25;
+
+// ============================================================================
+// Persistence Constants
+// ============================================================================
+
+/// File name of the settings file in the config directory
+pub const SETTINGS_FILENAME: &str = "settings.json";
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use directories::ProjectDirs;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::settings::components::GameSettings;
+use crate::settings::constants::SETTINGS_FILENAME;
+
+/// Gets the settings file path, creating the config directory if necessary
+fn get_settings_path() -> io::Result<PathBuf> {
+    let project_dirs = ProjectDirs::from("com", "BevyDriver", "BevyDriver")
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not determine config directory"))?;
+
+    let config_dir = project_dirs.config_dir();
+    fs::create_dir_all(config_dir)?;
+    Ok(config_dir.join(SETTINGS_FILENAME))
+}
+
+/// Saves the settings to the settings file
+pub fn save_settings(settings: &GameSettings) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(settings)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    fs::write(get_settings_path()?, json)?;
+    Ok(())
+}
+
+/// Loads the settings file, falling back to defaults if it is missing or invalid
+pub fn load_settings() -> GameSettings {
+    let Ok(file_path) = get_settings_path() else {
+        return GameSettings::default();
+    };
+    if !file_path.exists() {
+        return GameSettings::default();
+    }
+
+    let loaded = fs::read_to_string(&file_path).and_then(|json| {
+        serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    });
+    match loaded {
+        Ok(settings) => settings,
+        Err(e) => {
+            warn!("Ignoring invalid settings file {}: {}", file_path.display(), e);
+            GameSettings::default()
+        }
+    }
+}
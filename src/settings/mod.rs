@@ -0,0 +1,55 @@
+pub mod components;
+pub mod constants;
+pub mod io;
+pub mod systems;
+
+use bevy::prelude::*;
+use crate::constants::GameState;
+use crate::utils::despawn_all;
+use components::{GameSettings, OnSettingsScreen, VolumeChanged};
+use io::load_settings;
+use systems::{
+    apply_glow_to_sprites, handle_volume_hotkeys, persist_settings, settings_action, spawn_settings_menu,
+    sync_global_volume, sync_glow, sync_max_frame_time, sync_palette, update_settings_labels,
+};
+use crate::styles::menu::standard_button_system;
+use crate::styles::palette::Palette;
+use crate::styles::theme::GlowSettings;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(load_settings())
+            .add_message::<VolumeChanged>()
+            .init_resource::<Palette>()
+            .init_resource::<GlowSettings>()
+            .add_systems(OnEnter(GameState::Settings), spawn_settings_menu)
+            .add_systems(OnExit(GameState::Settings), despawn_all::<OnSettingsScreen>)
+            .add_systems(
+                Update,
+                (
+                    standard_button_system,
+                    settings_action,
+                    update_settings_labels.run_if(resource_changed::<GameSettings>),
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Settings)),
+            )
+            .add_systems(Update, handle_volume_hotkeys.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                Update,
+                (
+                    sync_palette,
+                    sync_glow,
+                    sync_global_volume,
+                    sync_max_frame_time,
+                    // The file is only rewritten on changes, not when it was just loaded
+                    persist_settings.run_if(not(resource_added::<GameSettings>)),
+                )
+                    .run_if(resource_changed::<GameSettings>),
+            )
+            .add_systems(Update, apply_glow_to_sprites.after(sync_glow));
+    }
+}
@@ -0,0 +1,246 @@
+use bevy::audio::{GlobalVolume, Volume};
+use bevy::prelude::*;
+
+use crate::constants::{CurrentLevel, GameState, PreviousState};
+use crate::settings::components::{
+    GameSettings, OnSettingsScreen, SettingsButtonAction, VolumeChanged,
+};
+use crate::settings::constants::VOLUME_STEP;
+use crate::settings::io::save_settings;
+use crate::styles::colors::MENU_BACKGROUND_COLOR;
+use crate::styles::palette::Palette;
+use crate::styles::theme::{CurrentTheme, GlowSettings, Glowing, Theme};
+use crate::styles::menu::{
+    button_row, column_centered, spawn_button_with_width, spawn_menu_container,
+    title_style, CONFIRM_BUTTON_WIDTH, LARGE_BUTTON_WIDTH,
+};
+
+// ============================================================================
+// Settings Menu Spawning
+// ============================================================================
+
+/// Spawns the settings menu UI
+pub fn spawn_settings_menu(mut commands: Commands, settings: Res<GameSettings>) {
+    spawn_menu_container(&mut commands, OnSettingsScreen, MENU_BACKGROUND_COLOR)
+        .with_children(|parent| {
+            parent.spawn(column_centered()).with_children(|parent| {
+                parent.spawn((Text::new("Settings"), title_style()));
+                // Gameplay assists share a row to keep the menu within the window height,
+                // with the ranking against the other profiles
+                parent.spawn(button_row()).with_children(|parent| {
+                    spawn_setting_button(parent, SettingsButtonAction::CycleDifficulty, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::CycleNosMode, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleGuideArrow, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleLocalRanking, &settings);
+                });
+                // Both progression rules share a row for the same reason, with the
+                // racing line trail for studying a lap and the coins for a score
+                parent.spawn(button_row()).with_children(|parent| {
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleVisitOrderCheck, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleMedalUnlock, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleRacingLine, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleCoins, &settings);
+                });
+                // Accessibility options, with the on-screen controls hint
+                parent.spawn(button_row()).with_children(|parent| {
+                    spawn_setting_button(parent, SettingsButtonAction::CycleColorblindMode, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleReducedGlow, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleReducedMotion, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleControlsHint, &settings);
+                });
+                // And the HUD options (with the save deletion style); the four buttons
+                // shrink a little to fit the window width
+                parent.spawn(button_row()).with_children(|parent| {
+                    spawn_setting_button(parent, SettingsButtonAction::CycleSpeedUnit, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleFogOfWar, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleMinimapRotation, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleHoldToDelete, &settings);
+                });
+                // The window's display options share the last row with the music volume
+                // and a narrower Back button; their short labels fit as the buttons shrink
+                parent.spawn(button_row()).with_children(|parent| {
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleMsaa, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleLowLatency, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::ToggleLetterbox, &settings);
+                    spawn_setting_button(parent, SettingsButtonAction::CycleMusicVolume, &settings);
+                    spawn_button_with_width(parent, "Back", SettingsButtonAction::Back, CONFIRM_BUTTON_WIDTH);
+                });
+            });
+        });
+}
+
+/// Spawns a button whose label shows the current value of a setting
+fn spawn_setting_button(
+    parent: &mut ChildSpawnerCommands,
+    action: SettingsButtonAction,
+    settings: &GameSettings,
+) {
+    let label = action.label(settings).unwrap_or_default();
+    spawn_button_with_width(parent, &label, action, LARGE_BUTTON_WIDTH);
+}
+
+// ============================================================================
+// Button Actions
+// ============================================================================
+
+/// Handles settings menu button actions
+pub fn settings_action(
+    interaction_query: Query<
+        (&Interaction, &SettingsButtonAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut settings: ResMut<GameSettings>,
+    mut game_state: ResMut<NextState<GameState>>,
+    previous_state: Res<PreviousState>,
+) {
+    for (interaction, button_action) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            match button_action {
+                SettingsButtonAction::CycleDifficulty => {
+                    settings.difficulty = settings.difficulty.next();
+                }
+                SettingsButtonAction::CycleNosMode => {
+                    settings.nos_mode = settings.nos_mode.next();
+                }
+                SettingsButtonAction::ToggleVisitOrderCheck => {
+                    settings.validate_visit_order = !settings.validate_visit_order;
+                }
+                SettingsButtonAction::ToggleMedalUnlock => {
+                    settings.require_medal_to_unlock = !settings.require_medal_to_unlock;
+                }
+                SettingsButtonAction::CycleColorblindMode => {
+                    settings.colorblind_mode = settings.colorblind_mode.next();
+                }
+                SettingsButtonAction::ToggleReducedGlow => {
+                    settings.reduced_glow = !settings.reduced_glow;
+                }
+                SettingsButtonAction::ToggleReducedMotion => {
+                    settings.reduced_motion = !settings.reduced_motion;
+                }
+                SettingsButtonAction::CycleSpeedUnit => {
+                    settings.speed_unit = settings.speed_unit.next();
+                }
+                SettingsButtonAction::ToggleFogOfWar => {
+                    settings.fog_of_war = !settings.fog_of_war;
+                }
+                SettingsButtonAction::ToggleGuideArrow => {
+                    settings.show_guide_arrow = !settings.show_guide_arrow;
+                }
+                SettingsButtonAction::ToggleRacingLine => {
+                    settings.show_racing_line = !settings.show_racing_line;
+                }
+                SettingsButtonAction::ToggleHoldToDelete => {
+                    settings.hold_to_delete = !settings.hold_to_delete;
+                }
+                SettingsButtonAction::ToggleControlsHint => {
+                    settings.show_controls_hint = !settings.show_controls_hint;
+                }
+                SettingsButtonAction::ToggleMsaa => {
+                    settings.msaa = !settings.msaa;
+                }
+                SettingsButtonAction::ToggleLowLatency => {
+                    settings.low_latency = !settings.low_latency;
+                }
+                SettingsButtonAction::ToggleMinimapRotation => {
+                    settings.rotate_minimap = !settings.rotate_minimap;
+                }
+                SettingsButtonAction::ToggleCoins => {
+                    settings.collect_coins = !settings.collect_coins;
+                }
+                SettingsButtonAction::CycleMusicVolume => {
+                    settings.cycle_music_volume();
+                }
+                SettingsButtonAction::ToggleLetterbox => {
+                    settings.letterbox = !settings.letterbox;
+                }
+                SettingsButtonAction::ToggleLocalRanking => {
+                    settings.local_ranking = !settings.local_ranking;
+                }
+                SettingsButtonAction::Back => {
+                    game_state.set(previous_state.0);
+                }
+            }
+        }
+    }
+}
+
+/// Swaps the status color palette to match the colorblind mode setting
+pub fn sync_palette(settings: Res<GameSettings>, mut palette: ResMut<Palette>) {
+    *palette = Palette::for_mode(settings.colorblind_mode);
+}
+
+/// Updates the glow scales to match the reduced glow setting.
+/// The current theme is reapplied so a race resumed from the pause menu picks it up.
+pub fn sync_glow(
+    settings: Res<GameSettings>,
+    current_level: Res<CurrentLevel>,
+    mut glow: ResMut<GlowSettings>,
+    mut theme: ResMut<CurrentTheme>,
+) {
+    // Only flagged as changed on an actual change, as the level menu redraws its previews then
+    glow.set_if_neq(GlowSettings::for_setting(settings.reduced_glow));
+    theme.0 = Theme::for_level(current_level.0).with_glow(&glow);
+}
+
+/// Dims each glowing sprite to the glow settings, as it's spawned and whenever they change
+pub fn apply_glow_to_sprites(glow: Res<GlowSettings>, mut query: Query<(Ref<Glowing>, &mut Sprite)>) {
+    for (glowing, mut sprite) in &mut query {
+        if glow.is_changed() || glowing.is_added() {
+            sprite.color = glow.dim(glowing.0);
+        }
+    }
+}
+
+/// Applies the master volume and mute setting to all audio
+pub fn sync_global_volume(settings: Res<GameSettings>, mut global_volume: ResMut<GlobalVolume>) {
+    global_volume.volume = Volume::Linear(settings.effective_volume());
+}
+
+/// Caps the game time a single frame advances by (see `GameSettings::max_frame_time`).
+/// Every gameplay system reads its delta from the virtual clock, so they're all capped alike.
+pub fn sync_max_frame_time(settings: Res<GameSettings>, mut time: ResMut<Time<Virtual>>) {
+    time.set_max_delta(settings.max_frame_time());
+}
+
+/// Writes the settings file whenever a setting changes
+pub fn persist_settings(settings: Res<GameSettings>) {
+    if let Err(e) = save_settings(&settings) {
+        warn!("Failed to save settings: {}", e);
+    }
+}
+
+/// Handles the gameplay volume hotkeys: M toggles mute, +/- adjust the master volume
+pub fn handle_volume_hotkeys(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut settings: ResMut<GameSettings>,
+    mut volume_changed: MessageWriter<VolumeChanged>,
+) {
+    if keyboard_input.just_pressed(KeyCode::KeyM) {
+        settings.muted = !settings.muted;
+    } else if keyboard_input.any_just_pressed([KeyCode::Equal, KeyCode::NumpadAdd]) {
+        settings.adjust_volume(VOLUME_STEP);
+    } else if keyboard_input.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+        settings.adjust_volume(-VOLUME_STEP);
+    } else {
+        return;
+    }
+    volume_changed.write(VolumeChanged);
+}
+
+/// Refreshes setting button labels after a setting changes
+pub fn update_settings_labels(
+    settings: Res<GameSettings>,
+    button_query: Query<(&SettingsButtonAction, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (action, children) in &button_query {
+        let Some(label) = action.label(&settings) else {
+            continue;
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = text_query.get_mut(child) {
+                **text = label.clone();
+            }
+        }
+    }
+}
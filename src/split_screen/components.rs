@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+use crate::car::components::PlayerId;
+
+/// Component for the camera following one player's car in a two-player race.
+/// Each draws half the window; the main camera only draws the UI over them.
+#[derive(Component)]
+pub struct SplitScreenCamera(pub PlayerId);
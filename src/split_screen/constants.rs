@@ -0,0 +1,13 @@
+use crate::constants::{WINDOW_HEIGHT, WINDOW_WIDTH};
+
+/// Width of the world each split view shows (half the playfield)
+pub const SPLIT_VIEW_WIDTH: f32 = WINDOW_WIDTH as f32 / 2.0;
+/// Height of the world each split view shows (the whole playfield)
+pub const SPLIT_VIEW_HEIGHT: f32 = WINDOW_HEIGHT as f32;
+
+/// Render orders of player one's and player two's views (before the main camera at 0,
+/// which draws the UI over them)
+pub const SPLIT_VIEW_ORDERS: [isize; 2] = [-2, -1];
+/// Render layers only drawn in player one's and player two's views (for their NOS bar glow).
+/// Past the layers of the level minimaps, the result card and the garage.
+pub const SPLIT_VIEW_RENDER_LAYERS: [usize; 2] = [34, 35];
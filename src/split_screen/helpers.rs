@@ -0,0 +1,84 @@
+use bevy::camera::visibility::RenderLayers;
+use bevy::camera::Viewport;
+use bevy::prelude::*;
+
+use crate::car::components::PlayerId;
+use crate::constants::{LEFT_BOUNDARY, RIGHT_BOUNDARY};
+use crate::split_screen::constants::{SPLIT_VIEW_ORDERS, SPLIT_VIEW_RENDER_LAYERS, SPLIT_VIEW_WIDTH};
+
+/// Index of the player's view: player one on the left, player two on the right
+fn view_index(player: PlayerId) -> usize {
+    match player {
+        PlayerId::One => 0,
+        PlayerId::Two => 1,
+    }
+}
+
+/// Render order of the camera drawing the player's view
+pub fn split_view_order(player: PlayerId) -> isize {
+    SPLIT_VIEW_ORDERS[view_index(player)]
+}
+
+/// Layers the camera drawing the player's view renders: the world, plus the player's own layer
+pub fn split_view_camera_layers(player: PlayerId) -> RenderLayers {
+    RenderLayers::from_layers(&[0, SPLIT_VIEW_RENDER_LAYERS[view_index(player)]])
+}
+
+/// Layer of sprites that should only show in the player's view
+pub fn split_view_layer(player: PlayerId) -> RenderLayers {
+    RenderLayers::layer(SPLIT_VIEW_RENDER_LAYERS[view_index(player)])
+}
+
+/// The player's half of the area the playfield is drawn in (the letterboxed viewport or
+/// the whole window). Player two's half takes the odd pixel of an odd width.
+pub fn split_viewport(area: &Viewport, player: PlayerId) -> Viewport {
+    let half_width = (area.physical_size.x / 2).max(1);
+    let (offset, width) = match player {
+        PlayerId::One => (0, half_width),
+        PlayerId::Two => (half_width, area.physical_size.x.saturating_sub(half_width).max(1)),
+    };
+    Viewport {
+        physical_position: area.physical_position + UVec2::new(offset, 0),
+        physical_size: UVec2::new(width, area.physical_size.y),
+        ..default()
+    }
+}
+
+/// Horizontal camera position following a car, kept where the view stays within the walls
+pub fn split_view_camera_x(car_x: f32) -> f32 {
+    let half_view = SPLIT_VIEW_WIDTH / 2.0;
+    car_x.clamp(LEFT_BOUNDARY + half_view, RIGHT_BOUNDARY - half_view)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_views_tile_the_area() {
+        for size in [UVec2::new(1300, 800), UVec2::new(1301, 799), UVec2::ONE] {
+            let area = Viewport {
+                physical_position: UVec2::new(10, 20),
+                physical_size: size,
+                ..default()
+            };
+            let left = split_viewport(&area, PlayerId::One);
+            let right = split_viewport(&area, PlayerId::Two);
+            assert_eq!(left.physical_position, area.physical_position);
+            assert_eq!(right.physical_position.x, left.physical_position.x + left.physical_size.x);
+            assert_eq!(left.physical_size.y, size.y);
+            assert_eq!(right.physical_size.y, size.y);
+            if size.x > 1 {
+                assert_eq!(left.physical_size.x + right.physical_size.x, size.x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_view_stays_within_the_walls() {
+        let half_view = SPLIT_VIEW_WIDTH / 2.0;
+        assert_eq!(split_view_camera_x(0.0), 0.0);
+        assert_eq!(split_view_camera_x(LEFT_BOUNDARY), LEFT_BOUNDARY + half_view);
+        assert_eq!(split_view_camera_x(RIGHT_BOUNDARY), RIGHT_BOUNDARY - half_view);
+    }
+}
@@ -0,0 +1,38 @@
+pub mod components;
+pub mod constants;
+pub mod helpers;
+pub mod systems;
+
+use bevy::prelude::*;
+use bevy::window::WindowResized;
+use crate::car::systems::move_car;
+use crate::constants::GameState;
+use crate::settings::components::GameSettings;
+use components::SplitScreenCamera;
+use systems::{follow_split_screen_cameras, sync_main_camera_layers, sync_split_screen_views};
+
+pub struct SplitScreenPlugin;
+
+impl Plugin for SplitScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            follow_split_screen_cameras
+                .after(move_car)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (
+                sync_split_screen_views.run_if(
+                    resource_changed::<GameSettings>
+                        .or(on_message::<WindowResized>)
+                        .or(any_match_filter::<Added<SplitScreenCamera>>),
+                ),
+                // Runs in every state, so the main camera draws the world again once the
+                // split views are despawned with the race
+                sync_main_camera_layers,
+            ),
+        );
+    }
+}
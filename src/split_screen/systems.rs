@@ -0,0 +1,115 @@
+use bevy::camera::visibility::RenderLayers;
+use bevy::camera::{CameraOutputMode, ScalingMode, Viewport};
+use bevy::core_pipeline::tonemapping::{DebandDither, Tonemapping};
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+
+use crate::car::components::{Car, PlayerId};
+use crate::constants::{MainCamera, LETTERBOX_COLOR};
+use crate::settings::components::GameSettings;
+use crate::split_screen::components::SplitScreenCamera;
+use crate::split_screen::constants::{SPLIT_VIEW_HEIGHT, SPLIT_VIEW_WIDTH};
+use crate::split_screen::helpers::{
+    split_view_camera_layers, split_view_camera_x, split_view_order, split_viewport,
+};
+use crate::start_menu::components::GameEntity;
+use crate::styles::theme::camera_bloom;
+use crate::utils::letterbox_viewport;
+
+/// Spawns the camera drawing the player's half of the window in a two-player race,
+/// set up like the main camera. Its viewport is set by `sync_split_screen_views`.
+pub fn spawn_split_screen_camera(
+    commands: &mut Commands,
+    player: PlayerId,
+    settings: &GameSettings,
+    bloom_intensity: f32,
+) -> Entity {
+    commands
+        .spawn((
+            Camera2d,
+            Camera {
+                order: split_view_order(player),
+                output_mode: CameraOutputMode::Write {
+                    blend_state: None,
+                    clear_color: ClearColorConfig::Custom(LETTERBOX_COLOR),
+                },
+                ..default()
+            },
+            SplitScreenCamera(player),
+            split_view_camera_layers(player),
+            settings.msaa(),
+            Tonemapping::TonyMcMapface,
+            camera_bloom(bloom_intensity),
+            DebandDither::Enabled,
+            GameEntity,
+        ))
+        .id()
+}
+
+/// Keeps each split view on its player's car, sliding sideways only
+/// (a view is as tall as the playfield)
+pub fn follow_split_screen_cameras(
+    car_query: Query<(&Transform, &PlayerId), With<Car>>,
+    mut camera_query: Query<(&mut Transform, &SplitScreenCamera), Without<Car>>,
+) {
+    for (mut camera_transform, view) in camera_query.iter_mut() {
+        let Some((car_transform, _)) = car_query.iter().find(|(_, player)| **player == view.0) else {
+            continue;
+        };
+        camera_transform.translation.x = split_view_camera_x(car_transform.translation.x);
+        camera_transform.translation.y = 0.0;
+    }
+}
+
+/// Splits the area the main camera draws in (see `sync_camera_letterbox`) between the
+/// split views, each showing half the playfield's width when letterboxed
+pub fn sync_split_screen_views(
+    settings: Res<GameSettings>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut camera_query: Query<(&SplitScreenCamera, &mut Camera, &mut Projection)>,
+) {
+    let area = if settings.letterbox {
+        letterbox_viewport(window.physical_size())
+    } else {
+        Viewport {
+            physical_size: window.physical_size().max(UVec2::ONE),
+            ..default()
+        }
+    };
+
+    for (view, mut camera, mut projection) in camera_query.iter_mut() {
+        camera.viewport = Some(split_viewport(&area, view.0));
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scaling_mode = if settings.letterbox {
+                ScalingMode::Fixed {
+                    width: SPLIT_VIEW_WIDTH,
+                    height: SPLIT_VIEW_HEIGHT,
+                }
+            } else {
+                ScalingMode::WindowSize
+            };
+        }
+    }
+}
+
+/// While split views are up, the main camera draws no world layer and doesn't clear
+/// behind itself, leaving only the UI on top of the views. Restored once they're gone.
+pub fn sync_main_camera_layers(
+    mut commands: Commands,
+    split_views: Query<(), With<SplitScreenCamera>>,
+    main_camera: Single<(Entity, &mut Camera, Has<RenderLayers>), With<MainCamera>>,
+) {
+    let (entity, mut camera, has_layers) = main_camera.into_inner();
+    let split = !split_views.is_empty();
+    if split == has_layers {
+        return;
+    }
+
+    if split {
+        commands.entity(entity).insert(RenderLayers::none());
+        camera.clear_color = ClearColorConfig::Custom(Color::NONE);
+    } else {
+        commands.entity(entity).remove::<RenderLayers>();
+        camera.clear_color = ClearColorConfig::Default;
+    }
+}
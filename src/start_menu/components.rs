@@ -4,14 +4,22 @@ use bevy::prelude::*;
 #[derive(Component)]
 pub struct OnMenuScreen;
 
-/// Marker component for gameplay entities that should be despawned when leaving the game
+/// Marker component for gameplay entities that should be despawned when leaving the game.
+/// Everything a race spawns carries it (HUD text included, see `spawn_hud_element`), as
+/// it's the only marker the cleanup on LevelComplete, LevelMenu and StartMenu looks for.
 #[derive(Component)]
 pub struct GameEntity;
 
 /// All actions that can be triggered from menu button clicks
 #[derive(Component)]
 pub enum MenuButtonAction {
+    /// Restarts the race the last session crashed during (only shown after a crash,
+    /// see `InterruptedRace`)
+    ResumeRace,
+    /// Loads the most recently played save (only shown when a save exists)
+    Continue,
     NewGame,
     LoadGame,
+    Settings,
     Quit,
 }
@@ -1,25 +1,61 @@
 use bevy::app::AppExit;
 use bevy::prelude::*;
 
-use crate::constants::GameState;
+use crate::constants::{CurrentLevel, GameState, PracticeMode, PreviousState, SeedOverride, TwoPlayerMode};
+use crate::save::{CurrentSave, InterruptedRace, SaveCache};
+use crate::sequence::components::SequenceState;
+use crate::settings::components::GameSettings;
 use crate::start_menu::components::{MenuButtonAction, OnMenuScreen};
-use crate::styles::colors::MENU_BACKGROUND_COLOR;
+use crate::styles::colors::{MENU_BACKGROUND_COLOR, SECONDARY_TEXT_COLOR};
 use crate::styles::menu::{
     column_centered, spawn_menu_container, spawn_standard_button, title_style,
+    SMALL_TEXT_FONT_SIZE,
 };
+use crate::utils::format_time;
 
 // ============================================================================
 // Menu Spawning
 // ============================================================================
 
-/// Spawns the main menu UI
-pub fn spawn_menu(mut commands: Commands) {
+/// Spawns the main menu UI. The Continue button is only shown when a save exists,
+/// and a race the last session crashed during is offered first, if its save is still there.
+pub fn spawn_menu(
+    mut commands: Commands,
+    mut save_cache: ResMut<SaveCache>,
+    interrupted: Res<InterruptedRace>,
+) {
+    let has_saves = !save_cache.saves().is_empty();
+    let resumable = interrupted.0.as_ref().and_then(|race| {
+        let save = save_cache.saves().iter().find(|save| save.file_stem() == race.save_file)?;
+        Some((race, save.player_name.clone()))
+    });
+
     spawn_menu_container(&mut commands, OnMenuScreen, MENU_BACKGROUND_COLOR)
         .with_children(|parent| {
             parent.spawn(column_centered()).with_children(|parent| {
                 parent.spawn((Text::new("Bevy Driver"), title_style()));
+                if let Some((race, player_name)) = resumable {
+                    parent.spawn((
+                        Text::new(format!(
+                            "The game closed {} into {}'s race",
+                            format_time(race.elapsed_secs),
+                            player_name
+                        )),
+                        TextFont {
+                            font_size: SMALL_TEXT_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(SECONDARY_TEXT_COLOR),
+                    ));
+                    let label = format!("Resume Level {}", race.level);
+                    spawn_standard_button(parent, &label, MenuButtonAction::ResumeRace);
+                }
+                if has_saves {
+                    spawn_standard_button(parent, "Continue", MenuButtonAction::Continue);
+                }
                 spawn_standard_button(parent, "New Game", MenuButtonAction::NewGame);
                 spawn_standard_button(parent, "Load Game", MenuButtonAction::LoadGame);
+                spawn_standard_button(parent, "Settings", MenuButtonAction::Settings);
                 spawn_standard_button(parent, "Quit", MenuButtonAction::Quit);
             });
         });
@@ -29,7 +65,7 @@ pub fn spawn_menu(mut commands: Commands) {
 // Button Actions
 // ============================================================================
 
-/// Handles menu button actions (NewGame, LoadGame, Quit)
+/// Handles menu button actions (ResumeRace, Continue, NewGame, LoadGame, Settings, Quit)
 pub fn menu_action(
     interaction_query: Query<
         (&Interaction, &MenuButtonAction),
@@ -37,12 +73,62 @@ pub fn menu_action(
     >,
     mut app_exit_writer: MessageWriter<AppExit>,
     mut game_state: ResMut<NextState<GameState>>,
+    (mut current_save, mut current_level, mut seed_override): (
+        ResMut<CurrentSave>,
+        ResMut<CurrentLevel>,
+        ResMut<SeedOverride>,
+    ),
+    mut previous_state: ResMut<PreviousState>,
+    (mut interrupted, mut save_cache, mut sequence): (
+        ResMut<InterruptedRace>,
+        ResMut<SaveCache>,
+        ResMut<SequenceState>,
+    ),
+    (mut practice, mut two_player, mut settings): (
+        ResMut<PracticeMode>,
+        ResMut<TwoPlayerMode>,
+        ResMut<GameSettings>,
+    ),
 ) {
     for (interaction, menu_button_action) in &interaction_query {
         if *interaction == Interaction::Pressed {
             match menu_button_action {
+                MenuButtonAction::ResumeRace => {
+                    // The race starts over on the same track, in the same mode and on the same
+                    // difficulty; the crash lost the car's progress. Practice runs are never marked.
+                    let Some(race) = interrupted.0.take() else {
+                        continue;
+                    };
+                    let save_data = save_cache
+                        .saves()
+                        .iter()
+                        .find(|save| save.file_stem() == race.save_file)
+                        .cloned();
+                    if let Some(save_data) = save_data {
+                        current_level.0 = race.level;
+                        seed_override.0 = race.seed.map(|seed| (race.level, seed));
+                        *sequence = race.sequence.unwrap_or_default();
+                        practice.0 = false;
+                        two_player.0 = race.two_player;
+                        settings.difficulty = race.difficulty;
+                        current_save.set(save_data);
+                        game_state.set(GameState::Playing);
+                    }
+                }
+                MenuButtonAction::Continue => {
+                    // Saves are sorted by last played, so the first is the most recent
+                    if let Some(save_data) = save_cache.saves().first().cloned() {
+                        current_level.0 = save_data.highest_level_unlocked;
+                        current_save.set(save_data);
+                        game_state.set(GameState::LevelMenu);
+                    }
+                }
                 MenuButtonAction::NewGame => game_state.set(GameState::NewGameNameEntry),
                 MenuButtonAction::LoadGame => game_state.set(GameState::LoadGameMenu),
+                MenuButtonAction::Settings => {
+                    previous_state.0 = GameState::StartMenu;
+                    game_state.set(GameState::Settings);
+                }
                 MenuButtonAction::Quit => { app_exit_writer.write(AppExit::Success); }
             }
         }
@@ -0,0 +1,323 @@
+//! Headless tests for the `GameState` flow between screens.
+//!
+//! Each test builds an `App` from `MinimalPlugins` and `StatesPlugin` (no window, renderer
+//! or input backend), adds the plugins under test, and drives them by pressing menu buttons
+//! (setting their `Interaction`) or keys (on the `ButtonInput` resource) between updates.
+//! A state set through `NextState` is applied at the start of the following update.
+//!
+//! Plugins whose setup builds meshes or render targets (gameplay, level menu) aren't added;
+//! their transition systems are registered on their own, the same way their plugin does.
+//! Systems that write the save go to a scratch directory in tests (see `save::io::data_dir`).
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+
+use crate::analysis::components::CurrentAnalysis;
+use crate::car::components::{CarClass, PlayerId};
+use crate::car::systems::spawn_car;
+use crate::constants::{
+    CurrentLevel, GameState, PracticeMode, PreviousState, ResumeFromPause, SeedOverride,
+    TwoPlayerMode,
+};
+use crate::game_plugin::RaceCleanupPlugin;
+use crate::hud::components::{
+    MultiplierText, NosBoostBarContainer, NosBoostBarFill, RaceState, TimerText,
+};
+use crate::hud::systems::{
+    check_race_finished, init_race_state, spawn_controls_hint, spawn_finish_blocked_text,
+    spawn_multiplier_ui, spawn_nos_boost_bar, spawn_nos_boost_bar_glow, spawn_speedometer_ui,
+    spawn_start_gantry, spawn_timer_ui, spawn_volume_overlay, spawn_wall_glow,
+};
+use crate::level_complete::components::LevelCompleteButtonAction;
+use crate::level_complete::LevelCompletePlugin;
+use crate::level_menu::minimap::MinimapLayers;
+use crate::pause_menu::components::PauseMenuButtonAction;
+use crate::pause_menu::PauseMenuPlugin;
+use crate::props::components::Score;
+use crate::props::systems::spawn_nos_powerup;
+use crate::replay::components::GhostRecorder;
+use crate::road::components::{Direction, PlayedTrackInfo};
+use crate::save::{
+    CurrentSave, InterruptedRace, SaveCache, SaveData, SaveResult, SaveStatus, ScratchDataDir,
+};
+use crate::sequence::components::SequenceState;
+use crate::settings::components::{Difficulty, FinishCondition, GameSettings, SettingsButtonAction};
+use crate::settings::systems::{settings_action, spawn_settings_menu};
+use crate::start_menu::components::{GameEntity, MenuButtonAction, OnMenuScreen};
+use crate::start_menu::StartMenuPlugin;
+use crate::styles::palette::Palette;
+
+// ============================================================================
+// Harness
+// ============================================================================
+
+/// Builds a headless app with the global resources `main` sets up
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .init_state::<GameState>()
+        .init_resource::<CurrentLevel>()
+        .init_resource::<CurrentSave>()
+        .init_resource::<ResumeFromPause>()
+        .init_resource::<PreviousState>()
+        .init_resource::<PracticeMode>()
+        .init_resource::<TwoPlayerMode>()
+        .init_resource::<SeedOverride>()
+        .init_resource::<GameSettings>()
+        .init_resource::<GhostRecorder>()
+        .init_resource::<CurrentAnalysis>()
+        .init_resource::<Score>()
+        .init_resource::<PlayedTrackInfo>()
+        .init_resource::<Palette>()
+        .init_resource::<SequenceState>()
+        .init_resource::<SaveStatus>()
+        .init_resource::<SaveCache>()
+        .init_resource::<InterruptedRace>()
+        .init_resource::<MinimapLayers>()
+        .init_resource::<ButtonInput<KeyCode>>()
+        .add_message::<SaveResult>();
+    app
+}
+
+fn current_state(app: &App) -> GameState {
+    *app.world().resource::<State<GameState>>().get()
+}
+
+/// Requests a state and runs an update so it's entered (and its OnEnter systems run)
+fn enter_state(app: &mut App, state: GameState) {
+    app.world_mut().resource_mut::<NextState<GameState>>().set(state);
+    app.update();
+}
+
+/// Presses the first spawned button whose action matches, as a click would
+fn press_button<A: Component>(app: &mut App, matches: impl Fn(&A) -> bool) {
+    let world = app.world_mut();
+    let mut query = world.query::<(&A, &mut Interaction)>();
+    for (action, mut interaction) in query.iter_mut(world) {
+        if matches(action) {
+            *interaction = Interaction::Pressed;
+            return;
+        }
+    }
+    panic!("no matching button was spawned");
+}
+
+/// Taps a key for one update, then runs another update to apply any state change
+fn tap_key(app: &mut App, key: KeyCode) {
+    app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(key);
+    app.update();
+    let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keyboard.release(key);
+    keyboard.clear();
+    app.update();
+}
+
+/// Number of entities in the world
+fn entity_count(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world.query::<Entity>().iter(world).count()
+}
+
+/// Spawns a race's car, HUD and a powerup with the gameplay spawn functions.
+/// (The road needs mesh assets, so it's left out.)
+fn spawn_race(app: &mut App, camera: Entity) {
+    let world = app.world_mut();
+    let palette = Palette::default();
+    let mut commands = world.commands();
+    let stats = CarClass::default().stats();
+    spawn_car(&mut commands, Vec2::ZERO, 0.0, PlayerId::One, stats, Color::WHITE);
+    spawn_speedometer_ui(&mut commands, PlayerId::One);
+    spawn_nos_powerup(&mut commands, Vec2::new(0.0, 100.0));
+    spawn_timer_ui(&mut commands);
+    spawn_multiplier_ui(&mut commands, &palette, 1.0);
+    spawn_nos_boost_bar(&mut commands, PlayerId::One, None);
+    spawn_nos_boost_bar_glow(&mut commands, PlayerId::One, camera, None);
+    spawn_volume_overlay(&mut commands);
+    spawn_wall_glow(&mut commands);
+    spawn_finish_blocked_text(&mut commands, Vec2::ZERO, &palette);
+    spawn_start_gantry(&mut commands, Vec2::ZERO, Direction::Up);
+    spawn_controls_hint(&mut commands);
+    world.flush();
+}
+
+/// Number of entities with the given component
+fn count_with<T: Component>(app: &mut App) -> usize {
+    let world = app.world_mut();
+    world.query_filtered::<(), With<T>>().iter(world).count()
+}
+
+/// Plays level 1 with a fresh save and finishes the race in `time` seconds
+fn finish_level_one(app: &mut App, time: f32) {
+    app.add_plugins(LevelCompletePlugin)
+        .add_systems(Update, check_race_finished.run_if(in_state(GameState::Playing)));
+    app.world_mut()
+        .resource_mut::<CurrentSave>()
+        .set(SaveData::new("State Flow Test".to_string()));
+
+    // The gameplay setup resets the race state when a level starts
+    let world = app.world_mut();
+    init_race_state(&mut world.commands(), None, Difficulty::default(), FinishCondition::default());
+    world.flush();
+    enter_state(app, GameState::Playing);
+
+    let mut race_state = app.world_mut().resource_mut::<RaceState>();
+    race_state.start_race();
+    race_state.finish_race(PlayerId::One);
+    race_state.final_time = Some(time);
+
+    // One update notices the finish, the next enters LevelComplete
+    app.update();
+    app.update();
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[test]
+fn test_new_game_button_opens_name_entry() {
+    let _data_dir = ScratchDataDir::new();
+    let mut app = test_app();
+    app.add_plugins(StartMenuPlugin);
+    app.update();
+
+    press_button::<MenuButtonAction>(&mut app, |action| {
+        matches!(action, MenuButtonAction::NewGame)
+    });
+    app.update();
+    app.update();
+
+    assert_eq!(current_state(&app), GameState::NewGameNameEntry);
+    let mut menu_query = app.world_mut().query_filtered::<(), With<OnMenuScreen>>();
+    assert_eq!(menu_query.iter(app.world()).count(), 0);
+}
+
+#[test]
+fn test_escape_pauses_and_resumes() {
+    let _data_dir = ScratchDataDir::new();
+    let mut app = test_app();
+    app.add_plugins(PauseMenuPlugin);
+    enter_state(&mut app, GameState::Playing);
+
+    tap_key(&mut app, KeyCode::Escape);
+    assert_eq!(current_state(&app), GameState::Paused);
+
+    tap_key(&mut app, KeyCode::Escape);
+    assert_eq!(current_state(&app), GameState::Playing);
+    // Tells the gameplay setup to keep the paused race instead of rebuilding it
+    assert!(app.world().resource::<ResumeFromPause>().0);
+}
+
+#[test]
+fn test_options_from_pause_returns_to_paused_race() {
+    let _data_dir = ScratchDataDir::new();
+    let mut app = test_app();
+    // The settings plugin loads and writes the real settings file, so only its screen is added
+    app.add_plugins(PauseMenuPlugin)
+        .add_systems(OnEnter(GameState::Settings), spawn_settings_menu)
+        .add_systems(Update, settings_action.run_if(in_state(GameState::Settings)));
+    app.world_mut().spawn(GameEntity);
+    enter_state(&mut app, GameState::Playing);
+    tap_key(&mut app, KeyCode::Escape);
+
+    press_button::<PauseMenuButtonAction>(&mut app, |action| {
+        matches!(action, PauseMenuButtonAction::Options)
+    });
+    app.update();
+    app.update();
+    assert_eq!(current_state(&app), GameState::Settings);
+
+    press_button::<SettingsButtonAction>(&mut app, |action| {
+        matches!(action, SettingsButtonAction::Back)
+    });
+    app.update();
+    app.update();
+    assert_eq!(current_state(&app), GameState::Paused);
+
+    let mut race_query = app.world_mut().query_filtered::<(), With<GameEntity>>();
+    assert_eq!(race_query.iter(app.world()).count(), 1);
+}
+
+#[test]
+fn test_finishing_race_records_time_and_unlocks_next_level() {
+    let _data_dir = ScratchDataDir::new();
+    let mut app = test_app();
+    finish_level_one(&mut app, 42.0);
+
+    assert_eq!(current_state(&app), GameState::LevelComplete);
+    let save = app.world().resource::<CurrentSave>().get().unwrap();
+    assert_eq!(save.level_times.get(&1), Some(&42.0));
+    assert_eq!(save.highest_level_unlocked, 2);
+    assert_eq!(save.races_completed, 1);
+}
+
+#[test]
+fn test_next_level_button_starts_next_level() {
+    let _data_dir = ScratchDataDir::new();
+    let mut app = test_app();
+    finish_level_one(&mut app, 42.0);
+
+    press_button::<LevelCompleteButtonAction>(&mut app, |action| {
+        matches!(action, LevelCompleteButtonAction::NextLevel(_))
+    });
+    app.update();
+    app.update();
+
+    assert_eq!(current_state(&app), GameState::Playing);
+    assert_eq!(app.world().resource::<CurrentLevel>().0, 2);
+}
+
+#[test]
+fn test_finished_race_hud_is_despawned() {
+    let _data_dir = ScratchDataDir::new();
+    let mut app = test_app();
+    let camera = app.world_mut().spawn(Transform::default()).id();
+    spawn_race(&mut app, camera);
+    finish_level_one(&mut app, 42.0);
+    assert_eq!(count_with::<TimerText>(&mut app), 1);
+
+    press_button::<LevelCompleteButtonAction>(&mut app, |action| {
+        matches!(action, LevelCompleteButtonAction::MainMenu)
+    });
+    app.update();
+    app.update();
+
+    assert_eq!(current_state(&app), GameState::StartMenu);
+    assert_eq!(count_with::<TimerText>(&mut app), 0);
+    assert_eq!(count_with::<MultiplierText>(&mut app), 0);
+    assert_eq!(count_with::<NosBoostBarContainer>(&mut app), 0);
+    assert_eq!(count_with::<NosBoostBarFill>(&mut app), 0);
+}
+
+/// Pauses a race, abandons it with the matching pause menu button, and checks that
+/// arriving at `menu` despawned everything the race spawned
+fn assert_abandoned_race_cleaned_up(menu: GameState, matches: impl Fn(&PauseMenuButtonAction) -> bool) {
+    let _data_dir = ScratchDataDir::new();
+    let mut app = test_app();
+    // The cleanup `GamePlugin` registers on entering the menus
+    app.add_plugins((PauseMenuPlugin, RaceCleanupPlugin));
+    let camera = app.world_mut().spawn(Transform::default()).id();
+    enter_state(&mut app, GameState::Playing);
+
+    let before_race = entity_count(&mut app);
+    spawn_race(&mut app, camera);
+    tap_key(&mut app, KeyCode::Escape);
+    press_button::<PauseMenuButtonAction>(&mut app, matches);
+    app.update();
+    app.update();
+
+    assert_eq!(current_state(&app), menu);
+    // Counting every entity also catches children left behind by a despawned parent
+    assert_eq!(entity_count(&mut app), before_race);
+    assert!(app.world().get_entity(camera).is_ok());
+}
+
+#[test]
+fn test_abandoned_race_leaves_no_gameplay_entities() {
+    assert_abandoned_race_cleaned_up(GameState::LevelMenu, |action| {
+        matches!(action, PauseMenuButtonAction::LevelMenu)
+    });
+    assert_abandoned_race_cleaned_up(GameState::StartMenu, |action| {
+        matches!(action, PauseMenuButtonAction::MainMenu)
+    });
+}
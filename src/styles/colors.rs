@@ -10,6 +10,12 @@ pub const OVERLAY_BACKGROUND_COLOR: Color = Color::srgba(0.1, 0.1, 0.15, 0.85);
 pub const BUTTON_NORMAL_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
 pub const BUTTON_HOVERED_COLOR: Color = Color::srgb(0.25, 0.25, 0.25);
 pub const BUTTON_PRESSED_COLOR: Color = Color::srgb(0.35, 0.35, 0.35);
+/// Red button colors for destructive actions (delete, reset)
+pub const DANGER_BUTTON_COLOR: Color = Color::srgb(0.6, 0.2, 0.2);
+pub const DANGER_BUTTON_HOVERED_COLOR: Color = Color::srgb(0.8, 0.3, 0.3);
+pub const DANGER_BUTTON_PRESSED_COLOR: Color = Color::srgb(0.9, 0.2, 0.2);
+/// Fill rising inside a danger button while it's held to confirm
+pub const DANGER_HOLD_FILL_COLOR: Color = Color::srgb(0.4, 0.05, 0.05);
 
 // ============================================================================
 // Shared Text Colors
@@ -31,3 +37,5 @@ pub const TIMER_WAITING_COLOR: Color = Color::srgb(0.7, 0.7, 0.7);
 pub const LEVEL_TEXT_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
 pub const TIMER_RACING_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
 pub const TIMER_FINISHED_COLOR: Color = Color::srgb(0.2, 1.0, 0.2);
+pub const SLOW_MO_TEXT_COLOR: Color = Color::srgb(1.0, 0.4, 1.0);
+pub const COIN_TEXT_COLOR: Color = Color::srgb(1.0, 0.85, 0.2);
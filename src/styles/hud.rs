@@ -1,12 +1,21 @@
 use bevy::prelude::*;
 
+use crate::car::components::PlayerId;
 use crate::hud::constants::{
     CONTROLS_HINT_COLOR, CONTROLS_HINT_FONT_SIZE, CONTROLS_HINT_LINE_HEIGHT,
-    CONTROLS_HINT_PADDING, CONTROLS_HINT_TEXT_OFFSET, HUD_FONT_SIZE, HUD_PADDING,
+    CONTROLS_HINT_PADDING, CONTROLS_HINT_TEXT_OFFSET, COIN_FONT_SIZE_RATIO, COIN_TOP_SPACING,
+    FINISH_BLOCKED_FONT_SIZE, HUD_FONT_SIZE,
+    HUD_PADDING, LIVE_MINIMAP_BG_COLOR, LIVE_MINIMAP_BOTTOM, LIVE_MINIMAP_MARKER_COLOR,
+    LIVE_MINIMAP_MARKER_SIZE, LIVE_MINIMAP_PADDING,
     MULTIPLIER_FONT_SIZE_RATIO, MULTIPLIER_TOP_SPACING, NOS_BAR_BG_COLOR, NOS_BAR_BORDER,
-    NOS_BAR_BORDER_COLOR, NOS_BAR_FILL_COLOR, NOS_BAR_HEIGHT, NOS_BAR_TOP, NOS_BAR_WIDTH,
+    NOS_BAR_BORDER_COLOR, NOS_BAR_FILL_COLOR, NOS_BAR_HEIGHT, NOS_BAR_TEXT_COLOR,
+    NOS_BAR_TEXT_FONT_SIZE, NOS_BAR_TOP, NOS_BAR_WIDTH,
+    SLOW_MO_FONT_SIZE_RATIO, SLOW_MO_TOP_SPACING, SPEEDOMETER_FONT_SIZE_RATIO,
+    VOLUME_BAR_BG_COLOR, VOLUME_BAR_BOTTOM, VOLUME_BAR_FILL_COLOR, VOLUME_BAR_HEIGHT,
+    VOLUME_BAR_WIDTH, VOLUME_TEXT_COLOR, VOLUME_TEXT_FONT_SIZE,
 };
 use crate::styles::colors::*;
+use crate::styles::palette::Palette;
 
 // ============================================================================
 // Style Builders
@@ -49,13 +58,13 @@ pub fn timer_style() -> (TextFont, TextColor, TextLayout, Node) {
 }
 
 /// Multiplier indicator text style (below timer, top-right corner)
-pub fn multiplier_style() -> (TextFont, TextColor, TextLayout, Node) {
+pub fn multiplier_style(palette: &Palette) -> (TextFont, TextColor, TextLayout, Node) {
     (
         TextFont {
             font_size: HUD_FONT_SIZE * MULTIPLIER_FONT_SIZE_RATIO,
             ..default()
         },
-        TextColor(WARNING_TEXT_COLOR),
+        TextColor(palette.warning_text),
         TextLayout::new_with_justify(Justify::Right),
         Node {
             position_type: PositionType::Absolute,
@@ -66,13 +75,85 @@ pub fn multiplier_style() -> (TextFont, TextColor, TextLayout, Node) {
     )
 }
 
+/// Coin counter text style (below the level text)
+pub fn coin_style() -> (TextFont, TextColor, TextLayout, Node) {
+    (
+        TextFont {
+            font_size: HUD_FONT_SIZE * COIN_FONT_SIZE_RATIO,
+            ..default()
+        },
+        TextColor(COIN_TEXT_COLOR),
+        TextLayout::new_with_justify(Justify::Left),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(HUD_PADDING + HUD_FONT_SIZE + COIN_TOP_SPACING),
+            left: Val::Px(HUD_PADDING),
+            ..default()
+        },
+    )
+}
+
+/// Slow-mo indicator text style (centered below the NOS bar)
+pub fn slow_mo_style() -> (TextFont, TextColor, TextLayout, Node) {
+    (
+        TextFont {
+            font_size: HUD_FONT_SIZE * SLOW_MO_FONT_SIZE_RATIO,
+            ..default()
+        },
+        TextColor(SLOW_MO_TEXT_COLOR),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(NOS_BAR_TOP + NOS_BAR_HEIGHT + SLOW_MO_TOP_SPACING),
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            ..default()
+        },
+    )
+}
+
+/// Speedometer text style (bottom-right corner for player one, bottom-left for player two)
+pub fn speedometer_style(player: PlayerId) -> (TextFont, TextColor, TextLayout, Node) {
+    let (justify, left, right) = match player {
+        PlayerId::One => (Justify::Right, Val::Auto, Val::Px(HUD_PADDING)),
+        PlayerId::Two => (Justify::Left, Val::Px(HUD_PADDING), Val::Auto),
+    };
+    (
+        TextFont {
+            font_size: HUD_FONT_SIZE * SPEEDOMETER_FONT_SIZE_RATIO,
+            ..default()
+        },
+        TextColor(TIMER_RACING_COLOR),
+        TextLayout::new_with_justify(justify),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(HUD_PADDING),
+            left,
+            right,
+            ..default()
+        },
+    )
+}
+
+/// "Complete the whole track first!" message style (world-space text near the finish line)
+pub fn finish_blocked_style(palette: &Palette) -> (TextFont, TextColor, TextLayout) {
+    (
+        TextFont {
+            font_size: FINISH_BLOCKED_FONT_SIZE,
+            ..default()
+        },
+        TextColor(palette.warning_text),
+        TextLayout::new_with_justify(Justify::Center),
+    )
+}
+
 /// Returns the timer color based on race status
-pub fn timer_color(status: &crate::hud::components::RaceStatus) -> TextColor {
+pub fn timer_color(status: &crate::hud::components::RaceStatus, palette: &Palette) -> TextColor {
     use crate::hud::components::RaceStatus;
     match status {
         RaceStatus::WaitingToStart => TextColor(TIMER_WAITING_COLOR),
         RaceStatus::Racing => TextColor(TIMER_RACING_COLOR),
-        RaceStatus::Finished => TextColor(TIMER_FINISHED_COLOR),
+        RaceStatus::Finished => TextColor(palette.timer_finished),
     }
 }
 
@@ -152,3 +233,142 @@ pub fn nos_bar_fill_style() -> Node {
 pub fn nos_bar_fill_color() -> BackgroundColor {
     BackgroundColor(NOS_BAR_FILL_COLOR)
 }
+
+/// Remaining-seconds label centered over the NOS bar (overlays the fill)
+pub fn nos_bar_text_style() -> (TextFont, TextColor, TextLayout, Node) {
+    (
+        TextFont {
+            font_size: NOS_BAR_TEXT_FONT_SIZE,
+            ..default()
+        },
+        TextColor(NOS_BAR_TEXT_COLOR),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            ..default()
+        },
+    )
+}
+
+// ============================================================================
+// Volume Overlay Style
+// ============================================================================
+
+/// Container node for the volume bar (centered near the bottom)
+pub fn volume_bar_container_style() -> (Node, BackgroundColor) {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(VOLUME_BAR_BOTTOM),
+            left: Val::Percent(50.0),
+            margin: UiRect::left(Val::Px(-VOLUME_BAR_WIDTH / 2.0)),
+            width: Val::Px(VOLUME_BAR_WIDTH),
+            height: Val::Px(VOLUME_BAR_HEIGHT),
+            ..default()
+        },
+        BackgroundColor(VOLUME_BAR_BG_COLOR),
+    )
+}
+
+/// Fill node for the volume bar (width follows the volume)
+pub fn volume_bar_fill_style() -> (Node, BackgroundColor) {
+    (
+        Node {
+            width: Val::Percent(0.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(VOLUME_BAR_FILL_COLOR),
+    )
+}
+
+/// Volume label centered over the bar
+pub fn volume_text_style() -> (TextFont, TextColor, TextLayout, Node) {
+    (
+        TextFont {
+            font_size: VOLUME_TEXT_FONT_SIZE,
+            ..default()
+        },
+        TextColor(VOLUME_TEXT_COLOR),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            right: Val::Px(0.0),
+            ..default()
+        },
+    )
+}
+
+// ============================================================================
+// Live Minimap Style
+// ============================================================================
+
+/// Container node for the live minimap (bottom-right, above the speedometer)
+/// The track is clipped to the container, which matters once it turns with the car
+pub fn live_minimap_container_style(track_size: Vec2) -> (Node, BackgroundColor) {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(LIVE_MINIMAP_BOTTOM),
+            right: Val::Px(HUD_PADDING),
+            width: Val::Px(track_size.x + LIVE_MINIMAP_PADDING * 2.0),
+            height: Val::Px(track_size.y + LIVE_MINIMAP_PADDING * 2.0),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BackgroundColor(LIVE_MINIMAP_BG_COLOR),
+    )
+}
+
+/// Node filling the live minimap container, turned around its center to keep the car heading up
+pub fn live_minimap_pivot_style() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        ..default()
+    }
+}
+
+/// Node holding the track's cells, inside the container's padding
+pub fn live_minimap_track_style(track_size: Vec2) -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(LIVE_MINIMAP_PADDING),
+        top: Val::Px(LIVE_MINIMAP_PADDING),
+        width: Val::Px(track_size.x),
+        height: Val::Px(track_size.y),
+        ..default()
+    }
+}
+
+/// Node for one segment's cell on the live minimap, positioned from the track's top-left
+pub fn live_minimap_cell_style(top_left: Vec2, size: Vec2) -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(top_left.x),
+        top: Val::Px(top_left.y),
+        width: Val::Px(size.x),
+        height: Val::Px(size.y),
+        ..default()
+    }
+}
+
+/// Car marker on the live minimap, centered on the track's top-left corner
+/// until it's moved to the car
+pub fn live_minimap_marker_style() -> (Node, BackgroundColor) {
+    (
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(LIVE_MINIMAP_PADDING - LIVE_MINIMAP_MARKER_SIZE.x / 2.0),
+            top: Val::Px(LIVE_MINIMAP_PADDING - LIVE_MINIMAP_MARKER_SIZE.y / 2.0),
+            width: Val::Px(LIVE_MINIMAP_MARKER_SIZE.x),
+            height: Val::Px(LIVE_MINIMAP_MARKER_SIZE.y),
+            ..default()
+        },
+        BackgroundColor(LIVE_MINIMAP_MARKER_COLOR),
+    )
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
 
 use crate::styles::colors::*;
 
@@ -21,6 +22,14 @@ impl ButtonColors {
     }
 }
 
+/// Marker for the notice overlay spawned by `spawn_notice_overlay`
+#[derive(Component)]
+pub struct NoticeOverlay;
+
+/// Marker for the notice overlay's "OK" button
+#[derive(Component)]
+pub struct DismissNoticeButton;
+
 /// Standard button interaction system - handles hover and press visual feedback.
 /// Supports optional ButtonColors component for custom colors per button.
 /// Use this for all menus instead of duplicating the system per module.
@@ -31,17 +40,27 @@ pub fn standard_button_system(
     >,
 ) {
     for (interaction, mut background_color, custom_colors) in &mut interaction_query {
-        let (normal, hovered, pressed) = if let Some(colors) = custom_colors {
-            (colors.normal, colors.hovered, colors.pressed)
-        } else {
-            (BUTTON_NORMAL_COLOR, BUTTON_HOVERED_COLOR, BUTTON_PRESSED_COLOR)
-        };
-
-        *background_color = match *interaction {
-            Interaction::Pressed => pressed.into(),
-            Interaction::Hovered => hovered.into(),
-            Interaction::None => normal.into(),
-        };
+        *background_color = button_color(*interaction, custom_colors).into();
+    }
+}
+
+/// Returns the (normal, hovered, pressed) colors for a button,
+/// using its ButtonColors override if present.
+pub fn button_colors(custom_colors: Option<&ButtonColors>) -> (Color, Color, Color) {
+    if let Some(colors) = custom_colors {
+        (colors.normal, colors.hovered, colors.pressed)
+    } else {
+        (BUTTON_NORMAL_COLOR, BUTTON_HOVERED_COLOR, BUTTON_PRESSED_COLOR)
+    }
+}
+
+/// Returns the background color a button should have for the given interaction state.
+pub fn button_color(interaction: Interaction, custom_colors: Option<&ButtonColors>) -> Color {
+    let (normal, hovered, pressed) = button_colors(custom_colors);
+    match interaction {
+        Interaction::Pressed => pressed,
+        Interaction::Hovered => hovered,
+        Interaction::None => normal,
     }
 }
 
@@ -57,6 +76,8 @@ pub const TITLE_FONT_SIZE: f32 = 60.0;
 pub const TITLE_MARGIN_BOTTOM: f32 = 50.0;
 pub const BUTTON_FONT_SIZE: f32 = 33.0;
 pub const PANEL_PADDING: f32 = 50.0;
+pub const CONFIRM_BUTTON_WIDTH: f32 = 150.0;
+pub const CONFIRM_MESSAGE_FONT_SIZE: f32 = 32.0;
 
 // ============================================================================
 // Shared Font Sizes
@@ -119,6 +140,15 @@ pub fn button_node(button_width: f32) -> Node {
     }
 }
 
+/// Horizontal row of buttons, centered
+pub fn button_row() -> Node {
+    Node {
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::Center,
+        ..default()
+    }
+}
+
 
 // ============================================================================
 // Text Style Builders
@@ -172,6 +202,128 @@ pub fn no_saves_message_bundle() -> impl Bundle {
     )
 }
 
+/// Spawns the full-screen, input-blocking root shared by the overlays below
+fn spawn_overlay_root<'a, M: Bundle>(
+    commands: &'a mut Commands,
+    overlay_marker: M,
+) -> EntityCommands<'a> {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            flex_direction: FlexDirection::Column,
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            ..default()
+        },
+        BackgroundColor(OVERLAY_BACKGROUND_COLOR),
+        overlay_marker,
+        GlobalZIndex(100),
+        FocusPolicy::Block,
+    ))
+}
+
+/// Creates the message text shown above an overlay's buttons
+fn overlay_message(message: &str) -> impl Bundle {
+    (
+        Text::new(message),
+        TextFont {
+            font_size: CONFIRM_MESSAGE_FONT_SIZE,
+            ..default()
+        },
+        TextColor(MENU_TEXT_COLOR),
+        TextLayout::new_with_justify(Justify::Center),
+        Node {
+            margin: UiRect::bottom(Val::Px(LARGE_MARGIN)),
+            ..default()
+        },
+    )
+}
+
+/// Spawns a full-screen confirmation overlay with a message and confirm/cancel buttons.
+/// The confirm button uses the red danger colors. The overlay blocks input to the
+/// menu underneath; despawn entities with `overlay_marker` (one marker component, or a tuple
+/// of them) to close it.
+pub fn spawn_confirmation_overlay<M: Bundle, A: Component>(
+    commands: &mut Commands,
+    overlay_marker: M,
+    message: &str,
+    confirm_label: &str,
+    confirm_action: A,
+    cancel_action: A,
+) {
+    spawn_overlay_root(commands, overlay_marker).with_children(|parent| {
+        parent.spawn(overlay_message(message));
+
+        parent.spawn(button_row()).with_children(|buttons| {
+            buttons
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(CONFIRM_BUTTON_WIDTH),
+                        height: Val::Px(BUTTON_HEIGHT),
+                        margin: UiRect::right(Val::Px(STANDARD_MARGIN)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(DANGER_BUTTON_COLOR),
+                    ButtonColors::new(
+                        DANGER_BUTTON_COLOR,
+                        DANGER_BUTTON_HOVERED_COLOR,
+                        DANGER_BUTTON_PRESSED_COLOR,
+                    ),
+                    confirm_action,
+                ))
+                .with_children(|btn| {
+                    btn.spawn((Text::new(confirm_label), button_text_style()));
+                });
+
+            buttons
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(CONFIRM_BUTTON_WIDTH),
+                        height: Val::Px(BUTTON_HEIGHT),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(BUTTON_NORMAL_COLOR),
+                    cancel_action,
+                ))
+                .with_children(|btn| {
+                    btn.spawn((Text::new("Cancel"), button_text_style()));
+                });
+        });
+    });
+}
+
+/// Spawns a full-screen notice overlay with a message and a single "OK" button.
+/// Used to report errors; menus showing it register `dismiss_notice_system`.
+pub fn spawn_notice_overlay(commands: &mut Commands, message: &str) {
+    spawn_overlay_root(commands, NoticeOverlay).with_children(|parent| {
+        parent.spawn(overlay_message(message));
+        spawn_button_with_width(parent, "OK", DismissNoticeButton, CONFIRM_BUTTON_WIDTH);
+    });
+}
+
+/// Closes the notice overlay when its "OK" button is pressed
+pub fn dismiss_notice_system(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<DismissNoticeButton>)>,
+    overlay_query: Query<Entity, With<NoticeOverlay>>,
+) {
+    if interaction_query.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        for entity in &overlay_query {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
 // ============================================================================
 // Generic Menu Builders
 // ============================================================================
@@ -1,3 +1,5 @@
 pub mod colors;
 pub mod hud;
 pub mod menu;
+pub mod palette;
+pub mod theme;
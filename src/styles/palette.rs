@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+
+use crate::road::constants::{MISSED_EDGE_COLOR, START_LINE_COLOR};
+use crate::settings::components::ColorblindMode;
+use crate::styles::colors::{
+    ERROR_TEXT_COLOR, SUCCESS_TEXT_COLOR, TIMER_FINISHED_COLOR, WARNING_TEXT_COLOR,
+};
+
+// ============================================================================
+// Status Color Palette
+// ============================================================================
+
+/// Resource holding the colors that carry meaning (success/failure, missed segments).
+/// Read at spawn/update time instead of the color constants so colorblind modes can
+/// swap red/green distinctions for ones that stay distinguishable.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct Palette {
+    /// Positive text (completed levels, new best time)
+    pub success_text: Color,
+    /// Error text (invalid runs, name entry errors)
+    pub error_text: Color,
+    /// HUD warnings (off-road multiplier, finish blocked)
+    pub warning_text: Color,
+    /// Timer color once the race is finished
+    pub timer_finished: Color,
+    /// Emissive color for segments missed on a too-early finish attempt
+    pub missed_edge: Color,
+    /// Start line color
+    pub start_line: Color,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::for_mode(ColorblindMode::default())
+    }
+}
+
+impl Palette {
+    /// Returns the palette for the given colorblind mode
+    pub fn for_mode(mode: ColorblindMode) -> Self {
+        match mode {
+            ColorblindMode::Off => Self {
+                success_text: SUCCESS_TEXT_COLOR,
+                error_text: ERROR_TEXT_COLOR,
+                warning_text: WARNING_TEXT_COLOR,
+                timer_finished: TIMER_FINISHED_COLOR,
+                missed_edge: MISSED_EDGE_COLOR,
+                start_line: START_LINE_COLOR,
+            },
+            // Green is weak: use blue for success and orange for failure
+            ColorblindMode::Deuteranopia => Self {
+                success_text: Color::srgb(0.3, 0.6, 1.0),
+                error_text: Color::srgb(1.0, 0.6, 0.1),
+                warning_text: Color::srgb(1.0, 0.55, 0.0),
+                timer_finished: Color::srgb(0.3, 0.6, 1.0),
+                missed_edge: Color::srgb(2.2, 1.1, 0.0),
+                start_line: Color::srgb(0.2, 0.5, 1.0),
+            },
+            // Red is weak and looks dark: use blue for success and bright yellow for failure
+            ColorblindMode::Protanopia => Self {
+                success_text: Color::srgb(0.35, 0.65, 1.0),
+                error_text: Color::srgb(1.0, 0.85, 0.2),
+                warning_text: Color::srgb(1.0, 0.8, 0.1),
+                timer_finished: Color::srgb(0.35, 0.65, 1.0),
+                missed_edge: Color::srgb(2.0, 1.8, 0.2),
+                start_line: Color::srgb(0.2, 0.5, 1.0),
+            },
+        }
+    }
+}
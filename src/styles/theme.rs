@@ -0,0 +1,195 @@
+use bevy::post_process::bloom::{Bloom, BloomCompositeMode, BloomPrefilter};
+use bevy::prelude::*;
+
+use crate::constants::{BLOOM_INTENSITY, GAME_BACKGROUND_COLOR};
+use crate::road::constants::{ROAD_SEGMENT_COLOR, UNVISITED_EDGE_COLOR, VISITED_EDGE_COLOR};
+
+// ============================================================================
+// Track Themes
+// ============================================================================
+
+/// Number of consecutive levels sharing a theme before moving on to the next one
+const LEVELS_PER_THEME: usize = 3;
+
+/// Colors of the track and the background behind it, and how strongly its edges bloom.
+/// Chosen per level, so the level menu previews match the track in gameplay.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    /// Road surface color
+    pub road: Color,
+    /// Background (clear) color behind the track
+    pub background: Color,
+    /// Road edges before their segment is visited
+    pub unvisited_edge: Color,
+    /// Emissive color for visited road edges (values > 1.0 for bloom glow)
+    pub visited_edge: Color,
+    /// Bloom intensity of the cameras showing the track
+    pub bloom_intensity: f32,
+}
+
+/// The themes cycled through by level, starting with the original night look
+const THEMES: [Theme; 3] = [
+    // Night
+    Theme {
+        road: ROAD_SEGMENT_COLOR,
+        background: GAME_BACKGROUND_COLOR,
+        unvisited_edge: UNVISITED_EDGE_COLOR,
+        visited_edge: VISITED_EDGE_COLOR,
+        bloom_intensity: BLOOM_INTENSITY,
+    },
+    // Desert
+    Theme {
+        road: Color::srgb(0.45, 0.36, 0.25),
+        background: Color::srgb(0.16, 0.1, 0.05),
+        unvisited_edge: Color::srgb(0.4, 0.32, 0.2),
+        visited_edge: Color::srgb(2.8, 1.6, 0.4),
+        // Warm daylight look, so the edges glow less
+        bloom_intensity: 0.35,
+    },
+    // Neon city
+    Theme {
+        road: Color::srgb(0.22, 0.2, 0.3),
+        background: Color::srgb(0.03, 0.02, 0.08),
+        unvisited_edge: Color::srgb(0.28, 0.22, 0.38),
+        visited_edge: Color::srgb(2.6, 0.5, 2.4),
+        bloom_intensity: 0.6,
+    },
+];
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::for_level(1)
+    }
+}
+
+impl Theme {
+    /// Returns the theme for a level: each theme covers `LEVELS_PER_THEME` levels, then they cycle
+    pub fn for_level(level: usize) -> Self {
+        THEMES[(level.saturating_sub(1) / LEVELS_PER_THEME) % THEMES.len()]
+    }
+
+    /// Returns the theme with its bloom and edge brightness scaled by the glow settings
+    pub fn with_glow(self, glow: &GlowSettings) -> Self {
+        Self {
+            visited_edge: glow.dim(self.visited_edge),
+            bloom_intensity: self.bloom_intensity * glow.bloom_scale,
+            ..self
+        }
+    }
+}
+
+/// Scales the part of each channel above 1.0, which is what bloom picks up.
+/// Channels in the normal range are kept so the edge color stays recognizable.
+fn dim_hdr_color(color: Color, scale: f32) -> Color {
+    let srgba = color.to_srgba();
+    let dim = |channel: f32| if channel > 1.0 { 1.0 + (channel - 1.0) * scale } else { channel };
+    Color::srgba(dim(srgba.red), dim(srgba.green), dim(srgba.blue), srgba.alpha)
+}
+
+/// Resource holding the theme of the level being played (or replayed), with glow applied
+#[derive(Resource, Default)]
+pub struct CurrentTheme(pub Theme);
+
+/// Switches to the given level's theme and returns it. The race's background follows it
+/// (see `sync_clear_color` in main.rs).
+pub fn apply_level_theme(commands: &mut Commands, level: usize, glow: &GlowSettings) -> Theme {
+    let theme = Theme::for_level(level).with_glow(glow);
+    commands.insert_resource(CurrentTheme(theme));
+    theme
+}
+
+// ============================================================================
+// Glow
+// ============================================================================
+
+/// Bloom, edge brightness and wall warning scales for a reduced glow setting
+const REDUCED_BLOOM_SCALE: f32 = 0.3;
+const REDUCED_EDGE_SCALE: f32 = 0.2;
+const REDUCED_WARNING_SCALE: f32 = 0.5;
+
+/// Resource scaling how strongly themes glow, read wherever a theme is applied or a sprite
+/// glows (see `Glowing`).
+/// Lowered by the reduced glow accessibility setting.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct GlowSettings {
+    /// Multiplier for the theme's bloom intensity
+    pub bloom_scale: f32,
+    /// Multiplier for how far edge and prop colors go past the normal (non-HDR) range
+    pub edge_scale: f32,
+    /// Multiplier for the wall warning glow's opacity
+    pub warning_scale: f32,
+}
+
+impl Default for GlowSettings {
+    fn default() -> Self {
+        Self::for_setting(false)
+    }
+}
+
+impl GlowSettings {
+    /// Returns the glow scales for the reduced glow setting
+    pub fn for_setting(reduced_glow: bool) -> Self {
+        if reduced_glow {
+            Self {
+                bloom_scale: REDUCED_BLOOM_SCALE,
+                edge_scale: REDUCED_EDGE_SCALE,
+                warning_scale: REDUCED_WARNING_SCALE,
+            }
+        } else {
+            Self { bloom_scale: 1.0, edge_scale: 1.0, warning_scale: 1.0 }
+        }
+    }
+
+    /// Returns a glowing color with the part bloom picks up scaled by `edge_scale`
+    pub fn dim(&self, color: Color) -> Color {
+        dim_hdr_color(color, self.edge_scale)
+    }
+}
+
+/// On a sprite that glows apart from the track (a prop, the NOS bar's outline): its color
+/// at full glow, dimmed by the glow settings (see `settings::systems::apply_glow_to_sprites`)
+#[derive(Component)]
+pub struct Glowing(pub Color);
+
+/// Bloom for a camera showing the track: only HDR pixels glow, in a tight localized halo
+pub fn camera_bloom(intensity: f32) -> Bloom {
+    Bloom {
+        intensity,
+        // Use prefilter to only bloom pixels above threshold (more localized)
+        prefilter: BloomPrefilter {
+            threshold: 1.1,           // Only bloom pixels brighter than 1.0 (HDR)
+            threshold_softness: 0.2,  // Soft transition
+        },
+        // Additive mode works better with prefilter for localized glow
+        composite_mode: BloomCompositeMode::Additive,
+        // Tighten the scatter for more localized glow
+        high_pass_frequency: 0.45,
+        ..default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_themes_cycle_by_level() {
+        // The first levels keep the original colors
+        assert_eq!(Theme::for_level(1), THEMES[0]);
+        assert_eq!(Theme::for_level(1).road, ROAD_SEGMENT_COLOR);
+        assert_eq!(Theme::for_level(LEVELS_PER_THEME), THEMES[0]);
+        assert_eq!(Theme::for_level(LEVELS_PER_THEME + 1), THEMES[1]);
+        assert_eq!(Theme::for_level(LEVELS_PER_THEME * THEMES.len() + 1), THEMES[0]);
+    }
+
+    #[test]
+    fn test_reduced_glow_dims_only_hdr_channels() {
+        let full = Theme::for_level(1);
+        let reduced = full.with_glow(&GlowSettings::for_setting(true));
+        assert!(reduced.bloom_intensity < full.bloom_intensity);
+        let (full_edge, reduced_edge) = (full.visited_edge.to_srgba(), reduced.visited_edge.to_srgba());
+        assert!(reduced_edge.blue < full_edge.blue && reduced_edge.blue > 1.0);
+        assert_eq!(reduced_edge.red, full_edge.red);
+        assert_eq!(reduced.road, full.road);
+    }
+}
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+use crate::road::components::Track;
+
+/// Marker component for entities that belong to the track preview screen
+#[derive(Component)]
+pub struct OnTrackPreviewScreen;
+
+/// All actions that can be triggered from the track preview buttons
+#[derive(Component)]
+pub enum TrackPreviewButtonAction {
+    /// Play the level on the previewed seed
+    Play,
+    /// Move on to the next seed that generates a track
+    Regenerate,
+    /// Return to the level menu
+    Back,
+}
+
+/// Marker for the image node showing the rendered track
+#[derive(Component)]
+pub struct TrackPreviewImage;
+
+/// Marker for the text listing the previewed track's stats
+#[derive(Component)]
+pub struct TrackPreviewStats;
+
+/// Resource holding the track shown on the preview screen and the seed it was generated from.
+/// Generated with the difficulty's powerup range, so it's the track that will be played.
+#[derive(Resource)]
+pub struct PreviewTrack {
+    pub seed: u64,
+    pub track: Track,
+}
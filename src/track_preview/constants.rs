@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+use crate::styles::menu::{SMALL_MARGIN, SMALL_TEXT_FONT_SIZE};
+
+// ============================================================================
+// Preview Settings
+// ============================================================================
+
+/// Size of the rendered track preview, in UI pixels (the window's aspect ratio)
+pub const TRACK_PREVIEW_SIZE: Vec2 = Vec2::new(520.0, 320.0);
+
+/// Number of new seeds tried by "Regenerate" before giving up (drawn from `GameRng`)
+pub const MAX_REGENERATE_ATTEMPTS: u64 = 10;
+
+/// Stars a track's difficulty rating is shown with, for the hardest tracks
+pub const DIFFICULTY_STARS: usize = 5;
+
+// ============================================================================
+// Layout
+// ============================================================================
+
+pub const STATS_FONT_SIZE: f32 = SMALL_TEXT_FONT_SIZE;
+pub const PREVIEW_MARGIN: f32 = SMALL_MARGIN;
@@ -0,0 +1,56 @@
+use crate::road::components::{RoadSegmentType, Track};
+use crate::road::track_generator::rate_track_difficulty;
+use crate::track_preview::constants::DIFFICULTY_STARS;
+
+/// Share of the track's grid cells that are corners (0.0 to 1.0), the same measure
+/// the generator's target difficulty aims for
+pub fn turn_density(layout: &[RoadSegmentType]) -> f32 {
+    let cells: usize = layout.iter().map(|segment| segment.length_in_cells()).sum();
+    let corners = layout
+        .iter()
+        .filter(|segment| matches!(segment, RoadSegmentType::CornerLeft | RoadSegmentType::CornerRight))
+        .count();
+    if cells == 0 { 0.0 } else { corners as f32 / cells as f32 }
+}
+
+/// The layout's difficulty rating (see `rate_track_difficulty`) as 1 to `DIFFICULTY_STARS`
+/// stars, drawn as `*` padded with `-` since the default font has no star glyph
+pub fn difficulty_stars(layout: &[RoadSegmentType]) -> String {
+    rating_stars(rate_track_difficulty(layout))
+}
+
+/// A difficulty rating from 0 to 1 as stars (see `difficulty_stars`)
+pub fn rating_stars(rating: f32) -> String {
+    let stars = 1 + (rating * (DIFFICULTY_STARS - 1) as f32).round() as usize;
+    format!("{}{}", "*".repeat(stars), "-".repeat(DIFFICULTY_STARS - stars))
+}
+
+/// Stats shown under the preview: seed, segment count, difficulty rating and powerups
+pub fn track_stats_text(seed: u64, track: &Track) -> String {
+    let density = turn_density(&track.layout);
+    format!(
+        "Seed {}  |  Segments: {}  |  Difficulty: {} ({:.0}% corners)  |  Powerups: {}",
+        seed,
+        track.layout.len(),
+        difficulty_stars(&track.layout),
+        density * 100.0,
+        track.prop_indices.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn_density_counts_cells() {
+        use RoadSegmentType::*;
+        // Four corners around 2 + 2 + 2 + 2 straight cells
+        let layout = [StraightRun(2), CornerRight, StraightRun(2), CornerRight,
+            StraightRun(2), CornerRight, StraightRun(2), CornerRight];
+        assert_eq!(turn_density(&layout), 4.0 / 12.0);
+        assert_eq!(turn_density(&[]), 0.0);
+        // Every track gets at least one star
+        assert_eq!(difficulty_stars(&[]), "*----");
+    }
+}
@@ -0,0 +1,47 @@
+pub mod components;
+pub mod constants;
+pub mod helpers;
+pub mod systems;
+
+use bevy::prelude::*;
+use crate::constants::GameState;
+use crate::level_menu::minimap::cleanup_minimap_rendering;
+use crate::utils::despawn_all;
+use components::{OnTrackPreviewScreen, PreviewTrack};
+use systems::{
+    clear_seed_override, remove_preview_track, render_track_preview, spawn_track_preview,
+    track_preview_action,
+};
+use crate::styles::menu::{dismiss_notice_system, standard_button_system, NoticeOverlay};
+
+/// Preview of a generated level's track before playing it, with the option to
+/// move on to another seed
+pub struct TrackPreviewPlugin;
+
+impl Plugin for TrackPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(GameState::TrackPreview), spawn_track_preview)
+            .add_systems(
+                OnExit(GameState::TrackPreview),
+                (
+                    despawn_all::<OnTrackPreviewScreen>,
+                    despawn_all::<NoticeOverlay>,
+                    cleanup_minimap_rendering,
+                    remove_preview_track,
+                ),
+            )
+            .add_systems(OnEnter(GameState::LevelMenu), clear_seed_override)
+            .add_systems(
+                Update,
+                (
+                    standard_button_system,
+                    track_preview_action.run_if(resource_exists::<PreviewTrack>),
+                    render_track_preview.run_if(resource_exists_and_changed::<PreviewTrack>),
+                    dismiss_notice_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::TrackPreview)),
+            );
+    }
+}
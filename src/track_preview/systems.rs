@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+
+use crate::constants::{CurrentLevel, GameRng, GameState, SeedOverride};
+use crate::level_menu::minimap::{
+    calculate_minimap_transform, create_minimap_image, spawn_minimap_camera, spawn_minimap_frame,
+    spawn_minimap_track, MinimapCamera, MinimapLayers, MinimapSceneEntity,
+};
+use crate::road::tracks::{load_track, TrackSource};
+use crate::settings::components::GameSettings;
+use crate::styles::colors::{MENU_BACKGROUND_COLOR, SECONDARY_TEXT_COLOR};
+use crate::styles::menu::{
+    button_row, column_centered, spawn_menu_container, spawn_notice_overlay, spawn_standard_button,
+    title_style,
+};
+use crate::styles::theme::{GlowSettings, Theme};
+use crate::track_preview::components::{
+    OnTrackPreviewScreen, PreviewTrack, TrackPreviewButtonAction, TrackPreviewImage, TrackPreviewStats,
+};
+use crate::track_preview::constants::*;
+use crate::track_preview::helpers::track_stats_text;
+
+// ============================================================================
+// Preview Screen Spawning
+// ============================================================================
+
+/// Generates the current level's track and spawns the preview screen around it.
+/// The track itself is drawn by `render_track_preview`.
+pub fn spawn_track_preview(
+    mut commands: Commands,
+    current_level: Res<CurrentLevel>,
+    seed_override: Res<SeedOverride>,
+    settings: Res<GameSettings>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    // The fixed tracks (up to `BUILTIN_LEVEL_COUNT`) have no seed to pick
+    let Some(seed) = seed_override.seed_for(current_level.0) else {
+        game_state.set(GameState::Playing);
+        return;
+    };
+    let (min_props, max_props) = settings.difficulty.powerup_count_range();
+    let Some((track, _)) = load_track(TrackSource::seeded(seed, min_props, max_props)) else {
+        error!("Could not generate a track for level {}, returning to level menu", current_level.0);
+        game_state.set(GameState::LevelMenu);
+        return;
+    };
+    commands.insert_resource(PreviewTrack { seed, track });
+
+    spawn_menu_container(&mut commands, OnTrackPreviewScreen, MENU_BACKGROUND_COLOR)
+        .with_children(|parent| {
+            parent.spawn(column_centered()).with_children(|parent| {
+                parent.spawn((Text::new(format!("Level {}", current_level.0)), title_style()));
+                parent.spawn((
+                    ImageNode::default(),
+                    Node {
+                        width: Val::Px(TRACK_PREVIEW_SIZE.x),
+                        height: Val::Px(TRACK_PREVIEW_SIZE.y),
+                        margin: UiRect::bottom(Val::Px(PREVIEW_MARGIN)),
+                        ..default()
+                    },
+                    TrackPreviewImage,
+                ));
+                parent.spawn((
+                    Text::default(),
+                    TextFont {
+                        font_size: STATS_FONT_SIZE,
+                        ..default()
+                    },
+                    TextColor(SECONDARY_TEXT_COLOR),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(PREVIEW_MARGIN)),
+                        ..default()
+                    },
+                    TrackPreviewStats,
+                ));
+                parent.spawn(button_row()).with_children(|parent| {
+                    spawn_standard_button(parent, "Play", TrackPreviewButtonAction::Play);
+                    spawn_standard_button(parent, "Regenerate", TrackPreviewButtonAction::Regenerate);
+                    spawn_standard_button(parent, "Back", TrackPreviewButtonAction::Back);
+                });
+            });
+        });
+}
+
+/// Query for the camera and scene of the previous render
+type PreviewSceneQuery<'w, 's> =
+    Query<'w, 's, (Entity, Option<&'static MinimapCamera>), Or<(With<MinimapCamera>, With<MinimapSceneEntity>)>>;
+
+/// Draws the previewed track with the level card minimap rendering, at the preview's size,
+/// and updates its stats. Runs again whenever another seed is picked, replacing the old scene.
+/// The camera keeps rendering while the screen is open.
+pub fn render_track_preview(
+    mut commands: Commands,
+    (mut images, mut layers): (ResMut<Assets<Image>>, ResMut<MinimapLayers>),
+    (mut meshes, mut materials): (ResMut<Assets<Mesh>>, ResMut<Assets<ColorMaterial>>),
+    (preview, current_level, glow): (Res<PreviewTrack>, Res<CurrentLevel>, Res<GlowSettings>),
+    mut image_node: Single<&mut ImageNode, With<TrackPreviewImage>>,
+    mut stats_text: Single<&mut Text, With<TrackPreviewStats>>,
+    old_scene: PreviewSceneQuery,
+) {
+    for (entity, camera) in old_scene.iter() {
+        commands.entity(entity).despawn();
+        if let Some(camera) = camera {
+            layers.give_back(camera.layer);
+        }
+    }
+    let Some(layer) = layers.take() else {
+        error!("No free render layer for the track preview");
+        return;
+    };
+
+    let level = current_level.0;
+    let theme = Theme::for_level(level).with_glow(&glow);
+    let image_handle = create_minimap_image(&mut images, TRACK_PREVIEW_SIZE);
+    let (scale, center) = calculate_minimap_transform(&preview.track, TRACK_PREVIEW_SIZE);
+
+    let camera = MinimapCamera { level, layer };
+    spawn_minimap_camera(&mut commands, image_handle.clone(), camera, &theme, center, scale);
+    spawn_minimap_track(&mut commands, &mut meshes, &mut materials, &preview.track, layer, &theme);
+    spawn_minimap_frame(&mut commands, layer, &theme, center, scale, TRACK_PREVIEW_SIZE);
+
+    // The previous image is freed once the node and its camera drop their handles
+    image_node.image = image_handle;
+    stats_text.0 = track_stats_text(preview.seed, &preview.track);
+}
+
+/// Removes the previewed track when leaving the screen
+pub fn remove_preview_track(mut commands: Commands) {
+    commands.remove_resource::<PreviewTrack>();
+}
+
+/// Drops any seed picked on the preview, so levels go back to their own tracks
+pub fn clear_seed_override(mut seed_override: ResMut<SeedOverride>) {
+    seed_override.0 = None;
+}
+
+// ============================================================================
+// Button Actions
+// ============================================================================
+
+/// Handles track preview button actions
+pub fn track_preview_action(
+    interaction_query: Query<
+        (&Interaction, &TrackPreviewButtonAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut commands: Commands,
+    mut preview: ResMut<PreviewTrack>,
+    current_level: Res<CurrentLevel>,
+    (mut seed_override, mut game_rng): (ResMut<SeedOverride>, ResMut<GameRng>),
+    settings: Res<GameSettings>,
+    mut game_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, button_action) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            match button_action {
+                TrackPreviewButtonAction::Play => {
+                    seed_override.0 = Some((current_level.0, preview.seed));
+                    game_state.set(GameState::Playing);
+                }
+                TrackPreviewButtonAction::Regenerate => {
+                    // Seeds that can't form a loop are skipped
+                    let (min_props, max_props) = settings.difficulty.powerup_count_range();
+                    let next = (1..=MAX_REGENERATE_ATTEMPTS)
+                        .map(|_| game_rng.track_seed())
+                        .find_map(|seed| {
+                            load_track(TrackSource::seeded(seed, min_props, max_props))
+                                .map(|(track, _)| PreviewTrack { seed, track })
+                        });
+                    match next {
+                        Some(next) => *preview = next,
+                        None => spawn_notice_overlay(
+                            &mut commands,
+                            "No track could be generated from the new seeds.",
+                        ),
+                    }
+                }
+                TrackPreviewButtonAction::Back => {
+                    game_state.set(GameState::LevelMenu);
+                }
+            }
+        }
+    }
+}
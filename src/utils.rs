@@ -1,4 +1,8 @@
+use bevy::camera::Viewport;
 use bevy::prelude::*;
+use crate::constants::{TwoPlayerMode, WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::hud::components::HudElement;
+use crate::sequence::components::SequenceState;
 use crate::start_menu::components::GameEntity;
 
 /// Generic despawn system that removes all entities with the specified marker component.
@@ -14,19 +18,82 @@ pub fn despawn_all<T: Component>(mut commands: Commands, query: Query<Entity, Wi
     }
 }
 
-/// Generic helper to spawn a HUD text element
+/// Run condition: returns true for local two-player races.
+/// Systems tracking a single car (checkpoints, ghost recording) are skipped in them.
+pub fn is_two_player(two_player: Res<TwoPlayerMode>) -> bool {
+    two_player.0
+}
+
+/// Run condition: returns true while a time trial sequence is running
+pub fn in_sequence(sequence: Res<SequenceState>) -> bool {
+    sequence.is_active()
+}
+
+/// Formats time in seconds to MM:SS.ss format
+pub fn format_time(seconds: f32) -> String {
+    let mins = (seconds / 60.0).floor() as u32;
+    let secs = seconds % 60.0;
+    format!("{:02}:{:05.2}", mins, secs)
+}
+
+/// Generic helper to spawn a HUD text element, tagged `GameEntity` so it goes with the race
+/// and `HudElement` so the HUD toggle hides it. Returns the element's entity.
 pub fn spawn_hud_element<B: Bundle, M: Component>(
     commands: &mut Commands,
     text: String,
     style_bundle: B,
     marker: M,
     visibility: Visibility,
-) {
-    commands.spawn((
-        Text::new(text),
-        style_bundle,
-        visibility,
-        marker,
-        GameEntity,
-    ));
+) -> Entity {
+    commands
+        .spawn((
+            Text::new(text),
+            style_bundle,
+            visibility,
+            marker,
+            HudElement::default(),
+            GameEntity,
+        ))
+        .id()
+}
+
+/// The largest viewport with the playfield's `WINDOW_WIDTH` x `WINDOW_HEIGHT` aspect that fits
+/// a window of the given physical size, centered so bars of equal size fill either the sides
+/// (a wider window) or the top and bottom (a taller one)
+pub fn letterbox_viewport(window_size: UVec2) -> Viewport {
+    let aspect = WINDOW_WIDTH as f32 / WINDOW_HEIGHT as f32;
+    let width = window_size.x.min((window_size.y as f32 * aspect).round() as u32);
+    let height = window_size.y.min((window_size.x as f32 / aspect).round() as u32);
+    // A minimized window has no size, but a viewport needs at least a pixel
+    let size = UVec2::new(width, height).max(UVec2::ONE);
+    Viewport {
+        physical_position: window_size.saturating_sub(size) / 2,
+        physical_size: size,
+        ..default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letterbox_keeps_playfield_aspect() {
+        let aspect = WINDOW_WIDTH as f32 / WINDOW_HEIGHT as f32;
+        for window_size in [
+            UVec2::new(WINDOW_WIDTH, WINDOW_HEIGHT),
+            UVec2::new(1920, 1080),
+            UVec2::new(800, 1200),
+            UVec2::new(3440, 1440),
+        ] {
+            let viewport = letterbox_viewport(window_size);
+            let size = viewport.physical_size;
+            assert!((size.x as f32 / size.y as f32 - aspect).abs() < 0.01, "{}", window_size);
+            // Fills the window one way, centered between bars the other
+            let bars = window_size - size;
+            assert_eq!(viewport.physical_position, bars / 2);
+            assert!(bars.x == 0 || bars.y == 0);
+        }
+        assert_eq!(letterbox_viewport(UVec2::ZERO).physical_size, UVec2::ONE);
+    }
 }